@@ -24,6 +24,47 @@ fn asn1tools(c: &mut Criterion) {
     bench_encoding_rules!(ber, der, cer, uper);
 }
 
+fn integers(c: &mut Criterion) {
+    let counters: Vec<u32> = black_box((0..1000).map(|i| i * 7919).collect());
+    let integers: Vec<rasn::types::Integer> =
+        black_box(counters.iter().map(|&i| i.into()).collect());
+
+    macro_rules! bench_encoding_rules {
+        ($($rules : ident),+) => {{
+            $(
+                let data: Vec<u8> = black_box(rasn::$rules::encode(&counters).unwrap());
+                let integer_data: Vec<u8> = black_box(rasn::$rules::encode(&integers).unwrap());
+                let mut group = c.benchmark_group(concat!("integers/", stringify!($rules)));
+                group.bench_function("encode u32", |b| b.iter_with_large_drop(|| black_box(rasn::$rules::encode(&counters).unwrap())));
+                group.bench_function("encode Integer", |b| b.iter_with_large_drop(|| black_box(rasn::$rules::encode(&integers).unwrap())));
+                group.bench_function("decode u32", |b| b.iter_with_large_drop(|| black_box(rasn::$rules::decode::<Vec<u32>>(&data).unwrap())));
+                group.bench_function("decode Integer", |b| b.iter_with_large_drop(|| black_box(rasn::$rules::decode::<Vec<rasn::types::Integer>>(&integer_data).unwrap())));
+                group.finish();
+            )+
+        }}
+    }
+
+    bench_encoding_rules!(ber, uper);
+
+    let data = black_box(rasn::jer::encode(&counters).unwrap());
+    let mut group = c.benchmark_group("integers/jer");
+    group.bench_function("encode u32", |b| {
+        b.iter_with_large_drop(|| black_box(rasn::jer::encode(&counters).unwrap()))
+    });
+    group.bench_function("encode Integer", |b| {
+        b.iter_with_large_drop(|| black_box(rasn::jer::encode(&integers).unwrap()))
+    });
+    group.bench_function("decode u32", |b| {
+        b.iter_with_large_drop(|| black_box(rasn::jer::decode::<Vec<u32>>(&data).unwrap()))
+    });
+    group.bench_function("decode Integer", |b| {
+        b.iter_with_large_drop(|| {
+            black_box(rasn::jer::decode::<Vec<rasn::types::Integer>>(&data).unwrap())
+        })
+    });
+    group.finish();
+}
+
 fn x509(c: &mut Criterion) {
     use x509_parser::prelude::*;
 
@@ -38,5 +79,5 @@ fn x509(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(codec, x509, asn1tools);
+criterion_group!(codec, x509, asn1tools, integers);
 criterion_main!(codec);
@@ -0,0 +1,267 @@
+//! The parsed form of ASN.1 modules.
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Module {
+    pub name: String,
+    pub tagging: TaggingMode,
+    pub extensibility_implied: bool,
+    pub imports: Vec<Import>,
+    pub assignments: Vec<Assignment>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum TaggingMode {
+    #[default]
+    Explicit,
+    Implicit,
+    Automatic,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Import {
+    pub symbols: Vec<String>,
+    pub module: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Assignment {
+    Type {
+        name: String,
+        ty: Type,
+    },
+    Value {
+        name: String,
+        ty: Type,
+        value: Value,
+    },
+    /// An assignment that the compiler can't represent, such as an information
+    /// object class or a parameterized type.
+    Unsupported {
+        name: String,
+        reason: String,
+    },
+}
+
+impl Assignment {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Type { name, .. } | Self::Value { name, .. } | Self::Unsupported { name, .. } => {
+                name
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Type {
+    pub tag: Option<Tag>,
+    pub kind: TypeKind,
+    /// Constraints applied serially, as in `INTEGER (0..10) (1..5)`.
+    pub constraints: Vec<Constraint>,
+}
+
+impl Type {
+    pub fn new(kind: TypeKind) -> Self {
+        Self {
+            tag: None,
+            kind,
+            constraints: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Tag {
+    pub class: Class,
+    pub number: Value,
+    pub kind: TagKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Class {
+    Universal,
+    Application,
+    Context,
+    Private,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TagKind {
+    /// The module's tagging default applies.
+    Default,
+    Explicit,
+    Implicit,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum TypeKind {
+    Boolean,
+    Null,
+    Integer(Vec<NamedNumber>),
+    Real,
+    Enumerated(Enumeration),
+    BitString,
+    OctetString,
+    ObjectIdentifier,
+    RelativeOid,
+    /// A restricted character string type, by its ASN.1 name.
+    CharacterString(String),
+    /// A time type, by its ASN.1 name.
+    Time(String),
+    ObjectDescriptor,
+    External,
+    EmbeddedPdv,
+    UnrestrictedCharacterString,
+    /// `ANY`, `ANY DEFINED BY`, and open types such as `CLASS.&Type`.
+    Any,
+    Sequence(Components),
+    Set(Components),
+    SequenceOf(Box<Type>),
+    SetOf(Box<Type>),
+    Choice(Components),
+    Reference(Reference),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Reference {
+    /// The module named by an external reference, as in `Module.Type`.
+    pub module: Option<String>,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct NamedNumber {
+    pub name: String,
+    pub value: Value,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Enumeration {
+    pub root: Vec<Enumeral>,
+    pub extensible: bool,
+    pub additions: Vec<Enumeral>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Enumeral {
+    pub name: String,
+    pub value: Option<Value>,
+}
+
+/// The components of a `SEQUENCE` or `SET`, or the alternatives of a
+/// `CHOICE`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Components {
+    pub root: Vec<Member>,
+    pub extensible: bool,
+    pub additions: Vec<Addition>,
+    /// Root components following a second extension marker.
+    pub trailing_root: Vec<Member>,
+}
+
+impl Components {
+    /// Every member, in the root or an extension addition.
+    pub fn members(&self) -> impl Iterator<Item = &Member> {
+        let additions = self.additions.iter().flat_map(|addition| match addition {
+            Addition::Member(member) => core::slice::from_ref(&**member),
+            Addition::Group(members) => members,
+        });
+
+        self.root.iter().chain(additions).chain(&self.trailing_root)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Member {
+    Named(Component),
+    /// `COMPONENTS OF Type`
+    ComponentsOf(Type),
+}
+
+impl Member {
+    pub fn ty(&self) -> &Type {
+        match self {
+            Self::Named(component) => &component.ty,
+            Self::ComponentsOf(ty) => ty,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Component {
+    pub name: String,
+    pub ty: Type,
+    pub optional: bool,
+    pub default: Option<Value>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Addition {
+    Member(Box<Member>),
+    /// `[[ ... ]]`
+    Group(Vec<Member>),
+}
+
+/// A constraint specification, such as `(SIZE (1..8), ...)`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Constraint {
+    pub root: ElementSet,
+    pub extensible: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ElementSet {
+    Union(Vec<ElementSet>),
+    Intersection(Vec<ElementSet>),
+    Element(Element),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Element {
+    Single(Value),
+    Range {
+        lower: Bound,
+        upper: Bound,
+    },
+    Size(Box<Constraint>),
+    From(Box<Constraint>),
+    /// A constraint that isn't visible to the encoding rules, such as a
+    /// table constraint or `WITH COMPONENTS`.
+    Unsupported,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Bound {
+    Min,
+    Max,
+    Inclusive(Value),
+    /// A bound written with `<`, as in `0<..<10`.
+    Exclusive(Value),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+    Boolean(bool),
+    Integer(i128),
+    Real(String),
+    Null,
+    CString(String),
+    BString(String),
+    HString(String),
+    /// A value reference, or the identifier of a named number or enumeral.
+    Reference(Reference),
+    ObjectIdentifier(Vec<OidComponent>),
+    /// `{}`, the empty `SEQUENCE OF`, `SET OF`, or named `BIT STRING` value.
+    Empty,
+    /// A value the compiler can't represent, in its source form.
+    Unsupported(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum OidComponent {
+    Number(u32),
+    /// `name(1)`
+    Named(String, u32),
+    /// A reference to a value, or a well-known arc name such as `iso`.
+    Name(String),
+}
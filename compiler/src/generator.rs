@@ -0,0 +1,1768 @@
+//! Generates Rust source from parsed modules, using `rasn`'s derive macros.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{ast::*, Error};
+
+const HEADER: &str = "// Generated by rasn-compiler. Do not edit.\n";
+
+const INTEGER: &str = "rasn::types::Integer";
+
+const DERIVES: &str = "AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash";
+/// The derives for types containing a `REAL`, `EXTERNAL`, `EMBEDDED PDV`, or
+/// `CHARACTER STRING`, which can't be ordered or hashed.
+const UNORDERED_DERIVES: &str = "AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq";
+const ENUMERATED_DERIVES: &str =
+    "AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash";
+
+const KNOWN_MULTIPLIER_STRINGS: &[&str] = &[
+    "BMPString",
+    "IA5String",
+    "ISO646String",
+    "NumericString",
+    "PrintableString",
+    "UniversalString",
+    "VisibleString",
+];
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+pub(crate) fn generate(modules: &[Module]) -> Result<String, Error> {
+    let generator = Generator::new(modules)?;
+    let mut output = String::from(HEADER);
+
+    for module in modules {
+        output.push('\n');
+        output.push_str(&ModuleGenerator::new(&generator, module).generate()?);
+    }
+
+    Ok(output)
+}
+
+/// Converts an ASN.1 type reference or identifier into `UpperCamelCase`.
+fn type_name(name: &str) -> String {
+    name.split('-')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// Converts an ASN.1 reference into `snake_case`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut output = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '-' || c == '_' {
+            if !output.is_empty() && !output.ends_with('_') {
+                output.push('_');
+            }
+        } else if c.is_uppercase() {
+            let previous = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+            let boundary = previous.is_some_and(char::is_lowercase)
+                || (previous.is_some_and(char::is_uppercase)
+                    && next.is_some_and(|c| c.is_lowercase()));
+            if boundary && !output.ends_with('_') {
+                output.push('_');
+            }
+            output.extend(c.to_lowercase());
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+fn field_name(name: &str) -> String {
+    let name = snake_case(name);
+    match &*name {
+        "crate" | "self" | "super" => format!("{name}_"),
+        name if KEYWORDS.contains(&name) => format!("r#{name}"),
+        _ => name,
+    }
+}
+
+fn constant_name(name: &str) -> String {
+    snake_case(name).to_uppercase()
+}
+
+fn module_name(name: &str) -> String {
+    field_name(name)
+}
+
+/// The smallest primitive integer type that can hold every value in `range`,
+/// or `Integer` if it is unbounded or extensible.
+fn integer_type(range: Option<Range>) -> &'static str {
+    let Some(Range {
+        lower: Some(lower),
+        upper: Some(upper),
+        extensible: false,
+    }) = range
+    else {
+        return INTEGER;
+    };
+
+    let fits = |min: i128, max: i128| lower >= min && upper <= max;
+    if lower >= 0 {
+        [
+            ("u8", u8::MAX.into()),
+            ("u16", u16::MAX.into()),
+            ("u32", u32::MAX.into()),
+            ("u64", u64::MAX.into()),
+        ]
+        .into_iter()
+        .find_map(|(name, max)| fits(0, max).then_some(name))
+        .unwrap_or(INTEGER)
+    } else {
+        [
+            ("i8", i8::MIN.into(), i8::MAX.into()),
+            ("i16", i16::MIN.into(), i16::MAX.into()),
+            ("i32", i32::MIN.into(), i32::MAX.into()),
+            ("i64", i64::MIN.into(), i64::MAX.into()),
+        ]
+        .into_iter()
+        .find_map(|(name, min, max)| fits(min, max).then_some(name))
+        .unwrap_or(INTEGER)
+    }
+}
+
+fn builtin_type(kind: &TypeKind) -> Option<String> {
+    let name = match kind {
+        TypeKind::Boolean => return Some("bool".into()),
+        TypeKind::Null => return Some("()".into()),
+        TypeKind::Real => "Real",
+        TypeKind::BitString => "BitString",
+        TypeKind::OctetString => "OctetString",
+        TypeKind::ObjectIdentifier => "ObjectIdentifier",
+        TypeKind::RelativeOid => "RelativeOid",
+        TypeKind::CharacterString(name) => match &**name {
+            "BMPString" => "BmpString",
+            "GeneralString" => "GeneralString",
+            "GraphicString" => "GraphicString",
+            "IA5String" => "Ia5String",
+            "NumericString" => "NumericString",
+            "PrintableString" => "PrintableString",
+            "T61String" | "TeletexString" => "TeletexString",
+            "UniversalString" => "UniversalString",
+            "UTF8String" => "Utf8String",
+            "VideotexString" => "VideotexString",
+            _ => "VisibleString",
+        },
+        TypeKind::Time(name) => match &**name {
+            "DATE" => "Date",
+            "DATE-TIME" => "DateTime",
+            "DURATION" => "Duration",
+            "GeneralizedTime" => "GeneralizedTime",
+            "TIME-OF-DAY" => "TimeOfDay",
+            "UTCTime" => "UtcTime",
+            _ => "TimeValue",
+        },
+        TypeKind::ObjectDescriptor => "ObjectDescriptor",
+        TypeKind::External => "External",
+        TypeKind::EmbeddedPdv => "EmbeddedPdv",
+        TypeKind::UnrestrictedCharacterString => "CharacterString",
+        TypeKind::Any => "Any",
+        _ => return None,
+    };
+
+    Some(format!("rasn::types::{name}"))
+}
+
+/// The arc of a well-known object identifier component name, following the
+/// arcs in `prefix`.
+fn well_known_arc(prefix: &[u32], name: &str) -> Option<u32> {
+    Some(match (prefix, name) {
+        ([], "itu-t" | "ccitt" | "itu-r") => 0,
+        ([], "iso") => 1,
+        ([], "joint-iso-itu-t" | "joint-iso-ccitt") => 2,
+        ([0], "recommendation") => 0,
+        ([0], "question") => 1,
+        ([0], "administration") => 2,
+        ([0], "network-operator") => 3,
+        ([0], "identified-organization") => 4,
+        ([1], "standard") => 0,
+        ([1], "member-body") => 2,
+        ([1], "identified-organization") => 3,
+        _ => return None,
+    })
+}
+
+fn bytes(bits: impl Iterator<Item = bool>) -> String {
+    let bits: Vec<bool> = bits.collect();
+    bits.chunks(8)
+        .map(|chunk| {
+            let byte = chunk
+                .iter()
+                .chain(core::iter::repeat(&false))
+                .take(8)
+                .fold(0u8, |byte, &bit| (byte << 1) | u8::from(bit));
+            format!("0x{byte:02x}")
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn hex_bits(hex: &str) -> impl Iterator<Item = bool> + '_ {
+    hex.chars().flat_map(|c| {
+        let nibble = c.to_digit(16).unwrap_or_default();
+        (0..4).rev().map(move |i| nibble & (1 << i) != 0)
+    })
+}
+
+/// Describes a value as it was written in ASN.1.
+fn value_source(value: &Value) -> String {
+    match value {
+        Value::Boolean(true) => "TRUE".into(),
+        Value::Boolean(false) => "FALSE".into(),
+        Value::Integer(integer) => integer.to_string(),
+        Value::Real(real) => real.clone(),
+        Value::Null => "NULL".into(),
+        Value::CString(string) => format!("\"{string}\""),
+        Value::BString(string) => format!("'{string}'B"),
+        Value::HString(string) => format!("'{string}'H"),
+        Value::Reference(reference) => reference.name.clone(),
+        Value::ObjectIdentifier(components) => {
+            let components: Vec<_> = components
+                .iter()
+                .map(|component| match component {
+                    OidComponent::Number(number) => number.to_string(),
+                    OidComponent::Named(name, number) => format!("{name}({number})"),
+                    OidComponent::Name(name) => name.clone(),
+                })
+                .collect();
+            format!("{{ {} }}", components.join(" "))
+        }
+        Value::Empty => "{}".into(),
+        Value::Unsupported(source) => source.clone(),
+    }
+}
+
+/// A range of integers, or of sizes, permitted by a constraint.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Range {
+    lower: Option<i128>,
+    upper: Option<i128>,
+    extensible: bool,
+}
+
+impl Range {
+    fn new(lower: Option<i128>, upper: Option<i128>) -> Self {
+        Self {
+            lower,
+            upper,
+            extensible: false,
+        }
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            lower: self.lower.zip(other.lower).map(|(a, b)| a.min(b)),
+            upper: self.upper.zip(other.upper).map(|(a, b)| a.max(b)),
+            extensible: self.extensible || other.extensible,
+        }
+    }
+
+    fn intersection(self, other: Self) -> Self {
+        let combine = |a: Option<i128>, b: Option<i128>, f: fn(i128, i128) -> i128| match (a, b) {
+            (Some(a), Some(b)) => Some(f(a, b)),
+            (a, b) => a.or(b),
+        };
+
+        Self {
+            lower: combine(self.lower, other.lower, i128::max),
+            upper: combine(self.upper, other.upper, i128::min),
+            extensible: self.extensible && other.extensible,
+        }
+    }
+
+    fn attribute(self, name: &str) -> Option<String> {
+        let range = match (self.lower, self.upper) {
+            (None, None) => return None,
+            (Some(lower), Some(upper)) if lower == upper => lower.to_string(),
+            (Some(lower), Some(upper)) => format!("{lower}..={upper}"),
+            (Some(lower), None) => format!("{lower}.."),
+            (None, Some(upper)) => format!("..={upper}"),
+        };
+        let extensible = if self.extensible { ", extensible" } else { "" };
+
+        Some(format!("{name}(\"{range}\"{extensible})"))
+    }
+}
+
+/// The characters permitted by a `FROM` constraint.
+#[derive(Clone, Debug, PartialEq)]
+struct Alphabet {
+    ranges: Vec<(char, char)>,
+    extensible: bool,
+}
+
+impl Alphabet {
+    fn union(mut self, other: Self) -> Self {
+        self.ranges.extend(other.ranges);
+        self.extensible |= other.extensible;
+        self
+    }
+
+    fn intersection(self, other: Self) -> Self {
+        let mut ranges = Vec::new();
+        for &(a_start, a_end) in &self.ranges {
+            for &(b_start, b_end) in &other.ranges {
+                let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+                if start <= end {
+                    ranges.push((start, end));
+                }
+            }
+        }
+
+        Self {
+            ranges,
+            extensible: self.extensible && other.extensible,
+        }
+    }
+
+    fn attribute(&self) -> Option<String> {
+        let mut ranges = self.ranges.clone();
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(char, char)> = Vec::new();
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last)) if u32::from(start) <= u32::from(*last) + 1 => {
+                    *last = (*last).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+
+        let mut arguments = Vec::new();
+        for (mut start, end) in merged {
+            // `rasn` reads `..` as a range, so a range starting with `.`
+            // is split.
+            if start == '.' && end != '.' {
+                arguments.push(String::from("\".\""));
+                start = '/';
+            }
+
+            if start == end && start.len_utf8() == 1 {
+                arguments.push(format!("{:?}", start.to_string()));
+            } else {
+                arguments.push(format!("{:?}", format!("{start}..={end}")));
+            }
+        }
+
+        (!arguments.is_empty()).then(|| format!("from({})", arguments.join(", ")))
+    }
+}
+
+/// The constraints on a type that are visible to the encoding rules.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Effective {
+    value: Option<Range>,
+    size: Option<Range>,
+    alphabet: Option<Alphabet>,
+}
+
+impl Effective {
+    fn union(self, other: Self) -> Self {
+        // A union only constrains what both sides constrain.
+        Self {
+            value: self.value.zip(other.value).map(|(a, b)| a.union(b)),
+            size: self.size.zip(other.size).map(|(a, b)| a.union(b)),
+            alphabet: self.alphabet.zip(other.alphabet).map(|(a, b)| a.union(b)),
+        }
+    }
+
+    fn intersection(self, other: Self) -> Self {
+        fn either<T>(a: Option<T>, b: Option<T>, f: impl FnOnce(T, T) -> T) -> Option<T> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(f(a, b)),
+                (a, b) => a.or(b),
+            }
+        }
+
+        Self {
+            value: either(self.value, other.value, Range::intersection),
+            size: either(self.size, other.size, Range::intersection),
+            alphabet: either(self.alphabet, other.alphabet, Alphabet::intersection),
+        }
+    }
+
+    /// Applies `other` to a type already constrained by `self`, where only
+    /// the last constraint's extensibility is kept.
+    fn then(self, other: Self) -> Self {
+        let value_extensible = other.value.map(|range| range.extensible);
+        let size_extensible = other.size.map(|range| range.extensible);
+        let alphabet_extensible = other.alphabet.as_ref().map(|alphabet| alphabet.extensible);
+        let mut effective = self.intersection(other);
+
+        if let (Some(range), Some(extensible)) = (&mut effective.value, value_extensible) {
+            range.extensible = extensible;
+        }
+        if let (Some(range), Some(extensible)) = (&mut effective.size, size_extensible) {
+            range.extensible = extensible;
+        }
+        if let (Some(alphabet), Some(extensible)) = (&mut effective.alphabet, alphabet_extensible) {
+            alphabet.extensible = extensible;
+        }
+
+        effective
+    }
+
+    fn set_extensible(&mut self) {
+        if let Some(range) = &mut self.value {
+            range.extensible = true;
+        }
+        if let Some(range) = &mut self.size {
+            range.extensible = true;
+        }
+        if let Some(alphabet) = &mut self.alphabet {
+            alphabet.extensible = true;
+        }
+    }
+}
+
+/// Resolves references across all of the modules being compiled.
+struct Generator<'a> {
+    modules: &'a [Module],
+    /// Why each assignment that can't be generated was skipped, by module
+    /// and assignment name.
+    skipped: HashMap<(&'a str, &'a str), String>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(modules: &'a [Module]) -> Result<Self, Error> {
+        let mut generator = Self {
+            modules,
+            skipped: HashMap::new(),
+        };
+
+        for module in modules {
+            for import in &module.imports {
+                generator.module(&import.module, &module.name)?;
+            }
+            let mut names = HashSet::new();
+            for assignment in &module.assignments {
+                if !names.insert(assignment.name()) {
+                    return Err(Error::Invalid {
+                        module: module.name.clone(),
+                        message: format!("`{}` is assigned more than once", assignment.name()),
+                    });
+                }
+                if let Assignment::Unsupported { name, reason } = assignment {
+                    generator
+                        .skipped
+                        .insert((&module.name, name), reason.clone());
+                }
+            }
+        }
+
+        // Anything that depends on a skipped assignment is skipped too.
+        loop {
+            let mut skipped = Vec::new();
+            for module in modules {
+                for assignment in &module.assignments {
+                    let key = (&*module.name, assignment.name());
+                    let ty = match assignment {
+                        _ if generator.skipped.contains_key(&key) => continue,
+                        Assignment::Type { ty, .. } | Assignment::Value { ty, .. } => ty,
+                        Assignment::Unsupported { .. } => continue,
+                    };
+
+                    let mut references = Vec::new();
+                    collect_references(ty, &mut references);
+                    for reference in references {
+                        let (target, dependency) = generator.resolve(module, reference)?;
+                        if generator
+                            .skipped
+                            .contains_key(&(&*target.name, dependency.name()))
+                        {
+                            skipped.push((
+                                key,
+                                format!("it depends on `{}`, which was skipped", dependency.name()),
+                            ));
+                            break;
+                        }
+                    }
+                }
+            }
+
+            if skipped.is_empty() {
+                break;
+            }
+            generator.skipped.extend(skipped);
+        }
+
+        Ok(generator)
+    }
+
+    fn module(&self, name: &str, referenced_by: &str) -> Result<&'a Module, Error> {
+        self.modules
+            .iter()
+            .find(|module| module.name == name)
+            .ok_or_else(|| Error::UnknownModule {
+                name: name.to_owned(),
+                referenced_by: referenced_by.to_owned(),
+            })
+    }
+
+    /// Finds the assignment that `reference` refers to from `module`,
+    /// following imports.
+    fn resolve(
+        &self,
+        module: &'a Module,
+        reference: &Reference,
+    ) -> Result<(&'a Module, &'a Assignment), Error> {
+        let module = match &reference.module {
+            Some(name) => self.module(name, &module.name)?,
+            None => module,
+        };
+
+        self.resolve_name(module, &reference.name, 0)
+    }
+
+    fn resolve_name(
+        &self,
+        module: &'a Module,
+        name: &str,
+        depth: usize,
+    ) -> Result<(&'a Module, &'a Assignment), Error> {
+        if let Some(assignment) = module
+            .assignments
+            .iter()
+            .find(|assignment| assignment.name() == name)
+        {
+            return Ok((module, assignment));
+        }
+
+        let import = module
+            .imports
+            .iter()
+            .find(|import| import.symbols.iter().any(|symbol| symbol == name));
+        match import {
+            Some(import) if depth < self.modules.len() => {
+                self.resolve_name(self.module(&import.module, &module.name)?, name, depth + 1)
+            }
+            _ => Err(Error::UnknownReference {
+                name: name.to_owned(),
+                module: module.name.clone(),
+            }),
+        }
+    }
+
+    /// Follows references from `ty` to the type they are defined as.
+    fn base(&self, module: &'a Module, ty: &'a Type) -> Result<(&'a Module, &'a Type), Error> {
+        let (mut module, mut ty) = (module, ty);
+
+        for _ in 0..=self.modules.iter().map(|m| m.assignments.len()).sum() {
+            let TypeKind::Reference(reference) = &ty.kind else {
+                break;
+            };
+            match self.resolve(module, reference)? {
+                (target, Assignment::Type { ty: target_ty, .. }) => {
+                    module = target;
+                    ty = target_ty;
+                }
+                _ => break,
+            }
+        }
+
+        Ok((module, ty))
+    }
+
+    /// Follows `value` if it's a reference to the value it's assigned.
+    fn resolve_value<'v>(
+        &self,
+        module: &'a Module,
+        value: &'v Value,
+    ) -> Result<(&'a Module, &'v Value), Error>
+    where
+        'a: 'v,
+    {
+        match value {
+            Value::Reference(reference) => match self.resolve(module, reference)? {
+                (target, Assignment::Value { value, .. }) => self.resolve_value(target, value),
+                (target, assignment) => Err(Error::Invalid {
+                    module: target.name.clone(),
+                    message: format!("`{}` is not a value", assignment.name()),
+                }),
+            },
+            value => Ok((module, value)),
+        }
+    }
+
+    fn integer(&self, module: &'a Module, value: &Value) -> Result<Option<i128>, Error> {
+        Ok(match self.resolve_value(module, value)?.1 {
+            Value::Integer(integer) => Some(*integer),
+            _ => None,
+        })
+    }
+
+    /// The arcs of an object identifier value, or `None` if a component
+    /// can't be resolved.
+    fn oid_arcs(
+        &self,
+        module: &'a Module,
+        components: &[OidComponent],
+    ) -> Result<Option<Vec<u32>>, Error> {
+        let mut arcs = Vec::new();
+
+        for (i, component) in components.iter().enumerate() {
+            match component {
+                OidComponent::Number(number) | OidComponent::Named(_, number) => arcs.push(*number),
+                OidComponent::Name(name) => {
+                    if let Some(arc) = well_known_arc(&arcs, name) {
+                        arcs.push(arc);
+                        continue;
+                    }
+
+                    let reference = Value::Reference(Reference {
+                        module: None,
+                        name: name.clone(),
+                    });
+                    match self.resolve_value(module, &reference)? {
+                        (target, Value::ObjectIdentifier(prefix)) if i == 0 => {
+                            match self.oid_arcs(target, prefix)? {
+                                Some(prefix) => arcs.extend(prefix),
+                                None => return Ok(None),
+                            }
+                        }
+                        (_, Value::Integer(integer)) => match u32::try_from(*integer) {
+                            Ok(arc) => arcs.push(arc),
+                            Err(_) => return Ok(None),
+                        },
+                        _ => return Ok(None),
+                    }
+                }
+            }
+        }
+
+        Ok(Some(arcs))
+    }
+
+    /// The constraints on `ty` that are visible to the encoding rules.
+    fn effective(
+        &self,
+        module: &'a Module,
+        constraints: &[Constraint],
+    ) -> Result<Effective, Error> {
+        let mut effective = Effective::default();
+        for constraint in constraints {
+            effective = effective.then(self.constraint(module, constraint, false)?);
+        }
+
+        // Extensible permitted alphabets aren't visible to PER.
+        if effective
+            .alphabet
+            .as_ref()
+            .is_some_and(|alphabet| alphabet.extensible)
+        {
+            effective.alphabet = None;
+        }
+
+        Ok(effective)
+    }
+
+    fn constraint(
+        &self,
+        module: &'a Module,
+        constraint: &Constraint,
+        alphabet: bool,
+    ) -> Result<Effective, Error> {
+        let mut effective = self.element_set(module, &constraint.root, alphabet)?;
+        if constraint.extensible {
+            effective.set_extensible();
+        }
+        Ok(effective)
+    }
+
+    fn element_set(
+        &self,
+        module: &'a Module,
+        set: &ElementSet,
+        alphabet: bool,
+    ) -> Result<Effective, Error> {
+        let (sets, combine): (_, fn(Effective, Effective) -> Effective) = match set {
+            ElementSet::Union(sets) => (sets, Effective::union),
+            ElementSet::Intersection(sets) => (sets, Effective::intersection),
+            ElementSet::Element(element) => return self.element(module, element, alphabet),
+        };
+
+        let mut effective: Option<Effective> = None;
+        for set in sets {
+            let next = self.element_set(module, set, alphabet)?;
+            effective = Some(match effective {
+                Some(effective) => combine(effective, next),
+                None => next,
+            });
+        }
+
+        Ok(effective.unwrap_or_default())
+    }
+
+    fn element(
+        &self,
+        module: &'a Module,
+        element: &Element,
+        alphabet: bool,
+    ) -> Result<Effective, Error> {
+        let characters = |value: &Value| match self.resolve_value(module, value) {
+            Ok((_, Value::CString(string))) => Ok(Some(string.clone())),
+            Ok(_) => Ok(None),
+            Err(error) => Err(error),
+        };
+
+        Ok(match element {
+            Element::Single(value) if alphabet => Effective {
+                alphabet: characters(value)?.map(|string| Alphabet {
+                    ranges: string.chars().map(|c| (c, c)).collect(),
+                    extensible: false,
+                }),
+                ..Effective::default()
+            },
+            Element::Single(value) => Effective {
+                value: self
+                    .integer(module, value)?
+                    .map(|integer| Range::new(Some(integer), Some(integer))),
+                ..Effective::default()
+            },
+            Element::Range {
+                lower: Bound::Inclusive(lower),
+                upper: Bound::Inclusive(upper),
+            } if alphabet => {
+                let single = |string: Option<String>| {
+                    let mut chars = string?.chars().collect::<Vec<_>>().into_iter();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Some(c),
+                        _ => None,
+                    }
+                };
+                Effective {
+                    alphabet: single(characters(lower)?)
+                        .zip(single(characters(upper)?))
+                        .map(|range| Alphabet {
+                            ranges: vec![range],
+                            extensible: false,
+                        }),
+                    ..Effective::default()
+                }
+            }
+            Element::Range { .. } if alphabet => Effective::default(),
+            Element::Range { lower, upper } => Effective {
+                value: Some(Range::new(
+                    self.bound(module, lower, false)?,
+                    self.bound(module, upper, true)?,
+                )),
+                ..Effective::default()
+            },
+            Element::Size(constraint) => Effective {
+                size: self.constraint(module, constraint, false)?.value,
+                ..Effective::default()
+            },
+            Element::From(constraint) => Effective {
+                alphabet: self.constraint(module, constraint, true)?.alphabet,
+                ..Effective::default()
+            },
+            Element::Unsupported => Effective::default(),
+        })
+    }
+
+    fn bound(&self, module: &'a Module, bound: &Bound, upper: bool) -> Result<Option<i128>, Error> {
+        Ok(match bound {
+            Bound::Min | Bound::Max => None,
+            Bound::Inclusive(value) => self.integer(module, value)?,
+            Bound::Exclusive(value) => {
+                self.integer(module, value)?
+                    .map(|integer| if upper { integer - 1 } else { integer + 1 })
+            }
+        })
+    }
+
+    /// Whether `ty` can only be tagged explicitly, because it is a `CHOICE`
+    /// or an open type.
+    fn requires_explicit_tag(&self, module: &'a Module, ty: &'a Type) -> Result<bool, Error> {
+        let (mut module, mut ty) = (module, ty);
+
+        for _ in 0..=self.modules.iter().map(|m| m.assignments.len()).sum() {
+            match &ty.kind {
+                TypeKind::Choice(_) | TypeKind::Any => return Ok(true),
+                TypeKind::Reference(reference) => match self.resolve(module, reference)? {
+                    (target, Assignment::Type { ty: target_ty, .. }) if target_ty.tag.is_none() => {
+                        module = target;
+                        ty = target_ty;
+                    }
+                    _ => break,
+                },
+                _ => break,
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn is_explicit(&self, module: &'a Module, tag: &Tag, ty: &'a Type) -> Result<bool, Error> {
+        Ok(match tag.kind {
+            TagKind::Explicit => true,
+            TagKind::Implicit => self.requires_explicit_tag(module, ty)?,
+            TagKind::Default => {
+                module.tagging == TaggingMode::Explicit || self.requires_explicit_tag(module, ty)?
+            }
+        })
+    }
+
+    /// Whether `ty` contains the type `target` directly, rather than through a
+    /// `SEQUENCE OF` or `SET OF`, so that it needs to be boxed.
+    fn reaches(
+        &self,
+        module: &'a Module,
+        ty: &'a Type,
+        target: (&str, &str),
+        visited: &mut HashSet<(&'a str, &'a str)>,
+    ) -> Result<bool, Error> {
+        match &ty.kind {
+            TypeKind::Reference(reference) => {
+                let (module, assignment) = self.resolve(module, reference)?;
+                let key = (&*module.name, assignment.name());
+                if key == target {
+                    return Ok(true);
+                }
+                match assignment {
+                    Assignment::Type { ty, .. } if visited.insert(key) => {
+                        self.reaches(module, ty, target, visited)
+                    }
+                    _ => Ok(false),
+                }
+            }
+            TypeKind::Sequence(components)
+            | TypeKind::Set(components)
+            | TypeKind::Choice(components) => {
+                for member in components.members() {
+                    if self.reaches(module, member.ty(), target, visited)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Whether `ty` contains a type that can't be ordered or hashed.
+    fn is_unordered(
+        &self,
+        module: &'a Module,
+        ty: &'a Type,
+        visited: &mut HashSet<(&'a str, &'a str)>,
+    ) -> Result<bool, Error> {
+        match &ty.kind {
+            TypeKind::Real
+            | TypeKind::External
+            | TypeKind::EmbeddedPdv
+            | TypeKind::UnrestrictedCharacterString => Ok(true),
+            TypeKind::SequenceOf(element) | TypeKind::SetOf(element) => {
+                self.is_unordered(module, element, visited)
+            }
+            TypeKind::Reference(reference) => {
+                let (module, assignment) = self.resolve(module, reference)?;
+                match assignment {
+                    Assignment::Type { ty, .. }
+                        if visited.insert((&module.name, assignment.name())) =>
+                    {
+                        self.is_unordered(module, ty, visited)
+                    }
+                    _ => Ok(false),
+                }
+            }
+            TypeKind::Sequence(components)
+            | TypeKind::Set(components)
+            | TypeKind::Choice(components) => {
+                for member in components.members() {
+                    if self.is_unordered(module, member.ty(), visited)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+fn collect_references<'a>(ty: &'a Type, references: &mut Vec<&'a Reference>) {
+    match &ty.kind {
+        TypeKind::Reference(reference) => references.push(reference),
+        TypeKind::SequenceOf(element) | TypeKind::SetOf(element) => {
+            collect_references(element, references);
+        }
+        TypeKind::Sequence(components)
+        | TypeKind::Set(components)
+        | TypeKind::Choice(components) => {
+            for member in components.members() {
+                collect_references(member.ty(), references);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_automatic(module: &Module, components: &Components) -> bool {
+    module.tagging == TaggingMode::Automatic
+        && !components
+            .members()
+            .any(|member| matches!(member, Member::Named(component) if component.ty.tag.is_some()))
+}
+
+/// Generates the Rust module for a single ASN.1 module.
+struct ModuleGenerator<'g, 'a> {
+    generator: &'g Generator<'a>,
+    module: &'a Module,
+    items: Vec<String>,
+    names: HashSet<String>,
+    /// The assignment being generated, which fields that refer back to are
+    /// boxed.
+    current: &'a str,
+}
+
+impl<'g, 'a> ModuleGenerator<'g, 'a> {
+    fn new(generator: &'g Generator<'a>, module: &'a Module) -> Self {
+        Self {
+            generator,
+            module,
+            items: Vec::new(),
+            names: HashSet::new(),
+            current: "",
+        }
+    }
+
+    fn generate(mut self) -> Result<String, Error> {
+        // Auxiliary types for nested definitions mustn't take the name of a
+        // type defined later in the module.
+        for assignment in &self.module.assignments {
+            if let Assignment::Type { name, .. } = assignment {
+                self.names.insert(type_name(name));
+            }
+        }
+
+        for assignment in &self.module.assignments {
+            self.current = assignment.name();
+            let key = (&*self.module.name, assignment.name());
+            if let Some(reason) = self.generator.skipped.get(&key) {
+                self.items.push(format!(
+                    "// `{}` was skipped, as {reason}.\n",
+                    assignment.name()
+                ));
+                continue;
+            }
+
+            match assignment {
+                Assignment::Type { name, ty } => {
+                    self.item(self.module, &type_name(name), ty, true)?
+                }
+                Assignment::Value { name, ty, value } => self.value(name, ty, value)?,
+                Assignment::Unsupported { .. } => {}
+            }
+        }
+
+        let mut output = format!(
+            "pub mod {} {{\n    #![allow(clippy::all, non_camel_case_types, unused_imports)]\n\n    use rasn::{{AsnType, Decode, Encode}};\n",
+            module_name(&self.module.name)
+        );
+        for item in &self.items {
+            output.push('\n');
+            for line in item.lines() {
+                if !line.is_empty() {
+                    output.push_str("    ");
+                    output.push_str(line);
+                }
+                output.push('\n');
+            }
+        }
+        output.push_str("}\n");
+
+        Ok(output)
+    }
+
+    fn unique_name(&mut self, name: &str) -> String {
+        let mut unique = name.to_owned();
+        let mut suffix = 2;
+        while !self.names.insert(unique.clone()) {
+            unique = format!("{name}{suffix}");
+            suffix += 1;
+        }
+        unique
+    }
+
+    /// The path to the Rust type generated for `reference`.
+    fn type_path(&self, module: &'a Module, reference: &Reference) -> Result<String, Error> {
+        let (target, assignment) = self.generator.resolve(module, reference)?;
+        let name = type_name(assignment.name());
+
+        Ok(if core::ptr::eq(target, self.module) {
+            name
+        } else {
+            format!("super::{}::{name}", module_name(&target.name))
+        })
+    }
+
+    fn tag_attribute(&self, module: &'a Module, tag: &Tag, ty: &'a Type) -> Result<String, Error> {
+        let number = self
+            .generator
+            .integer(module, &tag.number)?
+            .ok_or_else(|| Error::Invalid {
+                module: module.name.clone(),
+                message: format!(
+                    "tag number `{}` is not an integer",
+                    value_source(&tag.number)
+                ),
+            })?;
+        let class = match tag.class {
+            Class::Context => "",
+            Class::Application => "application, ",
+            Class::Universal => "universal, ",
+            Class::Private => "private, ",
+        };
+
+        Ok(if self.generator.is_explicit(module, tag, ty)? {
+            format!("tag(explicit({class}{number}))")
+        } else {
+            format!("tag({class}{number})")
+        })
+    }
+
+    fn constraint_attributes(
+        &self,
+        module: &'a Module,
+        ty: &'a Type,
+    ) -> Result<Vec<String>, Error> {
+        let effective = self.generator.effective(module, &ty.constraints)?;
+        let (_, base) = self.generator.base(module, ty)?;
+        let mut attributes = Vec::new();
+
+        // Only the constraints of known-multiplier character strings are
+        // visible to PER.
+        let known_multiplier = matches!(
+            &base.kind,
+            TypeKind::CharacterString(name) if KNOWN_MULTIPLIER_STRINGS.contains(&&**name)
+        );
+
+        if known_multiplier
+            || matches!(
+                base.kind,
+                TypeKind::BitString
+                    | TypeKind::OctetString
+                    | TypeKind::SequenceOf(_)
+                    | TypeKind::SetOf(_)
+            )
+        {
+            attributes.extend(effective.size.and_then(|size| size.attribute("size")));
+        }
+        if matches!(base.kind, TypeKind::Integer(_)) {
+            attributes.extend(effective.value.and_then(|value| value.attribute("value")));
+        }
+        if known_multiplier {
+            attributes.extend(effective.alphabet.and_then(|alphabet| alphabet.attribute()));
+        }
+
+        Ok(attributes)
+    }
+
+    fn derives(&self, module: &'a Module, ty: &'a Type) -> Result<&'static str, Error> {
+        Ok(
+            if self
+                .generator
+                .is_unordered(module, ty, &mut HashSet::new())?
+            {
+                UNORDERED_DERIVES
+            } else {
+                DERIVES
+            },
+        )
+    }
+
+    /// Generates the Rust type for `ty`, named `name`. If `tagged` is false,
+    /// the tag of `ty` is left for its field or variant to apply.
+    fn item(
+        &mut self,
+        module: &'a Module,
+        name: &str,
+        ty: &'a Type,
+        tagged: bool,
+    ) -> Result<(), Error> {
+        // The item goes before any auxiliary types generated along with it.
+        let index = self.items.len();
+        self.items.push(String::new());
+
+        let mut attributes = Vec::new();
+        let mut item = String::new();
+
+        match &ty.kind {
+            TypeKind::Sequence(components) | TypeKind::Set(components) => {
+                let automatic = is_automatic(module, components);
+                if matches!(ty.kind, TypeKind::Set(_)) {
+                    attributes.push(String::from("set"));
+                }
+                if automatic {
+                    attributes.push(String::from("automatic_tags"));
+                }
+                self.push_tag(module, ty, tagged, &mut attributes)?;
+                let derives = self.derives(module, ty)?;
+                let fields = self.fields(module, name, components, automatic, derives)?;
+                item = self.structure(
+                    name,
+                    derives,
+                    &attributes,
+                    components.extensible || module.extensibility_implied,
+                    &fields,
+                );
+            }
+            TypeKind::Choice(components) => {
+                let automatic = is_automatic(module, components);
+                attributes.push(String::from("choice"));
+                if automatic {
+                    attributes.push(String::from("automatic_tags"));
+                }
+                self.push_tag(module, ty, tagged, &mut attributes)?;
+                let variants = self.variants(module, name, components, automatic)?;
+                item.push_str(&format!("#[derive({})]\n", self.derives(module, ty)?));
+                item.push_str(&format!("#[rasn({})]\n", attributes.join(", ")));
+                if components.extensible || module.extensibility_implied {
+                    item.push_str("#[non_exhaustive]\n");
+                }
+                item.push_str(&format!("pub enum {name} {{\n"));
+                for variant in variants {
+                    for line in variant.lines() {
+                        item.push_str(&format!("    {line}\n"));
+                    }
+                }
+                item.push_str("}\n");
+            }
+            TypeKind::Enumerated(enumeration) => {
+                attributes.push(String::from("enumerated"));
+                self.push_tag(module, ty, tagged, &mut attributes)?;
+                item.push_str(&format!("#[derive({ENUMERATED_DERIVES})]\n"));
+                item.push_str(&format!("#[rasn({})]\n", attributes.join(", ")));
+                if enumeration.extensible || module.extensibility_implied {
+                    item.push_str("#[non_exhaustive]\n");
+                }
+                item.push_str(&format!("pub enum {name} {{\n"));
+                item.push_str(&self.enumerals(module, enumeration)?);
+                item.push_str("}\n");
+            }
+            _ => {
+                attributes.push(String::from("delegate"));
+                self.push_tag(module, ty, tagged, &mut attributes)?;
+                attributes.extend(self.constraint_attributes(module, ty)?);
+                let inner = self.rust_type(module, ty, name)?;
+                item.push_str(&format!("#[derive({})]\n", self.derives(module, ty)?));
+                item.push_str(&format!("#[rasn({})]\n", attributes.join(", ")));
+                item.push_str(&format!("pub struct {name}(pub {inner});\n"));
+            }
+        }
+
+        self.items[index] = item;
+        Ok(())
+    }
+
+    fn push_tag(
+        &self,
+        module: &'a Module,
+        ty: &'a Type,
+        tagged: bool,
+        attributes: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        if let Some(tag) = ty.tag.as_ref().filter(|_| tagged) {
+            attributes.push(self.tag_attribute(module, tag, ty)?);
+        }
+        Ok(())
+    }
+
+    fn structure(
+        &self,
+        name: &str,
+        derives: &str,
+        attributes: &[String],
+        extensible: bool,
+        fields: &[String],
+    ) -> String {
+        let mut item = format!("#[derive({derives})]\n");
+        if !attributes.is_empty() {
+            item.push_str(&format!("#[rasn({})]\n", attributes.join(", ")));
+        }
+        if extensible {
+            item.push_str("#[non_exhaustive]\n");
+        }
+        item.push_str(&format!("pub struct {name} {{\n"));
+        for field in fields {
+            for line in field.lines() {
+                item.push_str(&format!("    {line}\n"));
+            }
+        }
+        item.push_str("}\n");
+        item
+    }
+
+    /// The Rust type for `ty`, ignoring its tag and any constraints that are
+    /// applied by attributes. Nested definitions are generated as auxiliary
+    /// types named `hint`.
+    fn rust_type(&mut self, module: &'a Module, ty: &'a Type, hint: &str) -> Result<String, Error> {
+        Ok(match &ty.kind {
+            TypeKind::Integer(_) => {
+                integer_type(self.generator.effective(module, &ty.constraints)?.value).to_owned()
+            }
+            TypeKind::SequenceOf(element) => format!(
+                "rasn::types::SequenceOf<{}>",
+                self.element_type(module, element, &format!("{hint}Item"))?
+            ),
+            TypeKind::SetOf(element) => format!(
+                "rasn::types::SetOf<{}>",
+                self.element_type(module, element, &format!("{hint}Item"))?
+            ),
+            TypeKind::Sequence(_)
+            | TypeKind::Set(_)
+            | TypeKind::Choice(_)
+            | TypeKind::Enumerated(_) => {
+                let name = self.unique_name(hint);
+                self.item(module, &name, ty, false)?;
+                name
+            }
+            TypeKind::Reference(reference) => self.type_path(module, reference)?,
+            kind => builtin_type(kind).unwrap_or_default(),
+        })
+    }
+
+    /// The Rust type for the elements of a `SEQUENCE OF` or `SET OF`, which
+    /// need an auxiliary type if they are tagged or constrained.
+    fn element_type(
+        &mut self,
+        module: &'a Module,
+        element: &'a Type,
+        hint: &str,
+    ) -> Result<String, Error> {
+        let constrained = !self.constraint_attributes(module, element)?.is_empty()
+            || matches!(element.kind, TypeKind::Integer(_))
+                && self
+                    .generator
+                    .effective(module, &element.constraints)?
+                    .value
+                    .is_some();
+
+        if element.tag.is_some() || constrained {
+            let name = self.unique_name(hint);
+            self.item(module, &name, element, true)?;
+            Ok(name)
+        } else {
+            self.rust_type(module, element, hint)
+        }
+    }
+
+    fn fields(
+        &mut self,
+        module: &'a Module,
+        parent: &str,
+        components: &'a Components,
+        automatic: bool,
+        derives: &str,
+    ) -> Result<Vec<String>, Error> {
+        let mut fields = Vec::new();
+        for member in &components.root {
+            self.member_fields(module, parent, member, automatic, false, &mut fields)?;
+        }
+
+        let mut groups = 0;
+        for addition in &components.additions {
+            match addition {
+                Addition::Member(member) => {
+                    self.member_fields(module, parent, member, automatic, true, &mut fields)?;
+                }
+                Addition::Group(members) => {
+                    groups += 1;
+                    let name = self.unique_name(&format!("{parent}ExtensionGroup{groups}"));
+                    let index = self.items.len();
+                    self.items.push(String::new());
+
+                    let mut group_fields = Vec::new();
+                    for member in members {
+                        self.member_fields(
+                            module,
+                            &name,
+                            member,
+                            automatic,
+                            false,
+                            &mut group_fields,
+                        )?;
+                    }
+                    let attributes: &[String] = if automatic {
+                        &[String::from("automatic_tags")][..]
+                    } else {
+                        &[]
+                    };
+                    self.items[index] =
+                        self.structure(&name, derives, attributes, false, &group_fields);
+
+                    fields.push(format!(
+                        "#[rasn(extension_addition_group)]\npub extension_group_{groups}: Option<{name}>,"
+                    ));
+                }
+            }
+        }
+
+        for member in &components.trailing_root {
+            self.member_fields(module, parent, member, automatic, false, &mut fields)?;
+        }
+
+        Ok(fields)
+    }
+
+    fn member_fields(
+        &mut self,
+        module: &'a Module,
+        parent: &str,
+        member: &'a Member,
+        automatic: bool,
+        extension: bool,
+        fields: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        match member {
+            Member::Named(component) => {
+                let field = self.field(module, parent, component, automatic, extension)?;
+                fields.push(field);
+            }
+            Member::ComponentsOf(ty) => {
+                let (target, base) = self.generator.base(module, ty)?;
+                let (TypeKind::Sequence(components) | TypeKind::Set(components)) = &base.kind
+                else {
+                    return Err(Error::Invalid {
+                        module: module.name.clone(),
+                        message: String::from(
+                            "`COMPONENTS OF` must refer to a `SEQUENCE` or `SET`",
+                        ),
+                    });
+                };
+
+                for member in components.root.iter().chain(&components.trailing_root) {
+                    self.member_fields(target, parent, member, automatic, extension, fields)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether a field or variant of type `ty` refers back to the type being
+    /// generated, and needs to be boxed.
+    fn is_recursive(&self, module: &'a Module, ty: &'a Type) -> Result<bool, Error> {
+        Ok(matches!(ty.kind, TypeKind::Reference(_))
+            && self.generator.reaches(
+                module,
+                ty,
+                (&self.module.name, self.current),
+                &mut HashSet::new(),
+            )?)
+    }
+
+    fn field(
+        &mut self,
+        module: &'a Module,
+        parent: &str,
+        component: &'a Component,
+        automatic: bool,
+        extension: bool,
+    ) -> Result<String, Error> {
+        let name = field_name(&component.name);
+        let mut attributes = Vec::new();
+        if !automatic {
+            if let Some(tag) = &component.ty.tag {
+                attributes.push(self.tag_attribute(module, tag, &component.ty)?);
+            }
+        }
+        attributes.extend(self.constraint_attributes(module, &component.ty)?);
+        if extension {
+            attributes.push(String::from("extension_addition"));
+        }
+
+        let mut ty = self.rust_type(
+            module,
+            &component.ty,
+            &format!("{parent}{}", type_name(&component.name)),
+        )?;
+        let boxed = self.is_recursive(module, &component.ty)?;
+        if boxed {
+            ty = format!("Box<{ty}>");
+        }
+
+        let mut field = String::new();
+        match &component.default {
+            Some(default) => match self.default_expression(module, &component.ty, default)? {
+                Some(expression) => {
+                    let function = format!(
+                        "{}_{}_default",
+                        snake_case(parent),
+                        snake_case(&component.name)
+                    );
+                    let expression = if boxed {
+                        format!("Box::new({expression})")
+                    } else {
+                        expression
+                    };
+                    self.items.push(format!(
+                        "fn {function}() -> {ty} {{\n    {expression}\n}}\n"
+                    ));
+                    attributes.push(format!("default = \"{function}\""));
+                }
+                None => {
+                    field.push_str(&format!(
+                        "/// `None` is equivalent to the default of `{}`.\n",
+                        value_source(default)
+                    ));
+                    ty = format!("Option<{ty}>");
+                }
+            },
+            None if component.optional || extension => ty = format!("Option<{ty}>"),
+            None => {}
+        }
+
+        if !attributes.is_empty() {
+            field.push_str(&format!("#[rasn({})]\n", attributes.join(", ")));
+        }
+        field.push_str(&format!("pub {name}: {ty},"));
+        Ok(field)
+    }
+
+    fn variants(
+        &mut self,
+        module: &'a Module,
+        parent: &str,
+        components: &'a Components,
+        automatic: bool,
+    ) -> Result<Vec<String>, Error> {
+        let root = components
+            .root
+            .iter()
+            .chain(&components.trailing_root)
+            .map(|member| (member, false));
+        // Version brackets don't change how alternatives are encoded.
+        let additions = components
+            .additions
+            .iter()
+            .flat_map(|addition| match addition {
+                Addition::Member(member) => core::slice::from_ref(&**member),
+                Addition::Group(members) => members,
+            });
+
+        let mut variants = Vec::new();
+        for (member, extension) in root.chain(additions.map(|member| (member, true))) {
+            let Member::Named(component) = member else {
+                return Err(Error::Invalid {
+                    module: module.name.clone(),
+                    message: format!("`{parent}` can't use `COMPONENTS OF` in a `CHOICE`"),
+                });
+            };
+
+            let mut attributes = Vec::new();
+            if !automatic {
+                if let Some(tag) = &component.ty.tag {
+                    attributes.push(self.tag_attribute(module, tag, &component.ty)?);
+                }
+            }
+            attributes.extend(self.constraint_attributes(module, &component.ty)?);
+            if extension {
+                attributes.push(String::from("extension_addition"));
+            }
+
+            let name = type_name(&component.name);
+            let mut ty = self.rust_type(module, &component.ty, &format!("{parent}{name}"))?;
+            if self.is_recursive(module, &component.ty)? {
+                ty = format!("Box<{ty}>");
+            }
+
+            let mut variant = String::new();
+            if !attributes.is_empty() {
+                variant.push_str(&format!("#[rasn({})]\n", attributes.join(", ")));
+            }
+            variant.push_str(&format!("{name}({ty}),"));
+            variants.push(variant);
+        }
+
+        Ok(variants)
+    }
+
+    fn enumerals(&self, module: &'a Module, enumeration: &'a Enumeration) -> Result<String, Error> {
+        let number = |enumeral: &Enumeral| match &enumeral.value {
+            Some(value) => self
+                .generator
+                .integer(module, value)?
+                .map(Some)
+                .ok_or_else(|| Error::Invalid {
+                    module: module.name.clone(),
+                    message: format!("the value of `{}` is not an integer", enumeral.name),
+                }),
+            None => Ok(None),
+        };
+
+        // Unnumbered root enumerals take the lowest numbers not already used.
+        let mut used = HashSet::new();
+        for enumeral in &enumeration.root {
+            used.extend(number(enumeral)?);
+        }
+        let mut next = 0;
+        let mut numbers = Vec::new();
+        for enumeral in &enumeration.root {
+            numbers.push(match number(enumeral)? {
+                Some(number) => number,
+                None => {
+                    while used.contains(&next) {
+                        next += 1;
+                    }
+                    used.insert(next);
+                    next
+                }
+            });
+        }
+
+        // Unnumbered additions follow the largest number used so far.
+        for enumeral in &enumeration.additions {
+            let number = match number(enumeral)? {
+                Some(number) => number,
+                None => used.iter().max().map_or(0, |max| max + 1),
+            };
+            used.insert(number);
+            numbers.push(number);
+        }
+
+        // The encoding rules index root enumerals in order of their value,
+        // and `rasn` indexes variants in the order they're declared.
+        let mut enumerals: Vec<_> = enumeration
+            .root
+            .iter()
+            .chain(&enumeration.additions)
+            .zip(numbers)
+            .collect();
+        enumerals[..enumeration.root.len()].sort_by_key(|(_, number)| *number);
+
+        let mut output = String::new();
+        for (i, (enumeral, number)) in enumerals.into_iter().enumerate() {
+            if i >= enumeration.root.len() {
+                output.push_str("    #[rasn(extension_addition)]\n");
+            }
+            output.push_str(&format!("    {} = {number},\n", type_name(&enumeral.name)));
+        }
+
+        Ok(output)
+    }
+
+    /// A Rust expression for the `DEFAULT` value of a component, or `None`
+    /// if it can't be expressed.
+    fn default_expression(
+        &self,
+        module: &'a Module,
+        ty: &'a Type,
+        value: &'a Value,
+    ) -> Result<Option<String>, Error> {
+        // Identifiers can name an enumeral or named number of the type,
+        // rather than a value.
+        if let Value::Reference(Reference { module: None, name }) = value {
+            let (base_module, base) = self.generator.base(module, ty)?;
+            match &base.kind {
+                TypeKind::Enumerated(_) => return self.default_literal(module, ty, module, value),
+                TypeKind::Integer(named_numbers) => {
+                    if let Some(named_number) =
+                        named_numbers.iter().find(|named| &named.name == name)
+                    {
+                        return match self.generator.integer(base_module, &named_number.value)? {
+                            Some(integer) => {
+                                self.default_literal(module, ty, module, &Value::Integer(integer))
+                            }
+                            None => Ok(None),
+                        };
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (value_module, value) = self.generator.resolve_value(module, value)?;
+        self.default_literal(module, ty, value_module, value)
+    }
+
+    fn default_literal(
+        &self,
+        module: &'a Module,
+        ty: &'a Type,
+        value_module: &'a Module,
+        value: &Value,
+    ) -> Result<Option<String>, Error> {
+        Ok(match (&ty.kind, value) {
+            (TypeKind::Reference(reference), _) => {
+                let (target, assignment) = self.generator.resolve(module, reference)?;
+                let Assignment::Type { ty: inner, .. } = assignment else {
+                    return Ok(None);
+                };
+                let path = self.type_path(module, reference)?;
+
+                match (&inner.kind, value) {
+                    (TypeKind::Enumerated(enumeration), Value::Reference(enumeral)) => enumeration
+                        .root
+                        .iter()
+                        .chain(&enumeration.additions)
+                        .any(|candidate| candidate.name == enumeral.name)
+                        .then(|| format!("{path}::{}", type_name(&enumeral.name))),
+                    (
+                        TypeKind::Enumerated(_)
+                        | TypeKind::Sequence(_)
+                        | TypeKind::Set(_)
+                        | TypeKind::Choice(_),
+                        _,
+                    ) => None,
+                    _ => self
+                        .default_literal(target, inner, value_module, value)?
+                        .map(|inner| format!("{path}({inner})")),
+                }
+            }
+            (TypeKind::Boolean, Value::Boolean(boolean)) => Some(boolean.to_string()),
+            (TypeKind::Integer(_), Value::Integer(integer)) => Some(
+                match integer_type(self.generator.effective(module, &ty.constraints)?.value) {
+                    INTEGER => format!("{INTEGER}::from({integer})"),
+                    _ => integer.to_string(),
+                },
+            ),
+            (TypeKind::Null, Value::Null) => Some(String::from("()")),
+            (TypeKind::CharacterString(name), Value::CString(string)) if name == "UTF8String" => {
+                Some(format!("rasn::types::Utf8String::from({string:?})"))
+            }
+            (TypeKind::OctetString, Value::HString(hex)) => Some(format!(
+                "rasn::types::OctetString::from_static(&[{}])",
+                bytes(hex_bits(hex))
+            )),
+            (TypeKind::OctetString, Value::BString(binary)) if binary.len() % 8 == 0 => {
+                Some(format!(
+                    "rasn::types::OctetString::from_static(&[{}])",
+                    bytes(binary.chars().map(|c| c == '1'))
+                ))
+            }
+            (TypeKind::BitString, Value::Empty) => {
+                Some(String::from("rasn::types::BitString::new()"))
+            }
+            (TypeKind::BitString, Value::BString(binary)) if binary.is_empty() => {
+                Some(String::from("rasn::types::BitString::new()"))
+            }
+            (TypeKind::BitString, Value::BString(binary)) => Some(format!(
+                "[{}].into_iter().collect()",
+                binary
+                    .chars()
+                    .map(|c| if c == '1' { "true" } else { "false" })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            (TypeKind::SequenceOf(_) | TypeKind::SetOf(_), Value::Empty) => {
+                Some(String::from("Default::default()"))
+            }
+            (TypeKind::ObjectIdentifier, Value::ObjectIdentifier(components)) => {
+                self.generator
+                    .oid_arcs(value_module, components)?
+                    .map(|arcs| {
+                        format!(
+                        "rasn::types::ObjectIdentifier::from(rasn::types::Oid::const_new(&[{}]))",
+                        arcs.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+                    )
+                    })
+            }
+            _ => None,
+        })
+    }
+
+    fn value(&mut self, name: &'a str, ty: &'a Type, value: &'a Value) -> Result<(), Error> {
+        let constant = constant_name(name);
+        let (value_module, value) = self.generator.resolve_value(self.module, value)?;
+        let (base_module, base) = self.generator.base(self.module, ty)?;
+
+        let item = match (&base.kind, value) {
+            (TypeKind::Integer(_), Value::Integer(integer)) => {
+                let ty = match integer_type(self.generator.effective(base_module, &base.constraints)?.value) {
+                    INTEGER => "i128",
+                    ty => ty,
+                };
+                Some(format!("pub const {constant}: {ty} = {integer};"))
+            }
+            (TypeKind::Boolean, Value::Boolean(boolean)) => {
+                Some(format!("pub const {constant}: bool = {boolean};"))
+            }
+            (TypeKind::CharacterString(_), Value::CString(string)) => {
+                Some(format!("pub const {constant}: &str = {string:?};"))
+            }
+            (TypeKind::ObjectIdentifier, Value::ObjectIdentifier(components)) => self
+                .generator
+                .oid_arcs(value_module, components)?
+                .map(|arcs| {
+                    format!(
+                        "pub const {constant}: &rasn::types::Oid = rasn::types::Oid::const_new(&[{}]);",
+                        arcs.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+                    )
+                }),
+            _ => None,
+        };
+
+        self.items.push(match item {
+            Some(item) => format!("{item}\n"),
+            None => format!("// `{name}` was skipped, as values of its type are not supported.\n"),
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!(type_name("S1AP-PDU"), "S1APPDU");
+        assert_eq!(type_name("release-due-to-reason"), "ReleaseDueToReason");
+        assert_eq!(field_name("protocolIEs"), "protocol_i_es");
+        assert_eq!(field_name("id-MME-UE-S1AP-ID"), "id_mme_ue_s1ap_id");
+        assert_eq!(field_name("type"), "r#type");
+        assert_eq!(field_name("self"), "self_");
+        assert_eq!(constant_name("maxNrofCells"), "MAX_NROF_CELLS");
+    }
+
+    #[test]
+    fn integer_types() {
+        let range = |lower, upper| Some(Range::new(Some(lower), Some(upper)));
+        assert_eq!(integer_type(range(0, 255)), "u8");
+        assert_eq!(integer_type(range(1, 65536)), "u32");
+        assert_eq!(integer_type(range(-1, 127)), "i8");
+        assert_eq!(integer_type(range(0, i128::MAX)), INTEGER);
+        assert_eq!(integer_type(None), INTEGER);
+        assert_eq!(
+            integer_type(Some(Range {
+                extensible: true,
+                ..Range::new(Some(0), Some(1))
+            })),
+            INTEGER
+        );
+    }
+
+    #[test]
+    fn alphabets() {
+        let alphabet = Alphabet {
+            ranges: vec![('0', '9'), ('a', 'c'), ('d', 'f'), (' ', ' '), ('.', '/')],
+            extensible: false,
+        };
+        assert_eq!(
+            alphabet.attribute().unwrap(),
+            r#"from(" ", ".", "/..=9", "a..=f")"#
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! Splits ASN.1 source into tokens, discarding whitespace and comments.
+
+use crate::Error;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Token {
+    /// A type or value reference, keyword, or `&`-prefixed field reference.
+    Identifier(String),
+    Number(u128),
+    Real(String),
+    CString(String),
+    BString(String),
+    HString(String),
+    /// `::=`
+    Assignment,
+    /// `..`
+    Range,
+    /// `...`
+    Ellipsis,
+    /// `[[`
+    LeftVersionBrackets,
+    /// `]]`
+    RightVersionBrackets,
+    Symbol(char),
+}
+
+impl core::fmt::Display for Token {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Identifier(identifier) => f.write_str(identifier),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Real(real) => f.write_str(real),
+            Self::CString(string) => write!(f, "\"{string}\""),
+            Self::BString(string) => write!(f, "'{string}'B"),
+            Self::HString(string) => write!(f, "'{string}'H"),
+            Self::Assignment => f.write_str("::="),
+            Self::Range => f.write_str(".."),
+            Self::Ellipsis => f.write_str("..."),
+            Self::LeftVersionBrackets => f.write_str("[["),
+            Self::RightVersionBrackets => f.write_str("]]"),
+            Self::Symbol(symbol) => write!(f, "{symbol}"),
+        }
+    }
+}
+
+/// A token along with the line it starts on.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Spanned {
+    pub token: Token,
+    pub line: usize,
+}
+
+pub(crate) fn tokenize(source: &str) -> Result<Vec<Spanned>, Error> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    let peek = |i: usize| chars.get(i).copied();
+
+    while let Some(c) = peek(i) {
+        let start_line = line;
+        let token = match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+                continue;
+            }
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            // Comments run to the end of the line, or to the next `--`.
+            '-' if peek(i + 1) == Some('-') => {
+                i += 2;
+                while let Some(c) = peek(i) {
+                    if c == '\n' {
+                        break;
+                    } else if c == '-' && peek(i + 1) == Some('-') {
+                        i += 2;
+                        break;
+                    }
+                    i += 1;
+                }
+                continue;
+            }
+            // Block comments may be nested.
+            '/' if peek(i + 1) == Some('*') => {
+                let mut depth = 0;
+                while let Some(c) = peek(i) {
+                    if c == '/' && peek(i + 1) == Some('*') {
+                        depth += 1;
+                        i += 2;
+                    } else if c == '*' && peek(i + 1) == Some('/') {
+                        depth -= 1;
+                        i += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        if c == '\n' {
+                            line += 1;
+                        }
+                        i += 1;
+                    }
+                }
+                if depth != 0 {
+                    return Err(Error::syntax(start_line, "unterminated comment"));
+                }
+                continue;
+            }
+            ':' if peek(i + 1) == Some(':') && peek(i + 2) == Some('=') => {
+                i += 3;
+                Token::Assignment
+            }
+            '.' if peek(i + 1) == Some('.') => {
+                if peek(i + 2) == Some('.') {
+                    i += 3;
+                    Token::Ellipsis
+                } else {
+                    i += 2;
+                    Token::Range
+                }
+            }
+            '[' if peek(i + 1) == Some('[') => {
+                i += 2;
+                Token::LeftVersionBrackets
+            }
+            ']' if peek(i + 1) == Some(']') => {
+                i += 2;
+                Token::RightVersionBrackets
+            }
+            '"' => {
+                let mut string = String::new();
+                i += 1;
+                loop {
+                    match peek(i) {
+                        Some('"') if peek(i + 1) == Some('"') => {
+                            string.push('"');
+                            i += 2;
+                        }
+                        Some('"') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            string.push(c);
+                            i += 1;
+                        }
+                        None => return Err(Error::syntax(start_line, "unterminated string")),
+                    }
+                }
+                Token::CString(string)
+            }
+            '\'' => {
+                let mut string = String::new();
+                i += 1;
+                loop {
+                    match peek(i) {
+                        Some('\'') => {
+                            i += 1;
+                            break;
+                        }
+                        Some(c) if c.is_whitespace() => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            i += 1;
+                        }
+                        Some(c) => {
+                            string.push(c);
+                            i += 1;
+                        }
+                        None => return Err(Error::syntax(start_line, "unterminated string")),
+                    }
+                }
+                i += 1;
+                match peek(i - 1) {
+                    Some('B') if string.chars().all(|c| c == '0' || c == '1') => {
+                        Token::BString(string)
+                    }
+                    Some('H') if string.chars().all(|c| c.is_ascii_hexdigit()) => {
+                        Token::HString(string)
+                    }
+                    _ => {
+                        return Err(Error::syntax(
+                            start_line,
+                            format!("invalid binary or hexadecimal string '{string}'"),
+                        ))
+                    }
+                }
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while peek(i).is_some_and(|c| c.is_ascii_digit()) {
+                    i += 1;
+                }
+                if peek(i) == Some('.') && peek(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+                    i += 1;
+                    while peek(i).is_some_and(|c| c.is_ascii_digit()) {
+                        i += 1;
+                    }
+                    Token::Real(chars[start..i].iter().collect())
+                } else {
+                    let digits: String = chars[start..i].iter().collect();
+                    Token::Number(digits.parse().map_err(|_| {
+                        Error::syntax(start_line, format!("number `{digits}` is too large"))
+                    })?)
+                }
+            }
+            c if c.is_alphabetic()
+                || (c == '&' && peek(i + 1).is_some_and(char::is_alphabetic)) =>
+            {
+                let start = i;
+                i += 1;
+                loop {
+                    match peek(i) {
+                        Some(c) if c.is_alphanumeric() || c == '_' => i += 1,
+                        // Hyphens may not be doubled or end an identifier.
+                        Some('-') if peek(i + 1).is_some_and(|c| c.is_alphanumeric()) => i += 1,
+                        _ => break,
+                    }
+                }
+                Token::Identifier(chars[start..i].iter().collect())
+            }
+            '{' | '}' | '(' | ')' | '[' | ']' | ',' | ';' | '.' | '|' | '^' | '<' | '>' | '@'
+            | '!' | ':' | '-' | '*' | '&' | '=' => {
+                i += 1;
+                Token::Symbol(c)
+            }
+            c => {
+                return Err(Error::syntax(
+                    start_line,
+                    format!("unexpected character `{c}`"),
+                ))
+            }
+        };
+
+        tokens.push(Spanned {
+            token,
+            line: start_line,
+        });
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(source: &str) -> Vec<Token> {
+        tokenize(source)
+            .unwrap()
+            .into_iter()
+            .map(|spanned| spanned.token)
+            .collect()
+    }
+
+    #[test]
+    fn comments_and_identifiers() {
+        assert_eq!(
+            tokens("Foo-Bar ::= -- a comment -- INTEGER /* nested /* block */ */ (-1..10)"),
+            vec![
+                Token::Identifier("Foo-Bar".into()),
+                Token::Assignment,
+                Token::Identifier("INTEGER".into()),
+                Token::Symbol('('),
+                Token::Symbol('-'),
+                Token::Number(1),
+                Token::Range,
+                Token::Number(10),
+                Token::Symbol(')'),
+            ]
+        );
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            tokens("'0101'B 'AF'H \"say \"\"hi\"\"\" [[ ]] ..."),
+            vec![
+                Token::BString("0101".into()),
+                Token::HString("AF".into()),
+                Token::CString("say \"hi\"".into()),
+                Token::LeftVersionBrackets,
+                Token::RightVersionBrackets,
+                Token::Ellipsis,
+            ]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! # rasn-compiler
+//! Compiles ASN.1 modules into Rust source that uses `rasn`'s derive macros,
+//! so that schemas don't have to be transcribed by hand.
+//!
+//! Each ASN.1 module becomes a Rust module named after it in `snake_case`.
+//! Types are generated as structs and enums with the matching `#[rasn(...)]`
+//! attributes, and values as constants. The module's tagging default and
+//! `EXTENSIBILITY IMPLIED` are taken into account, and constraints that are
+//! visible to the encoding rules become `size`, `value`, and `from`
+//! attributes. References to `IMPORTS` are resolved against the other modules
+//! given to the same [`Compiler`].
+//!
+//! Assignments the compiler can't represent, such as information object
+//! classes and parameterized types, are skipped, along with anything that
+//! depends on them, and noted in a comment in their place.
+//!
+//! ## `build.rs`
+//! ```no_run
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+//!     let source = rasn_compiler::Compiler::new()
+//!         .add_file("asn1/PDU-Definitions.asn")?
+//!         .add_file("asn1/Common-Types.asn")?
+//!         .compile()?;
+//!
+//!     std::fs::write(out_dir.join("generated.rs"), source)?;
+//!     println!("cargo:rerun-if-changed=asn1");
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The generated modules can then be included with
+//! `include!(concat!(env!("OUT_DIR"), "/generated.rs"));`.
+//!
+//! ## Command line
+//! ```text
+//! rasn-compiler [-o OUTPUT] FILE...
+//! ```
+
+mod ast;
+mod generator;
+mod lexer;
+mod parser;
+
+use std::path::{Path, PathBuf};
+
+/// Parses ASN.1 modules and generates Rust source for them.
+#[derive(Clone, Debug, Default)]
+pub struct Compiler {
+    modules: Vec<ast::Module>,
+}
+
+impl Compiler {
+    /// Creates a compiler with no modules.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the modules in `source`, adding them to those to be compiled.
+    ///
+    /// # Errors
+    /// Returns an error if `source` isn't valid ASN.1, or if it defines a
+    /// module that has already been added.
+    pub fn add_source(&mut self, source: &str) -> Result<&mut Self, Error> {
+        let modules = parser::parse(lexer::tokenize(source)?)?;
+
+        for module in modules {
+            if self.modules.iter().any(|added| added.name == module.name) {
+                return Err(Error::DuplicateModule { name: module.name });
+            }
+            self.modules.push(module);
+        }
+
+        Ok(self)
+    }
+
+    /// Reads and parses the modules in the file at `path`, adding them to
+    /// those to be compiled.
+    ///
+    /// # Errors
+    /// Returns an error if the file can't be read, isn't valid ASN.1, or
+    /// defines a module that has already been added.
+    pub fn add_file(&mut self, path: impl AsRef<Path>) -> Result<&mut Self, Error> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|error| Error::Io {
+            path: path.to_owned(),
+            error,
+        })?;
+
+        self.add_source(&source).map_err(|error| match error {
+            Error::Syntax { line, message, .. } => Error::Syntax {
+                path: Some(path.to_owned()),
+                line,
+                message,
+            },
+            error => error,
+        })
+    }
+
+    /// Generates Rust source for every module that has been added.
+    ///
+    /// # Errors
+    /// Returns an error if a module imports from a module that hasn't been
+    /// added, or refers to something that isn't defined.
+    pub fn compile(&self) -> Result<String, Error> {
+        generator::generate(&self.modules)
+    }
+}
+
+/// An error that occurred while compiling ASN.1 modules.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A file couldn't be read.
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying error.
+        error: std::io::Error,
+    },
+    /// The source isn't valid ASN.1.
+    Syntax {
+        /// The path of the file, if the source was read from one.
+        path: Option<PathBuf>,
+        /// The line the error occurred on.
+        line: usize,
+        /// A description of the error.
+        message: String,
+    },
+    /// More than one module has the same name.
+    DuplicateModule {
+        /// The name of the module.
+        name: String,
+    },
+    /// A module imports from, or refers to, a module that wasn't added.
+    UnknownModule {
+        /// The name of the missing module.
+        name: String,
+        /// The module that refers to it.
+        referenced_by: String,
+    },
+    /// A reference doesn't name anything defined in, or imported by, its
+    /// module.
+    UnknownReference {
+        /// The name of the reference.
+        name: String,
+        /// The module the reference was looked up in.
+        module: String,
+    },
+    /// A definition is syntactically valid, but not meaningful, such as
+    /// `COMPONENTS OF` a type that isn't a `SEQUENCE` or `SET`.
+    Invalid {
+        /// The module the definition is in.
+        module: String,
+        /// A description of the error.
+        message: String,
+    },
+}
+
+impl Error {
+    pub(crate) fn syntax(line: usize, message: impl Into<String>) -> Self {
+        Self::Syntax {
+            path: None,
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            Self::Syntax {
+                path: Some(path),
+                line,
+                message,
+            } => write!(f, "{}:{line}: {message}", path.display()),
+            Self::Syntax {
+                path: None,
+                line,
+                message,
+            } => write!(f, "line {line}: {message}"),
+            Self::DuplicateModule { name } => {
+                write!(f, "module `{name}` is defined more than once")
+            }
+            Self::UnknownModule {
+                name,
+                referenced_by,
+            } => write!(
+                f,
+                "module `{name}`, referred to by `{referenced_by}`, was not added to the compiler"
+            ),
+            Self::UnknownReference { name, module } => {
+                write!(
+                    f,
+                    "`{name}` is not defined in or imported by module `{module}`"
+                )
+            }
+            Self::Invalid { module, message } => write!(f, "module `{module}`: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
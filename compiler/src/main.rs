@@ -0,0 +1,63 @@
+//! Compiles ASN.1 modules into Rust source for `rasn`.
+//!
+//! ```text
+//! rasn-compiler [-o OUTPUT] FILE...
+//! ```
+//!
+//! Writes to standard output when no output file is given.
+
+const USAGE: &str = "Usage: rasn-compiler [-o OUTPUT] FILE...
+
+Generates Rust types for the ASN.1 modules in each FILE.
+
+Options:
+  -o, --output OUTPUT  Write the generated source to OUTPUT
+  -h, --help           Print this message";
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("rasn-compiler: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut compiler = rasn_compiler::Compiler::new();
+    let mut output = None;
+    let mut has_files = false;
+    let mut arguments = std::env::args().skip(1);
+
+    while let Some(argument) = arguments.next() {
+        match &*argument {
+            "-o" | "--output" => {
+                output = Some(
+                    arguments
+                        .next()
+                        .ok_or_else(|| format!("`{argument}` requires a path\n\n{USAGE}"))?,
+                );
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if !argument.starts_with('-') => {
+                compiler.add_file(&argument).map_err(|e| e.to_string())?;
+                has_files = true;
+            }
+            _ => return Err(format!("unexpected argument `{argument}`\n\n{USAGE}")),
+        }
+    }
+
+    if !has_files {
+        return Err(format!("no files given\n\n{USAGE}"));
+    }
+
+    let source = compiler.compile().map_err(|e| e.to_string())?;
+    match output {
+        Some(path) => std::fs::write(&path, source).map_err(|e| format!("{path}: {e}")),
+        None => {
+            print!("{source}");
+            Ok(())
+        }
+    }
+}
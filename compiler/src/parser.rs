@@ -0,0 +1,1259 @@
+//! A recursive descent parser for X.680 modules.
+//!
+//! Assignments that can't be represented, such as information object classes
+//! and parameterized types, are recorded as unsupported and skipped so the
+//! rest of the module can still be compiled.
+
+use crate::{
+    ast::*,
+    lexer::{Spanned, Token},
+    Error,
+};
+
+/// Words reserved by X.680, which can't be used as references.
+const RESERVED_WORDS: &[&str] = &[
+    "ABSENT",
+    "ABSTRACT-SYNTAX",
+    "ALL",
+    "APPLICATION",
+    "AUTOMATIC",
+    "BEGIN",
+    "BIT",
+    "BMPString",
+    "BOOLEAN",
+    "BY",
+    "CHARACTER",
+    "CHOICE",
+    "CLASS",
+    "COMPONENT",
+    "COMPONENTS",
+    "CONSTRAINED",
+    "CONTAINING",
+    "DATE",
+    "DATE-TIME",
+    "DEFAULT",
+    "DEFINITIONS",
+    "DURATION",
+    "EMBEDDED",
+    "ENCODED",
+    "END",
+    "ENUMERATED",
+    "EXCEPT",
+    "EXPLICIT",
+    "EXPORTS",
+    "EXTENSIBILITY",
+    "EXTERNAL",
+    "FALSE",
+    "FROM",
+    "GeneralizedTime",
+    "GeneralString",
+    "GraphicString",
+    "IA5String",
+    "IDENTIFIER",
+    "IMPLICIT",
+    "IMPLIED",
+    "IMPORTS",
+    "INCLUDES",
+    "INSTANCE",
+    "INSTRUCTIONS",
+    "INTEGER",
+    "INTERSECTION",
+    "ISO646String",
+    "MAX",
+    "MIN",
+    "MINUS-INFINITY",
+    "NOT-A-NUMBER",
+    "NULL",
+    "NumericString",
+    "OBJECT",
+    "ObjectDescriptor",
+    "OCTET",
+    "OF",
+    "OID-IRI",
+    "OPTIONAL",
+    "PATTERN",
+    "PDV",
+    "PLUS-INFINITY",
+    "PRESENT",
+    "PrintableString",
+    "PRIVATE",
+    "REAL",
+    "RELATIVE-OID",
+    "RELATIVE-OID-IRI",
+    "SEQUENCE",
+    "SET",
+    "SETTINGS",
+    "SIZE",
+    "STRING",
+    "SYNTAX",
+    "T61String",
+    "TAGS",
+    "TeletexString",
+    "TIME",
+    "TIME-OF-DAY",
+    "TRUE",
+    "TYPE-IDENTIFIER",
+    "UNION",
+    "UNIQUE",
+    "UNIVERSAL",
+    "UniversalString",
+    "UTCTime",
+    "UTF8String",
+    "VideotexString",
+    "VisibleString",
+    "WITH",
+];
+
+const CHARACTER_STRINGS: &[&str] = &[
+    "BMPString",
+    "GeneralString",
+    "GraphicString",
+    "IA5String",
+    "ISO646String",
+    "NumericString",
+    "PrintableString",
+    "T61String",
+    "TeletexString",
+    "UniversalString",
+    "UTF8String",
+    "VideotexString",
+    "VisibleString",
+];
+
+const TIMES: &[&str] = &[
+    "DATE",
+    "DATE-TIME",
+    "DURATION",
+    "GeneralizedTime",
+    "TIME",
+    "TIME-OF-DAY",
+    "UTCTime",
+];
+
+/// Parses every module in `tokens`.
+pub(crate) fn parse(tokens: Vec<Spanned>) -> Result<Vec<Module>, Error> {
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+    };
+    let mut modules = Vec::new();
+
+    while parser.peek().is_some() {
+        modules.push(parser.module()?);
+    }
+
+    Ok(modules)
+}
+
+fn is_reserved(identifier: &str) -> bool {
+    RESERVED_WORDS.contains(&identifier)
+}
+
+fn is_type_reference(identifier: &str) -> bool {
+    identifier.starts_with(char::is_uppercase) && !is_reserved(identifier)
+}
+
+fn is_value_reference(identifier: &str) -> bool {
+    identifier.starts_with(char::is_lowercase)
+}
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.peek_nth(0)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens
+            .get(self.position + n)
+            .map(|spanned| &spanned.token)
+    }
+
+    fn next(&mut self) -> Result<Token, Error> {
+        let token = self
+            .peek()
+            .cloned()
+            .ok_or_else(|| Error::syntax(self.line(), "unexpected end of input"))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or_else(|| self.tokens.last())
+            .map_or(1, |spanned| spanned.line)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, Error> {
+        Err(Error::syntax(self.line(), message))
+    }
+
+    fn unexpected<T>(&self, expected: &str) -> Result<T, Error> {
+        match self.peek() {
+            Some(token) => self.error(format!("expected {expected}, found `{token}`")),
+            None => self.error(format!("expected {expected}, found end of input")),
+        }
+    }
+
+    fn is_keyword_at(&self, n: usize, keyword: &str) -> bool {
+        matches!(self.peek_nth(n), Some(Token::Identifier(identifier)) if identifier == keyword)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.is_keyword_at(0, keyword)
+    }
+
+    fn is_symbol_at(&self, n: usize, symbol: char) -> bool {
+        self.peek_nth(n) == Some(&Token::Symbol(symbol))
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.is_symbol_at(0, symbol)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let found = self.peek() == Some(token);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.is_keyword(keyword);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        self.eat(&Token::Symbol(symbol))
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<(), Error> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            self.unexpected(&format!("`{token}`"))
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), Error> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            self.unexpected(&format!("`{keyword}`"))
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), Error> {
+        self.expect(&Token::Symbol(symbol))
+    }
+
+    fn identifier(&mut self) -> Result<String, Error> {
+        match self.peek() {
+            Some(Token::Identifier(identifier)) if !is_reserved(identifier) => {
+                let identifier = identifier.clone();
+                self.position += 1;
+                Ok(identifier)
+            }
+            _ => self.unexpected("an identifier"),
+        }
+    }
+
+    fn number(&mut self) -> Result<u128, Error> {
+        match self.peek() {
+            Some(&Token::Number(number)) => {
+                self.position += 1;
+                Ok(number)
+            }
+            _ => self.unexpected("a number"),
+        }
+    }
+
+    /// Skips a bracketed group of tokens, such as `{ ... }`, if one starts at
+    /// the current token.
+    fn skip_balanced(&mut self) -> Result<(), Error> {
+        let mut depth = 0usize;
+        loop {
+            let line = self.line();
+            match self.next()? {
+                Token::Symbol('{' | '(' | '[') | Token::LeftVersionBrackets => depth += 1,
+                Token::Symbol('}' | ')' | ']') | Token::RightVersionBrackets => {
+                    depth = depth
+                        .checked_sub(1)
+                        .ok_or_else(|| Error::syntax(line, "unbalanced brackets"))?;
+                }
+                _ => {}
+            }
+
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Skips tokens until `stop` returns `true` for a token outside of any
+    /// brackets, leaving that token unconsumed.
+    fn skip_until(&mut self, stop: impl Fn(&Self) -> bool) -> Result<(), Error> {
+        while !stop(self) {
+            match self.peek() {
+                Some(Token::Symbol('{' | '(' | '[') | Token::LeftVersionBrackets) => {
+                    self.skip_balanced()?
+                }
+                Some(Token::Symbol('}' | ')' | ']') | Token::RightVersionBrackets) | None => {
+                    return self.unexpected("the end of the construct")
+                }
+                Some(_) => self.position += 1,
+            }
+        }
+        Ok(())
+    }
+
+    /// The source text of the tokens from `start` to the current position.
+    fn source_since(&self, start: usize) -> String {
+        self.tokens[start..self.position]
+            .iter()
+            .map(|spanned| spanned.token.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn module(&mut self) -> Result<Module, Error> {
+        let name = self.identifier()?;
+        if self.is_symbol('{') {
+            self.skip_balanced()?;
+        } else if matches!(self.peek(), Some(Token::CString(_))) {
+            self.position += 1;
+        }
+        self.expect_keyword("DEFINITIONS")?;
+
+        // Encoding instructions, as in `XER INSTRUCTIONS`, don't affect the
+        // generated types.
+        if self.is_keyword_at(1, "INSTRUCTIONS") {
+            self.position += 2;
+        }
+
+        let mut tagging = TaggingMode::Explicit;
+        for (keyword, mode) in [
+            ("EXPLICIT", TaggingMode::Explicit),
+            ("IMPLICIT", TaggingMode::Implicit),
+            ("AUTOMATIC", TaggingMode::Automatic),
+        ] {
+            if self.eat_keyword(keyword) {
+                self.expect_keyword("TAGS")?;
+                tagging = mode;
+            }
+        }
+
+        let extensibility_implied = self.eat_keyword("EXTENSIBILITY");
+        if extensibility_implied {
+            self.expect_keyword("IMPLIED")?;
+        }
+
+        self.expect(&Token::Assignment)?;
+        self.expect_keyword("BEGIN")?;
+
+        if self.eat_keyword("EXPORTS") {
+            self.skip_until(|parser| parser.is_symbol(';'))?;
+            self.expect_symbol(';')?;
+        }
+
+        let imports = if self.eat_keyword("IMPORTS") {
+            self.imports()?
+        } else {
+            Vec::new()
+        };
+
+        let mut assignments = Vec::new();
+        while !self.eat_keyword("END") {
+            if self.peek().is_none() {
+                return self.unexpected("`END`");
+            }
+            assignments.push(self.assignment()?);
+        }
+
+        Ok(Module {
+            name,
+            tagging,
+            extensibility_implied,
+            imports,
+            assignments,
+        })
+    }
+
+    fn imports(&mut self) -> Result<Vec<Import>, Error> {
+        let mut imports = Vec::new();
+        let mut symbols = Vec::new();
+
+        while !self.eat_symbol(';') {
+            if self.eat_keyword("FROM") {
+                let module = self.identifier()?;
+                if self.is_symbol('{') {
+                    self.skip_balanced()?;
+                } else if matches!(self.peek(), Some(Token::Identifier(identifier)) if is_value_reference(identifier))
+                    && !self.is_symbol_at(1, ',')
+                    && !self.is_keyword_at(1, "FROM")
+                {
+                    // A value reference naming the module's object identifier,
+                    // rather than the first symbol of the next list.
+                    self.position += 1;
+                }
+                imports.push(Import {
+                    symbols: core::mem::take(&mut symbols),
+                    module,
+                });
+                continue;
+            }
+
+            symbols.push(self.identifier()?);
+            // Parameterized references are imported as `Name{}`.
+            if self.is_symbol('{') {
+                self.skip_balanced()?;
+            }
+            if !self.eat_symbol(',') && !self.is_keyword("FROM") {
+                return self.unexpected("`,` or `FROM`");
+            }
+        }
+
+        Ok(imports)
+    }
+
+    fn assignment(&mut self) -> Result<Assignment, Error> {
+        let start = self.position;
+        let name = self.identifier()?;
+
+        let result = if self.is_keyword("MACRO") {
+            self.skip_until(|parser| parser.is_keyword("END"))?;
+            self.position += 1;
+            return Ok(Assignment::Unsupported {
+                name,
+                reason: String::from("macros are not supported"),
+            });
+        } else if self.is_symbol('{') {
+            Err(String::from("parameterized assignments are not supported"))
+        } else if is_value_reference(&name) {
+            self.value_assignment(name.clone())
+        } else if self.eat(&Token::Assignment) {
+            self.type_assignment(name.clone())
+        } else {
+            Err(String::from(
+                "value set and information object set assignments are not supported",
+            ))
+        };
+
+        match result {
+            Ok(assignment) => Ok(assignment),
+            Err(reason) => {
+                self.position = start + 1;
+                self.skip_assignment()?;
+                Ok(Assignment::Unsupported { name, reason })
+            }
+        }
+    }
+
+    /// Parses the right hand side of a type assignment, returning the reason
+    /// it is unsupported if it can't be represented.
+    fn type_assignment(&mut self, name: String) -> Result<Assignment, String> {
+        if self.is_keyword("CLASS") || self.is_keyword("TYPE-IDENTIFIER") {
+            return Err(String::from("information object classes are not supported"));
+        }
+
+        let ty = self.ty().map_err(reason)?;
+        if !self.at_assignment_end() {
+            return Err(reason(
+                self.unexpected::<()>("the next assignment").unwrap_err(),
+            ));
+        }
+
+        Ok(Assignment::Type { name, ty })
+    }
+
+    fn value_assignment(&mut self, name: String) -> Result<Assignment, String> {
+        let assignment = (|| {
+            let ty = self.ty()?;
+            self.expect(&Token::Assignment)?;
+            let value = self.value()?;
+            Ok::<_, Error>(Assignment::Value { name, ty, value })
+        })()
+        .map_err(reason)?;
+
+        if !self.at_assignment_end() {
+            return Err(String::from(
+                "information object assignments are not supported",
+            ));
+        }
+
+        Ok(assignment)
+    }
+
+    fn at_assignment_end(&self) -> bool {
+        self.is_keyword("END") || self.is_assignment_start(0)
+    }
+
+    /// Whether the `n`th token looks like the start of an assignment.
+    fn is_assignment_start(&self, n: usize) -> bool {
+        let Some(Token::Identifier(name)) = self.peek_nth(n) else {
+            return false;
+        };
+        if is_reserved(name) || name.starts_with('&') {
+            return false;
+        }
+
+        let is_assignment_at = |n| self.peek_nth(n) == Some(&Token::Assignment);
+        let is_identifier_at = |n| matches!(self.peek_nth(n), Some(Token::Identifier(_)));
+
+        if is_assignment_at(n + 1) || self.is_keyword_at(n + 1, "MACRO") {
+            return true;
+        }
+
+        if self.is_symbol_at(n + 1, '{') {
+            let mut depth = 0usize;
+            for i in n + 1.. {
+                match self.peek_nth(i) {
+                    Some(Token::Symbol('{')) => depth += 1,
+                    Some(Token::Symbol('}')) => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return is_assignment_at(i + 1);
+                        }
+                    }
+                    None => return false,
+                    _ => {}
+                }
+            }
+        }
+
+        if is_value_reference(name) {
+            // `name Type ::=`, `name Module.Type ::=`, or `name BUILTIN TYPE ::=`.
+            is_identifier_at(n + 1)
+                && (is_assignment_at(n + 2)
+                    || (self.is_symbol_at(n + 2, '.') && is_assignment_at(n + 4))
+                    || (is_identifier_at(n + 2) && is_assignment_at(n + 3)))
+        } else {
+            // `Name Class ::= {` for value and object sets.
+            matches!(self.peek_nth(n + 1), Some(Token::Identifier(class)) if is_type_reference(class) || class == "TYPE-IDENTIFIER")
+                && is_assignment_at(n + 2)
+                && self.is_symbol_at(n + 3, '{')
+        }
+    }
+
+    /// Skips to the start of the next assignment, or the end of the module.
+    fn skip_assignment(&mut self) -> Result<(), Error> {
+        // Skip past the assignment's own `::=` first.
+        self.skip_until(|parser| parser.peek() == Some(&Token::Assignment))?;
+        self.position += 1;
+        self.skip_until(|parser| parser.at_assignment_end())
+    }
+
+    fn ty(&mut self) -> Result<Type, Error> {
+        if self.eat_symbol('[') {
+            let class = if self.eat_keyword("UNIVERSAL") {
+                Class::Universal
+            } else if self.eat_keyword("APPLICATION") {
+                Class::Application
+            } else if self.eat_keyword("PRIVATE") {
+                Class::Private
+            } else {
+                Class::Context
+            };
+            let number = match self.peek() {
+                Some(&Token::Number(number)) => {
+                    self.position += 1;
+                    Value::Integer(number as i128)
+                }
+                _ => Value::Reference(Reference {
+                    module: None,
+                    name: self.identifier()?,
+                }),
+            };
+            self.expect_symbol(']')?;
+
+            let kind = if self.eat_keyword("IMPLICIT") {
+                TagKind::Implicit
+            } else if self.eat_keyword("EXPLICIT") {
+                TagKind::Explicit
+            } else {
+                TagKind::Default
+            };
+
+            let mut ty = self.ty()?;
+            if ty.tag.is_some() {
+                return self.error("types with more than one tag are not supported");
+            }
+            ty.tag = Some(Tag {
+                class,
+                number,
+                kind,
+            });
+            return Ok(ty);
+        }
+
+        // `SEQUENCE SIZE (..) OF` and `SEQUENCE (..) OF` constrain the
+        // collection, and are parsed along with it.
+        let mut constraints = Vec::new();
+        let mut ty = Type::new(self.type_kind(&mut constraints)?);
+        ty.constraints = constraints;
+        while self.is_symbol('(') {
+            ty.constraints.push(self.constraint()?);
+        }
+
+        Ok(ty)
+    }
+
+    fn type_kind(&mut self, constraints: &mut Vec<Constraint>) -> Result<TypeKind, Error> {
+        let Some(Token::Identifier(keyword)) = self.peek().cloned() else {
+            return self.unexpected("a type");
+        };
+        self.position += 1;
+
+        let kind = match &*keyword {
+            "BOOLEAN" => TypeKind::Boolean,
+            "NULL" => TypeKind::Null,
+            "REAL" => TypeKind::Real,
+            "INTEGER" => {
+                let mut named_numbers = Vec::new();
+                if self.eat_symbol('{') {
+                    loop {
+                        let name = self.identifier()?;
+                        self.expect_symbol('(')?;
+                        let value = self.value()?;
+                        self.expect_symbol(')')?;
+                        named_numbers.push(NamedNumber { name, value });
+                        if !self.eat_symbol(',') {
+                            break;
+                        }
+                    }
+                    self.expect_symbol('}')?;
+                }
+                TypeKind::Integer(named_numbers)
+            }
+            "ENUMERATED" => TypeKind::Enumerated(self.enumeration()?),
+            "BIT" => {
+                self.expect_keyword("STRING")?;
+                // Named bits only give names to positions, and aren't needed
+                // to encode or decode the value.
+                if self.is_symbol('{') {
+                    self.skip_balanced()?;
+                }
+                TypeKind::BitString
+            }
+            "OCTET" => {
+                self.expect_keyword("STRING")?;
+                TypeKind::OctetString
+            }
+            "OBJECT" => {
+                self.expect_keyword("IDENTIFIER")?;
+                TypeKind::ObjectIdentifier
+            }
+            "RELATIVE-OID" => TypeKind::RelativeOid,
+            "ObjectDescriptor" => TypeKind::ObjectDescriptor,
+            "EXTERNAL" => TypeKind::External,
+            "EMBEDDED" => {
+                self.expect_keyword("PDV")?;
+                TypeKind::EmbeddedPdv
+            }
+            "CHARACTER" => {
+                self.expect_keyword("STRING")?;
+                TypeKind::UnrestrictedCharacterString
+            }
+            "ANY" => {
+                if self.eat_keyword("DEFINED") {
+                    self.expect_keyword("BY")?;
+                    self.identifier()?;
+                }
+                TypeKind::Any
+            }
+            "SEQUENCE" | "SET" => {
+                let is_sequence = keyword == "SEQUENCE";
+                if self.is_symbol('{') {
+                    let components = self.components()?;
+                    if is_sequence {
+                        TypeKind::Sequence(components)
+                    } else {
+                        TypeKind::Set(components)
+                    }
+                } else {
+                    if self.is_symbol('(') {
+                        constraints.push(self.constraint()?);
+                    } else if self.eat_keyword("SIZE") {
+                        constraints.push(Constraint {
+                            root: ElementSet::Element(Element::Size(Box::new(self.constraint()?))),
+                            extensible: false,
+                        });
+                    }
+                    self.expect_keyword("OF")?;
+
+                    // The element may be named, as in `SEQUENCE OF item Item`.
+                    if matches!(self.peek(), Some(Token::Identifier(name)) if is_value_reference(name))
+                    {
+                        self.position += 1;
+                    }
+                    let element = Box::new(self.ty()?);
+
+                    if is_sequence {
+                        TypeKind::SequenceOf(element)
+                    } else {
+                        TypeKind::SetOf(element)
+                    }
+                }
+            }
+            "CHOICE" => TypeKind::Choice(self.components()?),
+            "INSTANCE" => return self.error("`INSTANCE OF` is not supported"),
+            keyword if CHARACTER_STRINGS.contains(&keyword) => {
+                TypeKind::CharacterString(keyword.to_owned())
+            }
+            keyword if TIMES.contains(&keyword) => TypeKind::Time(keyword.to_owned()),
+            name if is_type_reference(name) || name == "TYPE-IDENTIFIER" => {
+                if self.is_symbol('.') {
+                    self.position += 1;
+                    match self.next()? {
+                        Token::Identifier(field) if field.starts_with("&") => {
+                            if field[1..].starts_with(char::is_uppercase) {
+                                TypeKind::Any
+                            } else {
+                                return self.error(
+                                    "information object class value fields are not supported",
+                                );
+                            }
+                        }
+                        Token::Identifier(reference) if is_type_reference(&reference) => {
+                            TypeKind::Reference(Reference {
+                                module: Some(name.to_owned()),
+                                name: reference,
+                            })
+                        }
+                        _ => return self.error("expected a type reference"),
+                    }
+                } else if self.is_symbol('{') {
+                    return self.error("parameterized types are not supported");
+                } else if name == "TYPE-IDENTIFIER" {
+                    return self.error("information object classes are not supported");
+                } else {
+                    TypeKind::Reference(Reference {
+                        module: None,
+                        name: name.to_owned(),
+                    })
+                }
+            }
+            _ => {
+                self.position -= 1;
+                return self.unexpected("a type");
+            }
+        };
+
+        Ok(kind)
+    }
+
+    fn enumeration(&mut self) -> Result<Enumeration, Error> {
+        let mut enumeration = Enumeration {
+            root: Vec::new(),
+            extensible: false,
+            additions: Vec::new(),
+        };
+
+        self.expect_symbol('{')?;
+        loop {
+            if self.eat(&Token::Ellipsis) {
+                if enumeration.extensible {
+                    return self.error("enumerations may only have one extension marker");
+                }
+                enumeration.extensible = true;
+                if self.eat_symbol('!') {
+                    self.skip_until(|parser| parser.is_symbol(',') || parser.is_symbol('}'))?;
+                }
+            } else {
+                let name = self.identifier()?;
+                let value = if self.eat_symbol('(') {
+                    let value = self.value()?;
+                    self.expect_symbol(')')?;
+                    Some(value)
+                } else {
+                    None
+                };
+                let enumeral = Enumeral { name, value };
+                if enumeration.extensible {
+                    enumeration.additions.push(enumeral);
+                } else {
+                    enumeration.root.push(enumeral);
+                }
+            }
+
+            if !self.eat_symbol(',') {
+                break;
+            }
+        }
+        self.expect_symbol('}')?;
+
+        Ok(enumeration)
+    }
+
+    fn components(&mut self) -> Result<Components, Error> {
+        let mut components = Components::default();
+        let mut markers = 0;
+
+        self.expect_symbol('{')?;
+        if self.eat_symbol('}') {
+            return Ok(components);
+        }
+
+        loop {
+            if self.eat(&Token::Ellipsis) {
+                markers += 1;
+                if markers > 2 {
+                    return self.error("too many extension markers");
+                }
+                components.extensible = true;
+                if self.eat_symbol('!') {
+                    self.skip_until(|parser| parser.is_symbol(',') || parser.is_symbol('}'))?;
+                }
+            } else if self.eat(&Token::LeftVersionBrackets) {
+                if markers != 1 {
+                    return self.error("extension addition groups must follow an extension marker");
+                }
+                if matches!(self.peek(), Some(Token::Number(_))) {
+                    self.position += 1;
+                    self.expect_symbol(':')?;
+                }
+                let mut members = Vec::new();
+                loop {
+                    members.push(self.member()?);
+                    if !self.eat_symbol(',') {
+                        break;
+                    }
+                }
+                self.expect(&Token::RightVersionBrackets)?;
+                components.additions.push(Addition::Group(members));
+            } else {
+                let member = self.member()?;
+                match markers {
+                    0 => components.root.push(member),
+                    1 => components
+                        .additions
+                        .push(Addition::Member(Box::new(member))),
+                    _ => components.trailing_root.push(member),
+                }
+            }
+
+            if !self.eat_symbol(',') {
+                break;
+            }
+        }
+        self.expect_symbol('}')?;
+
+        Ok(components)
+    }
+
+    fn member(&mut self) -> Result<Member, Error> {
+        if self.eat_keyword("COMPONENTS") {
+            self.expect_keyword("OF")?;
+            return Ok(Member::ComponentsOf(self.ty()?));
+        }
+
+        let name = self.identifier()?;
+        if !is_value_reference(&name) {
+            return self.error(format!(
+                "component names must start with a lowercase letter, found `{name}`"
+            ));
+        }
+        let ty = self.ty()?;
+        let optional = self.eat_keyword("OPTIONAL");
+        let default = if !optional && self.eat_keyword("DEFAULT") {
+            Some(self.value()?)
+        } else {
+            None
+        };
+
+        Ok(Member::Named(Component {
+            name,
+            ty,
+            optional,
+            default,
+        }))
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        let start = self.position;
+        let value = match self.next()? {
+            Token::Identifier(identifier) => match &*identifier {
+                "TRUE" => Value::Boolean(true),
+                "FALSE" => Value::Boolean(false),
+                "NULL" => Value::Null,
+                "PLUS-INFINITY" | "MINUS-INFINITY" | "NOT-A-NUMBER" => {
+                    Value::Unsupported(identifier)
+                }
+                _ if is_reserved(&identifier) => {
+                    self.position -= 1;
+                    return self.unexpected("a value");
+                }
+                _ if self.eat_symbol(':') => {
+                    // A `CHOICE` value, as in `alternative : value`.
+                    self.value()?;
+                    Value::Unsupported(self.source_since(start))
+                }
+                _ if self.is_symbol('.') => {
+                    self.position += 1;
+                    Value::Reference(Reference {
+                        module: Some(identifier),
+                        name: self.identifier()?,
+                    })
+                }
+                _ => Value::Reference(Reference {
+                    module: None,
+                    name: identifier,
+                }),
+            },
+            Token::Number(number) => Value::Integer(self.integer(number, false)?),
+            Token::Real(real) => Value::Real(real),
+            Token::Symbol('-') => match self.next()? {
+                Token::Number(number) => Value::Integer(self.integer(number, true)?),
+                Token::Real(real) => Value::Real(format!("-{real}")),
+                _ => {
+                    self.position -= 1;
+                    return self.unexpected("a number");
+                }
+            },
+            Token::CString(string) => Value::CString(string),
+            Token::BString(string) => Value::BString(string),
+            Token::HString(string) => Value::HString(string),
+            Token::Symbol('{') => {
+                if self.eat_symbol('}') {
+                    Value::Empty
+                } else if let Some(components) = self.object_identifier()? {
+                    Value::ObjectIdentifier(components)
+                } else {
+                    self.position = start;
+                    self.skip_balanced()?;
+                    Value::Unsupported(self.source_since(start))
+                }
+            }
+            _ => {
+                self.position -= 1;
+                return self.unexpected("a value");
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn integer(&self, number: u128, negative: bool) -> Result<i128, Error> {
+        let integer =
+            i128::try_from(number).or_else(|_| self.error(format!("`{number}` is too large")))?;
+        Ok(if negative { -integer } else { integer })
+    }
+
+    /// Parses the components of an object identifier value after its opening
+    /// brace, or returns `None` if the value isn't an object identifier.
+    fn object_identifier(&mut self) -> Result<Option<Vec<OidComponent>>, Error> {
+        let start = self.position;
+        let mut components = Vec::new();
+
+        loop {
+            let component = match self.peek().cloned() {
+                Some(Token::Symbol('}')) if !components.is_empty() => {
+                    self.position += 1;
+                    return Ok(Some(components));
+                }
+                Some(Token::Number(number)) => {
+                    self.position += 1;
+                    u32::try_from(number).ok().map(OidComponent::Number)
+                }
+                Some(Token::Identifier(name)) if is_value_reference(&name) => {
+                    self.position += 1;
+                    if self.eat_symbol('(') {
+                        let number = self.number()?;
+                        self.expect_symbol(')')?;
+                        u32::try_from(number)
+                            .ok()
+                            .map(|number| OidComponent::Named(name, number))
+                    } else {
+                        Some(OidComponent::Name(name))
+                    }
+                }
+                _ => None,
+            };
+
+            match component {
+                Some(component) => components.push(component),
+                None => {
+                    self.position = start;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    fn constraint(&mut self) -> Result<Constraint, Error> {
+        self.expect_symbol('(')?;
+        let constraint = self.constraint_spec()?;
+        self.expect_symbol(')')?;
+        Ok(constraint)
+    }
+
+    /// Parses the contents of a constraint's parentheses.
+    fn constraint_spec(&mut self) -> Result<Constraint, Error> {
+        let root = if self.is_symbol('{') {
+            // Table constraints, as in `({ObjectSet}{@field})`.
+            while self.is_symbol('{') {
+                self.skip_balanced()?;
+            }
+            ElementSet::Element(Element::Unsupported)
+        } else if self.is_keyword("CONSTRAINED") {
+            self.position += 1;
+            self.expect_keyword("BY")?;
+            self.skip_balanced()?;
+            ElementSet::Element(Element::Unsupported)
+        } else if self.is_keyword("CONTAINING") || self.is_keyword("ENCODED") {
+            self.skip_until(|parser| parser.is_symbol(')'))?;
+            ElementSet::Element(Element::Unsupported)
+        } else if self.peek() == Some(&Token::Ellipsis) {
+            ElementSet::Element(Element::Unsupported)
+        } else {
+            self.element_set()?
+        };
+
+        let mut extensible = false;
+        if self.peek() == Some(&Token::Ellipsis)
+            || (self.is_symbol(',') && self.peek_nth(1) == Some(&Token::Ellipsis))
+        {
+            self.eat_symbol(',');
+            self.position += 1;
+            extensible = true;
+            // Additional elements aren't visible to the encoding rules.
+            if self.eat_symbol(',') {
+                self.element_set()?;
+            }
+        }
+
+        if self.eat_symbol('!') {
+            self.skip_until(|parser| parser.is_symbol(')'))?;
+        }
+
+        Ok(Constraint { root, extensible })
+    }
+
+    fn element_set(&mut self) -> Result<ElementSet, Error> {
+        let mut unions = vec![self.intersections()?];
+        while self.eat_symbol('|') || self.eat_keyword("UNION") {
+            unions.push(self.intersections()?);
+        }
+
+        Ok(if unions.len() == 1 {
+            unions.remove(0)
+        } else {
+            ElementSet::Union(unions)
+        })
+    }
+
+    fn intersections(&mut self) -> Result<ElementSet, Error> {
+        let mut intersections = vec![self.element()?];
+        loop {
+            if self.eat_symbol('^') || self.eat_keyword("INTERSECTION") {
+                intersections.push(self.element()?);
+            } else if self.eat_keyword("EXCEPT") {
+                // Exclusions aren't visible to the encoding rules.
+                self.element()?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(if intersections.len() == 1 {
+            intersections.remove(0)
+        } else {
+            ElementSet::Intersection(intersections)
+        })
+    }
+
+    fn element(&mut self) -> Result<ElementSet, Error> {
+        let element = if self.is_symbol('(') {
+            self.position += 1;
+            let constraint = self.constraint_spec()?;
+            self.expect_symbol(')')?;
+            return Ok(constraint.root);
+        } else if self.eat_keyword("SIZE") {
+            Element::Size(Box::new(self.constraint()?))
+        } else if self.eat_keyword("FROM") {
+            Element::From(Box::new(self.constraint()?))
+        } else if self.eat_keyword("WITH") {
+            if self.eat_keyword("COMPONENT") {
+                self.skip_balanced()?;
+            } else {
+                self.expect_keyword("COMPONENTS")?;
+                self.skip_balanced()?;
+            }
+            Element::Unsupported
+        } else if self.eat_keyword("PATTERN") || self.eat_keyword("SETTINGS") {
+            self.value()?;
+            Element::Unsupported
+        } else if self.eat_keyword("INCLUDES") {
+            self.ty()?;
+            Element::Unsupported
+        } else if self.eat_keyword("ALL") {
+            self.expect_keyword("EXCEPT")?;
+            self.element()?;
+            Element::Unsupported
+        } else if self.is_symbol('{') {
+            self.skip_balanced()?;
+            Element::Unsupported
+        } else if matches!(self.peek(), Some(Token::Identifier(name)) if is_type_reference(name)
+            || CHARACTER_STRINGS.contains(&&**name))
+        {
+            // A contained subtype, as in `(OtherType)`.
+            self.ty()?;
+            Element::Unsupported
+        } else {
+            let lower = self.bound()?;
+            let lower = if self.eat_symbol('<') {
+                match lower {
+                    Bound::Inclusive(value) => Bound::Exclusive(value),
+                    _ => return self.error("only values can be excluded from a range"),
+                }
+            } else {
+                lower
+            };
+
+            if self.eat(&Token::Range) {
+                let exclusive = self.eat_symbol('<');
+                let upper = match self.bound()? {
+                    Bound::Inclusive(value) if exclusive => Bound::Exclusive(value),
+                    upper => upper,
+                };
+                Element::Range { lower, upper }
+            } else {
+                match lower {
+                    Bound::Inclusive(value) => Element::Single(value),
+                    _ => return self.unexpected("`..`"),
+                }
+            }
+        };
+
+        Ok(ElementSet::Element(element))
+    }
+
+    fn bound(&mut self) -> Result<Bound, Error> {
+        Ok(if self.eat_keyword("MIN") {
+            Bound::Min
+        } else if self.eat_keyword("MAX") {
+            Bound::Max
+        } else {
+            Bound::Inclusive(self.value()?)
+        })
+    }
+}
+
+/// Describes why an assignment couldn't be parsed, without its position.
+fn reason(error: Error) -> String {
+    match error {
+        Error::Syntax { message, .. } => message,
+        error => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(source: &str) -> Module {
+        parse(crate::lexer::tokenize(source).unwrap())
+            .unwrap()
+            .remove(0)
+    }
+
+    #[test]
+    fn header() {
+        let module = module(
+            "Test { iso(1) 2 } DEFINITIONS AUTOMATIC TAGS EXTENSIBILITY IMPLIED ::= BEGIN
+             EXPORTS ALL;
+             IMPORTS A, b FROM Other { 1 2 } C FROM Third third-oid D FROM Fourth;
+             END",
+        );
+
+        assert_eq!(module.name, "Test");
+        assert_eq!(module.tagging, TaggingMode::Automatic);
+        assert!(module.extensibility_implied);
+        assert_eq!(
+            module.imports,
+            vec![
+                Import {
+                    symbols: vec!["A".into(), "b".into()],
+                    module: "Other".into()
+                },
+                Import {
+                    symbols: vec!["C".into()],
+                    module: "Third".into()
+                },
+                Import {
+                    symbols: vec!["D".into()],
+                    module: "Fourth".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn constraints() {
+        let module = module(
+            "Test DEFINITIONS ::= BEGIN
+             A ::= INTEGER (0..10 | 20, ...)
+             B ::= SEQUENCE SIZE (1..max) OF INTEGER
+             END",
+        );
+
+        let Assignment::Type { ty, .. } = &module.assignments[0] else {
+            panic!("expected a type assignment");
+        };
+        assert_eq!(
+            ty.constraints,
+            vec![Constraint {
+                root: ElementSet::Union(vec![
+                    ElementSet::Element(Element::Range {
+                        lower: Bound::Inclusive(Value::Integer(0)),
+                        upper: Bound::Inclusive(Value::Integer(10)),
+                    }),
+                    ElementSet::Element(Element::Single(Value::Integer(20))),
+                ]),
+                extensible: true,
+            }]
+        );
+
+        let Assignment::Type { ty, .. } = &module.assignments[1] else {
+            panic!("expected a type assignment");
+        };
+        assert!(matches!(ty.kind, TypeKind::SequenceOf(_)));
+        assert_eq!(ty.constraints.len(), 1);
+    }
+
+    #[test]
+    fn unsupported_assignments_are_skipped() {
+        let module = module(
+            "Test DEFINITIONS ::= BEGIN
+             CLS ::= CLASS { &id INTEGER UNIQUE } WITH SYNTAX { ID &id }
+             Set CLS ::= { { ID 1 } | { ID 2 } }
+             Param { T } ::= SEQUENCE { t T }
+             A ::= BOOLEAN
+             END",
+        );
+
+        let names: Vec<_> = module
+            .assignments
+            .iter()
+            .map(|assignment| {
+                (
+                    assignment.name(),
+                    matches!(assignment, Assignment::Unsupported { .. }),
+                )
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![("CLS", true), ("Set", true), ("Param", true), ("A", false)]
+        );
+    }
+}
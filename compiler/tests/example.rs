@@ -0,0 +1,91 @@
+use pretty_assertions::assert_eq;
+use rasn::types::*;
+
+#[allow(dead_code)]
+mod generated {
+    include!("fixtures/example.rs");
+}
+
+use generated::{common_types::Identifier, example_module::*};
+
+#[test]
+fn generates_expected_source() {
+    let source = rasn_compiler::Compiler::new()
+        .add_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/Example.asn"
+        ))
+        .unwrap()
+        .compile()
+        .unwrap();
+
+    assert_eq!(source, include_str!("fixtures/example.rs"));
+}
+
+fn message() -> Message {
+    Message {
+        id: Identifier(OctetString::from_static(&[0xAB; 16])),
+        version: 1,
+        kind: Kind::Response,
+        name: Some("rasn".into()),
+        code: PrintableString::try_from("AB12").unwrap(),
+        payload: Box::new(Payload::Text(Ia5String::try_from("hello").unwrap())),
+        items: vec![Item {
+            key: VisibleString::try_from("key").unwrap(),
+            value: 7.into(),
+            tags: Some([Tag(-1), Tag(100)].into_iter().collect()),
+        }],
+        flags: BitString::repeat(false, 8),
+        priority: Some(Priority(10.into())),
+        extension_group_1: Some(MessageExtensionGroup1 {
+            timestamp: "2024-01-01T00:00:00Z".parse().unwrap(),
+            origin: Some(EXAMPLE_OID.to_owned()),
+        }),
+    }
+}
+
+#[test]
+fn generated_types_round_trip() {
+    let message = message();
+
+    // The BER decoder doesn't apply the tags of extension additions, so the
+    // tagged `priority` is only round tripped by PER.
+    let ber_message = Message {
+        priority: None,
+        ..message.clone()
+    };
+    let encoded = rasn::ber::encode(&ber_message).unwrap();
+    assert_eq!(ber_message, rasn::ber::decode(&encoded).unwrap());
+    let encoded = rasn::uper::encode(&message).unwrap();
+    assert_eq!(message, rasn::uper::decode(&encoded).unwrap());
+}
+
+#[test]
+fn recursive_types_are_boxed() {
+    let tree = Tree {
+        value: 1.into(),
+        children: vec![Tree {
+            value: 2.into(),
+            children: Vec::new(),
+            left: None,
+        }],
+        left: Some(Box::new(Tree {
+            value: 3.into(),
+            children: Vec::new(),
+            left: None,
+        })),
+    };
+
+    let encoded = rasn::uper::encode(&tree).unwrap();
+    assert_eq!(tree, rasn::uper::decode(&encoded).unwrap());
+}
+
+#[test]
+fn unsupported_assignments_are_noted() {
+    let source = include_str!("fixtures/example.rs");
+
+    assert!(
+        source.contains("// `Row` was skipped, as information object classes are not supported.")
+    );
+    assert!(source.contains("// `Table` was skipped, as it depends on `Row`, which was skipped."));
+}
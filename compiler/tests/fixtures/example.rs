@@ -0,0 +1,112 @@
+// Generated by rasn-compiler. Do not edit.
+
+pub mod example_module {
+    #![allow(clippy::all, non_camel_case_types, unused_imports)]
+
+    use rasn::{AsnType, Decode, Encode};
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(automatic_tags)]
+    #[non_exhaustive]
+    pub struct Message {
+        pub id: super::common_types::Identifier,
+        #[rasn(value("0..=255"), default = "message_version_default")]
+        pub version: u8,
+        pub kind: Kind,
+        pub name: Option<rasn::types::Utf8String>,
+        #[rasn(size("4"), from("0..=9", "A..=Z"))]
+        pub code: rasn::types::PrintableString,
+        pub payload: Box<Payload>,
+        #[rasn(size("0..=32"))]
+        pub items: rasn::types::SequenceOf<Item>,
+        #[rasn(size("8"), default = "message_flags_default")]
+        pub flags: rasn::types::BitString,
+        #[rasn(extension_addition)]
+        pub priority: Option<Priority>,
+        #[rasn(extension_addition_group)]
+        pub extension_group_1: Option<MessageExtensionGroup1>,
+    }
+
+    fn message_version_default() -> u8 {
+        1
+    }
+
+    fn message_flags_default() -> rasn::types::BitString {
+        [false, false, false, false, false, false, false, false].into_iter().collect()
+    }
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(automatic_tags)]
+    pub struct MessageExtensionGroup1 {
+        pub timestamp: rasn::types::GeneralizedTime,
+        pub origin: Option<rasn::types::ObjectIdentifier>,
+    }
+
+    #[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(enumerated)]
+    #[non_exhaustive]
+    pub enum Kind {
+        Request = 0,
+        Notification = 1,
+        Response = 5,
+        #[rasn(extension_addition)]
+        Error = 6,
+    }
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(delegate, value("0..=10", extensible))]
+    pub struct Priority(pub rasn::types::Integer);
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(choice, automatic_tags)]
+    #[non_exhaustive]
+    pub enum Payload {
+        Data(rasn::types::OctetString),
+        Text(rasn::types::Ia5String),
+        Nested(Box<Message>),
+    }
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(set)]
+    pub struct Item {
+        #[rasn(tag(0))]
+        pub key: rasn::types::VisibleString,
+        #[rasn(tag(1))]
+        pub value: rasn::types::Integer,
+        pub tags: Option<rasn::types::SetOf<Tag>>,
+    }
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(delegate, tag(explicit(application, 3)), value("-128..=127"))]
+    pub struct Tag(pub i8);
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(automatic_tags)]
+    pub struct Tree {
+        pub value: rasn::types::Integer,
+        pub children: rasn::types::SequenceOf<Tree>,
+        pub left: Option<Box<Tree>>,
+    }
+
+    // `Table` was skipped, as it depends on `Row`, which was skipped.
+
+    // `Row` was skipped, as information object classes are not supported.
+
+    pub const EXAMPLE_OID: &rasn::types::Oid = rasn::types::Oid::const_new(&[1, 3, 3]);
+
+    pub const DEFAULT_NAME: &str = "example";
+
+    pub const ANSWER: i128 = 42;
+}
+
+pub mod common_types {
+    #![allow(clippy::all, non_camel_case_types, unused_imports)]
+
+    use rasn::{AsnType, Decode, Encode};
+
+    #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[rasn(delegate, tag(application, 1), size("16"))]
+    pub struct Identifier(pub rasn::types::OctetString);
+
+    pub const MAX_ITEMS: i128 = 32;
+}
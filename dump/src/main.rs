@@ -0,0 +1,79 @@
+//! Dumps the structure of BER, CER, and DER encoded data, in the style of
+//! `dumpasn1` and `openssl asn1parse`.
+//!
+//! ```text
+//! rasn-dump [--encapsulated] [FILE]
+//! ```
+//!
+//! Reads from standard input when no file is given. PEM input is detected and
+//! decoded automatically.
+
+use std::io::Read;
+
+use rasn::ber::dump::{dump_with_options, DumpOptions};
+
+const USAGE: &str = "Usage: rasn-dump [-e|--encapsulated] [FILE]
+
+Prints the structure of BER, CER, or DER encoded FILE, or standard input.
+
+Options:
+  -e, --encapsulated  Dump OCTET STRING and BIT STRING contents that are DER
+  -h, --help          Print this message";
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("rasn-dump: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut options = DumpOptions::default();
+    let mut path = None;
+
+    for argument in std::env::args().skip(1) {
+        match &*argument {
+            "-e" | "--encapsulated" => options.encapsulated = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if path.is_none() && !argument.starts_with('-') => path = Some(argument),
+            _ => return Err(format!("unexpected argument `{argument}`\n\n{USAGE}")),
+        }
+    }
+
+    let mut input = Vec::new();
+    match path {
+        Some(path) => input = std::fs::read(&path).map_err(|e| format!("{path}: {e}"))?,
+        None => {
+            std::io::stdin()
+                .read_to_end(&mut input)
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    for der in decode_pem(&input)? {
+        print!(
+            "{}",
+            dump_with_options(&der, options).map_err(|e| e.to_string())?
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the contents of every PEM block in `input`, or `input` itself if it
+/// is not PEM encoded.
+fn decode_pem(input: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    if !input.trim_ascii_start().starts_with(b"-----BEGIN") {
+        return Ok(vec![input.to_vec()]);
+    }
+
+    let blocks = pem::parse_many(input);
+    if blocks.is_empty() {
+        return Err(String::from("invalid PEM input"));
+    }
+
+    Ok(blocks.into_iter().map(|pem| pem.contents).collect())
+}
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::config::*;
+use crate::{config::*, ext::GenericsExt};
 
 pub fn derive_struct_impl(
     name: syn::Ident,
@@ -55,16 +55,7 @@ pub fn derive_struct_impl(
         })
         .collect::<Vec<_>>();
 
-    for param in generics.type_params_mut() {
-        param
-            .bounds
-            .push(syn::TypeParamBound::Trait(syn::TraitBound {
-                paren_token: Some(<_>::default()),
-                modifier: syn::TraitBoundModifier::None,
-                lifetimes: None,
-                path: syn::parse_str("rasn::AsnType").unwrap(),
-            }));
-    }
+    generics.add_field_bounds(&container.fields, config, quote::format_ident!("AsnType"));
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -93,6 +84,10 @@ pub fn derive_struct_impl(
     });
 
     let constraints_def = config.constraints.const_static_def(crate_root);
+    let identifier = {
+        use syn::ext::IdentExt;
+        name.unraw().to_string()
+    };
 
     quote! {
         #constructed_impl
@@ -104,6 +99,7 @@ pub fn derive_struct_impl(
 
                 #tag
             };
+            const IDENTIFIER: Option<&'static str> = Some(#identifier);
 
             #constraints_def
         }
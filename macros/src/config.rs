@@ -57,7 +57,7 @@ impl Constraints {
     fn size_def(&self, crate_root: &syn::Path) -> Option<proc_macro2::TokenStream> {
         self.size.as_ref().map(|value| {
             let extensible = value.extensible.is_some();
-            let constraint = match value.constraint {
+            let constraint = match &value.constraint {
                 Value::Range(Some(min), Some(max)) => {
                     quote!(#crate_root::types::constraints::Bounded::const_new(#min as usize, #max as usize))
                 }
@@ -90,26 +90,7 @@ impl Constraints {
     fn size_attr(&self) -> Option<proc_macro2::TokenStream> {
         self.size.as_ref().map(|value| {
             let extensible = value.extensible.is_some().then_some(quote!(extensible));
-            let constraint = match value.constraint {
-                Value::Range(Some(min), Some(max)) => {
-                    let string = quote!(#min..=#max).to_string();
-                    quote!(#string)
-                }
-                Value::Range(Some(min), None) => {
-                    let string = quote!(#min..).to_string();
-                    quote!(#string)
-                }
-                Value::Range(None, Some(max)) => {
-                    let string = quote!(..=#max).to_string();
-                    quote!(#string)
-                }
-                Value::Range(None, None) => {
-                    quote!("..")
-                }
-                Value::Single(length) => {
-                    quote!(#length)
-                }
-            };
+            let constraint = value.constraint.to_attribute_tokens();
 
             quote!(size(#constraint, #extensible))
         })
@@ -118,23 +99,7 @@ impl Constraints {
     fn value_attr(&self) -> Option<proc_macro2::TokenStream> {
         self.value.as_ref().map(|value| {
             let extensible = value.extensible.is_some().then_some(quote!(extensible));
-            let constraint = match value.constraint {
-                Value::Range(Some(min), Some(max)) => {
-                    quote!(#min..#max)
-                }
-                Value::Range(Some(min), None) => {
-                    quote!(#min..)
-                }
-                Value::Range(None, Some(max)) => {
-                    quote!(..#max)
-                }
-                Value::Range(None, None) => {
-                    quote!(..)
-                }
-                Value::Single(value) => {
-                    quote!(#value)
-                }
-            };
+            let constraint = value.constraint.to_attribute_tokens();
 
             quote!(value(#constraint, #extensible))
         })
@@ -143,7 +108,7 @@ impl Constraints {
     fn value_def(&self, crate_root: &syn::Path) -> Option<proc_macro2::TokenStream> {
         self.value.as_ref().map(|value| {
             let extensible = value.extensible.is_some();
-            let constraint = match value.constraint {
+            let constraint = match &value.constraint {
                 Value::Range(Some(min), Some(max)) => {
                     quote!(#crate_root::types::constraints::Bounded::const_new(#min as i128, #max as i128))
                 }
@@ -222,6 +187,7 @@ pub struct Config {
     pub delegate: bool,
     pub tag: Option<Tag>,
     pub constraints: Constraints,
+    pub bound: Option<Vec<syn::WherePredicate>>,
 }
 
 impl Config {
@@ -237,6 +203,7 @@ impl Config {
         let mut size = None;
         let mut value = None;
         let mut delegate = false;
+        let mut bound = None;
         let extensible = input
             .attrs
             .iter()
@@ -298,6 +265,23 @@ impl Config {
                     size = Some(Value::from_meta(item));
                 } else if path.is_ident("value") {
                     value = Some(Value::from_meta(item));
+                } else if path.is_ident("bound") {
+                    bound = Some(match item {
+                        syn::Meta::NameValue(syn::MetaNameValue {
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        }) => lit
+                            .parse_with(
+                                syn::punctuated::Punctuated::<
+                                    syn::WherePredicate,
+                                    syn::Token![,],
+                                >::parse_terminated,
+                            )
+                            .unwrap_or_else(|error| panic!("invalid `bound`: {error}"))
+                            .into_iter()
+                            .collect(),
+                        _ => panic!("`bound` must be a string of where predicates, as in `bound = \"T: Encode\"`"),
+                    });
                 } else {
                     panic!("unknown input provided: {}", path.to_token_stream());
                 }
@@ -310,6 +294,8 @@ impl Config {
             panic!("Structs cannot be annotated with `#[rasn(choice)]` or `#[rasn(enumerated)]`.");
         } else if is_enum && set {
             panic!("Enums cannot be annotated with `#[rasn(set)]`.");
+        } else if is_enum && bound.is_some() {
+            panic!("Enums cannot be annotated with `#[rasn(bound)]`.");
         } else if is_enum && ((choice && enumerated) || (!choice && !enumerated)) {
             panic!(
                 "Enums must be annotated with either `#[rasn(choice)]` OR `#[rasn(enumerated)]`."
@@ -368,6 +354,7 @@ impl Config {
                 size,
                 value,
             },
+            bound,
             crate_root: crate_root.unwrap_or_else(|| {
                 syn::LitStr::new(crate::CRATE_NAME, proc_macro2::Span::call_site())
                     .parse()
@@ -711,6 +698,8 @@ pub struct FieldConfig<'a> {
     pub default: Option<Option<syn::Path>>,
     pub extension_addition: bool,
     pub extension_addition_group: bool,
+    pub identified_by: Option<syn::Ident>,
+    pub containing: Option<Containing>,
     pub constraints: Constraints,
 }
 
@@ -730,6 +719,8 @@ impl<'a> FieldConfig<'a> {
         let mut extensible = false;
         let mut extension_addition = false;
         let mut extension_addition_group = false;
+        let mut identified_by = None;
+        let mut containing = None;
         let mut iter = field
             .attrs
             .iter()
@@ -764,6 +755,18 @@ impl<'a> FieldConfig<'a> {
                     extension_addition = true;
                 } else if path.is_ident("extension_addition_group") {
                     extension_addition_group = true;
+                } else if path.is_ident("identified_by") {
+                    identified_by = Some(match item {
+                        syn::Meta::NameValue(syn::MetaNameValue {
+                            lit: syn::Lit::Str(lit_str),
+                            ..
+                        }) => lit_str.parse::<syn::Ident>().unwrap(),
+                        _ => panic!(
+                            "`identified_by` must name a field, as in `identified_by = \"id\"`"
+                        ),
+                    });
+                } else if path.is_ident("containing") {
+                    containing = Some(Containing::from_meta(item));
                 } else {
                     panic!(
                         "unknown field tag {:?}",
@@ -777,6 +780,29 @@ impl<'a> FieldConfig<'a> {
             panic!("field cannot be both `extension_addition` and `extension_addition_group`, choose one");
         }
 
+        if identified_by.is_some()
+            && (default.is_some()
+                || extension_addition
+                || extension_addition_group
+                || size.is_some()
+                || value.is_some()
+                || from.is_some())
+        {
+            panic!("`identified_by` can't be combined with `default`, constraints, or extension additions");
+        }
+
+        if containing.is_some()
+            && (identified_by.is_some()
+                || default.is_some()
+                || extension_addition
+                || extension_addition_group
+                || size.is_some()
+                || value.is_some()
+                || from.is_some())
+        {
+            panic!("`containing` can't be combined with `identified_by`, `default`, constraints, or extension additions");
+        }
+
         Self {
             container_config,
             default,
@@ -784,6 +810,8 @@ impl<'a> FieldConfig<'a> {
             tag,
             extension_addition,
             extension_addition_group,
+            identified_by,
+            containing,
             constraints: Constraints {
                 extensible,
                 from,
@@ -808,6 +836,14 @@ impl<'a> FieldConfig<'a> {
         ty.strip_lifetimes();
         let default_fn = self.default_fn();
 
+        if self.identified_by.is_some() {
+            return self.encode_open_type(&tag, quote!(#this #field));
+        }
+
+        if self.containing.is_some() {
+            return self.encode_containing(&tag, quote!(#this #field));
+        }
+
         let encode = if self.tag.is_some() || self.container_config.automatic_tags {
             if self.tag.as_ref().map_or(false, |tag| tag.is_explicit()) {
                 let encode = quote!(encoder.encode_explicit_prefix(#tag, &self.#field)?;);
@@ -916,12 +952,6 @@ impl<'a> FieldConfig<'a> {
         }
     }
 
-    pub fn decode_field_def(&self, name: &syn::Ident, context: usize) -> proc_macro2::TokenStream {
-        let lhs = self.field.ident.as_ref().map(|i| quote!(#i :));
-        let decode_op = self.decode(name, context);
-        quote!(#lhs #decode_op)
-    }
-
     pub fn decode(&self, name: &syn::Ident, context: usize) -> proc_macro2::TokenStream {
         let crate_root = &self.container_config.crate_root;
         let ty = &self.field.ty;
@@ -939,6 +969,14 @@ impl<'a> FieldConfig<'a> {
         let default_fn = self.default_fn();
 
         let tag = self.tag(context);
+        if let Some(id) = &self.identified_by {
+            return self.decode_open_type(&tag, &field_binding(id), or_else);
+        }
+
+        if self.containing.is_some() {
+            return self.decode_containing(&tag, or_else);
+        }
+
         let constraints = self.constraints.const_expr(crate_root);
         let handle_extension = if self.is_not_option_or_default_type() {
             quote!(.ok_or_else(|| #crate_root::de::Error::field_error(#ident, crate::error::DecodeError::extension_present_but_not_required(#tag, decoder.codec()), decoder.codec()))?)
@@ -1065,6 +1103,116 @@ impl<'a> FieldConfig<'a> {
         }
     }
 
+    pub fn decode_borrowed_field_def(
+        &self,
+        name: &syn::Ident,
+        context: usize,
+    ) -> proc_macro2::TokenStream {
+        let lhs = self.field.ident.as_ref().map(|i| quote!(#i :));
+        let decode_op = self.decode_borrowed(name, context);
+        quote!(#lhs #decode_op)
+    }
+
+    /// Like [`Self::decode`], but for containers implementing `DecodeBorrowed`,
+    /// where fields may borrow from the decoder's input.
+    pub fn decode_borrowed(&self, name: &syn::Ident, context: usize) -> proc_macro2::TokenStream {
+        let crate_root = &self.container_config.crate_root;
+        let ty = &self.field.ty;
+        let ident = format!(
+            "{}.{}",
+            name,
+            self.field
+                .ident
+                .as_ref()
+                .map(|ident| ident.to_string())
+                .unwrap_or_else(|| context.to_string())
+        );
+
+        if self.extension_addition || self.extension_addition_group {
+            panic!("Extension additions are not supported in types decoded by borrowing, found in `{ident}`");
+        }
+
+        let or_else = quote!(.map_err(|error| #crate_root::de::Error::field_error(#ident, error.into(), decoder.codec()))?);
+        let default_fn = self.default_fn();
+        let is_tagged = self.tag.is_some() || self.container_config.automatic_tags;
+        let is_explicit = self.tag.as_ref().is_some_and(|tag| tag.is_explicit());
+        let tag = self.tag(context);
+        let constraints = self.constraints.const_expr(crate_root);
+
+        let decode = if is_explicit {
+            let or_else = if self.is_option_type() {
+                quote!(.ok())
+            } else if self.is_default_type() {
+                quote!(.ok().unwrap_or_else(#default_fn))
+            } else {
+                or_else
+            };
+
+            quote!(decoder.decode_explicit_prefix_borrowed(#tag) #or_else)
+        } else if self.is_option_type() || self.is_default_type() {
+            let unwrap = self
+                .is_default_type()
+                .then(|| quote!(.unwrap_or_else(#default_fn)));
+            if is_tagged {
+                quote!(decoder.decode_optional_borrowed_with_tag(#tag) #or_else #unwrap)
+            } else {
+                quote!(decoder.decode_optional_borrowed() #or_else #unwrap)
+            }
+        } else if self.constraints.has_constraints() {
+            quote!(
+                #crate_root::DecodeBorrowed::decode_borrowed_with_tag_and_constraints(
+                    decoder,
+                    #tag,
+                    <#ty as #crate_root::AsnType>::CONSTRAINTS.override_constraints(#constraints),
+                ) #or_else
+            )
+        } else if is_tagged {
+            quote!(#crate_root::DecodeBorrowed::decode_borrowed_with_tag(decoder, #tag) #or_else)
+        } else {
+            quote!(#crate_root::DecodeBorrowed::decode_borrowed(decoder) #or_else)
+        };
+
+        quote!({
+            #decode
+        })
+    }
+
+    /// Validates `value`, the value of the field, under the field's name.
+    /// Fields identified by another field aren't validated, as their
+    /// constraints depend on the information object.
+    pub fn validate(
+        &self,
+        context: usize,
+        value: proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        use syn::ext::IdentExt;
+
+        if self.identified_by.is_some() {
+            return None;
+        }
+
+        let crate_root = &self.container_config.crate_root;
+        let ty = &self.field.ty;
+        let name = self
+            .field
+            .ident
+            .as_ref()
+            .map(|ident| ident.unraw().to_string())
+            .unwrap_or_else(|| context.to_string());
+        let constraints = match self.constraints.const_expr(crate_root) {
+            Some(constraints) => {
+                quote!(<#ty as #crate_root::AsnType>::CONSTRAINTS.override_constraints(#constraints))
+            }
+            None => quote!(<#ty as #crate_root::AsnType>::CONSTRAINTS),
+        };
+
+        Some(quote! {
+            validator.field(#name, |validator| {
+                <#ty as #crate_root::Validate>::validate_with_constraints(#value, validator, #constraints);
+            });
+        })
+    }
+
     pub fn default_fn(&self) -> Option<proc_macro2::TokenStream> {
         let ty = &self.field.ty;
         self.default.as_ref().map(|default_fn| match default_fn {
@@ -1098,6 +1246,11 @@ impl<'a> FieldConfig<'a> {
             quote!(#tag)
         } else if self.container_config.automatic_tags {
             quote!(#crate_root::Tag::new(#crate_root::types::Class::Context, #context as u32))
+        } else if self.identified_by.is_some() {
+            quote!(<#crate_root::types::Any as #crate_root::AsnType>::TAG)
+        } else if let Some(containing) = &self.containing {
+            let string = containing.string_type(crate_root);
+            quote!(<#string as #crate_root::AsnType>::TAG)
         } else {
             let mut ty = self.field.ty.clone();
             ty.strip_lifetimes();
@@ -1112,11 +1265,175 @@ impl<'a> FieldConfig<'a> {
         if self.tag.is_some() || self.container_config.automatic_tags {
             let tag = self.tag(context);
             quote!(#crate_root::TagTree::Leaf(#tag))
+        } else if self.identified_by.is_some() {
+            // Open types have no tag of their own, but PER still needs a tag to
+            // find their presence bit.
+            quote!(#crate_root::TagTree::Leaf(<#crate_root::types::Any as #crate_root::AsnType>::TAG))
+        } else if self.containing.is_some() {
+            let tag = self.tag(context);
+            quote!(#crate_root::TagTree::Leaf(#tag))
         } else {
             self.container_config.tag_tree_for_ty(ty)
         }
     }
 
+    /// Encodes a field marked with `identified_by` as an open type. Tagged open
+    /// types are always explicitly tagged.
+    fn encode_open_type(
+        &self,
+        tag: &proc_macro2::TokenStream,
+        field: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let crate_root = &self.container_config.crate_root;
+        let is_tagged = self.tag.is_some() || self.container_config.automatic_tags;
+
+        let open_type = if self.is_option_type() {
+            quote!(#field.as_ref().map(|value| #crate_root::types::InformationObjectSet::to_open_type(value, encoder.codec())).transpose()?)
+        } else {
+            quote!(#crate_root::types::InformationObjectSet::to_open_type(&#field, encoder.codec())?)
+        };
+        let encode = match (is_tagged, self.is_option_type()) {
+            (true, true) => quote! {
+                if let Some(open_type) = &open_type {
+                    encoder.encode_explicit_prefix(#tag, open_type)?;
+                }
+            },
+            (true, false) => quote!(encoder.encode_explicit_prefix(#tag, &open_type)?;),
+            (false, true) => quote! {
+                match &open_type {
+                    Some(open_type) => encoder.encode_some_with_tag(<#crate_root::types::Any as #crate_root::AsnType>::TAG, open_type)?,
+                    None => encoder.encode_none_with_tag(<#crate_root::types::Any as #crate_root::AsnType>::TAG)?,
+                };
+            },
+            (false, false) => quote!(#crate_root::Encode::encode(&open_type, encoder)?;),
+        };
+
+        quote! {
+            let open_type = #open_type;
+            #encode
+        }
+    }
+
+    /// Decodes a field marked with `identified_by` as an open type, whose value
+    /// is selected by the already decoded field bound to `id`.
+    fn decode_open_type(
+        &self,
+        tag: &proc_macro2::TokenStream,
+        id: &syn::Ident,
+        or_else: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let crate_root = &self.container_config.crate_root;
+        let is_tagged = self.tag.is_some() || self.container_config.automatic_tags;
+
+        let open_type = match (is_tagged, self.is_option_type()) {
+            (true, true) => {
+                quote!(decoder.decode_explicit_prefix::<#crate_root::types::Any>(#tag).ok())
+            }
+            (true, false) => {
+                quote!(decoder.decode_explicit_prefix::<#crate_root::types::Any>(#tag) #or_else)
+            }
+            (false, true) => {
+                quote!(decoder.decode_optional_with_tag::<#crate_root::types::Any>(<#crate_root::types::Any as #crate_root::AsnType>::TAG) #or_else)
+            }
+            (false, false) => {
+                quote!(<#crate_root::types::Any as #crate_root::Decode>::decode(decoder) #or_else)
+            }
+        };
+        let value = if self.is_option_type() {
+            quote!(open_type.map(|open_type| <_ as #crate_root::types::InformationObjectSet>::from_open_type(&#id, decoder.codec(), &open_type)).transpose())
+        } else {
+            quote!(<_ as #crate_root::types::InformationObjectSet>::from_open_type(&#id, decoder.codec(), &open_type))
+        };
+
+        quote!({
+            let open_type = #open_type;
+            #value #or_else
+        })
+    }
+
+    /// The wrapper type that a field marked with `containing` is encoded and
+    /// decoded as.
+    fn containing_type(&self) -> proc_macro2::TokenStream {
+        let crate_root = &self.container_config.crate_root;
+        let containing = self.containing.as_ref().unwrap();
+        let ty = self
+            .container_config
+            .option_type
+            .map_to_inner_type(&self.field.ty)
+            .unwrap_or(&self.field.ty);
+        let encoding = containing.encoding(crate_root);
+        let string = containing.string_type(crate_root);
+
+        quote!(#crate_root::types::Containing<#ty, #encoding, #string>)
+    }
+
+    /// Encodes a field marked with `containing` as the string holding its
+    /// encoding.
+    fn encode_containing(
+        &self,
+        tag: &proc_macro2::TokenStream,
+        field: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let crate_root = &self.container_config.crate_root;
+        let containing = self.containing.as_ref().unwrap();
+        let encoding = containing.encoding(crate_root);
+        let string = containing.string_type(crate_root);
+        let wrap = quote!(#crate_root::types::Containing::<_, #encoding, #string>::new);
+        let is_explicit = self.tag.as_ref().is_some_and(|tag| tag.is_explicit());
+
+        match (is_explicit, self.is_option_type()) {
+            (true, true) => quote! {
+                if let Some(value) = #field.as_ref() {
+                    encoder.encode_explicit_prefix(#tag, &#wrap(value))?;
+                }
+            },
+            (true, false) => quote!(encoder.encode_explicit_prefix(#tag, &#wrap(&#field))?;),
+            (false, true) => quote! {
+                match #field.as_ref() {
+                    Some(value) => encoder.encode_some_with_tag(#tag, &#wrap(value))?,
+                    None => encoder.encode_none_with_tag(#tag)?,
+                };
+            },
+            (false, false) => {
+                quote!(#crate_root::Encode::encode_with_tag(&#wrap(&#field), encoder, #tag)?;)
+            }
+        }
+    }
+
+    /// Decodes a field marked with `containing` from the string holding its
+    /// encoding.
+    fn decode_containing(
+        &self,
+        tag: &proc_macro2::TokenStream,
+        or_else: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let crate_root = &self.container_config.crate_root;
+        let ty = self.containing_type();
+        let is_explicit = self.tag.as_ref().is_some_and(|tag| tag.is_explicit());
+
+        let decode = match (is_explicit, self.is_option_type()) {
+            (true, true) => quote! {
+                decoder
+                    .decode_explicit_prefix::<#ty>(#tag)
+                    .ok()
+                    .map(#crate_root::types::Containing::into_inner)
+            },
+            (true, false) => {
+                quote!(decoder.decode_explicit_prefix::<#ty>(#tag) #or_else .into_inner())
+            }
+            (false, true) => quote! {
+                decoder
+                    .decode_optional_with_tag::<#ty>(#tag) #or_else
+                    .map(#crate_root::types::Containing::into_inner)
+            },
+            (false, false) => {
+                quote!(<#ty as #crate_root::Decode>::decode_with_tag(decoder, #tag) #or_else .into_inner())
+            }
+        };
+
+        quote!({ #decode })
+    }
+
     pub fn to_field_metadata(&self, context: usize) -> proc_macro2::TokenStream {
         let crate_root = &self.container_config.crate_root;
         let tag = self.tag(context);
@@ -1178,6 +1495,84 @@ impl<'a> FieldConfig<'a> {
     }
 }
 
+/// The contents constraint of a field, from
+/// `#[rasn(containing(encoded_by = "der", bit_string))]`.
+#[derive(Clone, Debug, Default)]
+pub struct Containing {
+    pub encoded_by: Option<syn::Ident>,
+    pub bit_string: bool,
+}
+
+impl Containing {
+    fn from_meta(item: &syn::Meta) -> Self {
+        let mut containing = Self::default();
+        let list = match item {
+            syn::Meta::Path(_) => return containing,
+            syn::Meta::List(list) => list,
+            syn::Meta::NameValue(_) => {
+                panic!("`containing` takes options in parentheses, as in `containing(encoded_by = \"der\")`")
+            }
+        };
+
+        for item in &list.nested {
+            match item {
+                NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("bit_string") => {
+                    containing.bit_string = true;
+                }
+                NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                })) if path.is_ident("encoded_by") => {
+                    let codec = match &*lit.value() {
+                        "aper" => "Aper",
+                        "ber" => "Ber",
+                        "cer" => "Cer",
+                        "der" => "Der",
+                        "oer" => "Oer",
+                        "coer" => "Coer",
+                        "uper" => "Uper",
+                        other => panic!("`encoded_by` must be one of `aper`, `ber`, `cer`, `der`, `oer`, `coer`, or `uper`, found `{other}`"),
+                    };
+                    containing.encoded_by = Some(syn::Ident::new(codec, lit.span()));
+                }
+                _ => panic!("unknown `containing` option, expected `encoded_by` or `bit_string`"),
+            }
+        }
+
+        containing
+    }
+
+    fn encoding(&self, crate_root: &syn::Path) -> proc_macro2::TokenStream {
+        let encoding = self
+            .encoded_by
+            .clone()
+            .unwrap_or_else(|| format_ident!("Outer"));
+
+        quote!(#crate_root::types::encoded_by::#encoding)
+    }
+
+    fn string_type(&self, crate_root: &syn::Path) -> proc_macro2::TokenStream {
+        if self.bit_string {
+            quote!(#crate_root::types::BitString)
+        } else {
+            quote!(#crate_root::types::OctetString)
+        }
+    }
+}
+
+/// The local variable that a decoded field named `name` is bound to, before
+/// the container is constructed. It's hygienic, so that it can't shadow the
+/// decoder.
+pub fn field_binding(name: &syn::Ident) -> syn::Ident {
+    use syn::ext::IdentExt;
+
+    syn::Ident::new(
+        &format!("__field_{}", name.unraw()),
+        proc_macro2::Span::mixed_site(),
+    )
+}
+
 #[derive(Clone, Debug)]
 pub struct Constraint<T> {
     pub constraint: T,
@@ -1278,19 +1673,72 @@ impl StringValue {
 
 #[derive(Clone, Debug)]
 pub enum Value {
-    Single(i128),
-    Range(Option<i128>, Option<i128>),
+    Single(Bound),
+    Range(Option<Bound>, Option<Bound>),
+}
+
+/// A bound of a size or value constraint. Bounds can be expressions, such as
+/// a const generic parameter or an associated constant of a type parameter,
+/// which makes it possible to parameterize a type's constraints.
+#[derive(Clone, Debug)]
+pub enum Bound {
+    Literal(i128),
+    Expr(Box<syn::Expr>),
+}
+
+impl Bound {
+    fn parse(string: &str) -> Option<Self> {
+        let string = string.trim();
+
+        if string.is_empty() {
+            None
+        } else if let Ok(number) = string.parse() {
+            Some(Self::Literal(number))
+        } else {
+            Some(Self::Expr(Box::new(syn::parse_str(string).unwrap_or_else(
+                |_| {
+                    panic!("unknown bound: `{string}`, must be an integer or a constant expression")
+                },
+            ))))
+        }
+    }
+
+    /// The bound before `self`, used to make an exclusive end inclusive.
+    fn predecessor(self) -> Self {
+        match self {
+            Self::Literal(number) => Self::Literal(number - 1),
+            Self::Expr(expr) => Self::Expr(Box::new(syn::parse_quote!((#expr) - 1))),
+        }
+    }
+}
+
+impl quote::ToTokens for Bound {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Literal(number) => number.to_tokens(tokens),
+            Self::Expr(expr) => quote!((#expr)).to_tokens(tokens),
+        }
+    }
 }
 
 impl Value {
+    /// The argument of a `size` or `value` attribute that parses as `self`.
+    fn to_attribute_tokens(&self) -> proc_macro2::TokenStream {
+        let string = match self {
+            Value::Range(Some(min), Some(max)) => quote!(#min..=#max).to_string(),
+            Value::Range(Some(min), None) => quote!(#min..).to_string(),
+            Value::Range(None, Some(max)) => quote!(..=#max).to_string(),
+            Value::Range(None, None) => String::from(".."),
+            Value::Single(value) => quote!(#value).to_string(),
+        };
+
+        quote!(#string)
+    }
+
     fn from_meta(item: &syn::Meta) -> Constraint<Value> {
         let mut extensible = None;
         let mut constraint = None;
 
-        fn parse_character(string: &str) -> Option<i128> {
-            string.parse().ok()
-        }
-
         let syn::Meta::List(list) = item else {
             panic!("Unsupported meta item: {:?}", item);
         };
@@ -1300,7 +1748,7 @@ impl Value {
                 NestedMeta::Lit(Lit::Str(string)) => string.value(),
                 NestedMeta::Meta(syn::Meta::Path(path)) => path.get_ident().unwrap().to_string(),
                 NestedMeta::Lit(Lit::Int(int)) => {
-                    constraint = Some(int.base10_parse().map(Value::Single).unwrap());
+                    constraint = Some(Value::Single(Bound::Literal(int.base10_parse().unwrap())));
                     continue;
                 }
                 _ => panic!("Unsupported meta item: {item:?}"),
@@ -1311,21 +1759,20 @@ impl Value {
                 continue;
             }
 
-            let value = if let Some(number) = parse_character(&string) {
-                Value::Single(number)
-            } else {
-                let Some((start, mut end)) = string.split_once("..") else {
-                    panic!("unknown format: {string}, must be a single character or range of characters (`..`, `..=`)")
-                };
-
-                let start = parse_character(start);
+            let value = if let Some((start, mut end)) = string.split_once("..") {
+                let start = Bound::parse(start);
                 let is_inclusive = end.starts_with('=');
                 if is_inclusive {
                     end = &end[1..];
                 }
 
-                let end = parse_character(end).map(|end| end - (!is_inclusive) as i128);
+                let end =
+                    Bound::parse(end).map(|end| if is_inclusive { end } else { end.predecessor() });
                 Value::Range(start, end)
+            } else {
+                Value::Single(Bound::parse(&string).unwrap_or_else(|| {
+                    panic!("unknown format: {string}, must be a single value or range of values (`..`, `..=`)")
+                }))
             };
 
             if let Some(extensible_values) = extensible.as_mut() {
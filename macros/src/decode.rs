@@ -11,8 +11,15 @@ pub fn derive_struct_impl(
 ) -> proc_macro2::TokenStream {
     let mut list = vec![];
     let crate_root = &config.crate_root;
-    generics.add_trait_bounds(crate_root, quote::format_ident!("Decode"));
+    generics.add_field_bounds(&container.fields, config, quote::format_ident!("Decode"));
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // Structs with a lifetime parameter may borrow from the input, and
+    // implement `DecodeBorrowed` for that lifetime instead of `Decode`.
+    let borrowed = generics.lifetimes().next().map(|param| &param.lifetime);
+
+    if borrowed.is_some() && config.set {
+        panic!("`SET` types can't be decoded by borrowing, remove the lifetime from `{name}`");
+    }
 
     let decode_impl = if config.delegate {
         let ty = &container.fields.iter().next().unwrap().ty;
@@ -23,8 +30,29 @@ pub fn derive_struct_impl(
             .map(|tag| tag.is_explicit())
             .unwrap_or_default()
         {
+            if borrowed.is_some() {
+                quote! {
+                    decoder.decode_explicit_prefix_borrowed::<#ty>(tag).map(Self)
+                }
+            } else {
+                quote! {
+                    decoder.decode_explicit_prefix::<#ty>(tag).map(Self)
+                }
+            }
+        } else if let Some(lifetime) = borrowed {
             quote! {
-                decoder.decode_explicit_prefix::<#ty>(tag).map(Self)
+                match tag {
+                    #crate_root::Tag::EOC => {
+                        Ok(Self(<#ty as #crate_root::DecodeBorrowed<#lifetime>>::decode_borrowed(decoder)?))
+                    }
+                    _ => {
+                        <#ty as #crate_root::DecodeBorrowed<#lifetime>>::decode_borrowed_with_tag_and_constraints(
+                            decoder,
+                            tag,
+                            <#ty as #crate_root::AsnType>::CONSTRAINTS.override_constraints(constraints),
+                        ).map(Self)
+                    }
+                }
             }
         } else {
             quote! {
@@ -43,6 +71,22 @@ pub fn derive_struct_impl(
             }
         }
     } else if config.set {
+        if container
+            .fields
+            .iter()
+            .any(|field| FieldConfig::new(field, config).identified_by.is_some())
+        {
+            panic!("`identified_by` is only supported in a `SEQUENCE`, as the fields of a `SET` can be decoded in any order");
+        }
+
+        if container
+            .fields
+            .iter()
+            .any(|field| FieldConfig::new(field, config).containing.is_some())
+        {
+            panic!("`containing` isn't supported in a `SET`, use the `Containing` type instead");
+        }
+
         let field_names = container.fields.iter().map(|field| field.ident.clone());
         let field_names2 = field_names.clone();
         let required_field_names = container
@@ -114,18 +158,20 @@ pub fn derive_struct_impl(
                     quote!(<_>::decode(decoder)?)
                 };
                 let ident = &field.ident;
+                // Hygienic, so that it can't collide with a field named `value`.
+                let value = syn::Ident::new("value", proc_macro2::Span::mixed_site());
 
                 let set_field_impl = if config.extension_addition || config.extension_addition_group {
                     quote! {
                         if #ident.is_some() {
                             return Err(rasn::de::Error::duplicate_field(stringify!(#ident), codec))
                         } else {
-                            #ident = value.0;
+                            #ident = #value.0;
                         }
                     }
                 } else {
                     quote! {
-                        if #ident.replace(value.0).is_some() {
+                        if #ident.replace(#value.0).is_some() {
                             return Err(rasn::de::Error::duplicate_field(stringify!(#ident), codec))
                         }
                     }
@@ -134,7 +180,7 @@ pub fn derive_struct_impl(
                 (
                     quote!(const #const_name: #crate_root::Tag = #tag;),
                     quote!((#context, #const_name) => { #choice_name::#field_name(#decode_impl) }),
-                    quote!(#choice_name::#field_name(value) => { #set_field_impl })
+                    quote!(#choice_name::#field_name(#value) => { #set_field_impl })
                 )
             }));
 
@@ -168,6 +214,8 @@ pub fn derive_struct_impl(
         }
     } else {
         let mut all_fields_optional_or_default = true;
+        let mut decoded_fields = Vec::new();
+        let mut bindings = Vec::new();
         for (i, field) in container.fields.iter().enumerate() {
             let field_config = FieldConfig::new(field, config);
 
@@ -175,7 +223,35 @@ pub fn derive_struct_impl(
                 all_fields_optional_or_default = false;
             }
 
-            list.push(field_config.decode_field_def(&name, i));
+            if let Some(id) = &field_config.identified_by {
+                if borrowed.is_some() {
+                    panic!("`identified_by` isn't supported on types that borrow from the input");
+                }
+                if !decoded_fields.contains(&id) {
+                    panic!("`identified_by` must name an earlier field of the same `SEQUENCE`, found `{id}`");
+                }
+            }
+            if field_config.containing.is_some() && borrowed.is_some() {
+                panic!("`containing` isn't supported on types that borrow from the input");
+            }
+            decoded_fields.extend(field.ident.as_ref());
+
+            if borrowed.is_some() {
+                list.push(field_config.decode_borrowed_field_def(&name, i));
+            } else {
+                // Decoded fields are bound first, so that open types can refer
+                // to the field that identifies them.
+                let binding = match &field.ident {
+                    Some(ident) => crate::config::field_binding(ident),
+                    None => {
+                        syn::Ident::new(&format!("__field_{i}"), proc_macro2::Span::mixed_site())
+                    }
+                };
+                let decode_op = field_config.decode(&name, i);
+                bindings.push(quote!(let #binding = #decode_op;));
+                let lhs = field.ident.as_ref().map(|ident| quote!(#ident :));
+                list.push(quote!(#lhs #binding));
+            }
         }
 
         let fields = match container.fields {
@@ -215,6 +291,7 @@ pub fn derive_struct_impl(
 
         quote! {
             decoder.decode_sequence(tag, #initializer_fn, |decoder| {
+                #(#bindings)*
                 Ok(Self #fields)
             })
         }
@@ -237,10 +314,20 @@ pub fn derive_struct_impl(
             decode_impl
         };
 
-    quote! {
-        impl #impl_generics #crate_root::Decode for #name #ty_generics #where_clause {
-            fn decode_with_tag_and_constraints<'constraints, D: #crate_root::Decoder>(decoder: &mut D, tag: #crate_root::Tag, constraints: #crate_root::types::Constraints<'constraints>) -> core::result::Result<Self, D::Error> {
-                #decode_impl
+    if let Some(lifetime) = borrowed {
+        quote! {
+            impl #impl_generics #crate_root::DecodeBorrowed<#lifetime> for #name #ty_generics #where_clause {
+                fn decode_borrowed_with_tag_and_constraints<'constraints, D: #crate_root::BorrowDecoder<#lifetime>>(decoder: &mut D, tag: #crate_root::Tag, constraints: #crate_root::types::Constraints<'constraints>) -> core::result::Result<Self, D::Error> {
+                    #decode_impl
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics #crate_root::Decode for #name #ty_generics #where_clause {
+                fn decode_with_tag_and_constraints<'constraints, D: #crate_root::Decoder>(decoder: &mut D, tag: #crate_root::Tag, constraints: #crate_root::types::Constraints<'constraints>) -> core::result::Result<Self, D::Error> {
+                    #decode_impl
+                }
             }
         }
     }
@@ -270,10 +357,19 @@ pub fn map_from_inner_type(
         quote!(#name : inner.#name)
     });
 
-    let decode_op = if is_explicit {
-        quote!(decoder.decode_explicit_prefix::<#inner_name>(#tag)?)
-    } else {
-        quote!(<#inner_name>::decode_with_tag(decoder, #tag)?)
+    // The inner struct shares `generics`, so it is decoded by borrowing
+    // whenever they declare a lifetime.
+    let borrowed = generics.lifetimes().next().map(|param| &param.lifetime);
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let decode_op = match (is_explicit, borrowed) {
+        (true, Some(_)) => {
+            quote!(decoder.decode_explicit_prefix_borrowed::<#inner_name #ty_generics>(#tag)?)
+        }
+        (true, None) => quote!(decoder.decode_explicit_prefix::<#inner_name>(#tag)?),
+        (false, Some(lifetime)) => {
+            quote!(<#inner_name #ty_generics as #crate_root::DecodeBorrowed<#lifetime>>::decode_borrowed_with_tag(decoder, #tag)?)
+        }
+        (false, None) => quote!(<#inner_name>::decode_with_tag(decoder, #tag)?),
     };
 
     quote! {
@@ -14,7 +14,7 @@ pub fn derive_struct_impl(
         .map(|(i, field)| FieldConfig::new(field, config).encode(i, true))
         .collect();
 
-    generics.add_trait_bounds(crate_root, quote::format_ident!("Encode"));
+    generics.add_field_bounds(&container.fields, config, quote::format_ident!("Encode"));
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let encode_impl = if config.delegate {
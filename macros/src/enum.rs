@@ -25,6 +25,11 @@ impl Enum {
             |t| t.to_tokens(crate_root),
         );
 
+        let identifier = {
+            use syn::ext::IdentExt;
+            self.name.unraw().to_string()
+        };
+
         let error_message = format!(
             "{}'s variants is not unique, ensure that your variants' tags are correct.",
             self.name
@@ -112,6 +117,10 @@ impl Enum {
                 quote!((Self::#variant, #discriminant))
             });
 
+            let identifiers = variants
+                .iter()
+                .chain(extended_variants.iter())
+                .map(|config| syn::LitStr::new(&config.variant.ident.to_string(), proc_macro2::Span::call_site()));
             let variants = variants.iter().map(|config| config.variant.ident.clone());
             let extended_variant_idents = extended_variants.iter().map(|config| config.variant.ident.clone());
             let extended_variants = extensible
@@ -128,6 +137,8 @@ impl Enum {
 
                     const DISCRIMINANTS: &'static [(Self, isize)] = &[#(#discriminants,)*];
                     const EXTENDED_DISCRIMINANTS: Option<&'static [(Self, isize)]> = #extended_discriminants;
+
+                    const IDENTIFIERS: &'static [&'static str] = &[#(#identifiers,)*];
                 }
             }
         });
@@ -143,6 +154,7 @@ impl Enum {
                     const _: () = assert!(TAG_TREE.is_unique(), #error_message);
                     #return_val
                 };
+                const IDENTIFIER: Option<&'static str> = Some(#identifier);
 
                 #constraints_def
             }
@@ -171,6 +183,89 @@ impl Enum {
         }
     }
 
+    pub fn impl_validate(&self) -> proc_macro2::TokenStream {
+        let crate_root = &self.config.crate_root;
+        let mut generics = self.generics.clone();
+        generics.add_trait_bounds(crate_root, quote::format_ident!("Validate"));
+
+        let name = &self.name;
+        let validate = self.config.choice.then(|| self.validate_choice(&generics));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #crate_root::Validate for #name #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn validate_with_constraints(
+                    &self,
+                    validator: &mut #crate_root::validate::Validator,
+                    constraints: #crate_root::types::Constraints,
+                ) {
+                    #validate
+                }
+            }
+        }
+    }
+
+    fn validate_choice(&self, generics: &syn::Generics) -> proc_macro2::TokenStream {
+        use syn::ext::IdentExt;
+
+        let crate_root = &self.config.crate_root;
+        let name = &self.name;
+
+        let variants = self.variants.iter().map(|v| {
+            let ident = &v.ident;
+            let identifier = ident.unraw().to_string();
+
+            match &v.fields {
+                syn::Fields::Named(_) => {
+                    let idents = v.fields.iter().map(|f| f.ident.as_ref().unwrap());
+                    let idents_prefixed = v
+                        .fields
+                        .iter()
+                        .map(|f| format_ident!("__rasn_field_{}", f.ident.as_ref().unwrap()))
+                        .collect::<Vec<_>>();
+                    let fields = v.fields.iter().zip(&idents_prefixed).enumerate().filter_map(
+                        |(i, (field, binding))| {
+                            FieldConfig::new(field, &self.config).validate(i, quote!(#binding))
+                        },
+                    );
+
+                    quote! {
+                        #name::#ident { #(#idents: #idents_prefixed),* } => {
+                            validator.field(#identifier, |validator| {
+                                #(#fields)*
+                            });
+                        }
+                    }
+                }
+                syn::Fields::Unnamed(_) => {
+                    let ty = &v.fields.iter().next().unwrap().ty;
+                    let variant_config = VariantConfig::new(v, generics, &self.config);
+                    let constraints = match variant_config.constraints.const_expr(crate_root) {
+                        Some(constraints) => quote!(<#ty as #crate_root::AsnType>::CONSTRAINTS.override_constraints(#constraints)),
+                        None => quote!(<#ty as #crate_root::AsnType>::CONSTRAINTS),
+                    };
+
+                    quote! {
+                        #name::#ident(value) => {
+                            validator.field(#identifier, |validator| {
+                                <#ty as #crate_root::Validate>::validate_with_constraints(value, validator, #constraints);
+                            });
+                        }
+                    }
+                }
+                syn::Fields::Unit => quote!(#name::#ident => {}),
+            }
+        });
+
+        quote! {
+            match self {
+                #(#variants),*
+            }
+        }
+    }
+
     pub fn impl_decode(&self) -> proc_macro2::TokenStream {
         let crate_root = &self.config.crate_root;
         let mut generics = self.generics.clone();
@@ -1,3 +1,5 @@
+use quote::ToTokens;
+
 pub trait TypeExt {
     fn strip_lifetimes(&mut self);
 }
@@ -12,6 +14,12 @@ impl TypeExt for syn::Type {
 
 pub trait GenericsExt {
     fn add_trait_bounds(&mut self, crate_root: &syn::Path, r#trait: syn::Ident);
+    fn add_field_bounds(
+        &mut self,
+        fields: &syn::Fields,
+        config: &crate::config::Config,
+        r#trait: syn::Ident,
+    );
 }
 
 impl GenericsExt for syn::Generics {
@@ -42,4 +50,69 @@ impl GenericsExt for syn::Generics {
             };
         }
     }
+
+    /// Bounds each type parameter that's used by a field by `trait`, like
+    /// `add_trait_bounds`, but keeps the parameter's own bounds. The types of
+    /// fields that are identified by another field are bounded by
+    /// `InformationObjectSet` instead, and parameters that are only used by
+    /// those fields aren't bounded by `trait`. `#[rasn(bound = "...")]`
+    /// replaces all of these bounds.
+    fn add_field_bounds(
+        &mut self,
+        fields: &syn::Fields,
+        config: &crate::config::Config,
+        ident: syn::Ident,
+    ) {
+        fn uses_param(tokens: proc_macro2::TokenStream, param: &syn::Ident) -> bool {
+            tokens.into_iter().any(|token| match token {
+                proc_macro2::TokenTree::Ident(ident) => ident == *param,
+                proc_macro2::TokenTree::Group(group) => uses_param(group.stream(), param),
+                _ => false,
+            })
+        }
+
+        if let Some(bound) = &config.bound {
+            self.make_where_clause()
+                .predicates
+                .extend(bound.iter().cloned());
+            return;
+        }
+
+        let crate_root = &config.crate_root;
+        let (identified, other): (Vec<_>, Vec<_>) = fields.iter().partition(|field| {
+            crate::config::FieldConfig::new(field, config)
+                .identified_by
+                .is_some()
+        });
+        let params: Vec<_> = self
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect();
+
+        let mut predicates: Vec<syn::WherePredicate> = params
+            .iter()
+            .filter(|param| {
+                other
+                    .iter()
+                    .any(|field| uses_param(field.ty.to_token_stream(), param))
+            })
+            .map(|param| syn::parse_quote!(#param: #crate_root::#ident))
+            .collect();
+        predicates.extend(
+            identified
+                .iter()
+                .filter(|field| {
+                    params
+                        .iter()
+                        .any(|param| uses_param(field.ty.to_token_stream(), param))
+                })
+                .map(|field| -> syn::WherePredicate {
+                    let ty = &field.ty;
+                    let ty = config.option_type.map_to_inner_type(ty).unwrap_or(ty);
+                    syn::parse_quote!(#ty: #crate_root::types::InformationObjectSet)
+                }),
+        );
+
+        self.make_where_clause().predicates.extend(predicates);
+    }
 }
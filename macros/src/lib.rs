@@ -8,6 +8,7 @@ mod encode;
 mod r#enum;
 mod ext;
 mod tag;
+mod validate;
 
 use config::Config;
 
@@ -73,6 +74,33 @@ pub fn encode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     .into()
 }
 
+/// An automatic derive of the `Validate` trait.
+///
+/// Will automatically generate an implementation that checks each field (if
+/// struct) or the present alternative (if a choice style enum) against its
+/// constraints, using the same attributes as [`AsnType`](`asn_type_derive`).
+/// Enumerated enums have no constraints to check.
+#[proc_macro_derive(Validate, attributes(rasn))]
+pub fn validate_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    let config = Config::from_attributes(&input);
+    let name = input.ident;
+    let generics = input.generics;
+
+    match input.data {
+        syn::Data::Struct(v) => validate::derive_struct_impl(name, generics, v, &config),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => r#enum::Enum {
+            name,
+            generics,
+            variants,
+            config,
+        }
+        .impl_validate(),
+        _ => panic!("Union types are not supported."),
+    }
+    .into()
+}
+
 /// An automatic derive of the `AsnType` trait.
 ///
 /// This macro will automatically generate an implementation of `AsnType`,
@@ -0,0 +1,56 @@
+use crate::{config::*, ext::GenericsExt};
+
+pub fn derive_struct_impl(
+    name: syn::Ident,
+    mut generics: syn::Generics,
+    container: syn::DataStruct,
+    config: &Config,
+) -> proc_macro2::TokenStream {
+    let crate_root = &config.crate_root;
+
+    generics.add_field_bounds(&container.fields, config, quote::format_ident!("Validate"));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let validate_impl = if config.delegate {
+        let ty = &container.fields.iter().next().unwrap().ty;
+
+        quote! {
+            <#ty as #crate_root::Validate>::validate_with_constraints(
+                &self.0,
+                validator,
+                <#ty as #crate_root::AsnType>::CONSTRAINTS.override_constraints(constraints),
+            );
+        }
+    } else {
+        let list = container
+            .fields
+            .iter()
+            .enumerate()
+            .filter_map(|(i, field)| {
+                let index = syn::Index::from(i);
+                let value = field
+                    .ident
+                    .as_ref()
+                    .map(|name| quote!(&self.#name))
+                    .unwrap_or_else(|| quote!(&self.#index));
+
+                FieldConfig::new(field, config).validate(i, value)
+            });
+
+        quote!(#(#list)*)
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #crate_root::Validate for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn validate_with_constraints(
+                &self,
+                validator: &mut #crate_root::validate::Validator,
+                constraints: #crate_root::types::Constraints,
+            ) {
+                #validate_impl
+            }
+        }
+    }
+}
@@ -12,6 +12,27 @@ pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeE
     crate::per::decode(de::DecoderOptions::aligned(), input)
 }
 
+/// Attempts to decode `T` from the start of `input` using APER-BASIC, returning it
+/// together with the offset in bits of the input following it.
+pub fn decode_with_remainder<T: crate::Decode>(
+    input: &[u8],
+) -> Result<(T, usize), crate::error::DecodeError> {
+    crate::per::decode_with_remainder(de::DecoderOptions::aligned(), input)
+}
+
+/// Returns an iterator decoding successive values of `T` from `input` using
+/// APER-BASIC, where each value is a complete encoding padded to a whole number
+/// of octets.
+pub fn decode_iter<T: crate::Decode>(input: &[u8]) -> crate::de::DecodeIter<'_, T> {
+    crate::de::DecodeIter::new(input, crate::Codec::Aper, |input| {
+        let (value, offset) = decode_with_remainder(input)?;
+        Ok((
+            value,
+            crate::per::remainder_after(input, offset, crate::Codec::Aper)?,
+        ))
+    })
+}
+
 /// Attempts to encode `value` to APER-CANONICAL.
 pub fn encode<T: crate::Encode>(
     value: &T,
@@ -19,6 +40,35 @@ pub fn encode<T: crate::Encode>(
     crate::per::encode(enc::EncoderOptions::aligned(), value)
 }
 
+/// Attempts to encode `value` to APER, writing the encoding to `writer`.
+/// # Errors
+/// Returns error specific to APER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    mut writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    writer
+        .write_all(&encode(value)?)
+        .map_err(|error| crate::error::EncodeError::io(error, crate::Codec::Aper))
+}
+
+/// Attempts to decode `T` from the rest of `reader` using APER.
+/// # Errors
+/// Returns error specific to APER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    mut reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    let mut input = alloc::vec::Vec::new();
+    reader
+        .read_to_end(&mut input)
+        .map_err(|error| crate::error::DecodeError::io(error, crate::Codec::Aper))?;
+    decode(&input)
+}
+
 /// Attempts to decode `T` from `input` using APER-BASIC.
 pub fn decode_with_constraints<T: crate::Decode>(
     constraints: Constraints,
@@ -435,6 +485,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bmp_string() {
+        round_trip!(
+            aper,
+            BmpString,
+            "Hi".try_into().unwrap(),
+            &[0x02, 0x00, 0x48, 0x00, 0x69]
+        );
+        round_trip_with_constraints!(
+            aper,
+            BmpString,
+            Constraints::new(&[Constraint::Size(Size::new(Bounded::Single(2)).into())]),
+            "Hi".try_into().unwrap(),
+            &[0x00, 0x48, 0x00, 0x69]
+        );
+    }
+
+    #[test]
+    fn universal_string() {
+        round_trip!(
+            aper,
+            UniversalString,
+            "Hi".into(),
+            &[0x02, 0x00, 0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x69]
+        );
+    }
+
     #[test]
     fn issue_192() {
         // https://github.com/XAMPPRocky/rasn/issues/192
@@ -492,4 +569,55 @@ mod tests {
         let encoded = rasn::aper::encode(&connect_data).expect("failed to encode");
         let _: ConnectData = rasn::aper::decode(&encoded).expect("failed to decode");
     }
+
+    #[test]
+    fn time_types() {
+        let date = Date::from_ymd_opt(2024, 2, 29).unwrap();
+
+        round_trip!(aper, Date, date, &[0x40, 0x03, 0x1E, 0x00]);
+        round_trip!(
+            aper,
+            TimeValue,
+            "R/P1D".parse().unwrap(),
+            &[0x05, b'R', b'/', b'P', b'1', b'D']
+        );
+
+        let error = crate::aper::decode::<TimeValue>(&[0x03, b'R', b'/', 0x85]).unwrap_err();
+        assert!(matches!(
+            &*error.kind,
+            crate::error::DecodeErrorKind::InvalidTime { .. }
+        ));
+    }
+
+    #[test]
+    fn canonical_padding() {
+        use super::{de, enc};
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Padded {
+            flag: bool,
+            value: Integer,
+        }
+
+        let value = Padded {
+            flag: true,
+            value: 5.into(),
+        };
+        let encoded = crate::per::encode(enc::EncoderOptions::canonical_aligned(), &value).unwrap();
+        assert_eq!(&[0x80, 0x01, 0x05][..], &*encoded);
+
+        let canonical = de::DecoderOptions::canonical_aligned();
+        assert_eq!(
+            value,
+            crate::per::decode::<Padded>(canonical, &encoded).unwrap()
+        );
+
+        let dirty_padding = [0x81, 0x01, 0x05];
+        assert_eq!(
+            value,
+            crate::per::decode::<Padded>(de::DecoderOptions::aligned(), &dirty_padding).unwrap()
+        );
+        assert!(crate::per::decode::<Padded>(canonical, &dirty_padding).is_err());
+    }
 }
@@ -1,9 +1,11 @@
 //! # Basic Encoding Rules
 
 pub mod de;
+pub mod dump;
 pub mod enc;
 mod identifier;
 mod rules;
+pub mod stream;
 
 pub use identifier::Identifier;
 pub(crate) use rules::EncodingRules;
@@ -12,7 +14,35 @@ pub(crate) use rules::EncodingRules;
 /// # Errors
 /// Returns error specific to BER decoder if decoding is not possible.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeError> {
-    T::decode(&mut de::Decoder::new(input, de::DecoderOptions::ber()))
+    de::Decoder::new(input, de::DecoderOptions::ber()).decode_with_offset()
+}
+
+/// Attempts to decode `T` from the start of `input` using BER, returning it
+/// together with the input following it.
+/// # Errors
+/// Returns error specific to BER decoder if decoding is not possible.
+pub fn decode_with_remainder<T: crate::Decode>(
+    input: &[u8],
+) -> Result<(T, &[u8]), crate::error::DecodeError> {
+    let mut decoder = de::Decoder::new(input, de::DecoderOptions::ber());
+    let value = decoder.decode_with_offset()?;
+    Ok((value, decoder.input()))
+}
+
+/// Returns an iterator decoding successive values of `T` from `input` using
+/// BER.
+pub fn decode_iter<T: crate::Decode>(input: &[u8]) -> crate::de::DecodeIter<'_, T> {
+    crate::de::DecodeIter::new(input, crate::Codec::Ber, decode_with_remainder)
+}
+
+/// Attempts to decode `T` from `input` using BER, borrowing from `input`
+/// where `T` allows it.
+/// # Errors
+/// Returns error specific to BER decoder if decoding is not possible.
+pub fn decode_borrowed<'de, T: crate::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, crate::error::DecodeError> {
+    de::Decoder::new(input, de::DecoderOptions::ber()).decode_borrowed_with_offset()
 }
 
 /// Attempts to encode `value` to BER.
@@ -28,6 +58,37 @@ pub fn encode<T: crate::Encode>(
     Ok(enc.output())
 }
 
+/// Attempts to encode `value` to BER, writing the encoding to `writer` as
+/// it's produced. Constructed values are encoded with the indefinite length
+/// form, so the output can differ from [`encode`]'s.
+/// # Errors
+/// Returns error specific to BER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    let mut enc = enc::Encoder::with_writer(enc::EncoderOptions::ber(), writer);
+
+    value.encode(&mut enc)?;
+
+    enc.finish().map(drop)
+}
+
+/// Attempts to decode `T` from `reader` using BER. Only the bytes of the
+/// value are read, so `reader` is left at the start of whatever follows it,
+/// such as the next message on a socket.
+/// # Errors
+/// Returns error specific to BER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    crate::ber::stream::StreamDecoder::new(de::DecoderOptions::ber()).read_value(reader)
+}
+
 /// Creates a new BER encoder that can be used to encode any value.
 /// # Errors
 /// Returns error specific to BER encoder if encoding is not possible.
@@ -218,6 +279,227 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn bmp_and_teletex_strings() {
+        round_trip!(
+            ber,
+            BmpString,
+            BmpString::try_from("Hi").unwrap(),
+            &[0x1E, 0x04, 0x00, 0x48, 0x00, 0x69]
+        );
+        round_trip!(
+            ber,
+            TeletexString,
+            TeletexString::try_from("Łódź").unwrap(),
+            &[0x14, 0x06, 0xE8, 0xC2, 0x6F, 0x64, 0xC2, 0x7A]
+        );
+        assert!(decode::<BmpString>(&[0x1E, 0x03, 0x00, 0x48, 0x00]).is_err());
+    }
+
+    #[test]
+    fn primitive_integers() {
+        for value in [0, 1, -1, 127, 128, -128, -129, i64::MIN, i64::MAX] {
+            let bigint = encode(&Integer::from(value)).unwrap();
+            assert_eq!(bigint, encode(&value).unwrap());
+            assert_eq!(value, decode::<i64>(&bigint).unwrap());
+        }
+
+        round_trip!(
+            ber,
+            u64,
+            u64::MAX,
+            &[0x02, 0x09, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        round_trip!(ber, i8, -128, &[0x02, 0x01, 0x80]);
+        assert!(decode::<u8>(&[0x02, 0x02, 0x01, 0x00]).is_err());
+        assert!(decode::<u8>(&[0x02, 0x01, 0x80]).is_err());
+        assert!(decode::<u64>(&[0x02, 0x0A, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        assert!(
+            decode::<u64>(&[0x02, 0x11, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn universal_graphic_and_videotex_strings() {
+        round_trip!(
+            ber,
+            UniversalString,
+            UniversalString::from("H😀"),
+            &[0x1C, 0x08, 0x00, 0x00, 0x00, 0x48, 0x00, 0x01, 0xF6, 0x00]
+        );
+        round_trip!(
+            ber,
+            GraphicString,
+            GraphicString::try_from(String::from("Hi")).unwrap(),
+            &[0x19, 0x02, 0x48, 0x69]
+        );
+        round_trip!(
+            ber,
+            VideotexString,
+            VideotexString::from(vec![0x48, 0x0A]),
+            &[0x15, 0x02, 0x48, 0x0A]
+        );
+        round_trip!(
+            ber,
+            ObjectDescriptor,
+            ObjectDescriptor::new(GraphicString::try_from(String::from("Hi")).unwrap()),
+            &[0x07, 0x02, 0x48, 0x69]
+        );
+
+        assert!(decode::<UniversalString>(&[0x1C, 0x03, 0x00, 0x00, 0x48]).is_err());
+        assert!(decode::<UniversalString>(&[0x1C, 0x04, 0x00, 0x00, 0xD8, 0x00]).is_err());
+        assert!(decode::<GraphicString>(&[0x19, 0x02, 0x48, 0x0A]).is_err());
+    }
+
+    #[test]
+    fn relative_oid_and_iri_types() {
+        // X.690 §8.20.5 example
+        round_trip!(
+            ber,
+            RelativeOid,
+            RelativeOid::new(vec![8571, 3, 2]).unwrap(),
+            &[0x0D, 0x04, 0xC2, 0x7B, 0x03, 0x02]
+        );
+        round_trip!(
+            ber,
+            OidIri,
+            OidIri::new("/ISO/A").unwrap(),
+            &[0x1F, 0x23, 0x06, 0x2F, 0x49, 0x53, 0x4F, 0x2F, 0x41]
+        );
+        round_trip!(
+            ber,
+            RelativeOidIri,
+            RelativeOidIri::new("A/1").unwrap(),
+            &[0x1F, 0x24, 0x03, 0x41, 0x2F, 0x31]
+        );
+
+        assert!(decode::<RelativeOid>(&[0x0D, 0x00]).is_err());
+        assert!(decode::<RelativeOid>(&[0x0D, 0x01, 0x80]).is_err());
+        assert!(decode::<OidIri>(&[0x1F, 0x23, 0x03, 0x49, 0x53, 0x4F]).is_err());
+    }
+
+    #[test]
+    fn external_types() {
+        let external = External {
+            direct_reference: Some(ObjectIdentifier::new(vec![2, 1, 1]).unwrap()),
+            indirect_reference: None,
+            data_value_descriptor: None,
+            encoding: ExternalEncoding::SingleAsn1Type(Any::new(vec![0x02, 0x01, 0x05])),
+        };
+        round_trip!(
+            ber,
+            External,
+            external,
+            &[0x28, 0x09, 0x06, 0x02, 0x51, 0x01, 0xA0, 0x03, 0x02, 0x01, 0x05]
+        );
+        round_trip!(
+            ber,
+            EmbeddedPdv,
+            EmbeddedPdv {
+                identification: Identification::Fixed,
+                data_value: OctetString::from_static(&[0xAB]),
+            },
+            &[0x2B, 0x07, 0xA0, 0x02, 0x85, 0x00, 0x82, 0x01, 0xAB]
+        );
+        round_trip!(
+            ber,
+            CharacterString,
+            CharacterString {
+                identification: Identification::Syntax(ObjectIdentifier::new(vec![1, 2]).unwrap()),
+                string_value: OctetString::from_static(b"hi"),
+            },
+            &[0x3D, 0x09, 0xA0, 0x03, 0x81, 0x01, 0x2A, 0x82, 0x02, 0x68, 0x69]
+        );
+    }
+
+    #[test]
+    fn time_types() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let time = date.and_hms_opt(13, 30, 5).unwrap();
+
+        round_trip!(
+            ber,
+            Date,
+            date,
+            &[&[0x1F, 0x1F, 0x08][..], b"20240229"].concat()
+        );
+        round_trip!(
+            ber,
+            TimeOfDay,
+            time.time(),
+            &[&[0x1F, 0x20, 0x06][..], b"133005"].concat()
+        );
+        round_trip!(
+            ber,
+            crate::types::DateTime,
+            time,
+            &[&[0x1F, 0x21, 0x0E][..], b"20240229133005"].concat()
+        );
+        round_trip!(
+            ber,
+            Duration,
+            "P1Y2M10DT2H30M".parse().unwrap(),
+            &[&[0x1F, 0x22, 0x0E][..], b"P1Y2M10DT2H30M"].concat()
+        );
+        round_trip!(
+            ber,
+            TimeValue,
+            "2024-02-29T13:30Z".parse().unwrap(),
+            &[&[0x0E, 0x11][..], b"2024-02-29T13:30Z"].concat()
+        );
+        assert!(decode::<Date>(&[&[0x1F, 0x1F, 0x08][..], b"20240230"].concat()).is_err());
+        assert!(encode(&Duration::default()).is_err());
+    }
+
+    #[test]
+    fn real() {
+        round_trip!(ber, f64, 0.0, &[0x09, 0x00]);
+        round_trip!(ber, f64, 1.0, &[0x09, 0x03, 0x80, 0x00, 0x01]);
+        round_trip!(ber, f64, 0.5, &[0x09, 0x03, 0x80, 0xFF, 0x01]);
+        round_trip!(ber, f32, -10.0, &[0x09, 0x03, 0xC0, 0x01, 0x05]);
+        round_trip!(
+            ber,
+            Real,
+            Real::binary(1, 300),
+            &[0x09, 0x04, 0x81, 0x01, 0x2C, 0x01]
+        );
+        round_trip!(ber, f64, f64::INFINITY, &[0x09, 0x01, 0x40]);
+        round_trip!(ber, f64, f64::NEG_INFINITY, &[0x09, 0x01, 0x41]);
+        round_trip!(ber, Real, Real::NotANumber, &[0x09, 0x01, 0x42]);
+        round_trip!(ber, Real, Real::MinusZero, &[0x09, 0x01, 0x43]);
+        // "314.E-2"
+        round_trip!(
+            ber,
+            Real,
+            Real::decimal(314, -2),
+            &[0x09, 0x08, 0x03, 0x33, 0x31, 0x34, 0x2E, 0x45, 0x2D, 0x32]
+        );
+        // "1.E+0"
+        round_trip!(
+            ber,
+            Real,
+            Real::decimal(10, -1),
+            &[0x09, 0x06, 0x03, 0x31, 0x2E, 0x45, 0x2B, 0x30]
+        );
+
+        // Base 8, and the NR1 form "12", are only allowed in BER.
+        let base_eight = [0x09, 0x03, 0x90, 0x01, 0x01];
+        assert_eq!(8.0, decode::<f64>(&base_eight).unwrap());
+        assert!(crate::der::decode::<f64>(&base_eight).is_err());
+        let nr1 = [0x09, 0x03, 0x01, 0x31, 0x32];
+        assert_eq!(Real::decimal(12, 0), decode::<Real>(&nr1).unwrap());
+        assert!(crate::der::decode::<Real>(&nr1).is_err());
+        // Base 16 with a scale factor of 1: 2 × 2^1 × 16^-1
+        assert_eq!(
+            0.25,
+            decode::<f64>(&[0x09, 0x03, 0xA4, 0xFF, 0x02]).unwrap()
+        );
+
+        assert!(decode::<f64>(&[0x09, 0x01, 0x44]).is_err());
+        assert!(decode::<f64>(&[0x09, 0x02, 0x83, 0x00]).is_err());
+    }
+
     #[test]
     fn test_generalized_time() {
         // "20801009130005.342Z"
@@ -304,6 +586,37 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(dt1, result.unwrap());
     }
+
+    #[test]
+    fn lossless_generalized_time() {
+        for string in [
+            "2024010203,5+01",
+            "20240102030405.120Z",
+            "202401020304-0530",
+            "20240102030405",
+        ] {
+            let time: LosslessGeneralizedTime = string.parse().unwrap();
+            let mut expected = vec![0x18, string.len() as u8];
+            expected.extend_from_slice(string.as_bytes());
+            round_trip!(ber, LosslessGeneralizedTime, time.clone(), &expected);
+
+            assert!(!time.is_canonical());
+            assert!(crate::der::decode::<LosslessGeneralizedTime>(&expected).is_err());
+            let options = de::DecoderOptions::der().with_lenient_time(true);
+            assert_eq!(
+                time,
+                crate::Decode::decode(&mut de::Decoder::new(&expected, options)).unwrap()
+            );
+        }
+
+        let time: LosslessGeneralizedTime = "20240102030405.12Z".parse().unwrap();
+        assert!(time.is_canonical());
+        assert_eq!(time, GeneralizedTime::from(time.clone()).into());
+        assert_eq!(
+            time,
+            crate::der::decode(&crate::der::encode(&time).unwrap()).unwrap()
+        );
+    }
     #[test]
     fn test_utc_time() {
         // "180122132900Z"
@@ -348,4 +661,32 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(dt1, result.unwrap());
     }
+
+    #[test]
+    fn borrowed() {
+        let input = [0x04, 0x03, 0x01, 0x02, 0x03];
+        let bytes: &[u8] = decode_borrowed(&input).unwrap();
+        assert_eq!(bytes, &[1, 2, 3]);
+        assert_eq!(bytes.as_ptr(), input[2..].as_ptr());
+        assert_eq!(&*encode(&bytes).unwrap(), &input);
+
+        let input = [0x0c, 0x02, b'h', b'i'];
+        let string: &str = decode_borrowed(&input).unwrap();
+        assert_eq!(string, "hi");
+        assert_eq!(&*encode(&string).unwrap(), &input);
+        assert!(decode_borrowed::<&str>(&[0x0c, 0x01, 0xff]).is_err());
+
+        // A constructed OCTET STRING can only be decoded into an owned value.
+        let constructed = [0x24, 0x80, 0x04, 0x01, 0x01, 0x04, 0x01, 0x02, 0x00, 0x00];
+        assert!(decode_borrowed::<&[u8]>(&constructed).is_err());
+        assert_eq!(
+            decode::<OctetString>(&constructed).unwrap(),
+            OctetString::from_static(&[1, 2])
+        );
+
+        let any: AnyRef = decode_borrowed(&constructed).unwrap();
+        assert_eq!(any.as_bytes(), &constructed);
+        assert_eq!(any.to_any(), decode::<Any>(&constructed).unwrap());
+        assert_eq!(&*encode(&any).unwrap(), &constructed);
+    }
 }
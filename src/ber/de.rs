@@ -1,19 +1,24 @@
 //! # Decoding BER
 
 mod config;
-pub(super) mod parser;
+pub(crate) mod parser;
 
 use super::identifier::Identifier;
 use crate::{
+    error::Offset,
     types::{
         self,
         oid::{MAX_OID_FIRST_OCTET, MAX_OID_SECOND_OCTET},
         Constraints, Enumerated, Tag,
     },
-    Decode,
+    Decode, DecodeBorrowed,
 };
-use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
-use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::{DateTime, NaiveDateTime};
 
 pub use self::config::DecoderOptions;
 
@@ -28,6 +33,16 @@ pub struct Decoder<'input> {
     input: &'input [u8],
     config: DecoderOptions,
     initial_len: usize,
+    /// How many constructed values the current value is nested in.
+    depth: usize,
+    /// The sum of the lengths of the primitive values decoded so far,
+    /// checked against the maximum total length.
+    total_length: usize,
+    /// The offset of `input` in the outermost input, when this decoder was
+    /// created.
+    origin: usize,
+    /// The offset of the value that was last started to be decoded.
+    value_offset: usize,
 }
 
 impl<'input> Decoder<'input> {
@@ -43,15 +58,46 @@ impl<'input> Decoder<'input> {
             input,
             config,
             initial_len: input.len(),
+            depth: 0,
+            total_length: 0,
+            origin: 0,
+            value_offset: 0,
         }
     }
 
+    /// Decodes a `T`, recording where in the input decoding failed in any
+    /// error.
+    pub(crate) fn decode_with_offset<T: Decode>(&mut self) -> Result<T> {
+        T::decode(self).map_err(|error| error.at_offset(Offset::Byte(self.value_offset)))
+    }
+
+    pub(crate) fn decode_borrowed_with_offset<T: DecodeBorrowed<'input>>(&mut self) -> Result<T> {
+        T::decode_borrowed(self).map_err(|error| error.at_offset(Offset::Byte(self.value_offset)))
+    }
+
+    /// Returns the offset of the remaining input in the outermost input.
+    fn position(&self) -> usize {
+        self.origin + self.decoded_len()
+    }
+
     /// Return a number of the decoded bytes by this decoder
     #[must_use]
     pub fn decoded_len(&self) -> usize {
         self.initial_len - self.input.len()
     }
 
+    /// Returns the remaining input, if any.
+    #[must_use]
+    pub fn input(&self) -> &'input [u8] {
+        self.input
+    }
+
+    /// Whether there is another component of a constructed value left to
+    /// decode, which is not the case at the end of its contents.
+    fn has_component(&self) -> bool {
+        !self.input.is_empty() && !self.input.starts_with(EOC)
+    }
+
     fn parse_eoc(&mut self) -> Result<()> {
         let (i, _) = nom::bytes::streaming::tag(EOC)(self.input)
             .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
@@ -60,6 +106,7 @@ impl<'input> Decoder<'input> {
     }
 
     pub(crate) fn parse_value(&mut self, tag: Tag) -> Result<(Identifier, Option<&'input [u8]>)> {
+        self.value_offset = self.position();
         let (input, (identifier, contents)) =
             self::parser::parse_value(&self.config, self.input, Some(tag))?;
         self.input = input;
@@ -67,15 +114,37 @@ impl<'input> Decoder<'input> {
     }
 
     pub(crate) fn parse_primitive_value(&mut self, tag: Tag) -> Result<(Identifier, &'input [u8])> {
+        self.value_offset = self.position();
         let (input, (identifier, contents)) =
             self::parser::parse_value(&self.config, self.input, Some(tag))?;
         self.input = input;
         match contents {
-            Some(contents) => Ok((identifier, contents)),
+            Some(contents) => {
+                self.add_to_total_length(contents.len())?;
+                Ok((identifier, contents))
+            }
             None => Err(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into()),
         }
     }
 
+    /// Adds the `length` of a decoded value to the total, checking it against
+    /// the maximum total length.
+    fn add_to_total_length(&mut self, length: usize) -> Result<()> {
+        self.total_length = self
+            .config
+            .limits
+            .check_total_length(self.total_length.saturating_add(length), self.codec())?;
+        Ok(())
+    }
+
+    /// Decodes one of the time types from a primitive value containing its
+    /// ISO 8601 basic format representation (X.690 8.26).
+    fn decode_time_type<T: types::TimeType>(&mut self, tag: Tag) -> Result<T> {
+        let contents = self.parse_primitive_value(tag)?.1;
+        T::from_basic_bytes(contents)
+            .map_err(|reason| DecodeError::invalid_time(reason, self.codec()))
+    }
+
     /// Parses a constructed ASN.1 value, checking the `tag`, and optionally
     /// checking if the identifier is marked as encoded. This should be true
     /// in all cases except explicit prefixes.
@@ -96,14 +165,23 @@ impl<'input> Decoder<'input> {
             return Err(BerDecodeErrorKind::InvalidConstructedIdentifier.into());
         }
 
-        let (streaming, contents) = match contents {
-            Some(contents) => (false, contents),
-            None => (true, self.input),
+        let (streaming, contents, origin) = match contents {
+            Some(contents) => (false, contents, self.position() - contents.len()),
+            None => (true, self.input, self.position()),
         };
 
         let mut inner = Self::new(contents, self.config);
-
-        let result = (decode_fn)(&mut inner)?;
+        inner.depth = self
+            .config
+            .limits
+            .check_depth(self.depth + 1, self.codec())?;
+        inner.total_length = self.total_length;
+        inner.origin = origin;
+        inner.value_offset = origin;
+
+        let result = (decode_fn)(&mut inner)
+            .map_err(|error| error.at_offset(Offset::Byte(inner.value_offset)))?;
+        self.total_length = inner.total_length;
 
         if streaming {
             self.input = inner.input;
@@ -154,88 +232,111 @@ impl<'input> Decoder<'input> {
         crate::types::ObjectIdentifier::new(buffer)
             .ok_or_else(|| BerDecodeErrorKind::InvalidObjectIdentifier.into())
     }
-    /// Parse any GeneralizedTime string, allowing for any from ASN.1 definition
-    /// TODO, move to type itself?
-    pub fn parse_any_generalized_time_string(
-        string: alloc::string::String,
-    ) -> Result<types::GeneralizedTime, DecodeError> {
-        // Reference https://obj-sys.com/asn1tutorial/node14.html
-        // If data contains ., 3 decimal places of seconds are expected
-        // If data contains explict Z, result is UTC
-        // If data contains + or -, explicit timezone is given
-        // If neither Z nor + nor -, purely local time is implied
-        let len = string.len();
-        // Helper function to deal with fractions and without timezone
-        let parse_without_timezone = |string: &str| -> Result<NaiveDateTime, DecodeError> {
-            // Handle both decimal cases (dot . and comma , )
-            let string: &str = &string.replace(",", ".");
-            if string.contains('.') {
-                // Use chrono to parse the string every time, since we don't the know the number of decimals places
-                NaiveDateTime::parse_from_str(string, "%Y%m%d%H%.f")
-                    .or_else(|_| NaiveDateTime::parse_from_str(string, "%Y%m%d%H%M%.f"))
-                    .or_else(|_| NaiveDateTime::parse_from_str(string, "%Y%m%d%H%M%S%.f"))
-                    .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()).into())
-            } else {
-                let fmt_string = match string.len() {
-                    8 => "%Y%m%d",
-                    10 => "%Y%m%d%H",
-                    12 => "%Y%m%d%H%M",
-                    14 => "%Y%m%d%H%M%S",
-                    _ => "",
+    /// Decode a relative object identifier from a byte slice in BER format.
+    /// Function is public to be used by other codecs.
+    pub fn decode_relative_oid_from_bytes(
+        &self,
+        mut contents: &[u8],
+    ) -> Result<crate::types::RelativeOid, DecodeError> {
+        use num_traits::ToPrimitive;
+        let mut buffer = Vec::new();
+
+        while !contents.is_empty() {
+            let (c, number) = parser::parse_base128_number(contents)
+                .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
+            contents = c;
+            buffer.push(
+                number
+                    .to_u32()
+                    .ok_or_else(|| DecodeError::integer_overflow(32u32, self.codec()))?,
+            );
+        }
+        crate::types::RelativeOid::new(buffer)
+            .ok_or_else(|| BerDecodeErrorKind::InvalidObjectIdentifier.into())
+    }
+    /// Decodes the UTF-8 contents of a primitive `OID-IRI` or
+    /// `RELATIVE-OID-IRI` identified by `tag`.
+    fn decode_iri<T>(&mut self, tag: Tag, new: fn(String) -> Option<T>) -> Result<T> {
+        let contents = self.parse_primitive_value(tag)?.1;
+        let iri = String::from_utf8(contents.to_vec())
+            .map_err(|e| DecodeError::string_conversion_failed(tag, e.to_string(), self.codec()))?;
+        new(iri.clone()).ok_or_else(|| DecodeError::invalid_oid_iri(iri, self.codec()))
+    }
+    /// Decode a REAL value from its contents octets in BER format, see
+    /// X.690 section 8.5. CER and DER only accept the canonical form.
+    /// Function is public to be used by other codecs.
+    pub fn decode_real_from_bytes(&self, data: &[u8]) -> Result<types::Real, DecodeError> {
+        use num_traits::ToPrimitive;
+        let codec = self.codec();
+        let invalid = |reason| DecodeError::invalid_real_encoding(reason, codec);
+
+        let value = match data {
+            [] => types::Real::zero(),
+            [0x40] => types::Real::PlusInfinity,
+            [0x41] => types::Real::MinusInfinity,
+            [0x42] => types::Real::NotANumber,
+            [0x43] => types::Real::MinusZero,
+            [first, rest @ ..] if first & 0x80 != 0 => {
+                let bits_per_digit = match (first >> 4) & 0b11 {
+                    0 => 1,
+                    1 => 3,
+                    2 => 4,
+                    _ => return Err(invalid("reserved base")),
+                };
+                let scale = i64::from((first >> 2) & 0b11);
+                let (exponent_length, rest) = match (first & 0b11, rest) {
+                    (0b11, [length, rest @ ..]) => (usize::from(*length), rest),
+                    (0b11, []) => return Err(invalid("missing exponent length")),
+                    (length, rest) => (usize::from(length) + 1, rest),
                 };
-                match fmt_string.len() {
-                    l if l > 0 => NaiveDateTime::parse_from_str(string, fmt_string)
-                        .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()).into()),
-                    _ => Err(BerDecodeErrorKind::invalid_date(string.to_string()).into()),
+                if exponent_length == 0 || rest.len() < exponent_length {
+                    return Err(invalid("exponent is truncated"));
+                }
+                let (exponent, magnitude) = rest.split_at(exponent_length);
+                let exponent = types::Integer::from_signed_bytes_be(exponent)
+                    .to_i64()
+                    .and_then(|exponent| exponent.checked_mul(bits_per_digit))
+                    .and_then(|exponent| exponent.checked_add(scale))
+                    .ok_or_else(|| invalid("exponent is too large"))?;
+                let sign = if first & 0x40 == 0 {
+                    num_bigint::Sign::Plus
+                } else {
+                    num_bigint::Sign::Minus
+                };
+                types::Real::Binary {
+                    mantissa: types::Integer::from_bytes_be(sign, magnitude),
+                    exponent,
                 }
             }
+            [first, ..] if first & 0xC0 == 0x40 => return Err(invalid("unknown special value")),
+            [1..=3, text @ ..] => core::str::from_utf8(text)
+                .ok()
+                .and_then(types::Real::from_iso6093)
+                .ok_or_else(|| invalid("decimal value is not an ISO 6093 number"))?,
+            _ => return Err(invalid("unknown encoding form")),
         };
-        if string.ends_with('Z') {
-            let naive = parse_without_timezone(&string[..len - 1])?;
-            return Ok(naive.and_utc().into());
-        }
-        // Check for timezone offset
-        if len > 5
-            && string
-                .chars()
-                .nth(len - 5)
-                .map_or(false, |c| c == '+' || c == '-')
+
+        if !self.config.encoding_rules.is_ber()
+            && crate::ber::enc::Encoder::real_to_canonical_bytes(&value) != data
         {
-            let naive = parse_without_timezone(&string[..len - 5])?;
-            let sign = match string.chars().nth(len - 5) {
-                Some('+') => 1,
-                Some('-') => -1,
-                _ => {
-                    return Err(BerDecodeErrorKind::invalid_date(string.to_string()).into());
-                }
-            };
-            let offset_hours = string
-                .chars()
-                .skip(len - 4)
-                .take(2)
-                .collect::<alloc::string::String>()
-                .parse::<i32>()
-                .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?;
-            let offset_minutes = string
-                .chars()
-                .skip(len - 2)
-                .take(2)
-                .collect::<alloc::string::String>()
-                .parse::<i32>()
-                .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?;
-            if offset_hours > 23 || offset_minutes > 59 {
-                return Err(BerDecodeErrorKind::invalid_date(string.to_string()).into());
-            }
-            let offset = FixedOffset::east_opt(sign * (offset_hours * 3600 + offset_minutes * 60))
-                .ok_or_else(|| BerDecodeErrorKind::invalid_date(string.to_string()))?;
-            return Ok(TimeZone::from_local_datetime(&offset, &naive)
-                .single()
-                .ok_or_else(|| BerDecodeErrorKind::invalid_date(string.to_string()))?);
+            return Err(DecodeError::non_canonical_encoding(
+                "REAL is not in its canonical form",
+                codec,
+            ));
         }
 
-        // Parse without timezone details
-        let naive = parse_without_timezone(&string)?;
-        Ok(naive.and_utc().into())
+        Ok(value)
+    }
+    /// Parse any GeneralizedTime string, allowing for any from ASN.1 definition
+    pub fn parse_any_generalized_time_string(
+        string: alloc::string::String,
+    ) -> Result<types::GeneralizedTime, DecodeError> {
+        // Reference https://obj-sys.com/asn1tutorial/node14.html
+        // Local times without a time zone are treated as UTC.
+        string
+            .parse::<types::LosslessGeneralizedTime>()
+            .map(|time| time.to_date_time())
+            .map_err(|_| BerDecodeErrorKind::invalid_date(string).into())
     }
     /// Enforce CER/DER restrictions defined in Section 11.7, strictly raise error on non-compliant
     pub fn parse_canonical_generalized_time_string(
@@ -319,6 +420,16 @@ impl<'input> Decoder<'input> {
     }
 }
 
+/// Turns a failed attempt at decoding a value that may be absent into `None`,
+/// unless decoding failed because one of the decoder's limits was exceeded.
+fn optional<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if error.is_limit_exceeded() => Err(error),
+        Err(_) => Ok(None),
+    }
+}
+
 impl<'input> crate::Decoder for Decoder<'input> {
     type Error = DecodeError;
 
@@ -326,12 +437,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         Self::codec(self)
     }
     fn decode_any(&mut self) -> Result<types::Any> {
+        self.value_offset = self.position();
         let (mut input, (identifier, contents)) =
             self::parser::parse_value(&self.config, self.input, None)?;
 
         if contents.is_none() {
             let (i, _) = self::parser::parse_encoded_value(
                 &self.config,
+                self.depth,
                 self.input,
                 identifier.tag,
                 |input, _| Ok(alloc::vec::Vec::from(input)),
@@ -341,6 +454,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
         let diff = self.input.len() - input.len();
         let contents = &self.input[..diff];
         self.input = input;
+        self.add_to_total_length(contents.len())?;
 
         Ok(types::Any {
             contents: contents.to_vec(),
@@ -381,23 +495,37 @@ impl<'input> crate::Decoder for Decoder<'input> {
         ))
     }
 
+    fn decode_primitive_integer(&mut self, tag: Tag, _: Constraints) -> Result<i128> {
+        let contents = self.parse_primitive_value(tag)?.1;
+        crate::num::i128_from_signed_bytes_be(contents)
+            .ok_or_else(|| DecodeError::integer_overflow(i128::BITS, self.codec()))
+    }
+
     fn decode_octet_string(&mut self, tag: Tag, _: Constraints) -> Result<Vec<u8>> {
         let (identifier, contents) = self.parse_value(tag)?;
 
         if identifier.is_primitive() {
             match contents {
-                Some(c) => Ok(c.to_vec()),
+                Some(c) => {
+                    self.add_to_total_length(c.len())?;
+                    Ok(c.to_vec())
+                }
                 None => Err(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into()),
             }
         } else if identifier.is_constructed() && self.config.encoding_rules.is_der() {
             Err(DerDecodeErrorKind::ConstructedEncodingNotAllowed.into())
         } else {
+            let depth = self
+                .config
+                .limits
+                .check_depth(self.depth + 1, self.codec())?;
             let mut buffer = Vec::new();
 
             if let Some(mut contents) = contents {
                 while !contents.is_empty() {
                     let (c, mut vec) = self::parser::parse_encoded_value(
                         &self.config,
+                        depth,
                         contents,
                         Tag::OCTET_STRING,
                         |input, _| Ok(alloc::vec::Vec::from(input)),
@@ -410,6 +538,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
                 while !self.input.starts_with(EOC) {
                     let (c, mut vec) = self::parser::parse_encoded_value(
                         &self.config,
+                        depth,
                         self.input,
                         Tag::OCTET_STRING,
                         |input, _| Ok(alloc::vec::Vec::from(input)),
@@ -422,10 +551,16 @@ impl<'input> crate::Decoder for Decoder<'input> {
                 self.parse_eoc()?;
             }
 
+            self.add_to_total_length(buffer.len())?;
             Ok(buffer)
         }
     }
 
+    fn decode_real(&mut self, tag: Tag, _: Constraints) -> Result<types::Real> {
+        let contents = self.parse_primitive_value(tag)?.1;
+        self.decode_real_from_bytes(contents)
+    }
+
     fn decode_null(&mut self, tag: Tag) -> Result<()> {
         let (_, contents) = self.parse_primitive_value(tag)?;
         DecodeError::assert_length(0, contents.len(), self.codec())?;
@@ -437,9 +572,27 @@ impl<'input> crate::Decoder for Decoder<'input> {
         self.decode_object_identifier_from_bytes(contents)
     }
 
+    fn decode_relative_oid(&mut self, tag: Tag) -> Result<types::RelativeOid> {
+        let contents = self.parse_primitive_value(tag)?.1;
+        self.decode_relative_oid_from_bytes(contents)
+    }
+
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri> {
+        self.decode_iri(tag, types::OidIri::new)
+    }
+
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri> {
+        self.decode_iri(tag, types::RelativeOidIri::new)
+    }
+
     fn decode_bit_string(&mut self, tag: Tag, _: Constraints) -> Result<types::BitString> {
-        let (input, bs) =
-            self::parser::parse_encoded_value(&self.config, self.input, tag, |input, codec| {
+        self.value_offset = self.position();
+        let (input, bs) = self::parser::parse_encoded_value(
+            &self.config,
+            self.depth,
+            self.input,
+            tag,
+            |input, codec| {
                 let Some(unused_bits) = input.first().copied() else {
                     return Ok(types::BitString::new());
                 };
@@ -463,9 +616,11 @@ impl<'input> crate::Decoder for Decoder<'input> {
                     }
                     _ => Err(DecodeError::invalid_bit_string(unused_bits, codec)),
                 }
-            })?;
+            },
+        )?;
 
         self.input = input;
+        self.add_to_total_length(bs.as_raw_slice().len())?;
         Ok(bs)
     }
 
@@ -530,17 +685,38 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::TeletexString> {
-        types::TeletexString::try_from(self.decode_octet_string(tag, constraints)?).map_err(|e| {
+        self.decode_octet_string(tag, constraints)
+            .map(types::TeletexString::from)
+    }
+
+    fn decode_bmp_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::BmpString> {
+        types::BmpString::try_from(&*self.decode_octet_string(tag, constraints)?).map_err(|e| {
             DecodeError::string_conversion_failed(
-                types::Tag::TELETEX_STRING,
+                types::Tag::BMP_STRING,
                 e.to_string(),
                 self.codec(),
             )
         })
     }
 
-    fn decode_bmp_string(&mut self, _: Tag, _constraints: Constraints) -> Result<types::BmpString> {
-        todo!()
+    fn decode_universal_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::UniversalString> {
+        types::UniversalString::try_from(&*self.decode_octet_string(tag, constraints)?).map_err(
+            |e| {
+                DecodeError::string_conversion_failed(
+                    types::Tag::UNIVERSAL_STRING,
+                    e.to_string(),
+                    self.codec(),
+                )
+            },
+        )
     }
 
     fn decode_utf8_string(
@@ -572,25 +748,81 @@ impl<'input> crate::Decoder for Decoder<'input> {
         })
     }
 
+    fn decode_graphic_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::GraphicString> {
+        <types::GraphicString>::try_from(self.decode_octet_string(tag, constraints)?).map_err(|e| {
+            DecodeError::string_conversion_failed(
+                types::Tag::GRAPHIC_STRING,
+                e.to_string(),
+                self.codec(),
+            )
+        })
+    }
+
+    fn decode_videotex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::VideotexString> {
+        self.decode_octet_string(tag, constraints)
+            .map(types::VideotexString::from)
+    }
+
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime> {
         let string = self.decode_utf8_string(tag, <_>::default())?;
-        if self.config.encoding_rules.is_ber() {
+        if self.config.encoding_rules.is_ber() || self.config.lenient_time {
             Self::parse_any_generalized_time_string(string)
         } else {
             Self::parse_canonical_generalized_time_string(string)
         }
     }
 
+    fn decode_lossless_generalized_time(
+        &mut self,
+        tag: Tag,
+    ) -> Result<types::LosslessGeneralizedTime> {
+        let string = self.decode_utf8_string(tag, <_>::default())?;
+        let is_canonical_required =
+            !self.config.encoding_rules.is_ber() && !self.config.lenient_time;
+        match string.parse::<types::LosslessGeneralizedTime>() {
+            Ok(time) if time.is_canonical() || !is_canonical_required => Ok(time),
+            _ => Err(BerDecodeErrorKind::invalid_date(string).into()),
+        }
+    }
+
     fn decode_utc_time(&mut self, tag: Tag) -> Result<types::UtcTime> {
         // Reference https://obj-sys.com/asn1tutorial/node15.html
         let string = self.decode_utf8_string(tag, <_>::default())?;
-        if self.config.encoding_rules.is_ber() {
+        if self.config.encoding_rules.is_ber() || self.config.lenient_time {
             Self::parse_any_utc_time_string(string)
         } else {
             Self::parse_canonical_utc_time_string(&string)
         }
     }
 
+    fn decode_date(&mut self, tag: Tag) -> Result<types::Date> {
+        self.decode_time_type(tag)
+    }
+
+    fn decode_time_of_day(&mut self, tag: Tag) -> Result<types::TimeOfDay> {
+        self.decode_time_type(tag)
+    }
+
+    fn decode_date_time(&mut self, tag: Tag) -> Result<types::DateTime> {
+        self.decode_time_type(tag)
+    }
+
+    fn decode_duration(&mut self, tag: Tag) -> Result<types::Duration> {
+        self.decode_time_type(tag)
+    }
+
+    fn decode_time(&mut self, tag: Tag) -> Result<types::TimeValue> {
+        self.decode_time_type(tag)
+    }
+
     fn decode_sequence_of<D: Decode>(
         &mut self,
         tag: Tag,
@@ -599,16 +831,13 @@ impl<'input> crate::Decoder for Decoder<'input> {
         self.parse_constructed_contents(tag, true, |decoder| {
             let mut items = Vec::new();
 
-            if decoder.input.is_empty() {
-                return Ok(items);
-            }
-
-            while let Ok(item) = D::decode(decoder) {
-                items.push(item);
-
-                if decoder.input.is_empty() {
-                    return Ok(items);
-                }
+            while decoder.has_component() {
+                let index = items.len();
+                decoder
+                    .config
+                    .limits
+                    .check_elements(index + 1, decoder.codec())?;
+                items.push(D::decode(decoder).map_err(|error| error.at_index(index))?);
             }
 
             Ok(items)
@@ -622,9 +851,15 @@ impl<'input> crate::Decoder for Decoder<'input> {
     ) -> Result<types::SetOf<D>, Self::Error> {
         self.parse_constructed_contents(tag, true, |decoder| {
             let mut items = types::SetOf::new();
-
-            while let Ok(item) = D::decode(decoder) {
-                items.insert(item);
+            let mut count = 0;
+
+            while decoder.has_component() {
+                let index = count;
+                count = decoder
+                    .config
+                    .limits
+                    .check_elements(count + 1, decoder.codec())?;
+                items.insert(D::decode(decoder).map_err(|error| error.at_index(index))?);
             }
 
             Ok(items)
@@ -678,7 +913,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
         self.parse_constructed_contents(tag, true, |decoder| {
             let mut fields = Vec::new();
 
-            while let Ok(value) = FIELDS::decode(decoder) {
+            while let Some(value) = optional(FIELDS::decode(decoder))? {
                 fields.push(value);
             }
 
@@ -688,7 +923,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
 
     fn decode_optional<D: Decode>(&mut self) -> Result<Option<D>, Self::Error> {
         if D::TAG == Tag::EOC {
-            Ok(D::decode(self).ok())
+            optional(D::decode(self))
         } else {
             self.decode_optional_with_tag(D::TAG)
         }
@@ -698,14 +933,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
     /// Passing the correct tag is required even when used with codecs where
     /// the tag is not present.
     fn decode_optional_with_tag<D: Decode>(&mut self, tag: Tag) -> Result<Option<D>, Self::Error> {
-        Ok(D::decode_with_tag(self, tag).ok())
+        optional(D::decode_with_tag(self, tag))
     }
 
     fn decode_optional_with_constraints<D: Decode>(
         &mut self,
         constraints: Constraints,
     ) -> Result<Option<D>, Self::Error> {
-        Ok(D::decode_with_constraints(self, constraints).ok())
+        optional(D::decode_with_constraints(self, constraints))
     }
 
     fn decode_optional_with_tag_and_constraints<D: Decode>(
@@ -713,7 +948,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<Option<D>, Self::Error> {
-        Ok(D::decode_with_tag_and_constraints(self, tag, constraints).ok())
+        optional(D::decode_with_tag_and_constraints(self, tag, constraints))
     }
 
     fn decode_choice<D>(&mut self, _: Constraints) -> Result<D, Self::Error>
@@ -743,6 +978,79 @@ impl<'input> crate::Decoder for Decoder<'input> {
     }
 }
 
+impl<'input> crate::BorrowDecoder<'input> for Decoder<'input> {
+    fn decode_octet_string_borrowed(&mut self, tag: Tag, _: Constraints) -> Result<&'input [u8]> {
+        let (identifier, contents) = self.parse_value(tag)?;
+
+        match contents {
+            Some(contents) if identifier.is_primitive() => Ok(contents),
+            _ if identifier.is_constructed() && self.config.encoding_rules.is_der() => {
+                Err(DerDecodeErrorKind::ConstructedEncodingNotAllowed.into())
+            }
+            _ => Err(BerDecodeErrorKind::ConstructedEncodingNotBorrowable.into()),
+        }
+    }
+
+    fn decode_utf8_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<&'input str> {
+        let contents = self.decode_octet_string_borrowed(tag, constraints)?;
+        core::str::from_utf8(contents).map_err(|e| {
+            DecodeError::string_conversion_failed(
+                types::Tag::UTF8_STRING,
+                e.to_string(),
+                self.codec(),
+            )
+        })
+    }
+
+    fn decode_any_borrowed(&mut self) -> Result<types::AnyRef<'input>> {
+        self.value_offset = self.position();
+        let (mut input, (identifier, contents)) =
+            self::parser::parse_value(&self.config, self.input, None)?;
+
+        if contents.is_none() {
+            let (i, _) = self::parser::parse_encoded_value(
+                &self.config,
+                self.depth,
+                self.input,
+                identifier.tag,
+                |_, _| Ok(()),
+            )?;
+            input = i;
+        }
+        let diff = self.input.len() - input.len();
+        let contents = &self.input[..diff];
+        self.input = input;
+
+        Ok(types::AnyRef::new(contents))
+    }
+
+    fn decode_explicit_prefix_borrowed<D: DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<D> {
+        self.parse_constructed_contents(tag, false, D::decode_borrowed)
+    }
+
+    fn decode_optional_borrowed<D: DecodeBorrowed<'input>>(&mut self) -> Result<Option<D>> {
+        if D::TAG == Tag::EOC {
+            optional(D::decode_borrowed(self))
+        } else {
+            self.decode_optional_borrowed_with_tag(D::TAG)
+        }
+    }
+
+    fn decode_optional_borrowed_with_tag<D: DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<Option<D>> {
+        optional(D::decode_borrowed_with_tag(self, tag))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::String;
@@ -1088,4 +1396,121 @@ mod tests {
         let oid = oid.unwrap();
         assert_eq!(ObjectIdentifier::new([2, 999, 1].to_vec()).unwrap(), oid);
     }
+
+    #[test]
+    fn limits() {
+        use crate::de::{Limit, Limits};
+
+        fn decode<T: crate::Decode>(input: &[u8], limits: Limits) -> Result<T, Limit> {
+            let options = DecoderOptions::ber().with_limits(limits);
+            T::decode(&mut Decoder::new(input, options)).map_err(|error| match *error.kind {
+                DecodeErrorKind::LimitExceeded { limit, .. } => limit,
+                kind => panic!("unexpected error: {kind}"),
+            })
+        }
+
+        let depth = |max_depth| Limits {
+            max_depth,
+            ..Limits::default()
+        };
+        let nested = [0x30, 0x04, 0x30, 0x02, 0x30, 0x00];
+        assert!(decode::<Vec<Vec<Vec<()>>>>(&nested, depth(3)).is_ok());
+        assert_eq!(
+            Err(Limit::Depth),
+            decode::<Vec<Vec<Vec<()>>>>(&nested, depth(2))
+        );
+        let constructed = [0x24, 0x80, 0x24, 0x80, 0x04, 0x01, 0xAA, 0, 0, 0, 0];
+        assert!(decode::<OctetString>(&constructed, depth(2)).is_ok());
+        assert_eq!(
+            Err(Limit::Depth),
+            decode::<OctetString>(&constructed, depth(1))
+        );
+
+        let elements = |max_elements| Limits {
+            max_elements,
+            ..Limits::default()
+        };
+        let nulls = [0x30, 0x06, 0x05, 0x00, 0x05, 0x00, 0x05, 0x00];
+        assert!(decode::<Vec<()>>(&nulls, elements(3)).is_ok());
+        assert_eq!(Err(Limit::Elements), decode::<Vec<()>>(&nulls, elements(2)));
+        assert_eq!(
+            Err(Limit::Elements),
+            decode::<SetOf<()>>(&[0x31, 0x04, 0x05, 0x00, 0x05, 0x00], elements(1))
+        );
+
+        let length = |max_length| Limits {
+            max_length,
+            ..Limits::default()
+        };
+        let octets = [0x04, 0x03, 0x01, 0x02, 0x03];
+        assert!(decode::<OctetString>(&octets, length(3)).is_ok());
+        assert_eq!(
+            Err(Limit::Length),
+            decode::<OctetString>(&octets, length(2))
+        );
+
+        let total_length = |max_total_length| Limits {
+            max_total_length,
+            ..Limits::default()
+        };
+        let strings = [0x30, 0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x02, 0x03, 0x04];
+        assert!(decode::<Vec<OctetString>>(&strings, total_length(4)).is_ok());
+        assert_eq!(
+            Err(Limit::TotalLength),
+            decode::<Vec<OctetString>>(&strings, total_length(3))
+        );
+    }
+
+    #[test]
+    fn recursion_is_limited() {
+        #[derive(AsnType, Decode)]
+        #[rasn(crate_root = "crate", choice)]
+        enum Nested {
+            #[rasn(tag(0))]
+            Leaf(()),
+            #[rasn(tag(1))]
+            Node(Box<Nested>),
+        }
+
+        let shallow = [0xA1, 0x80, 0x80, 0x00, 0x00, 0x00];
+        assert!(matches!(
+            decode::<Nested>(&shallow),
+            Ok(Nested::Node(node)) if matches!(*node, Nested::Leaf(()))
+        ));
+
+        let mut input = [0xA1, 0x80].repeat(100_000);
+        input.extend_from_slice(&[0x80, 0x00]);
+        assert!(decode::<Nested>(&input).is_err_and(|error| error.is_limit_exceeded()));
+    }
+
+    #[test]
+    fn error_offset_and_path() {
+        use crate::error::Offset;
+
+        #[derive(AsnType, Decode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Outer {
+            id: Integer,
+            inners: SequenceOf<Inner>,
+        }
+
+        #[derive(AsnType, Decode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Inner {
+            flag: bool,
+        }
+
+        let input = [
+            0x30, 0x10, 0x02, 0x01, 0x01, 0x30, 0x0B, 0x30, 0x03, 0x01, 0x01, 0xFF, 0x30, 0x04,
+            0x01, 0x02, 0xFF, 0xFF,
+        ];
+        let error = crate::der::decode::<Outer>(&input).unwrap_err();
+        assert_eq!(Some(Offset::Byte(14)), error.offset);
+        assert_eq!("inners[1].flag", error.path.to_string());
+        assert!(matches!(*error.kind, DecodeErrorKind::FieldError { .. }));
+
+        let error = crate::der::decode::<Outer>(&input[..17]).unwrap_err();
+        assert_eq!(Some(Offset::Byte(0)), error.offset);
+        assert!(error.path.is_empty());
+    }
 }
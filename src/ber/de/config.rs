@@ -1,9 +1,12 @@
 use crate::ber::EncodingRules;
+use crate::de::Limits;
 
 /// The options for the [`Decoder`][super::Decoder].
 #[derive(Clone, Copy, Debug)]
 pub struct DecoderOptions {
     pub(crate) encoding_rules: EncodingRules,
+    pub(crate) limits: Limits,
+    pub(crate) lenient_time: bool,
 }
 
 impl DecoderOptions {
@@ -12,6 +15,8 @@ impl DecoderOptions {
     pub const fn ber() -> Self {
         Self {
             encoding_rules: EncodingRules::Ber,
+            limits: Limits::DEFAULT,
+            lenient_time: false,
         }
     }
 
@@ -20,6 +25,8 @@ impl DecoderOptions {
     pub const fn cer() -> Self {
         Self {
             encoding_rules: EncodingRules::Cer,
+            limits: Limits::DEFAULT,
+            lenient_time: false,
         }
     }
 
@@ -28,8 +35,37 @@ impl DecoderOptions {
     pub const fn der() -> Self {
         Self {
             encoding_rules: EncodingRules::Der,
+            limits: Limits::DEFAULT,
+            lenient_time: false,
         }
     }
+    /// Returns these options with the given resource `limits`.
+    #[must_use]
+    pub const fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the resource limits of these options.
+    #[must_use]
+    pub const fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// Returns these options set to accept `UTCTime` and `GeneralizedTime`
+    /// values in any form BER allows, even when decoding CER or DER.
+    ///
+    /// Some DER structures, such as timestamp tokens from older issuers,
+    /// contain times that aren't in their canonical form. Decoding those into
+    /// [`LosslessGeneralizedTime`][crate::types::LosslessGeneralizedTime]
+    /// with this option keeps them as they were written, so that the structure
+    /// still encodes back to the same bytes.
+    #[must_use]
+    pub const fn with_lenient_time(mut self, lenient: bool) -> Self {
+        self.lenient_time = lenient;
+        self
+    }
+
     #[must_use]
     pub fn current_codec(&self) -> crate::Codec {
         match self.encoding_rules {
@@ -26,11 +26,20 @@ pub(crate) fn parse_value<'input>(
     let (input, contents) = parse_contents(config, identifier, input)
         .map_err(|e| DecodeError::map_nom_err(e, config.current_codec()))?;
 
+    if let Some(contents) = contents {
+        config
+            .limits
+            .check_length(contents.len(), config.current_codec())?;
+    }
+
     Ok((input, (identifier, contents)))
 }
 
+/// Parses a string type value, which outside of DER can be split into a
+/// constructed value of segments, nested `depth` constructed values deep.
 pub(crate) fn parse_encoded_value<'config, 'input, RV>(
     config: &'config DecoderOptions,
+    depth: usize,
     slice: &'input [u8],
     tag: Tag,
     primitive_callback: fn(&'input [u8], crate::Codec) -> super::Result<RV>,
@@ -46,6 +55,9 @@ where
             (primitive_callback)(contents.unwrap(), config.current_codec())?,
         ))
     } else if config.encoding_rules.allows_constructed_strings() {
+        let depth = config
+            .limits
+            .check_depth(depth + 1, config.current_codec())?;
         let mut container = RV::new();
         let mut input = input;
 
@@ -55,7 +67,7 @@ where
             let (_, identifier) = parse_identifier_octet(input)
                 .map_err(|e| DecodeError::map_nom_err(e, config.current_codec()))?;
             let (i, mut child) =
-                parse_encoded_value(config, input, identifier.tag, primitive_callback)?;
+                parse_encoded_value(config, depth, input, identifier.tag, primitive_callback)?;
             input = i;
             container.append(&mut child);
         }
@@ -175,6 +187,13 @@ impl Appendable for Vec<u8> {
     }
 }
 
+/// Used to walk over nested values without collecting their contents.
+impl Appendable for () {
+    fn new() -> Self {}
+
+    fn append(&mut self, _: &mut Self) {}
+}
+
 impl Appendable for crate::types::BitString {
     fn new() -> Self {
         Self::new()
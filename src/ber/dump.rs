@@ -0,0 +1,450 @@
+//! # Dumping
+//!
+//! A human readable tree of BER encoded data, in the style of `dumpasn1` and
+//! `openssl asn1parse`. Each line starts with the offset of the value in the
+//! input and the length of its contents, followed by its tag and value:
+//!
+//! ```text
+//!      0   11: SEQUENCE {
+//!      2    1:   INTEGER 1
+//!      5    1:   [0] FF
+//!      8    3:   [1] {
+//!     10    1:     PrintableString 'a'
+//!            :     }
+//!            :   }
+//! ```
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use super::de::{parser, Decoder, DecoderOptions};
+use crate::{
+    error::DecodeError,
+    types::{self, Class, Tag},
+    Decode,
+};
+
+const END_OF_CONTENTS: &[u8] = &[0, 0];
+const OCTETS_PER_LINE: usize = 16;
+
+/// The options for [`dump_with_options`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DumpOptions {
+    /// Whether to dump the contents of `OCTET STRING` and `BIT STRING` values
+    /// which are themselves valid DER, such as certificate extensions and
+    /// public keys.
+    pub encapsulated: bool,
+}
+
+/// Dumps every value in `input` as an indented tree.
+/// # Errors
+/// Returns error specific to BER decoder if `input` is not valid BER.
+pub fn dump(input: &[u8]) -> Result<String, DecodeError> {
+    dump_with_options(input, DumpOptions::default())
+}
+
+/// Dumps every value in `input` as an indented tree, using `options`.
+/// # Errors
+/// Returns error specific to BER decoder if `input` is not valid BER.
+pub fn dump_with_options(input: &[u8], options: DumpOptions) -> Result<String, DecodeError> {
+    let mut dumper = Dumper {
+        output: String::new(),
+        options,
+        config: DecoderOptions::ber(),
+    };
+
+    dumper.elements(input, 0, 0)?;
+    Ok(dumper.output)
+}
+
+struct Dumper {
+    output: String,
+    options: DumpOptions,
+    config: DecoderOptions,
+}
+
+impl Dumper {
+    /// Dumps every element in `input`, which starts at `offset` in the
+    /// original input.
+    fn elements(&mut self, input: &[u8], offset: usize, depth: usize) -> Result<(), DecodeError> {
+        let mut position = 0;
+        while position < input.len() {
+            position += self.element(&input[position..], offset + position, depth)?;
+        }
+
+        Ok(())
+    }
+
+    /// Dumps the element at the start of `input`, returning the number of
+    /// octets it occupies.
+    fn element(&mut self, input: &[u8], offset: usize, depth: usize) -> Result<usize, DecodeError> {
+        self.config
+            .limits
+            .check_depth(depth, self.config.current_codec())?;
+        let (rest, (identifier, contents)) = parser::parse_value(&self.config, input, None)?;
+        let label = label(identifier.tag);
+
+        let Some(contents) = contents else {
+            let header_len = input.len() - rest.len();
+            self.line(Some((offset, None)), depth, &format!("{label} {{"));
+            let mut position = header_len;
+            while !input[position..].starts_with(END_OF_CONTENTS) {
+                position += self.element(&input[position..], offset + position, depth + 1)?;
+            }
+            self.line(None, depth + 1, "}");
+            return Ok(position + END_OF_CONTENTS.len());
+        };
+
+        let header_len = input.len() - rest.len() - contents.len();
+        let header = Some((offset, Some(contents.len())));
+        let contents_offset = offset + header_len;
+        if identifier.is_constructed() {
+            self.line(header, depth, &format!("{label} {{"));
+            self.elements(contents, contents_offset, depth + 1)?;
+            self.line(None, depth + 1, "}");
+        } else {
+            let encoding = &input[..header_len + contents.len()];
+            match identifier.tag {
+                Tag::BIT_STRING if self.encapsulates(contents.get(1..), contents.first()) => {
+                    self.line(header, depth, &format!("{label}, encapsulates {{"));
+                    self.elements(&contents[1..], contents_offset + 1, depth + 1)?;
+                    self.line(None, depth + 1, "}");
+                }
+                Tag::OCTET_STRING if self.encapsulates(Some(contents), Some(&0)) => {
+                    self.line(header, depth, &format!("{label}, encapsulates {{"));
+                    self.elements(contents, contents_offset, depth + 1)?;
+                    self.line(None, depth + 1, "}");
+                }
+                Tag::BIT_STRING if !contents.is_empty() => {
+                    let unused = match contents[0] {
+                        0 => String::new(),
+                        1 => String::from(", 1 unused bit"),
+                        bits => format!(", {bits} unused bits"),
+                    };
+                    self.hex(header, depth, &format!("{label}{unused}"), &contents[1..]);
+                }
+                tag => match self.display(tag, encoding) {
+                    Some(value) if value.is_empty() => self.line(header, depth, &label),
+                    Some(value) => self.line(header, depth, &format!("{label} {value}")),
+                    None => self.hex(header, depth, &label, contents),
+                },
+            }
+        }
+
+        Ok(header_len + contents.len())
+    }
+
+    /// Whether `contents` should be dumped as encapsulated values, which is
+    /// the case when they are complete DER values, and `unused_bits` is zero.
+    fn encapsulates(&self, contents: Option<&[u8]>, unused_bits: Option<&u8>) -> bool {
+        self.options.encapsulated
+            && unused_bits == Some(&0)
+            && contents.is_some_and(|contents| !contents.is_empty() && is_der(contents, 0))
+    }
+
+    /// Formats the value of a primitive universal type, returning `None` for
+    /// values that are displayed as hexadecimal.
+    fn display(&self, tag: Tag, encoding: &[u8]) -> Option<String> {
+        fn decode<T: Decode>(encoding: &[u8], config: DecoderOptions, tag: Tag) -> Option<T> {
+            T::decode_with_tag(&mut Decoder::new(encoding, config), tag).ok()
+        }
+
+        if tag.class != Class::Universal {
+            return None;
+        }
+
+        let config = self.config;
+        match tag {
+            Tag::BOOL => decode::<bool>(encoding, config, tag)
+                .map(|value| String::from(if value { "TRUE" } else { "FALSE" })),
+            Tag::INTEGER | Tag::ENUMERATED => {
+                decode::<types::Integer>(encoding, config, tag).map(|value| value.to_string())
+            }
+            Tag::NULL => decode::<()>(encoding, config, tag).map(|()| String::new()),
+            Tag::OBJECT_IDENTIFIER => {
+                decode::<types::ObjectIdentifier>(encoding, config, tag).map(|oid| {
+                    let dotted = oid
+                        .iter()
+                        .map(|arc| arc.to_string())
+                        .collect::<Vec<_>>()
+                        .join(".");
+                    match oid.known_name() {
+                        Some(name) => format!("{dotted} ({name})"),
+                        None => dotted,
+                    }
+                })
+            }
+            Tag::RELATIVE_OID => {
+                decode::<types::RelativeOid>(encoding, config, tag).map(|relative| {
+                    relative
+                        .iter()
+                        .map(|arc| arc.to_string())
+                        .collect::<Vec<_>>()
+                        .join(".")
+                })
+            }
+            Tag::REAL => decode::<types::Real>(encoding, config, tag).map(|real| real.to_string()),
+            Tag::OID_IRI => {
+                decode::<types::OidIri>(encoding, config, tag).map(|iri| quote(iri.to_string()))
+            }
+            Tag::RELATIVE_OID_IRI => decode::<types::RelativeOidIri>(encoding, config, tag)
+                .map(|iri| quote(iri.to_string())),
+            Tag::UTF8_STRING => decode::<types::Utf8String>(encoding, config, tag).map(quote),
+            Tag::BMP_STRING => decode::<types::BmpString>(encoding, config, tag).map(|string| {
+                quote(
+                    string
+                        .to_bytes()
+                        .chunks(2)
+                        .map(|ch| u32::from(u16::from_be_bytes([ch[0], ch[1]])))
+                        .map(|ch| char::from_u32(ch).unwrap_or(char::REPLACEMENT_CHARACTER))
+                        .collect(),
+                )
+            }),
+            Tag::UNIVERSAL_STRING => decode::<types::UniversalString>(encoding, config, tag)
+                .map(|string| quote(string.to_string())),
+            Tag::TELETEX_STRING => decode::<types::TeletexString>(encoding, config, tag)
+                .and_then(|string| string.to_unicode().ok())
+                .map(quote),
+            Tag::NUMERIC_STRING
+            | Tag::PRINTABLE_STRING
+            | Tag::IA5_STRING
+            | Tag::VISIBLE_STRING
+            | Tag::GRAPHIC_STRING
+            | Tag::GENERAL_STRING
+            | Tag::UTC_TIME
+            | Tag::GENERALIZED_TIME
+            | Tag::TIME
+            | Tag::DATE
+            | Tag::TIME_OF_DAY
+            | Tag::DATE_TIME
+            | Tag::DURATION => decode::<types::OctetString>(encoding, config, tag)
+                .filter(|octets| octets.is_ascii())
+                .map(|octets| quote(octets.iter().copied().map(char::from).collect())),
+            _ => None,
+        }
+    }
+
+    /// Writes `label`, followed by `octets` in hexadecimal, on the same line
+    /// if they fit, and otherwise on lines of their own.
+    fn hex(
+        &mut self,
+        header: Option<(usize, Option<usize>)>,
+        depth: usize,
+        label: &str,
+        octets: &[u8],
+    ) {
+        if octets.len() <= OCTETS_PER_LINE {
+            let separator = if octets.is_empty() { "" } else { " " };
+            self.line(header, depth, &format!("{label}{separator}{}", hex(octets)));
+        } else {
+            self.line(header, depth, label);
+            for chunk in octets.chunks(OCTETS_PER_LINE) {
+                self.line(None, depth + 1, &hex(chunk));
+            }
+        }
+    }
+
+    /// Writes a line of output, prefixed by the offset and contents length in
+    /// `header`, or padding for lines that continue the previous value.
+    fn line(&mut self, header: Option<(usize, Option<usize>)>, depth: usize, text: &str) {
+        let _ = match header {
+            Some((offset, Some(length))) => write!(self.output, "{offset:>6} {length:>4}: "),
+            Some((offset, None)) => write!(self.output, "{offset:>6} NDEF: "),
+            None => write!(self.output, "{:>11}: ", ""),
+        };
+
+        for _ in 0..depth {
+            self.output.push_str("  ");
+        }
+
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+}
+
+/// Whether `input` consists only of complete DER values, nested no more than
+/// the maximum depth.
+fn is_der(mut input: &[u8], depth: usize) -> bool {
+    let config = DecoderOptions::der();
+    if depth > config.limits.max_depth {
+        return false;
+    }
+
+    while !input.is_empty() {
+        let Ok((rest, (identifier, Some(contents)))) = parser::parse_value(&config, input, None)
+        else {
+            return false;
+        };
+
+        if identifier.is_constructed() && !is_der(contents, depth + 1) {
+            return false;
+        }
+
+        input = rest;
+    }
+
+    true
+}
+
+fn quote(string: String) -> String {
+    format!("'{string}'")
+}
+
+fn hex(octets: &[u8]) -> String {
+    octets
+        .iter()
+        .map(|octet| format!("{octet:02X}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn label(tag: Tag) -> String {
+    let name = match tag {
+        Tag::BOOL => "BOOLEAN",
+        Tag::INTEGER => "INTEGER",
+        Tag::BIT_STRING => "BIT STRING",
+        Tag::OCTET_STRING => "OCTET STRING",
+        Tag::NULL => "NULL",
+        Tag::OBJECT_IDENTIFIER => "OBJECT IDENTIFIER",
+        Tag::OBJECT_DESCRIPTOR => "ObjectDescriptor",
+        Tag::EXTERNAL => "EXTERNAL",
+        Tag::REAL => "REAL",
+        Tag::ENUMERATED => "ENUMERATED",
+        Tag::EMBEDDED_PDV => "EMBEDDED PDV",
+        Tag::UTF8_STRING => "UTF8String",
+        Tag::RELATIVE_OID => "RELATIVE-OID",
+        Tag::TIME => "TIME",
+        Tag::SEQUENCE => "SEQUENCE",
+        Tag::SET => "SET",
+        Tag::NUMERIC_STRING => "NumericString",
+        Tag::PRINTABLE_STRING => "PrintableString",
+        Tag::TELETEX_STRING => "TeletexString",
+        Tag::VIDEOTEX_STRING => "VideotexString",
+        Tag::IA5_STRING => "IA5String",
+        Tag::UTC_TIME => "UTCTime",
+        Tag::GENERALIZED_TIME => "GeneralizedTime",
+        Tag::GRAPHIC_STRING => "GraphicString",
+        Tag::VISIBLE_STRING => "VisibleString",
+        Tag::GENERAL_STRING => "GeneralString",
+        Tag::UNIVERSAL_STRING => "UniversalString",
+        Tag::CHARACTER_STRING => "CHARACTER STRING",
+        Tag::BMP_STRING => "BMPString",
+        Tag::DATE => "DATE",
+        Tag::TIME_OF_DAY => "TIME-OF-DAY",
+        Tag::DATE_TIME => "DATE-TIME",
+        Tag::DURATION => "DURATION",
+        Tag::OID_IRI => "OID-IRI",
+        Tag::RELATIVE_OID_IRI => "RELATIVE-OID-IRI",
+        Tag {
+            class: Class::Universal,
+            value,
+        } => return format!("[UNIVERSAL {value}]"),
+        Tag {
+            class: Class::Application,
+            value,
+        } => return format!("[APPLICATION {value}]"),
+        Tag {
+            class: Class::Context,
+            value,
+        } => return format!("[{value}]"),
+        Tag {
+            class: Class::Private,
+            value,
+        } => return format!("[PRIVATE {value}]"),
+    };
+
+    String::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tree() {
+        let input = [
+            0x30, 0x0B, 0x02, 0x01, 0x01, 0x80, 0x01, 0xFF, 0xA1, 0x03, 0x13, 0x01, 0x61,
+        ];
+
+        pretty_assertions::assert_eq!(
+            dump(&input).unwrap(),
+            concat!(
+                "     0   11: SEQUENCE {\n",
+                "     2    1:   INTEGER 1\n",
+                "     5    1:   [0] FF\n",
+                "     8    3:   [1] {\n",
+                "    10    1:     PrintableString 'a'\n",
+                "           :     }\n",
+                "           :   }\n",
+            )
+        );
+    }
+
+    #[test]
+    fn indefinite_length_and_names() {
+        let input = [
+            0x30, 0x80, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01, 0x05,
+            0x00, 0x00, 0x00,
+        ];
+
+        pretty_assertions::assert_eq!(
+            dump(&input).unwrap(),
+            concat!(
+                "     0 NDEF: SEQUENCE {\n",
+                "     2    9:   OBJECT IDENTIFIER 1.2.840.113549.1.1.1 (ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA)\n",
+                "    13    0:   NULL\n",
+                "           :   }\n",
+            )
+        );
+    }
+
+    #[test]
+    fn encapsulated() {
+        let input = [
+            0x04, 0x08, 0x30, 0x06, 0x01, 0x01, 0xFF, 0x02, 0x01, 0x00, // OCTET STRING
+            0x03, 0x04, 0x00, 0x02, 0x01, 0x07, // BIT STRING
+            0x04, 0x12, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B,
+            0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11,
+        ];
+
+        pretty_assertions::assert_eq!(
+            dump(&input[..16]).unwrap(),
+            concat!(
+                "     0    8: OCTET STRING 30 06 01 01 FF 02 01 00\n",
+                "    10    4: BIT STRING 02 01 07\n",
+            )
+        );
+        pretty_assertions::assert_eq!(
+            dump_with_options(&input, DumpOptions { encapsulated: true }).unwrap(),
+            concat!(
+                "     0    8: OCTET STRING, encapsulates {\n",
+                "     2    6:   SEQUENCE {\n",
+                "     4    1:     BOOLEAN TRUE\n",
+                "     7    1:     INTEGER 0\n",
+                "           :     }\n",
+                "           :   }\n",
+                "    10    4: BIT STRING, encapsulates {\n",
+                "    13    1:   INTEGER 7\n",
+                "           :   }\n",
+                "    16   18: OCTET STRING\n",
+                "           :   00 01 02 03 04 05 06 07 08 09 0A 0B 0C 0D 0E 0F\n",
+                "           :   10 11\n",
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(dump(&[0x30, 0x05, 0x02, 0x01]).is_err());
+    }
+
+    #[test]
+    fn deep_nesting_is_limited() {
+        let input = [0x30, 0x80].repeat(100_000);
+        assert!(dump(&input).unwrap_err().is_limit_exceeded());
+    }
+}
@@ -11,9 +11,10 @@ use crate::{
     types::{
         self,
         oid::{MAX_OID_FIRST_OCTET, MAX_OID_SECOND_OCTET},
+        strings::StaticPermittedAlphabet,
         Constraints, Enumerated, Tag,
     },
-    Codec, Encode,
+    validate, Codec, Encode,
 };
 
 pub use crate::error::{BerEncodeErrorKind, EncodeError, EncodeErrorKind};
@@ -22,12 +23,25 @@ pub use config::EncoderOptions;
 const START_OF_CONTENTS: u8 = 0x80;
 const END_OF_CONTENTS: &[u8] = &[0, 0];
 
+/// How much output a writer-backed encoder holds before passing it on.
+const WRITE_THRESHOLD: usize = 8 * 1024;
+
+/// Writes `bytes` to a writer, reporting failures as errors of `Codec`.
+type WriteFn<W> = fn(&mut W, &[u8], Codec) -> Result<(), EncodeError>;
+
 /// A BER and variants encoder. Capable of encoding to BER, CER, and DER.
-pub struct Encoder {
+///
+/// An encoder created with [`Encoder::with_writer`] passes its output on to
+/// the writer as it goes. Constructed values are then encoded with the
+/// indefinite length form in CER and BER, so only values whose definite
+/// length must precede them, such as the members of a SET, are held in
+/// memory.
+pub struct Encoder<W = ()> {
     output: Vec<u8>,
     config: EncoderOptions,
     is_set_encoding: bool,
     set_buffer: alloc::collections::BTreeMap<Tag, Vec<u8>>,
+    writer: Option<(W, WriteFn<W>)>,
 }
 
 /// A convenience type around results needing to return one or many bytes.
@@ -45,12 +59,9 @@ impl Encoder {
             is_set_encoding: false,
             output: <_>::default(),
             set_buffer: <_>::default(),
+            writer: None,
         }
     }
-    #[must_use]
-    pub fn codec(&self) -> crate::Codec {
-        self.config.current_codec()
-    }
 
     /// Creates a new instance from the given `config`, and uses SET encoding
     /// logic, ensuring that all messages are encoded in order by tag.
@@ -61,6 +72,7 @@ impl Encoder {
             is_set_encoding: true,
             output: <_>::default(),
             set_buffer: <_>::default(),
+            writer: None,
         }
     }
 
@@ -75,10 +87,119 @@ impl Encoder {
             config,
             is_set_encoding: false,
             set_buffer: <_>::default(),
+            writer: None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Encoder<W> {
+    /// Creates a new instance from the given `config` that writes its output
+    /// to `writer` as it encodes. Call [`Encoder::finish`] once the value is
+    /// encoded to write the rest of the output.
+    pub fn with_writer(config: EncoderOptions, writer: W) -> Self {
+        Self {
+            config,
+            is_set_encoding: false,
+            output: <_>::default(),
+            set_buffer: <_>::default(),
+            writer: Some((writer, |writer, bytes, codec| {
+                writer
+                    .write_all(bytes)
+                    .map_err(|error| EncodeError::io(error, codec))
+            })),
+        }
+    }
+
+    /// Writes the rest of the output, returning the writer.
+    /// # Errors
+    /// Returns an error if writing fails.
+    pub fn finish(mut self) -> Result<W, EncodeError> {
+        self.write_output()?;
+        let (writer, _) = self.writer.expect("writer-backed encoder");
+        Ok(writer)
+    }
+}
+
+impl<W> Encoder<W> {
+    #[must_use]
+    pub fn codec(&self) -> crate::Codec {
+        self.config.current_codec()
+    }
+
+    /// Runs `check` when the encoder is configured to validate constraints.
+    fn check_constraints(
+        &self,
+        check: impl FnOnce() -> Result<(), crate::error::ViolationKind>,
+    ) -> Result<(), EncodeError> {
+        if self.config.validate_constraints {
+            check().map_err(|kind| EncodeError::constraint_violation(kind, self.codec()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Creates an encoder for the contents of a value, which are held in
+    /// memory until they're encoded into this encoder's output.
+    fn nested(&self, is_set_encoding: bool) -> Self {
+        Self {
+            config: self.config,
+            is_set_encoding,
+            output: <_>::default(),
+            set_buffer: <_>::default(),
+            writer: None,
+        }
+    }
+
+    /// Whether constructed values are encoded directly into the output with
+    /// the indefinite length form, rather than encoded separately so that
+    /// their definite length can precede them.
+    fn encodes_in_place(&self) -> bool {
+        !self.is_set_encoding
+            && (self.config.encoding_rules.is_cer()
+                || (self.writer.is_some() && self.config.encoding_rules.is_ber()))
+    }
+
+    /// Encodes a constructed value with the indefinite length form, with
+    /// `encode_contents` encoding its contents directly into the output.
+    fn encode_constructed_in_place(
+        &mut self,
+        tag: Tag,
+        encode_contents: impl FnOnce(&mut Self) -> Result<(), EncodeError>,
+    ) -> Result<(), EncodeError> {
+        let ident_bytes = self.encode_identifier(Identifier::from_tag(tag, true));
+        self.append_byte_or_bytes(ident_bytes);
+        self.output.push(START_OF_CONTENTS);
+        (encode_contents)(self)?;
+        self.output.extend_from_slice(END_OF_CONTENTS);
+        self.write_output_if_full()
+    }
+
+    /// Passes the output on to the writer, if there is one.
+    fn write_output(&mut self) -> Result<(), EncodeError> {
+        let codec = self.codec();
+        if let Some((writer, write)) = &mut self.writer {
+            if !self.output.is_empty() {
+                write(writer, &self.output, codec)?;
+                self.output.clear();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Passes the output on to the writer once enough of it has built up.
+    fn write_output_if_full(&mut self) -> Result<(), EncodeError> {
+        if self.output.len() >= WRITE_THRESHOLD {
+            self.write_output()
+        } else {
+            Ok(())
         }
     }
 
-    /// Consumes the encoder and returns the output of the encoding.
+    /// Consumes the encoder and returns the output of the encoding. For an
+    /// encoder created with [`Encoder::with_writer`], this is only the output
+    /// that hasn't been written yet.
     pub fn output(self) -> Vec<u8> {
         if self.is_set_encoding {
             self.set_buffer
@@ -217,6 +338,7 @@ impl Encoder {
 
             for chunk in value.chunks(max_string_length) {
                 self.encode_primitive(nested_tag, chunk);
+                self.write_output_if_full()?;
             }
 
             self.output.extend_from_slice(END_OF_CONTENTS);
@@ -228,11 +350,26 @@ impl Encoder {
         Ok(())
     }
 
-    fn encode_primitive(&mut self, tag: Tag, value: &[u8]) {
+    pub(crate) fn encode_primitive(&mut self, tag: Tag, value: &[u8]) {
         self.encode_value(Identifier::from_tag(tag, false), value);
     }
 
-    fn encode_constructed(&mut self, tag: Tag, value: &[u8]) {
+    /// Encodes one of the time types as a primitive value containing its
+    /// ISO 8601 basic format representation (X.690 8.26).
+    fn encode_time_type<T: types::TimeType>(
+        &mut self,
+        tag: Tag,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        let string = value
+            .to_basic()
+            .map_err(|reason| EncodeError::invalid_time(reason, self.codec()))?;
+        self.encode_primitive(tag, string.as_bytes());
+
+        Ok(())
+    }
+
+    pub(crate) fn encode_constructed(&mut self, tag: Tag, value: &[u8]) {
         self.encode_value(Identifier::from_tag(tag, true), value);
     }
 
@@ -272,6 +409,18 @@ impl Encoder {
         }
         Ok(bytes)
     }
+    /// Converts a relative object identifier into a byte vector in BER format.
+    /// Reusable function by other codecs.
+    pub fn relative_oid_as_bytes(&self, oid: &[u32]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for component in oid {
+            self.encode_as_base128(*component, &mut bytes);
+        }
+        bytes
+    }
+}
+
+impl Encoder {
     #[must_use]
     /// Canonical byte presentation for CER/DER as defined in X.690 section 11.7.
     /// Also used for BER on this crate.
@@ -294,6 +443,53 @@ impl Encoder {
         string.into_bytes()
     }
 
+    #[must_use]
+    /// Canonical contents octets for CER/DER REAL values as defined in X.690
+    /// section 11.3, using base 2 for binary values and the NR3 form for
+    /// decimal values. Also used for BER, PER, and OER on this crate.
+    pub fn real_to_canonical_bytes(value: &types::Real) -> Vec<u8> {
+        match value.normalize() {
+            types::Real::PlusInfinity => alloc::vec![0x40],
+            types::Real::MinusInfinity => alloc::vec![0x41],
+            types::Real::NotANumber => alloc::vec![0x42],
+            types::Real::MinusZero => alloc::vec![0x43],
+            types::Real::Binary { mantissa, .. } | types::Real::Decimal { mantissa, .. }
+                if mantissa.sign() == num_bigint::Sign::NoSign =>
+            {
+                Vec::new()
+            }
+            types::Real::Binary { mantissa, exponent } => {
+                let exponent = types::Integer::from(exponent).to_signed_bytes_be();
+                let (sign, magnitude) = mantissa.into_parts();
+                let mut bytes = Vec::with_capacity(exponent.len() + 2);
+                let sign_bit = if sign == num_bigint::Sign::Minus {
+                    0x40
+                } else {
+                    0
+                };
+                // Exponents of up to three octets have their length in the
+                // first octet, longer ones are prefixed with their length.
+                match u8::try_from(exponent.len()) {
+                    Ok(length @ 1..=3) => bytes.push(0x80 | sign_bit | (length - 1)),
+                    _ => bytes.extend([0x80 | sign_bit | 0b11, exponent.len() as u8]),
+                }
+                bytes.extend(exponent);
+                bytes.extend(magnitude.to_bytes_be());
+                bytes
+            }
+            types::Real::Decimal { mantissa, exponent } => {
+                let exponent = if exponent == 0 {
+                    "+0".to_owned()
+                } else {
+                    exponent.to_string()
+                };
+                let mut bytes = alloc::vec![0x03];
+                bytes.extend(alloc::format!("{mantissa}.E{exponent}").into_bytes());
+                bytes
+            }
+        }
+    }
+
     #[must_use]
     /// Canonical byte presentation for CER/DER UTCTime as defined in X.690 section 11.8.
     /// Also used for BER on this crate.
@@ -306,7 +502,7 @@ impl Encoder {
     }
 }
 
-impl crate::Encoder for Encoder {
+impl<W> crate::Encoder for Encoder<W> {
     type Ok = ();
     type Error = EncodeError;
 
@@ -326,9 +522,10 @@ impl crate::Encoder for Encoder {
     fn encode_bit_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::BitStr,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         if value.is_empty() {
             self.encode_primitive(tag, &[]);
             Ok(())
@@ -377,13 +574,35 @@ impl crate::Encoder for Encoder {
     fn encode_integer(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &num_bigint::BigInt,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_value(value, &constraints))?;
         self.encode_primitive(tag, &value.to_signed_bytes_be());
         Ok(())
     }
 
+    fn encode_primitive_integer(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: i128,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_value(&value.into(), &constraints))?;
+        self.encode_primitive(tag, &crate::num::IntegerBytes::signed(value));
+        Ok(())
+    }
+
+    fn encode_real(
+        &mut self,
+        tag: Tag,
+        _constraints: Constraints,
+        value: &types::Real,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, &Encoder::real_to_canonical_bytes(value));
+        Ok(())
+    }
+
     fn encode_null(&mut self, tag: Tag) -> Result<Self::Ok, Self::Error> {
         self.encode_primitive(tag, &[]);
         Ok(())
@@ -395,84 +614,161 @@ impl crate::Encoder for Encoder {
         Ok(())
     }
 
+    fn encode_relative_oid(&mut self, tag: Tag, oid: &[u32]) -> Result<Self::Ok, Self::Error> {
+        let bytes = self.relative_oid_as_bytes(oid);
+        self.encode_primitive(tag, &bytes);
+        Ok(())
+    }
+
+    fn encode_oid_iri(&mut self, tag: Tag, value: &types::OidIri) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.as_str().as_bytes());
+        Ok(())
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.as_str().as_bytes());
+        Ok(())
+    }
+
     fn encode_octet_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &[u8],
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         self.encode_octet_string_(tag, value)
     }
 
     fn encode_visible_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::VisibleString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.encode_octet_string_(tag, value.as_iso646_bytes())
     }
 
     fn encode_ia5_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::Ia5String,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.encode_octet_string_(tag, value.as_iso646_bytes())
     }
 
     fn encode_general_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::GeneralString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
+        self.encode_octet_string_(tag, value)
+    }
+
+    fn encode_graphic_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::GraphicString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
+        self.encode_octet_string_(tag, value)
+    }
+
+    fn encode_videotex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::VideotexString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         self.encode_octet_string_(tag, value)
     }
 
     fn encode_printable_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::PrintableString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.encode_octet_string_(tag, value.as_bytes())
     }
 
     fn encode_numeric_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::NumericString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.encode_octet_string_(tag, value.as_bytes())
     }
 
     fn encode_teletex_string(
         &mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
         value: &types::TeletexString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         self.encode_octet_string_(tag, value)
     }
 
     fn encode_bmp_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::BmpString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
+        self.encode_octet_string_(tag, &value.to_bytes())
+    }
+
+    fn encode_universal_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::UniversalString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.encode_octet_string_(tag, &value.to_bytes())
     }
 
     fn encode_utf8_string(
         &mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
         value: &str,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(
+                value.chars().count(),
+                value.chars().map(u32::from),
+                &constraints,
+            )
+        })?;
         self.encode_octet_string_(tag, value.as_bytes())
     }
 
@@ -483,7 +779,7 @@ impl crate::Encoder for Encoder {
     ) -> Result<Self::Ok, Self::Error> {
         self.encode_primitive(
             tag,
-            Self::datetime_to_canonical_utc_time_bytes(value).as_slice(),
+            Encoder::datetime_to_canonical_utc_time_bytes(value).as_slice(),
         );
 
         Ok(())
@@ -496,12 +792,54 @@ impl crate::Encoder for Encoder {
     ) -> Result<Self::Ok, Self::Error> {
         self.encode_primitive(
             tag,
-            Self::datetime_to_canonical_generalized_time_bytes(value).as_slice(),
+            Encoder::datetime_to_canonical_generalized_time_bytes(value).as_slice(),
         );
 
         Ok(())
     }
 
+    fn encode_date(&mut self, tag: Tag, value: &types::Date) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &types::TimeOfDay,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &types::DateTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &types::Duration,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_time(&mut self, tag: Tag, value: &types::TimeValue) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_lossless_generalized_time(
+        &mut self,
+        tag: Tag,
+        value: &types::LosslessGeneralizedTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.to_string().as_bytes());
+
+        Ok(())
+    }
+
     fn encode_some<E: Encode>(&mut self, value: &E) -> Result<Self::Ok, Self::Error> {
         value.encode(self)
     }
@@ -535,9 +873,20 @@ impl crate::Encoder for Encoder {
         &mut self,
         tag: Tag,
         values: &[E],
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<Self::Ok, Self::Error> {
-        let mut sequence_encoder = Self::new(self.config);
+        self.check_constraints(|| validate::check_size(values.len(), &constraints))?;
+        if self.encodes_in_place() {
+            return self.encode_constructed_in_place(tag, |encoder| {
+                for value in values {
+                    value.encode(encoder)?;
+                    encoder.write_output_if_full()?;
+                }
+                Ok(())
+            });
+        }
+
+        let mut sequence_encoder = self.nested(false);
 
         for value in values {
             value.encode(&mut sequence_encoder)?;
@@ -552,12 +901,13 @@ impl crate::Encoder for Encoder {
         &mut self,
         tag: Tag,
         values: &types::SetOf<E>,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(values.len(), &constraints))?;
         let mut encoded_values = values
             .iter()
             .map(|val| {
-                let mut sequence_encoder = Self::new(self.config);
+                let mut sequence_encoder = self.nested(false);
                 val.encode(&mut sequence_encoder)
                     .map(|_| sequence_encoder.output)
             })
@@ -578,7 +928,11 @@ impl crate::Encoder for Encoder {
         tag: Tag,
         value: &V,
     ) -> Result<Self::Ok, Self::Error> {
-        let mut encoder = Self::new(self.config);
+        if self.encodes_in_place() {
+            return self.encode_constructed_in_place(tag, |encoder| value.encode(encoder));
+        }
+
+        let mut encoder = self.nested(false);
         value.encode(&mut encoder)?;
         self.encode_constructed(tag, &encoder.output);
         Ok(())
@@ -589,7 +943,11 @@ impl crate::Encoder for Encoder {
         C: crate::types::Constructed,
         F: FnOnce(&mut Self) -> Result<Self::Ok, Self::Error>,
     {
-        let mut encoder = Self::new(self.config);
+        if self.encodes_in_place() {
+            return self.encode_constructed_in_place(tag, encoder_scope);
+        }
+
+        let mut encoder = self.nested(false);
 
         (encoder_scope)(&mut encoder)?;
 
@@ -603,7 +961,7 @@ impl crate::Encoder for Encoder {
         C: crate::types::Constructed,
         F: FnOnce(&mut Self) -> Result<Self::Ok, Self::Error>,
     {
-        let mut encoder = Self::new_set(self.config);
+        let mut encoder = self.nested(true);
 
         (encoder_scope)(&mut encoder)?;
 
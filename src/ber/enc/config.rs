@@ -4,6 +4,7 @@ use crate::ber::EncodingRules;
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderOptions {
     pub(crate) encoding_rules: EncodingRules,
+    pub(crate) validate_constraints: bool,
 }
 
 impl EncoderOptions {
@@ -12,6 +13,7 @@ impl EncoderOptions {
     pub const fn ber() -> Self {
         Self {
             encoding_rules: EncodingRules::Ber,
+            validate_constraints: false,
         }
     }
 
@@ -20,6 +22,7 @@ impl EncoderOptions {
     pub const fn cer() -> Self {
         Self {
             encoding_rules: EncodingRules::Cer,
+            validate_constraints: false,
         }
     }
 
@@ -28,8 +31,23 @@ impl EncoderOptions {
     pub const fn der() -> Self {
         Self {
             encoding_rules: EncodingRules::Der,
+            validate_constraints: false,
         }
     }
+
+    /// Returns these options set to check the size, value, and permitted
+    /// alphabet constraints of each value as it's encoded, failing on the
+    /// first one that isn't satisfied. BER doesn't need to know a value's
+    /// constraints to encode it, so they aren't checked by default.
+    ///
+    /// Use [`Validate`][crate::Validate] instead to find every constraint
+    /// that a value doesn't satisfy.
+    #[must_use]
+    pub const fn with_constraint_validation(mut self, validate_constraints: bool) -> Self {
+        self.validate_constraints = validate_constraints;
+        self
+    }
+
     #[must_use]
     pub fn current_codec(&self) -> crate::Codec {
         match self.encoding_rules {
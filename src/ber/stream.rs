@@ -0,0 +1,342 @@
+//! # Streaming
+//!
+//! Incremental decoding of BER and CER values arriving in chunks, such as
+//! LDAP or SNMP messages read from a TCP stream. Chunks are buffered by a
+//! [`StreamDecoder`] until the next top-level value is complete, including
+//! values encoded with indefinite length.
+//!
+//! ```rust
+//! use rasn::ber::stream::StreamDecoder;
+//!
+//! let encoded = rasn::ber::encode(&rasn::types::Utf8String::from("hello")).unwrap();
+//! let mut stream = StreamDecoder::ber();
+//!
+//! stream.push(&encoded[..3]);
+//! assert_eq!(stream.needed().unwrap(), Some(rasn::de::Needed::new(4)));
+//! assert_eq!(stream.decode::<rasn::types::Utf8String>().unwrap(), None);
+//!
+//! stream.push(&encoded[3..]);
+//! assert_eq!(stream.decode::<rasn::types::Utf8String>().unwrap().unwrap(), "hello");
+//! ```
+
+use alloc::vec::Vec;
+
+use nom::Needed;
+
+use super::de::{parser, Decoder, DecoderOptions};
+use crate::{
+    de::Error as _,
+    error::{DecodeError, DecodeErrorKind},
+    Decode,
+};
+
+/// Buffers chunks of input and decodes complete top-level values from them.
+///
+/// The input is only scanned once: how far into the next value the scan has
+/// got is kept between calls, so a large value arriving in small chunks takes
+/// time linear in its length.
+#[derive(Clone, Debug)]
+pub struct StreamDecoder {
+    buffer: Vec<u8>,
+    /// The start of the next value in `buffer`, before which the input has
+    /// been decoded.
+    start: usize,
+    scan: Scan,
+    config: DecoderOptions,
+}
+
+/// How far the scan for the end of the next top-level value has got.
+#[derive(Clone, Copy, Debug, Default)]
+struct Scan {
+    /// The offset from the start of the value up to which it's been scanned.
+    offset: usize,
+    /// The number of values of indefinite length the scan is inside of.
+    depth: usize,
+}
+
+impl StreamDecoder {
+    /// Creates a new stream decoder using `config`.
+    #[must_use]
+    pub fn new(config: DecoderOptions) -> Self {
+        Self {
+            buffer: Vec::new(),
+            start: 0,
+            scan: Scan::default(),
+            config,
+        }
+    }
+
+    /// Creates a new stream decoder for BER.
+    #[must_use]
+    pub fn ber() -> Self {
+        Self::new(DecoderOptions::ber())
+    }
+
+    /// Creates a new stream decoder for CER.
+    #[must_use]
+    pub fn cer() -> Self {
+        Self::new(DecoderOptions::cer())
+    }
+
+    /// Appends `chunk` to the buffered input.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.compact();
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Returns the input that has been buffered but not yet decoded.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        &self.buffer[self.start..]
+    }
+
+    /// Returns how many more bytes are needed to complete the next top-level
+    /// value, or `None` if it is already complete.
+    ///
+    /// For values of indefinite length, the amount needed is only known for
+    /// the innermost value currently being read, so more may be requested once
+    /// it is complete.
+    ///
+    /// # Errors
+    /// Returns an error if the buffered input can't be the start of a valid
+    /// value, or if the value would exceed the decoder's limits.
+    pub fn needed(&mut self) -> Result<Option<Needed>, DecodeError> {
+        match self.value_length() {
+            Ok(_) => Ok(None),
+            Err(error) => match *error.kind {
+                DecodeErrorKind::Incomplete { needed } => Ok(Some(needed)),
+                _ => Err(error),
+            },
+        }
+    }
+
+    /// Decodes the next top-level value if it is complete, removing it from
+    /// the buffered input. Returns `Ok(None)` if more input is needed.
+    ///
+    /// # Errors
+    /// Returns an error if the buffered input can't be the start of a valid
+    /// value, or if the value would exceed the decoder's limits. If the next
+    /// value is complete but can't be decoded as `T`, it is still removed, so
+    /// decoding can continue with the value after it.
+    pub fn decode<T: Decode>(&mut self) -> Result<Option<T>, DecodeError> {
+        let length = match self.value_length() {
+            Ok(length) => length,
+            Err(error) => {
+                return match *error.kind {
+                    DecodeErrorKind::Incomplete { .. } => Ok(None),
+                    _ => Err(error),
+                }
+            }
+        };
+
+        let value = Decoder::new(&self.buffered()[..length], self.config).decode_with_offset();
+        self.start += length;
+        self.scan = Scan::default();
+        if self.start == self.buffer.len() {
+            self.buffer.clear();
+            self.start = 0;
+        }
+
+        value.map(Some)
+    }
+
+    /// Reads from `reader` until the next top-level value is complete, and
+    /// decodes it. No more is read than is needed to complete the value, so
+    /// `reader` is left at the start of whatever follows it.
+    ///
+    /// # Errors
+    /// Returns an error if reading fails, including if `reader` ends before
+    /// the value does, or if the value can't be decoded as `T`.
+    #[cfg(feature = "std")]
+    pub fn read_value<T: Decode>(
+        &mut self,
+        mut reader: impl std::io::Read,
+    ) -> Result<T, DecodeError> {
+        loop {
+            if let Some(value) = self.decode()? {
+                return Ok(value);
+            }
+
+            let needed = match self.needed()? {
+                Some(Needed::Size(needed)) => needed.get(),
+                _ => 1,
+            };
+            self.compact();
+            let start = self.buffer.len();
+            self.buffer.resize(start + needed, 0);
+            if let Err(error) = reader.read_exact(&mut self.buffer[start..]) {
+                self.buffer.truncate(start);
+                return Err(DecodeError::io(error, self.config.current_codec()));
+            }
+        }
+    }
+
+    /// Removes the input that has already been decoded from the buffer.
+    fn compact(&mut self) {
+        if self.start > 0 {
+            self.buffer.drain(..self.start);
+            self.start = 0;
+        }
+    }
+
+    /// Returns the length of the next top-level value, checking that the
+    /// amount of input still needed to complete it is within the limits.
+    ///
+    /// The scan continues from where the previous call stopped, walking the
+    /// nested values of indefinite length encodings to find their end.
+    fn value_length(&mut self) -> Result<usize, DecodeError> {
+        const EOC: &[u8] = &[0, 0];
+
+        let input = &self.buffer[self.start..];
+        let codec = self.config.current_codec();
+        let incomplete = |needed: Needed| {
+            if let Needed::Size(needed) = needed {
+                self.config
+                    .limits
+                    .check_length(input.len().saturating_add(needed.get()), codec)?;
+            }
+
+            Err(DecodeError::incomplete(needed, codec))
+        };
+
+        loop {
+            if self.scan.depth == 0 && self.scan.offset > 0 {
+                return Ok(self.scan.offset);
+            }
+
+            let rest = &input[self.scan.offset..];
+            if self.scan.depth > 0 {
+                if rest.starts_with(EOC) {
+                    self.scan.offset += EOC.len();
+                    self.scan.depth -= 1;
+                    continue;
+                }
+
+                // `parse_identifier_octet` rejects a lone end-of-contents octet.
+                if EOC.starts_with(rest) {
+                    return incomplete(Needed::new(EOC.len() - rest.len()));
+                }
+            }
+
+            match parser::parse_value(&self.config, rest, None) {
+                Ok((after, (_, contents))) => {
+                    if contents.is_none() {
+                        self.scan.depth =
+                            self.config.limits.check_depth(self.scan.depth + 1, codec)?;
+                    }
+                    self.scan.offset += rest.len() - after.len();
+                }
+                Err(error) => {
+                    return match *error.kind {
+                        DecodeErrorKind::Incomplete { needed } => incomplete(needed),
+                        _ => Err(error),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{de::Limits, types::*};
+
+    #[test]
+    fn chunks() {
+        let values: Vec<Vec<Integer>> = vec![vec![1.into(), 2.into()], vec![3.into()]];
+        let encoded: Vec<u8> = values
+            .iter()
+            .flat_map(|value| crate::ber::encode(value).unwrap())
+            .collect();
+
+        let mut stream = StreamDecoder::ber();
+        let mut decoded = Vec::new();
+        for byte in &encoded {
+            assert!(stream.needed().unwrap().is_some());
+            stream.push(core::slice::from_ref(byte));
+            while let Some(value) = stream.decode::<Vec<Integer>>().unwrap() {
+                decoded.push(value);
+            }
+        }
+
+        assert_eq!(values, decoded);
+        assert!(stream.buffered().is_empty());
+    }
+
+    #[test]
+    fn indefinite_length() {
+        // A constructed `OCTET STRING` of indefinite length, followed by `NULL`.
+        let encoded = [
+            0x24, 0x80, 0x04, 0x01, 0x01, 0x24, 0x80, 0x04, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00,
+            0x05, 0x00,
+        ];
+        let mut stream = StreamDecoder::cer();
+
+        stream.push(&encoded[..4]);
+        assert_eq!(stream.needed().unwrap(), Some(Needed::new(1)));
+        stream.push(&encoded[4..11]);
+        assert_eq!(stream.needed().unwrap(), Some(Needed::new(1)));
+        assert_eq!(stream.decode::<OctetString>().unwrap(), None);
+        stream.push(&encoded[11..13]);
+        assert_eq!(stream.needed().unwrap(), Some(Needed::new(1)));
+        stream.push(&encoded[13..]);
+        assert_eq!(stream.needed().unwrap(), None);
+
+        assert_eq!(
+            stream.decode::<OctetString>().unwrap(),
+            Some(OctetString::from_static(&[1, 2]))
+        );
+        assert_eq!(stream.buffered(), &[0x05, 0x00]);
+        assert_eq!(stream.decode::<()>().unwrap(), Some(()));
+        assert_eq!(stream.needed().unwrap(), Some(Needed::new(1)));
+    }
+
+    #[test]
+    fn scan_is_resumed() {
+        // A `SEQUENCE` of indefinite length holding 1000 `NULL`s.
+        let mut encoded = vec![0x30, 0x80];
+        for _ in 0..1000 {
+            encoded.extend_from_slice(&[0x05, 0x00]);
+        }
+        encoded.extend_from_slice(&[0x00, 0x00, 0x05, 0x00]);
+
+        let mut stream = StreamDecoder::ber();
+        for chunk in encoded[..1002].chunks(3) {
+            stream.push(chunk);
+            assert_eq!(stream.decode::<Vec<()>>().unwrap(), None);
+        }
+        // The complete values inside the sequence aren't scanned again.
+        assert_eq!(stream.scan.depth, 1);
+        assert_eq!(stream.scan.offset, 1002);
+
+        stream.push(&encoded[1002..]);
+        assert_eq!(stream.decode::<Vec<()>>().unwrap().unwrap().len(), 1000);
+        assert_eq!(stream.buffered(), &[0x05, 0x00]);
+        assert_eq!(stream.decode::<()>().unwrap(), Some(()));
+        assert!(stream.buffered().is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let mut stream = StreamDecoder::ber();
+        // Decoding a complete value as the wrong type still consumes it.
+        stream.push(&[0x05, 0x00, 0x01, 0x01, 0xff]);
+        assert!(stream.decode::<bool>().is_err());
+        assert_eq!(stream.decode::<bool>().unwrap(), Some(true));
+
+        // A length longer than the limits fails before it is buffered.
+        let mut stream = StreamDecoder::new(DecoderOptions::ber().with_limits(Limits {
+            max_length: 16,
+            ..Limits::DEFAULT
+        }));
+        stream.push(&[0x04, 0x82, 0x01, 0x00, 0x00]);
+        assert!(stream.needed().unwrap_err().is_limit_exceeded());
+        assert!(stream.decode::<OctetString>().is_err());
+
+        // Indefinite length is not allowed for primitive values.
+        let mut stream = StreamDecoder::ber();
+        stream.push(&[0x04, 0x80]);
+        assert!(stream.needed().is_err());
+    }
+}
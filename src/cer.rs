@@ -2,10 +2,25 @@
 
 /// Attempts to decode `T` from `input` using CER.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeError> {
-    T::decode(&mut crate::ber::de::Decoder::new(
-        input,
-        crate::ber::de::DecoderOptions::cer(),
-    ))
+    crate::ber::de::Decoder::new(input, crate::ber::de::DecoderOptions::cer()).decode_with_offset()
+}
+
+/// Attempts to decode `T` from the start of `input` using CER, returning it
+/// together with the input following it.
+/// # Errors
+/// Returns error specific to CER decoder if decoding is not possible.
+pub fn decode_with_remainder<T: crate::Decode>(
+    input: &[u8],
+) -> Result<(T, &[u8]), crate::error::DecodeError> {
+    let mut decoder = crate::ber::de::Decoder::new(input, crate::ber::de::DecoderOptions::cer());
+    let value = decoder.decode_with_offset()?;
+    Ok((value, decoder.input()))
+}
+
+/// Returns an iterator decoding successive values of `T` from `input` using
+/// CER.
+pub fn decode_iter<T: crate::Decode>(input: &[u8]) -> crate::de::DecodeIter<'_, T> {
+    crate::de::DecodeIter::new(input, crate::Codec::Cer, decode_with_remainder)
 }
 
 /// Attempts to encode `value` to CER.
@@ -18,3 +33,34 @@ pub fn encode<T: crate::Encode>(
 
     Ok(enc.output())
 }
+
+/// Attempts to encode `value` to CER, writing the encoding to `writer` as
+/// it's produced.
+/// # Errors
+/// Returns error specific to CER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    let mut enc =
+        crate::ber::enc::Encoder::with_writer(crate::ber::enc::EncoderOptions::cer(), writer);
+
+    value.encode(&mut enc)?;
+
+    enc.finish().map(drop)
+}
+
+/// Attempts to decode `T` from `reader` using CER. Only the bytes of the
+/// value are read, so `reader` is left at the start of whatever follows it,
+/// such as the next message on a socket.
+/// # Errors
+/// Returns error specific to CER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    crate::ber::stream::StreamDecoder::new(crate::ber::de::DecoderOptions::cer()).read_value(reader)
+}
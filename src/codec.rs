@@ -13,10 +13,16 @@ pub enum Codec {
     Cer,
     /// X.690 — Distinguished Encoding Rules
     Der,
+    /// X.696 — Octet Encoding Rules
+    Oer,
+    /// X.696 — Canonical Octet Encoding Rules
+    Coer,
     /// X.691 — Packed Encoding Rules (Unaligned)
     Uper,
     /// [JSON Encoding Rules](https://obj-sys.com/docs/JSONEncodingRules.pdf)
     Jer,
+    /// X.693 — XML Encoding Rules
+    Xer,
 }
 
 impl core::fmt::Display for Codec {
@@ -26,8 +32,11 @@ impl core::fmt::Display for Codec {
             Self::Ber => write!(f, "BER"),
             Self::Cer => write!(f, "CER"),
             Self::Der => write!(f, "DER"),
+            Self::Oer => write!(f, "OER"),
+            Self::Coer => write!(f, "COER"),
             Self::Uper => write!(f, "UPER"),
             Self::Jer => write!(f, "JER"),
+            Self::Xer => write!(f, "XER"),
         }
     }
 }
@@ -47,8 +56,11 @@ impl Codec {
             Self::Ber => crate::ber::encode(value),
             Self::Cer => crate::cer::encode(value),
             Self::Der => crate::der::encode(value),
+            Self::Oer => crate::oer::encode(value),
+            Self::Coer => crate::coer::encode(value),
             Self::Uper => crate::uper::encode(value),
             Self::Jer => crate::jer::encode(value).map(alloc::string::String::into_bytes),
+            Self::Xer => crate::xer::encode(value).map(alloc::string::String::into_bytes),
         }
     }
 
@@ -66,6 +78,8 @@ impl Codec {
             Self::Ber => crate::ber::decode(input),
             Self::Cer => crate::cer::decode(input),
             Self::Der => crate::der::decode(input),
+            Self::Oer => crate::oer::decode(input),
+            Self::Coer => crate::coer::decode(input),
             Self::Uper => crate::uper::decode(input),
             Self::Jer => alloc::string::String::from_utf8(input.to_vec()).map_or_else(
                 |e| {
@@ -78,6 +92,17 @@ impl Codec {
                 },
                 |s| crate::jer::decode(&s),
             ),
+            Self::Xer => alloc::string::String::from_utf8(input.to_vec()).map_or_else(
+                |e| {
+                    Err(crate::error::DecodeError::from_kind(
+                        crate::error::DecodeErrorKind::Custom {
+                            msg: alloc::format!("Failed to decode XER from UTF8 bytes: {e:?}"),
+                        },
+                        self.clone(),
+                    ))
+                },
+                |s| crate::xer::decode(&s),
+            ),
         }
     }
 
@@ -93,6 +118,7 @@ impl Codec {
     ) -> Result<alloc::string::String, crate::error::EncodeError> {
         match self {
             Self::Jer => crate::jer::encode(value),
+            Self::Xer => crate::xer::encode(value),
             codec => Err(crate::error::EncodeError::from_kind(
                 crate::error::EncodeErrorKind::Custom {
                     msg: alloc::format!("{codec} is a binary-based encoding. Call `Codec::encode_to_binary` instead."),
@@ -111,6 +137,7 @@ impl Codec {
     pub fn decode_from_str<D: Decode>(&self, input: &str) -> Result<D, crate::error::DecodeError> {
         match self {
             Self::Jer => crate::jer::decode(input),
+            Self::Xer => crate::xer::decode(input),
             codec => Err(crate::error::DecodeError::from_kind(
                 crate::error::DecodeErrorKind::Custom {
                     msg: alloc::format!("{codec} is a text-based encoding. Call `Codec::decode_from_binary` instead."),
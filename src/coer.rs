@@ -0,0 +1,102 @@
+//! # Canonical Octet Encoding Rules
+//!
+//! Codec functions for C-OER. rasn's OER encoder is already canonical, the
+//! C-OER decoder additionally rejects any input that isn't in canonical form,
+//! so that re-encoding a decoded value always reproduces the original bytes.
+use crate::types::Constraints;
+
+pub use crate::oer::*;
+
+/// Attempts to decode `T` from `input` using C-OER.
+/// # Errors
+/// Returns error specific to C-OER decoder if decoding is not possible.
+pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeError> {
+    T::decode(&mut Decoder::new(input, de::DecoderOptions::coer()))
+}
+
+/// Attempts to decode `T` from the start of `input` using C-OER, returning it
+/// together with the input following it.
+/// # Errors
+/// Returns error specific to C-OER decoder if decoding is not possible.
+pub fn decode_with_remainder<T: crate::Decode>(
+    input: &[u8],
+) -> Result<(T, &[u8]), crate::error::DecodeError> {
+    let mut decoder = Decoder::new(input, de::DecoderOptions::coer());
+    let value = T::decode(&mut decoder)?;
+    Ok((value, decoder.input()))
+}
+
+/// Returns an iterator decoding successive values of `T` from `input` using
+/// C-OER.
+pub fn decode_iter<T: crate::Decode>(input: &[u8]) -> crate::de::DecodeIter<'_, T> {
+    crate::de::DecodeIter::new(input, crate::Codec::Coer, decode_with_remainder)
+}
+
+/// Attempts to encode `value` to C-OER.
+/// # Errors
+/// Returns error specific to C-OER encoder if encoding is not possible.
+pub fn encode<T: crate::Encode>(
+    value: &T,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let mut enc = Encoder::new(enc::EncoderOptions::coer());
+
+    value.encode(&mut enc)?;
+
+    Ok(enc.output())
+}
+
+/// Attempts to encode `value` to C-OER, writing the encoding to `writer`.
+/// # Errors
+/// Returns error specific to C-OER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    mut writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    writer
+        .write_all(&encode(value)?)
+        .map_err(|error| crate::error::EncodeError::io(error, crate::Codec::Coer))
+}
+
+/// Attempts to decode `T` from the rest of `reader` using C-OER.
+/// # Errors
+/// Returns error specific to C-OER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    mut reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    let mut input = alloc::vec::Vec::new();
+    reader
+        .read_to_end(&mut input)
+        .map_err(|error| crate::error::DecodeError::io(error, crate::Codec::Coer))?;
+    decode(&input)
+}
+
+/// Attempts to decode `T` from `input` using C-OER with `constraints`.
+/// # Errors
+/// Returns error specific to C-OER decoder if decoding is not possible.
+pub fn decode_with_constraints<T: crate::Decode>(
+    constraints: Constraints,
+    input: &[u8],
+) -> Result<T, crate::error::DecodeError> {
+    T::decode_with_constraints(
+        &mut Decoder::new(input, de::DecoderOptions::coer()),
+        constraints,
+    )
+}
+
+/// Attempts to encode `value` to C-OER with `constraints`.
+/// # Errors
+/// Returns error specific to C-OER encoder if encoding is not possible.
+pub fn encode_with_constraints<T: crate::Encode>(
+    constraints: Constraints,
+    value: &T,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let mut enc = Encoder::new(enc::EncoderOptions::coer());
+
+    value.encode_with_constraints(&mut enc, constraints)?;
+
+    Ok(enc.output())
+}
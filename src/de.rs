@@ -74,6 +74,27 @@ pub trait Decoder: Sized {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::Integer, Self::Error>;
+    /// Decode a `INTEGER` identified by `tag` from the available input into a
+    /// primitive Rust integer.
+    ///
+    /// The default implementation decodes an [`types::Integer`] and converts
+    /// it, codecs override it to decode without allocating.
+    fn decode_primitive_integer(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<i128, Self::Error> {
+        let integer = self.decode_integer(tag, constraints)?;
+        i128::try_from(&integer).map_err(|e| {
+            DecodeError::integer_type_conversion_failed(e.to_string(), self.codec()).into()
+        })
+    }
+    /// Decode a `REAL` identified by `tag` from the available input.
+    fn decode_real(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::Real, Self::Error>;
     /// Decode `NULL` identified by `tag` from the available input.
     fn decode_null(&mut self, tag: Tag) -> Result<(), Self::Error>;
     /// Decode a `OBJECT IDENTIFIER` identified by `tag` from the available input.
@@ -81,6 +102,12 @@ pub trait Decoder: Sized {
         &mut self,
         tag: Tag,
     ) -> Result<types::ObjectIdentifier, Self::Error>;
+    /// Decode a `RELATIVE-OID` identified by `tag` from the available input.
+    fn decode_relative_oid(&mut self, tag: Tag) -> Result<types::RelativeOid, Self::Error>;
+    /// Decode a `OID-IRI` identified by `tag` from the available input.
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri, Self::Error>;
+    /// Decode a `RELATIVE-OID-IRI` identified by `tag` from the available input.
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri, Self::Error>;
     /// Decode a `SEQUENCE` identified by `tag` from the available input. Returning
     /// a new `Decoder` containing the sequence's contents to be decoded.
     fn decode_sequence<D, DF, F>(
@@ -132,6 +159,27 @@ pub trait Decoder: Sized {
         constraints: Constraints,
     ) -> Result<types::GeneralString, Self::Error>;
 
+    /// Decode a `GraphicString` identified by `tag` from the available input.
+    fn decode_graphic_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::GraphicString, Self::Error>;
+
+    /// Decode a `VideotexString` identified by `tag` from the available input.
+    fn decode_videotex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::VideotexString, Self::Error>;
+
+    /// Decode a `UniversalString` identified by `tag` from the available input.
+    fn decode_universal_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::UniversalString, Self::Error>;
+
     /// Decode a `Ia5String` identified by `tag` from the available input.
     fn decode_ia5_string(
         &mut self,
@@ -173,6 +221,22 @@ pub trait Decoder: Sized {
     fn decode_utc_time(&mut self, tag: Tag) -> Result<types::UtcTime, Self::Error>;
     /// Decode a `GeneralizedTime` identified by `tag` from the available input.
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime, Self::Error>;
+    /// Decode a `GeneralizedTime` identified by `tag` from the available
+    /// input, keeping the exact form it was written in.
+    fn decode_lossless_generalized_time(
+        &mut self,
+        tag: Tag,
+    ) -> Result<types::LosslessGeneralizedTime, Self::Error>;
+    /// Decode a `DATE` identified by `tag` from the available input.
+    fn decode_date(&mut self, tag: Tag) -> Result<types::Date, Self::Error>;
+    /// Decode a `TIME-OF-DAY` identified by `tag` from the available input.
+    fn decode_time_of_day(&mut self, tag: Tag) -> Result<types::TimeOfDay, Self::Error>;
+    /// Decode a `DATE-TIME` identified by `tag` from the available input.
+    fn decode_date_time(&mut self, tag: Tag) -> Result<types::DateTime, Self::Error>;
+    /// Decode a `DURATION` identified by `tag` from the available input.
+    fn decode_duration(&mut self, tag: Tag) -> Result<types::Duration, Self::Error>;
+    /// Decode a `TIME` identified by `tag` from the available input.
+    fn decode_time(&mut self, tag: Tag) -> Result<types::TimeValue, Self::Error>;
 
     /// Decode a `SET` identified by `tag` from the available input. Decoding
     /// `SET`s works a little different than other methods, as you need to
@@ -303,6 +367,70 @@ pub trait Decoder: Sized {
     ) -> Result<Option<D>, Self::Error>;
 }
 
+/// A **data type** that can be decoded by borrowing from the decoder's input,
+/// such as `&'de [u8]`, `&'de str` or [`types::AnyRef`].
+///
+/// Every [`Decode`] type is also `DecodeBorrowed`, so borrowed and owned
+/// fields can be mixed freely. Deriving [`Decode`] on a struct with a lifetime
+/// parameter implements this trait instead of [`Decode`].
+pub trait DecodeBorrowed<'de>: Sized + AsnType {
+    /// Decode this value from a given ASN.1 decoder, borrowing from its input
+    /// where possible.
+    fn decode_borrowed<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+        Self::decode_borrowed_with_tag(decoder, Self::TAG)
+    }
+
+    /// Decode this value implicitly tagged with `tag`, borrowing from the
+    /// decoder's input where possible.
+    fn decode_borrowed_with_tag<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        Self::decode_borrowed_with_tag_and_constraints(decoder, tag, Self::CONSTRAINTS)
+    }
+
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error>;
+}
+
+/// A **data format** whose input outlives the decoded values, allowing
+/// [`DecodeBorrowed`] types to reference it without copying.
+pub trait BorrowDecoder<'de>: Decoder {
+    /// Decode a primitive `OCTET STRING` identified by `tag`, returning a slice
+    /// of the input. Fails if the contents are not stored contiguously, such as
+    /// with BER's constructed encoding.
+    fn decode_octet_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<&'de [u8], Self::Error>;
+    /// Decode a `UTF8String` identified by `tag`, returning a slice of the input.
+    fn decode_utf8_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<&'de str, Self::Error>;
+    /// Decode an unknown ASN.1 value, returning a slice of the input.
+    fn decode_any_borrowed(&mut self) -> Result<types::AnyRef<'de>, Self::Error>;
+    /// Decode an explicit tag followed by a borrowed value.
+    fn decode_explicit_prefix_borrowed<D: DecodeBorrowed<'de>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<D, Self::Error>;
+    /// Decode an optional borrowed value in a `SEQUENCE` or `SET`.
+    fn decode_optional_borrowed<D: DecodeBorrowed<'de>>(
+        &mut self,
+    ) -> Result<Option<D>, Self::Error>;
+    /// Decode an optional borrowed value in a `SEQUENCE` or `SET` with `tag`.
+    fn decode_optional_borrowed_with_tag<D: DecodeBorrowed<'de>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<Option<D>, Self::Error>;
+}
+
 /// A generic error that can occur while decoding ASN.1.
 /// Caller needs always to pass a `crate::Codec` variant to `Error` when implementing the decoder
 pub trait Error: core::fmt::Display {
@@ -333,6 +461,224 @@ pub trait Error: core::fmt::Display {
     fn unknown_field(index: usize, tag: Tag, codec: crate::Codec) -> Self;
 }
 
+/// Bounds on the resources a decoder may use for a single input, so that
+/// untrusted input can't exhaust the stack or memory. Exceeding any of them
+/// fails decoding with [`DecodeErrorKind::LimitExceeded`].
+///
+/// [`DecodeErrorKind::LimitExceeded`]: crate::error::DecodeErrorKind::LimitExceeded
+///
+/// ```
+/// use rasn::{ber::de::{Decoder, DecoderOptions}, de::Limits, Decode};
+///
+/// let options = DecoderOptions::der().with_limits(Limits {
+///     max_depth: 1,
+///     ..Limits::default()
+/// });
+/// // A `SEQUENCE OF SEQUENCE OF NULL` is two levels deep.
+/// let input = [0x30, 0x04, 0x30, 0x02, 0x05, 0x00];
+/// let mut decoder = Decoder::new(&input, options);
+/// assert!(<Vec<Vec<()>>>::decode(&mut decoder).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum nesting depth of values within `SEQUENCE`, `SET`,
+    /// `SEQUENCE OF`, `SET OF` and `CHOICE` values, or for BER, within
+    /// constructed encodings, and for XER, within XML elements.
+    pub max_depth: usize,
+    /// The maximum value of a single length determinant. This is measured in
+    /// octets for BER and OER, and in the units of the type (bits, characters
+    /// or octets) for PER, JER and XER.
+    pub max_length: usize,
+    /// The maximum number of components in a single `SEQUENCE OF` or
+    /// `SET OF` value.
+    pub max_elements: usize,
+    /// The maximum sum of the lengths of every value decoded from a single
+    /// input, measured as for `max_length`, except that BER only counts
+    /// primitive encodings. `max_length` bounds the memory allocated for any
+    /// one value, this bounds it for the decoded value as a whole.
+    pub max_total_length: usize,
+}
+
+impl Limits {
+    /// The limits used by every decoder unless configured otherwise.
+    pub const DEFAULT: Self = Self {
+        max_depth: 64,
+        max_length: 64 * 1024 * 1024,
+        max_elements: 1024 * 1024,
+        max_total_length: 256 * 1024 * 1024,
+    };
+
+    /// Limits that never fail decoding.
+    pub const UNLIMITED: Self = Self {
+        max_depth: usize::MAX,
+        max_length: usize::MAX,
+        max_elements: usize::MAX,
+        max_total_length: usize::MAX,
+    };
+
+    /// Returns `depth` if it doesn't exceed the maximum nesting depth.
+    pub(crate) fn check_depth(
+        &self,
+        depth: usize,
+        codec: crate::Codec,
+    ) -> Result<usize, DecodeError> {
+        Self::check(Limit::Depth, depth, self.max_depth, codec)
+    }
+
+    /// Returns `length` if it doesn't exceed the maximum length.
+    pub(crate) fn check_length(
+        &self,
+        length: usize,
+        codec: crate::Codec,
+    ) -> Result<usize, DecodeError> {
+        Self::check(Limit::Length, length, self.max_length, codec)
+    }
+
+    /// Returns `elements` if it doesn't exceed the maximum number of elements.
+    pub(crate) fn check_elements(
+        &self,
+        elements: usize,
+        codec: crate::Codec,
+    ) -> Result<usize, DecodeError> {
+        Self::check(Limit::Elements, elements, self.max_elements, codec)
+    }
+
+    /// Returns `length` if it doesn't exceed the maximum length, after adding
+    /// it to `total`, the lengths checked so far, if that doesn't exceed the
+    /// maximum total length.
+    pub(crate) fn check_length_within_total(
+        &self,
+        length: usize,
+        total: &mut usize,
+        codec: crate::Codec,
+    ) -> Result<usize, DecodeError> {
+        let length = self.check_length(length, codec)?;
+        *total = self.check_total_length(total.saturating_add(length), codec)?;
+        Ok(length)
+    }
+
+    /// Returns `total` if it doesn't exceed the maximum total length.
+    pub(crate) fn check_total_length(
+        &self,
+        total: usize,
+        codec: crate::Codec,
+    ) -> Result<usize, DecodeError> {
+        Self::check(Limit::TotalLength, total, self.max_total_length, codec)
+    }
+
+    fn check(
+        limit: Limit,
+        value: usize,
+        maximum: usize,
+        codec: crate::Codec,
+    ) -> Result<usize, DecodeError> {
+        if value > maximum {
+            Err(DecodeError::limit_exceeded(limit, maximum, codec))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The kind of limit in [`Limits`] that was exceeded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Limit {
+    /// [`Limits::max_depth`]
+    Depth,
+    /// [`Limits::max_length`]
+    Length,
+    /// [`Limits::max_elements`]
+    Elements,
+    /// [`Limits::max_total_length`]
+    TotalLength,
+}
+
+impl core::fmt::Display for Limit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Depth => "nesting depth",
+            Self::Length => "length",
+            Self::Elements => "number of elements",
+            Self::TotalLength => "total length",
+        })
+    }
+}
+
+/// An iterator over successive values of `T` encoded back to back in one
+/// input, such as a stream of LDAP messages. Created by the `decode_iter`
+/// function of each codec.
+///
+/// Iteration ends once the input is exhausted, or after the first error, in
+/// which case [`DecodeIter::remaining`] returns the input that couldn't be
+/// decoded. A value that takes up no input, such as `NULL` in OER, is an
+/// error when there's input left, as it would otherwise be decoded forever.
+pub struct DecodeIter<'input, T> {
+    input: &'input [u8],
+    codec: crate::Codec,
+    decode_fn: DecodeWithRemainderFn<'input, T>,
+    finished: bool,
+}
+
+/// Decodes a value from the start of the input, returning the input following it.
+type DecodeWithRemainderFn<'input, T> = fn(&'input [u8]) -> Result<(T, &'input [u8]), DecodeError>;
+
+impl<'input, T> DecodeIter<'input, T> {
+    /// Creates an iterator calling `decode_fn` on the remaining input for each
+    /// value, which returns the value and the input following it.
+    pub fn new(
+        input: &'input [u8],
+        codec: crate::Codec,
+        decode_fn: DecodeWithRemainderFn<'input, T>,
+    ) -> Self {
+        Self {
+            input,
+            codec,
+            decode_fn,
+            finished: false,
+        }
+    }
+
+    /// Returns the input that is yet to be decoded.
+    #[must_use]
+    pub fn remaining(&self) -> &'input [u8] {
+        self.input
+    }
+}
+
+impl<T> Iterator for DecodeIter<'_, T> {
+    type Item = Result<T, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished || self.input.is_empty() {
+            return None;
+        }
+
+        let result = (self.decode_fn)(self.input).and_then(|(value, remaining)| {
+            if remaining.len() < self.input.len() {
+                self.input = remaining;
+                Ok(value)
+            } else {
+                Err(DecodeError::unexpected_extra_data(
+                    self.input.len(),
+                    self.codec,
+                ))
+            }
+        });
+        self.finished = result.is_err();
+
+        Some(result)
+    }
+}
+
+impl<T> core::iter::FusedIterator for DecodeIter<'_, T> {}
+
 impl Decode for () {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
@@ -383,12 +729,12 @@ macro_rules! impl_integers {
         $(
         impl Decode for $int {
             fn decode_with_tag_and_constraints<D: Decoder>(decoder: &mut D, tag: Tag, constraints: Constraints) -> Result<Self, D::Error> {
-                core::convert::TryInto::try_into(
-                    decoder.decode_integer(
+                <$int>::try_from(
+                    decoder.decode_primitive_integer(
                         tag,
                         constraints,
                     )?
-                ).map_err(|e: num_bigint::TryFromBigIntError<types::Integer>|D::Error::from(DecodeError::integer_type_conversion_failed(e.to_string(), decoder.codec())))
+                ).map_err(|e|D::Error::from(DecodeError::integer_type_conversion_failed(e.to_string(), decoder.codec())))
             }
         }
         )+
@@ -428,6 +774,40 @@ impl Decode for types::Integer {
     }
 }
 
+impl Decode for types::Real {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_real(tag, constraints)
+    }
+}
+
+impl Decode for f64 {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder
+            .decode_real(tag, constraints)
+            .map(|real| real.to_f64())
+    }
+}
+
+impl Decode for f32 {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder
+            .decode_real(tag, constraints)
+            .map(|real| real.to_f32())
+    }
+}
+
 impl<T: Decode> Decode for Box<T> {
     fn decode<D: Decoder>(decoder: &mut D) -> Result<Self, D::Error> {
         T::decode(decoder).map(Box::new)
@@ -475,6 +855,36 @@ impl Decode for types::ObjectIdentifier {
     }
 }
 
+impl Decode for types::RelativeOid {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_relative_oid(tag)
+    }
+}
+
+impl Decode for types::OidIri {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_oid_iri(tag)
+    }
+}
+
+impl Decode for types::RelativeOidIri {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_relative_oid_iri(tag)
+    }
+}
+
 impl Decode for types::Utf8String {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
@@ -505,6 +915,66 @@ impl Decode for types::GeneralizedTime {
     }
 }
 
+impl Decode for types::LosslessGeneralizedTime {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_lossless_generalized_time(tag)
+    }
+}
+
+impl Decode for types::Date {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_date(tag)
+    }
+}
+
+impl Decode for types::TimeOfDay {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_time_of_day(tag)
+    }
+}
+
+impl Decode for types::DateTime {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_date_time(tag)
+    }
+}
+
+impl Decode for types::Duration {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_duration(tag)
+    }
+}
+
+impl Decode for types::TimeValue {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_time(tag)
+    }
+}
+
 impl Decode for types::Any {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
@@ -515,6 +985,61 @@ impl Decode for types::Any {
     }
 }
 
+impl<'de, T: Decode> DecodeBorrowed<'de> for T {
+    fn decode_borrowed<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+        T::decode(decoder)
+    }
+
+    fn decode_borrowed_with_tag<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        T::decode_with_tag(decoder, tag)
+    }
+
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        T::decode_with_tag_and_constraints(decoder, tag, constraints)
+    }
+}
+
+impl<'de> DecodeBorrowed<'de> for &'de [u8] {
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_octet_string_borrowed(tag, constraints)
+    }
+}
+
+impl<'de> DecodeBorrowed<'de> for &'de str {
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_utf8_string_borrowed(tag, constraints)
+    }
+}
+
+impl<'de> DecodeBorrowed<'de> for types::AnyRef<'de> {
+    fn decode_borrowed<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+        decoder.decode_any_borrowed()
+    }
+
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        _: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_any_borrowed()
+    }
+}
+
 impl<T: Decode> Decode for alloc::vec::Vec<T> {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
@@ -4,10 +4,34 @@ pub use crate::ber::*;
 
 /// Attempts to decode `T` from `input` using DER.
 pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeError> {
-    T::decode(&mut crate::ber::de::Decoder::new(
-        input,
-        crate::ber::de::DecoderOptions::der(),
-    ))
+    crate::ber::de::Decoder::new(input, crate::ber::de::DecoderOptions::der()).decode_with_offset()
+}
+
+/// Attempts to decode `T` from the start of `input` using DER, returning it
+/// together with the input following it.
+/// # Errors
+/// Returns error specific to DER decoder if decoding is not possible.
+pub fn decode_with_remainder<T: crate::Decode>(
+    input: &[u8],
+) -> Result<(T, &[u8]), crate::error::DecodeError> {
+    let mut decoder = crate::ber::de::Decoder::new(input, crate::ber::de::DecoderOptions::der());
+    let value = decoder.decode_with_offset()?;
+    Ok((value, decoder.input()))
+}
+
+/// Returns an iterator decoding successive values of `T` from `input` using
+/// DER.
+pub fn decode_iter<T: crate::Decode>(input: &[u8]) -> crate::de::DecodeIter<'_, T> {
+    crate::de::DecodeIter::new(input, crate::Codec::Der, decode_with_remainder)
+}
+
+/// Attempts to decode `T` from `input` using DER, borrowing from `input`
+/// where `T` allows it.
+pub fn decode_borrowed<'de, T: crate::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, crate::error::DecodeError> {
+    crate::ber::de::Decoder::new(input, crate::ber::de::DecoderOptions::der())
+        .decode_borrowed_with_offset()
 }
 
 /// Attempts to encode `value` to DER.
@@ -21,6 +45,33 @@ pub fn encode<T: crate::Encode>(
     Ok(enc.output())
 }
 
+/// Attempts to encode `value` to DER, writing the encoding to `writer`.
+/// # Errors
+/// Returns error specific to DER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    mut writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    writer
+        .write_all(&encode(value)?)
+        .map_err(|error| crate::error::EncodeError::io(error, crate::Codec::Der))
+}
+
+/// Attempts to decode `T` from `reader` using DER. Only the bytes of the
+/// value are read, so `reader` is left at the start of whatever follows it,
+/// such as the next message on a socket.
+/// # Errors
+/// Returns error specific to DER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    crate::ber::stream::StreamDecoder::new(crate::ber::de::DecoderOptions::der()).read_value(reader)
+}
+
 /// Creates a new DER encoder that can be used to encode any value.
 pub fn encode_scope(
     encode_fn: impl FnOnce(&mut crate::ber::enc::Encoder) -> Result<(), crate::error::EncodeError>,
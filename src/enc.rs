@@ -79,6 +79,19 @@ pub trait Encoder {
         value: &[u32],
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `RELATIVE-OID` value.
+    fn encode_relative_oid(&mut self, tag: Tag, value: &[u32]) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `OID-IRI` value.
+    fn encode_oid_iri(&mut self, tag: Tag, value: &types::OidIri) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `RELATIVE-OID-IRI` value.
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+    ) -> Result<Self::Ok, Self::Error>;
+
     /// Encode a `INTEGER` value.
     fn encode_integer(
         &mut self,
@@ -87,6 +100,27 @@ pub trait Encoder {
         value: &num_bigint::BigInt,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `INTEGER` value from a primitive Rust integer.
+    ///
+    /// The default implementation converts `value` into an [`types::Integer`],
+    /// codecs override it to encode without allocating.
+    fn encode_primitive_integer(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: i128,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_integer(tag, constraints, &value.into())
+    }
+
+    /// Encode a `REAL` value.
+    fn encode_real(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::Real,
+    ) -> Result<Self::Ok, Self::Error>;
+
     /// Encode a `NULL` value.
     fn encode_null(&mut self, tag: Tag) -> Result<Self::Ok, Self::Error>;
 
@@ -106,6 +140,30 @@ pub trait Encoder {
         value: &types::GeneralString,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `GraphicString` value.
+    fn encode_graphic_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::GraphicString,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `VideotexString` value.
+    fn encode_videotex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::VideotexString,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `UniversalString` value.
+    fn encode_universal_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::UniversalString,
+    ) -> Result<Self::Ok, Self::Error>;
+
     /// Encode a `Utf8String` value.
     fn encode_utf8_string(
         &mut self,
@@ -169,6 +227,13 @@ pub trait Encoder {
         value: &types::GeneralizedTime,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `GeneralizedTime` value in the exact form it was written in.
+    fn encode_lossless_generalized_time(
+        &mut self,
+        tag: Tag,
+        value: &types::LosslessGeneralizedTime,
+    ) -> Result<Self::Ok, Self::Error>;
+
     /// Encode a `UtcTime` value.
     fn encode_utc_time(
         &mut self,
@@ -176,6 +241,33 @@ pub trait Encoder {
         value: &types::UtcTime,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `DATE` value.
+    fn encode_date(&mut self, tag: Tag, value: &types::Date) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `TIME-OF-DAY` value.
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &types::TimeOfDay,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `DATE-TIME` value.
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &types::DateTime,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `DURATION` value.
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &types::Duration,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `TIME` value.
+    fn encode_time(&mut self, tag: Tag, value: &types::TimeValue) -> Result<Self::Ok, Self::Error>;
+
     /// Encode a explicitly tagged value.
     fn encode_explicit_prefix<V: Encode>(
         &mut self,
@@ -456,10 +548,10 @@ macro_rules! impl_integers {
         $(
             impl Encode for $int {
                 fn encode_with_tag_and_constraints<E: Encoder>(&self, encoder: &mut E, tag: Tag, constraints: Constraints) -> Result<(), E::Error> {
-                    encoder.encode_integer(
+                    encoder.encode_primitive_integer(
                         tag,
                         constraints,
-                        &(*self).into()
+                        *self as i128
                     ).map(drop)
                 }
             }
@@ -502,6 +594,43 @@ impl Encode for types::Integer {
     }
 }
 
+impl Encode for types::Real {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_real(tag, constraints, self).map(drop)
+    }
+}
+
+impl Encode for f64 {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_real(tag, constraints, &types::Real::from(*self))
+            .map(drop)
+    }
+}
+
+impl Encode for f32 {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_real(tag, constraints, &types::Real::from(*self))
+            .map(drop)
+    }
+}
+
 impl Encode for types::OctetString {
     fn encode_with_tag_and_constraints<E: Encoder>(
         &self,
@@ -537,6 +666,19 @@ impl Encode for &'_ str {
     }
 }
 
+impl Encode for &'_ [u8] {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_octet_string(tag, constraints, self)
+            .map(drop)
+    }
+}
+
 impl Encode for types::ObjectIdentifier {
     fn encode_with_tag_and_constraints<E: Encoder>(
         &self,
@@ -559,6 +701,50 @@ impl Encode for types::Oid {
     }
 }
 
+impl Encode for types::RelativeOid {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_relative_oid(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::RelativeOidRef {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_relative_oid(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::OidIri {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_oid_iri(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::RelativeOidIri {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_relative_oid_iri(tag, self).map(drop)
+    }
+}
+
 impl Encode for types::UtcTime {
     fn encode_with_tag_and_constraints<E: Encoder>(
         &self,
@@ -581,6 +767,74 @@ impl Encode for types::GeneralizedTime {
     }
 }
 
+impl Encode for types::LosslessGeneralizedTime {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_lossless_generalized_time(tag, self)
+            .map(drop)
+    }
+}
+
+impl Encode for types::Date {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_date(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::TimeOfDay {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_time_of_day(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::DateTime {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_date_time(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::Duration {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_duration(tag, self).map(drop)
+    }
+}
+
+impl Encode for types::TimeValue {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_time(tag, self).map(drop)
+    }
+}
+
 impl Encode for types::Any {
     fn encode_with_tag_and_constraints<E: Encoder>(
         &self,
@@ -592,6 +846,17 @@ impl Encode for types::Any {
     }
 }
 
+impl Encode for types::AnyRef<'_> {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder.encode_any(tag, &self.to_any()).map(drop)
+    }
+}
+
 impl<E: Encode> Encode for alloc::boxed::Box<E> {
     fn encode<EN: Encoder>(&self, encoder: &mut EN) -> Result<(), EN::Error> {
         E::encode(self, encoder)
@@ -1,5 +1,5 @@
 use super::strings::PermittedAlphabetError;
-use alloc::{boxed::Box, string::ToString};
+use alloc::{boxed::Box, string::ToString, vec::Vec};
 
 use jzon::JsonValue;
 use snafu::Snafu;
@@ -21,6 +21,9 @@ pub enum CodecDecodeError {
     Uper(UperDecodeErrorKind),
     Aper(AperDecodeErrorKind),
     Jer(JerDecodeErrorKind),
+    Oer(OerDecodeErrorKind),
+    Coer(CoerDecodeErrorKind),
+    Xer(XerDecodeErrorKind),
 }
 
 macro_rules! impl_from {
@@ -40,6 +43,9 @@ impl_from!(Der, DerDecodeErrorKind);
 impl_from!(Uper, UperDecodeErrorKind);
 impl_from!(Aper, AperDecodeErrorKind);
 impl_from!(Jer, JerDecodeErrorKind);
+impl_from!(Oer, OerDecodeErrorKind);
+impl_from!(Coer, CoerDecodeErrorKind);
+impl_from!(Xer, XerDecodeErrorKind);
 
 impl From<CodecDecodeError> for DecodeError {
     fn from(error: CodecDecodeError) -> Self {
@@ -52,6 +58,9 @@ impl From<CodecDecodeError> for DecodeError {
 ///
 /// `kind` field is used to determine the kind of error that occurred.
 /// `codec` field is used to determine the codec that failed.
+/// `offset` field is used to determine where in the input decoding failed,
+/// for the codecs that track it.
+/// `path` field is used to determine which field of the decoded value failed.
 /// `backtrace` field is used to determine the backtrace of the error.
 ///
 /// There is `Kind::CodecSpecific` variant which wraps the codec-specific
@@ -132,6 +141,8 @@ impl From<CodecDecodeError> for DecodeError {
 pub struct DecodeError {
     pub kind: Box<DecodeErrorKind>,
     pub codec: Codec,
+    pub offset: Option<Offset>,
+    pub path: FieldPath,
     #[cfg(feature = "backtraces")]
     pub backtrace: Backtrace,
 }
@@ -139,6 +150,12 @@ impl core::fmt::Display for DecodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         writeln!(f, "Error Kind: {}", self.kind)?;
         writeln!(f, "Codec: {}", self.kind)?;
+        if let Some(offset) = self.offset {
+            writeln!(f, "Offset: {offset}")?;
+        }
+        if !self.path.is_empty() {
+            writeln!(f, "Path: {}", self.path)?;
+        }
         #[cfg(feature = "backtraces")]
         write!(f, "\nBacktrace:\n{}", self.backtrace)?;
         Ok(())
@@ -196,6 +213,38 @@ impl DecodeError {
         Self::from_kind(DecodeErrorKind::InvalidBitString { bits }, codec)
     }
     #[must_use]
+    pub fn invalid_real_encoding(reason: &'static str, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::InvalidRealEncoding { reason }, codec)
+    }
+    #[must_use]
+    pub fn invalid_time(reason: crate::types::InvalidTimeValue, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::InvalidTime { reason }, codec)
+    }
+    /// An error from reading the encoded value from a [`std::io::Read`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn io(error: std::io::Error, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::Io { error }, codec)
+    }
+    #[must_use]
+    pub fn invalid_oid_iri(iri: alloc::string::String, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::InvalidOidIri { iri }, codec)
+    }
+    #[must_use]
+    pub fn limit_exceeded(limit: crate::de::Limit, maximum: usize, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::LimitExceeded { limit, maximum }, codec)
+    }
+    /// Returns whether decoding failed because of one of the decoder's
+    /// [`Limits`][crate::de::Limits], including inside of a field.
+    #[must_use]
+    pub fn is_limit_exceeded(&self) -> bool {
+        match &*self.kind {
+            DecodeErrorKind::LimitExceeded { .. } => true,
+            DecodeErrorKind::FieldError { nested, .. } => nested.is_limit_exceeded(),
+            _ => false,
+        }
+    }
+    #[must_use]
     pub fn missing_tag_class_or_value_in_sequence_or_set(
         class: crate::types::Class,
         value: u32,
@@ -207,6 +256,11 @@ impl DecodeError {
         )
     }
 
+    #[must_use]
+    pub fn non_canonical_encoding(reason: &'static str, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::NonCanonicalEncoding { reason }, codec)
+    }
+
     #[must_use]
     pub fn type_not_extensible(codec: Codec) -> Self {
         Self::from_kind(DecodeErrorKind::TypeNotExtensible, codec)
@@ -260,6 +314,15 @@ impl DecodeError {
     pub fn unexpected_extra_data(length: usize, codec: Codec) -> Self {
         Self::from_kind(DecodeErrorKind::UnexpectedExtraData { length }, codec)
     }
+    #[must_use]
+    pub fn unknown_information_object(id: &dyn core::fmt::Debug, codec: Codec) -> Self {
+        Self::from_kind(
+            DecodeErrorKind::UnknownInformationObject {
+                id: alloc::format!("{id:?}"),
+            },
+            codec,
+        )
+    }
 
     pub fn assert_length(
         expected: usize,
@@ -286,11 +349,27 @@ impl DecodeError {
         };
         DecodeError::parser_fail(msg, codec)
     }
+    /// Sets where in the input decoding failed, unless it is already known
+    /// from further inside of the value.
+    #[must_use]
+    pub(crate) fn at_offset(mut self, offset: Offset) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+    /// Marks this error as having occurred in the `index`th component of a
+    /// `SEQUENCE OF` or `SET OF` value.
+    #[must_use]
+    pub(crate) fn at_index(mut self, index: usize) -> Self {
+        self.path.0.insert(0, PathSegment::Index(index));
+        self
+    }
     #[must_use]
     pub fn from_kind(kind: DecodeErrorKind, codec: Codec) -> Self {
         Self {
             kind: Box::new(kind),
             codec,
+            offset: None,
+            path: FieldPath::default(),
             #[cfg(feature = "backtraces")]
             backtrace: Backtrace::generate(),
         }
@@ -304,16 +383,88 @@ impl DecodeError {
             CodecDecodeError::Uper(_) => crate::Codec::Uper,
             CodecDecodeError::Aper(_) => crate::Codec::Aper,
             CodecDecodeError::Jer(_) => crate::Codec::Jer,
+            CodecDecodeError::Oer(_) => crate::Codec::Oer,
+            CodecDecodeError::Coer(_) => crate::Codec::Coer,
+            CodecDecodeError::Xer(_) => crate::Codec::Xer,
         };
         Self {
             kind: Box::new(DecodeErrorKind::CodecSpecific { inner }),
             codec,
+            offset: None,
+            path: FieldPath::default(),
             #[cfg(feature = "backtraces")]
             backtrace: Backtrace::generate(),
         }
     }
 }
 
+/// A position in the input of a decoder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Offset {
+    /// The number of octets from the start of the input.
+    Byte(usize),
+    /// The number of bits from the start of the input, used by PER.
+    Bit(usize),
+}
+
+impl core::fmt::Display for Offset {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Byte(offset) => write!(f, "byte {offset}"),
+            Self::Bit(offset) => write!(f, "bit {offset}"),
+        }
+    }
+}
+
+/// The fields and components leading from the outermost decoded value to the
+/// value that failed to decode, displayed as e.g. `extensions[3].extn_value`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldPath(Vec<PathSegment>);
+
+impl FieldPath {
+    /// Returns the segments of the path, outermost first.
+    #[must_use]
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    /// Returns whether the error occurred in the outermost value.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn push(&mut self, segment: PathSegment) {
+        self.0.push(segment);
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.0.pop();
+    }
+}
+
+impl core::fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A single step in a [`FieldPath`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A field of a `SEQUENCE` or `SET`.
+    Field(&'static str),
+    /// A component of a `SEQUENCE OF` or `SET OF`.
+    Index(usize),
+}
+
 /// `DecodeError` kinds which are common for all codecs.
 #[derive(Snafu)]
 #[snafu(visibility(pub))]
@@ -408,6 +559,34 @@ pub enum DecodeErrorKind {
         value
     ))]
     InvalidBool { value: u8 },
+    #[snafu(display("Invalid REAL encoding: {}", reason))]
+    InvalidRealEncoding {
+        /// Why the contents could not be decoded as a REAL value.
+        reason: &'static str,
+    },
+    #[snafu(display("Invalid time value: {}", reason))]
+    InvalidTime {
+        /// Which time type the value isn't valid for.
+        reason: crate::types::InvalidTimeValue,
+    },
+    /// Reading the encoded value failed.
+    #[cfg(feature = "std")]
+    #[snafu(display("I/O error: {}", error))]
+    Io { error: std::io::Error },
+    /// The value isn't a valid `OID-IRI` or `RELATIVE-OID-IRI`.
+    #[snafu(display("Invalid OID-IRI: {}", iri))]
+    InvalidOidIri {
+        /// The invalid IRI.
+        iri: alloc::string::String,
+    },
+    /// One of the decoder's [`Limits`][crate::de::Limits] was exceeded.
+    #[snafu(display("Exceeded the maximum {} of {}", limit, maximum))]
+    LimitExceeded {
+        /// Which limit was exceeded.
+        limit: crate::de::Limit,
+        /// The configured maximum.
+        maximum: usize,
+    },
     /// The length does not match what was expected.
     #[snafu(display("Expected {:?} bytes, actual length: {:?}", expected, actual))]
     MismatchedLength {
@@ -428,6 +607,11 @@ pub enum DecodeErrorKind {
         class: crate::types::Class,
         value: u32,
     },
+    #[snafu(display("Encoding is not in its canonical form: {}", reason))]
+    NonCanonicalEncoding {
+        /// Which canonical encoding requirement was violated.
+        reason: &'static str,
+    },
 
     #[snafu(display("integer range larger than possible to address on this platform. needed: {needed} present: {present}"))]
     RangeExceedsPlatformWidth {
@@ -486,6 +670,12 @@ pub enum DecodeErrorKind {
     },
     #[snafu(display("Unknown field with index {} and tag {}", index, tag))]
     UnknownField { index: usize, tag: Tag },
+    /// An open type's identifier doesn't match any object in its set.
+    #[snafu(display("No information object in the set is identified by {}", id))]
+    UnknownInformationObject {
+        /// The identifier, as formatted by `Debug`.
+        id: alloc::string::String,
+    },
 }
 
 /// `DecodeError` kinds of `Kind::CodecSpecific` which are specific for BER.
@@ -498,6 +688,10 @@ pub enum BerDecodeErrorKind {
         /// The found value of the discriminant
         discriminant: isize,
     },
+    /// A value stored with the constructed encoding was decoded into a type
+    /// that borrows from the input.
+    #[snafu(display("Constructed encoding cannot be borrowed from the input."))]
+    ConstructedEncodingNotBorrowable,
     #[snafu(display("Indefinite length encountered but not allowed."))]
     IndefiniteLengthNotAllowed,
     #[snafu(display("Invalid constructed identifier for ASN.1 value: not primitive."))]
@@ -580,6 +774,38 @@ impl JerDecodeErrorKind {
     }
 }
 
+/// An error that occurred when decoding XER.
+#[derive(Snafu, Debug)]
+#[snafu(visibility(pub), module)]
+#[non_exhaustive]
+pub enum XerDecodeErrorKind {
+    #[snafu(display("Unexpected end of input while decoding XER XML."))]
+    EndOfInput {},
+    #[snafu(display(
+        "Found mismatching XML value. Expected type {}. Found value {}.",
+        needed,
+        found
+    ))]
+    TypeMismatch {
+        needed: &'static str,
+        found: alloc::string::String,
+    },
+    #[snafu(display("Found invalid character {invalid} in bit string."))]
+    InvalidBitString { invalid: char },
+    #[snafu(display("Found invalid character in octet string."))]
+    InvalidOctetString {},
+    #[snafu(display("Failed to construct OID from value {value}",))]
+    InvalidObjectIdentifier { value: alloc::string::String },
+    #[snafu(display("Found invalid enumerated identifier {identifier}",))]
+    InvalidEnumIdentifier { identifier: alloc::string::String },
+}
+
+impl XerDecodeErrorKind {
+    pub fn eoi() -> CodecDecodeError {
+        CodecDecodeError::Xer(XerDecodeErrorKind::EndOfInput {})
+    }
+}
+
 // TODO check if there codec-specific errors here
 /// `DecodeError` kinds of `Kind::CodecSpecific` which are specific for UPER.
 #[derive(Snafu, Debug)]
@@ -594,6 +820,46 @@ pub enum UperDecodeErrorKind {}
 #[non_exhaustive]
 pub enum AperDecodeErrorKind {}
 
+/// `DecodeError` kinds of `Kind::CodecSpecific` which are specific for OER.
+#[derive(Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[non_exhaustive]
+pub enum OerDecodeErrorKind {
+    #[snafu(display("Invalid length determinant with initial octet {:#04x}", initial))]
+    InvalidLengthDeterminant {
+        /// The initial octet of the length determinant.
+        initial: u8,
+    },
+    #[snafu(display("No CHOICE alternative with tag {}", tag))]
+    UnknownChoiceTag {
+        /// The tag found in the encoding.
+        tag: Tag,
+    },
+}
+
+/// `DecodeError` kinds of `Kind::CodecSpecific` which are specific for C-OER.
+#[derive(Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[non_exhaustive]
+pub enum CoerDecodeErrorKind {
+    #[snafu(display("Length determinant for {} is not in its shortest form", length))]
+    NonCanonicalLengthDeterminant {
+        /// The decoded length.
+        length: usize,
+    },
+    #[snafu(display("Integer is not encoded in the fewest octets possible"))]
+    NonMinimalInteger,
+    #[snafu(display("Enumerated value {} must use the short form", discriminant))]
+    NonCanonicalEnumerated {
+        /// The decoded discriminant.
+        discriminant: isize,
+    },
+    #[snafu(display("Unused trailing bits are not zero"))]
+    NonZeroPadding,
+    #[snafu(display("Extension bit is set, but no extension additions are present"))]
+    EmptyExtensionBitmap,
+}
+
 impl crate::de::Error for DecodeError {
     fn custom<D: core::fmt::Display>(msg: D, codec: Codec) -> Self {
         Self::from_kind(
@@ -620,13 +886,21 @@ impl crate::de::Error for DecodeError {
     }
 
     fn field_error(name: &'static str, nested: DecodeError, codec: Codec) -> Self {
-        Self::from_kind(
+        // Derived implementations name fields as `Type.field`.
+        let field = name.rsplit('.').next().unwrap_or(name);
+        let mut path = nested.path.clone();
+        path.0.insert(0, PathSegment::Field(field));
+        let offset = nested.offset;
+        let mut error = Self::from_kind(
             DecodeErrorKind::FieldError {
                 name,
                 nested: Box::new(nested),
             },
             codec,
-        )
+        );
+        error.offset = offset;
+        error.path = path;
+        error
     }
 
     fn duplicate_field(name: &'static str, codec: Codec) -> Self {
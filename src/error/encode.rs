@@ -15,6 +15,9 @@ pub enum CodecEncodeError {
     Uper(UperEncodeErrorKind),
     Aper(AperEncodeErrorKind),
     Jer(JerEncodeErrorKind),
+    Oer(OerEncodeErrorKind),
+    Coer(CoerEncodeErrorKind),
+    Xer(XerEncodeErrorKind),
 }
 macro_rules! impl_from {
     ($variant:ident, $error_kind:ty) => {
@@ -33,6 +36,9 @@ impl_from!(Der, DerEncodeErrorKind);
 impl_from!(Uper, UperEncodeErrorKind);
 impl_from!(Aper, AperEncodeErrorKind);
 impl_from!(Jer, JerEncodeErrorKind);
+impl_from!(Oer, OerEncodeErrorKind);
+impl_from!(Coer, CoerEncodeErrorKind);
+impl_from!(Xer, XerEncodeErrorKind);
 
 impl From<CodecEncodeError> for EncodeError {
     fn from(error: CodecEncodeError) -> Self {
@@ -148,10 +154,58 @@ impl EncodeError {
         Self::from_kind(EncodeErrorKind::IntegerTypeConversionFailed { msg }, codec)
     }
     #[must_use]
+    pub fn invalid_time(reason: crate::types::InvalidTimeValue, codec: crate::Codec) -> Self {
+        Self::from_kind(EncodeErrorKind::InvalidTime { reason }, codec)
+    }
+    /// An error from writing the encoded value to a [`std::io::Write`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn io(error: std::io::Error, codec: crate::Codec) -> Self {
+        Self::from_kind(EncodeErrorKind::Io { error }, codec)
+    }
+    #[must_use]
     pub fn opaque_conversion_failed(msg: alloc::string::String, codec: crate::Codec) -> Self {
         Self::from_kind(EncodeErrorKind::OpaqueConversionFailed { msg }, codec)
     }
     #[must_use]
+    pub fn teletex_conversion_failed(
+        reason: super::strings::InvalidTeletexString,
+        codec: crate::Codec,
+    ) -> Self {
+        Self::from_kind(EncodeErrorKind::TeletexConversionFailed { reason }, codec)
+    }
+    #[must_use]
+    pub fn value_constraint_not_satisfied(
+        value: num_bigint::BigInt,
+        expected: Bounded<i128>,
+        codec: crate::Codec,
+    ) -> Self {
+        Self::from_kind(
+            EncodeErrorKind::ValueConstraintNotSatisfied { value, expected },
+            codec,
+        )
+    }
+    /// An error for a constraint that was found not to be satisfied while
+    /// validating constraints during encoding.
+    #[must_use]
+    pub(crate) fn constraint_violation(kind: super::ViolationKind, codec: crate::Codec) -> Self {
+        let kind = match kind {
+            super::ViolationKind::Size { length, expected } => {
+                EncodeErrorKind::InvalidLength { length, expected }
+            }
+            super::ViolationKind::Value { value, expected } => {
+                EncodeErrorKind::ValueConstraintNotSatisfied { value, expected }
+            }
+            super::ViolationKind::PermittedAlphabet { character } => {
+                EncodeErrorKind::AlphabetConstraintNotSatisfied {
+                    reason: super::strings::PermittedAlphabetError::CharacterNotFound { character },
+                }
+            }
+        };
+
+        Self::from_kind(kind, codec)
+    }
+    #[must_use]
     pub fn variant_not_in_choice(codec: crate::Codec) -> Self {
         Self::from_kind(EncodeErrorKind::VariantNotInChoice, codec)
     }
@@ -173,6 +227,9 @@ impl EncodeError {
             CodecEncodeError::Uper(_) => crate::Codec::Uper,
             CodecEncodeError::Aper(_) => crate::Codec::Aper,
             CodecEncodeError::Jer(_) => crate::Codec::Jer,
+            CodecEncodeError::Oer(_) => crate::Codec::Oer,
+            CodecEncodeError::Coer(_) => crate::Codec::Coer,
+            CodecEncodeError::Xer(_) => crate::Codec::Xer,
         };
         Self {
             kind: Box::new(EncodeErrorKind::CodecSpecific { inner }),
@@ -208,10 +265,31 @@ pub enum EncodeErrorKind {
     },
     #[snafu(display("Failed to cast integer to another integer type: {msg} "))]
     IntegerTypeConversionFailed { msg: alloc::string::String },
+    #[snafu(display("Invalid time value: {reason}"))]
+    InvalidTime {
+        /// Which time type the value isn't valid for.
+        reason: crate::types::InvalidTimeValue,
+    },
     #[snafu(display("Conversion to Opaque type failed: {msg}"))]
     OpaqueConversionFailed { msg: alloc::string::String },
+    #[snafu(display("Failed to convert TeletexString to Unicode: {reason}"))]
+    TeletexConversionFailed {
+        /// Inner error from mapping T.61 characters to Unicode
+        reason: super::strings::InvalidTeletexString,
+    },
+    /// Writing the encoded value failed.
+    #[cfg(feature = "std")]
+    #[snafu(display("I/O error: {error}"))]
+    Io { error: std::io::Error },
     #[snafu(display("Selected Variant not found from Choice"))]
     VariantNotInChoice,
+    #[snafu(display("value constraint not satisfied, expected: {expected}; actual: {value}"))]
+    ValueConstraintNotSatisfied {
+        /// Actual value of the data
+        value: num_bigint::BigInt,
+        /// Expected value range of the data
+        expected: Bounded<i128>,
+    },
 }
 /// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for BER.
 #[derive(Snafu, Debug)]
@@ -267,6 +345,14 @@ pub enum JerEncodeErrorKind {
         /// value failed to encode
         value: num_bigint::BigInt,
     },
+    #[snafu(display(
+        "Exceeds supported real range of 19 significant digits and exponents -2^15..2^15 ({}).",
+        value
+    ))]
+    ExceedsSupportedRealRange {
+        /// value failed to encode
+        value: crate::types::Real,
+    },
     #[snafu(display("Invalid character: {:?}", error))]
     InvalidCharacter {
         /// value failed to encode
@@ -274,12 +360,49 @@ pub enum JerEncodeErrorKind {
     },
 }
 
+/// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for XER.
+#[derive(Snafu, Debug)]
+#[snafu(visibility(pub), module)]
+#[non_exhaustive]
+pub enum XerEncodeErrorKind {
+    /// Error to be thrown when the XER encoder contains no encoded root value
+    #[snafu(display("No encoded XML root value found!"))]
+    NoRootValueFound,
+    /// Internal XML encoder error
+    #[snafu(display("Error in XML encoder: {}", msg))]
+    XmlEncoder {
+        /// The error's message.
+        msg: alloc::string::String,
+    },
+    /// Error to be thrown when an `ENUMERATED` value has no identifier
+    #[snafu(display("Enumerated value has no identifier for text-based encoding rules"))]
+    MissingIdentifier,
+    /// Error to be thrown when a `REAL` value is too large to write in decimal
+    #[snafu(display("REAL value {} has no supported decimal representation", value))]
+    UnrepresentableReal {
+        /// value failed to encode
+        value: crate::types::Real,
+    },
+}
+
 /// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for UPER.
 #[derive(Snafu, Debug)]
 #[snafu(visibility(pub))]
 #[non_exhaustive]
 pub enum UperEncodeErrorKind {}
 
+/// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for OER.
+#[derive(Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[non_exhaustive]
+pub enum OerEncodeErrorKind {}
+
+/// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for C-OER.
+#[derive(Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[non_exhaustive]
+pub enum CoerEncodeErrorKind {}
+
 /// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for APER.
 #[derive(Snafu, Debug)]
 #[snafu(visibility(pub))]
@@ -6,18 +6,25 @@
 mod decode;
 mod encode;
 mod string;
+mod validate;
 
 pub mod strings {
     //! Errors specific to string conversions, permitted alphabets, and other type problems.
     pub use super::string::{
-        InvalidBmpString, InvalidGeneralString, InvalidIso646Character, InvalidNumericString,
-        InvalidPrintableString, PermittedAlphabetError,
+        InvalidBmpString, InvalidGeneralString, InvalidGraphicString, InvalidIso646Character,
+        InvalidNumericString, InvalidPrintableString, InvalidTeletexString, InvalidUniversalString,
+        PermittedAlphabetError,
     };
 }
 
 pub use decode::DecodeErrorKind;
 pub use decode::{
-    BerDecodeErrorKind, CodecDecodeError, DecodeError, DerDecodeErrorKind, JerDecodeErrorKind,
+    BerDecodeErrorKind, CodecDecodeError, CoerDecodeErrorKind, DecodeError, DerDecodeErrorKind,
+    FieldPath, JerDecodeErrorKind, OerDecodeErrorKind, Offset, PathSegment, XerDecodeErrorKind,
 };
 pub use encode::EncodeErrorKind;
-pub use encode::{BerEncodeErrorKind, CodecEncodeError, EncodeError, JerEncodeErrorKind};
+pub use encode::{
+    BerEncodeErrorKind, CodecEncodeError, CoerEncodeErrorKind, EncodeError, JerEncodeErrorKind,
+    OerEncodeErrorKind, XerEncodeErrorKind,
+};
+pub use validate::{ConstraintViolation, ValidationError, ViolationKind};
@@ -4,7 +4,7 @@ use alloc::string::String;
 #[snafu(visibility(pub))]
 #[snafu(display("Invalid BMP string, character decimal value: {}", character))]
 pub struct InvalidBmpString {
-    pub character: u16,
+    pub character: u32,
 }
 
 #[derive(snafu::Snafu, Debug)]
@@ -14,6 +14,13 @@ pub struct InvalidGeneralString {
     pub character: u8,
 }
 
+#[derive(snafu::Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[snafu(display("Invalid graphic string, character decimal value: {}", character))]
+pub struct InvalidGraphicString {
+    pub character: u8,
+}
+
 #[derive(snafu::Snafu, Debug)]
 #[snafu(visibility(pub))]
 #[snafu(display("Invalid ISO 646/ASCII, character decimal value: {}", character))]
@@ -35,6 +42,20 @@ pub struct InvalidPrintableString {
     pub character: u32,
 }
 
+#[derive(snafu::Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[snafu(display("Invalid teletex string, character decimal value: {}", character))]
+pub struct InvalidTeletexString {
+    pub character: u32,
+}
+
+#[derive(snafu::Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[snafu(display("Invalid universal string, character decimal value: {}", character))]
+pub struct InvalidUniversalString {
+    pub character: u32,
+}
+
 #[derive(Debug, snafu::Snafu)]
 #[snafu(visibility(pub))]
 pub enum PermittedAlphabetError {
@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+
+use snafu::Snafu;
+
+use super::FieldPath;
+use crate::types::constraints::Bounded;
+
+/// The constraints that a value didn't satisfy, returned by
+/// [`Validate::validate`][crate::Validate::validate].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// Every violation found in the value, in the order its fields were
+    /// checked.
+    pub violations: Vec<ConstraintViolation>,
+}
+
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} constraint violation(s)", self.violations.len())?;
+        for violation in &self.violations {
+            write!(f, "\n  {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// A single constraint that a value didn't satisfy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstraintViolation {
+    /// The fields and components leading to the value, which is empty when
+    /// it's the outermost value.
+    pub path: FieldPath,
+    /// The constraint that wasn't satisfied.
+    pub kind: ViolationKind,
+}
+
+impl core::fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+/// The kinds of constraints that can be checked independently of a codec.
+#[derive(Snafu, Clone, Debug, PartialEq)]
+#[snafu(visibility(pub))]
+#[non_exhaustive]
+pub enum ViolationKind {
+    /// The length of a string, or the number of components of a
+    /// `SEQUENCE OF` or `SET OF`, is outside of its size constraint.
+    #[snafu(display("size constraint not satisfied, expected: {expected}; actual: {length}"))]
+    Size {
+        length: usize,
+        expected: Bounded<usize>,
+    },
+    /// An integer is outside of its value constraint.
+    #[snafu(display("value constraint not satisfied, expected: {expected}; actual: {value}"))]
+    Value {
+        value: num_bigint::BigInt,
+        expected: Bounded<i128>,
+    },
+    /// A string contains a character outside of its permitted alphabet.
+    #[snafu(display(
+        "permitted alphabet constraint not satisfied, character with decimal value {character} is not permitted"
+    ))]
+    PermittedAlphabet { character: u32 },
+}
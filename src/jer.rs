@@ -19,6 +19,36 @@ pub fn encode<T: crate::Encode>(
     Ok(encoder.to_json())
 }
 
+/// Attempts to encode `value` to JER, writing the encoding to `writer`.
+/// # Errors
+/// Returns error specific to JER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    mut writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    writer
+        .write_all(encode(value)?.as_bytes())
+        .map_err(|error| crate::error::EncodeError::io(error, crate::Codec::Jer))
+}
+
+/// Attempts to decode `T` from the rest of `reader` using JER, which must
+/// contain UTF-8.
+/// # Errors
+/// Returns error specific to JER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    mut reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    let mut input = alloc::string::String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|error| crate::error::DecodeError::io(error, crate::Codec::Jer))?;
+    decode(&input)
+}
+
 #[cfg(test)]
 mod tests {
     macro_rules! round_trip_jer {
@@ -161,11 +191,47 @@ mod tests {
         round_trip_jer!(u16, 0, "0");
         round_trip_jer!(i16, -14321, "-14321");
         round_trip_jer!(i64, -1213428598524996264, "-1213428598524996264");
+        round_trip_jer!(i64, i64::MIN + 1, "-9223372036854775807");
+        assert!(crate::jer::encode(&u64::MAX).is_err());
+        assert!(crate::jer::decode::<u8>("256").is_err());
         round_trip_jer!(Integer, 1.into(), "1");
         round_trip_jer!(Integer, (-1235352).into(), "-1235352");
         round_trip_jer!(ConstrainedInt, ConstrainedInt(1.into()), "1");
     }
 
+    #[test]
+    fn time_types() {
+        let date = Date::from_ymd_opt(2024, 2, 29).unwrap();
+
+        round_trip_jer!(Date, date, "\"2024-02-29\"");
+        round_trip_jer!(
+            DateTime,
+            date.and_hms_opt(13, 30, 5).unwrap(),
+            "\"2024-02-29T13:30:05\""
+        );
+        round_trip_jer!(Duration, "PT1.5S".parse().unwrap(), "\"PT1.5S\"");
+        round_trip_jer!(TimeValue, "2024-W09".parse().unwrap(), "\"2024-W09\"");
+        assert!(crate::jer::decode::<TimeOfDay>("\"1:30:05\"").is_err());
+        round_trip_jer!(
+            LosslessGeneralizedTime,
+            "2024010203,5+01".parse().unwrap(),
+            "\"2024010203,5+01\""
+        );
+    }
+
+    #[test]
+    fn real() {
+        round_trip_jer!(f64, 1.5, "1.5");
+        round_trip_jer!(f64, -0.1, "-0.1");
+        round_trip_jer!(f32, 0.0, "0");
+        round_trip_jer!(Real, Real::decimal(25, 10), "250000000000");
+        round_trip_jer!(Real, Real::PlusInfinity, "\"INF\"");
+        round_trip_jer!(Real, Real::MinusInfinity, "\"-INF\"");
+        round_trip_jer!(Real, Real::NotANumber, "\"NaN\"");
+        round_trip_jer!(Real, Real::MinusZero, "\"-0\"");
+        assert!(crate::jer::encode(&Real::decimal(u128::MAX, 0)).is_err());
+    }
+
     #[test]
     fn bit_string() {
         round_trip_jer!(
@@ -197,17 +263,55 @@ mod tests {
             ObjectIdentifier::from(Oid::JOINT_ISO_ITU_T_DS_NAME_FORM),
             "\"2.5.15\""
         );
+        round_trip_jer!(
+            RelativeOid,
+            RelativeOid::new(vec![8571, 3, 2]).unwrap(),
+            "\"8571.3.2\""
+        );
+        round_trip_jer!(
+            OidIri,
+            OidIri::new("/ISO/Registration_Authority").unwrap(),
+            "\"/ISO/Registration_Authority\""
+        );
+        round_trip_jer!(
+            RelativeOidIri,
+            RelativeOidIri::new("19785.CBEFF/4").unwrap(),
+            "\"19785.CBEFF/4\""
+        );
+        assert!(crate::jer::decode::<OidIri>("\"ISO\"").is_err());
     }
 
     #[test]
     fn string_types() {
         round_trip_string_type!(NumericString);
         round_trip_string_type!(GeneralString);
+        round_trip_string_type!(GraphicString);
+        round_trip_string_type!(VideotexString);
         round_trip_string_type!(VisibleString);
         round_trip_string_type!(UniversalString);
         round_trip_string_type!(PrintableString);
         round_trip_string_type!(Ia5String);
         round_trip_string_type!(Utf8String);
+        round_trip_string_type!(BmpString);
+        round_trip_string_type!(TeletexString);
+
+        round_trip_jer!(BmpString, "Grüße".try_into().unwrap(), "\"Grüße\"");
+        round_trip_jer!(
+            TeletexString,
+            TeletexString::from(alloc::vec![0xE8, 0xC2, b'o', b'd', 0xC2, b'z']),
+            "\"Łódź\""
+        );
+
+        // A lone surrogate can't be written to JSON.
+        let surrogate = BmpString::try_from(&[0xD8, 0x00][..]).unwrap();
+        assert!(matches!(
+            *crate::jer::encode(&surrogate).unwrap_err().kind,
+            crate::error::EncodeErrorKind::AlphabetConstraintNotSatisfied {
+                reason: crate::error::strings::PermittedAlphabetError::CharacterNotFound {
+                    character: 0xD800
+                }
+            }
+        ));
     }
 
     #[test]
@@ -288,4 +392,60 @@ mod tests {
             "{\"a\":{\"very\":{},\"nested\":false}}"
         );
     }
+
+    #[test]
+    fn limits() {
+        use crate::de::{Limit, Limits};
+
+        fn decode<T: crate::Decode>(input: &str, limits: Limits) -> Result<T, Limit> {
+            let mut decoder = crate::jer::de::Decoder::with_limits(input, limits).unwrap();
+            T::decode(&mut decoder).map_err(|error| match *error.kind {
+                crate::error::DecodeErrorKind::LimitExceeded { limit, .. } => limit,
+                kind => panic!("unexpected error: {kind}"),
+            })
+        }
+
+        let nested = "[[[null]]]";
+        assert!(decode::<Vec<Vec<Vec<()>>>>(nested, Limits::default()).is_ok());
+        assert_eq!(
+            Err(Limit::Depth),
+            decode::<Vec<Vec<Vec<()>>>>(
+                nested,
+                Limits {
+                    max_depth: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        assert_eq!(
+            Err(Limit::Elements),
+            decode::<Vec<()>>(
+                "[null,null,null]",
+                Limits {
+                    max_elements: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        assert_eq!(
+            Err(Limit::Length),
+            decode::<Utf8String>(
+                "\"abc\"",
+                Limits {
+                    max_length: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        let strings = "[\"0102\",\"0304\"]";
+        let total_length = |max_total_length| Limits {
+            max_total_length,
+            ..Limits::default()
+        };
+        assert!(decode::<Vec<OctetString>>(strings, total_length(4)).is_ok());
+        assert_eq!(
+            Err(Limit::TotalLength),
+            decode::<Vec<OctetString>>(strings, total_length(3))
+        );
+    }
 }
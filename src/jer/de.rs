@@ -3,6 +3,7 @@
 use jzon::JsonValue;
 
 use crate::{
+    de::Limits,
     error::*,
     types::{fields::Fields, *},
     Decode,
@@ -19,19 +20,60 @@ macro_rules! decode_jer_value {
 
 pub struct Decoder {
     stack: alloc::vec::Vec<JsonValue>,
+    limits: Limits,
+    /// How many constructed values the current value is nested in.
+    depth: usize,
+    /// The sum of the lengths decoded so far, checked against the maximum
+    /// total length.
+    total_length: usize,
 }
 
 impl Decoder {
     pub fn new(input: &str) -> Result<Self, <Decoder as crate::de::Decoder>::Error> {
+        Self::with_limits(input, Limits::DEFAULT)
+    }
+
+    /// Creates a decoder for `input` that fails if the decoded value exceeds
+    /// `limits`. Lengths are measured in characters for strings, and in
+    /// octets or bits for `OCTET STRING` and `BIT STRING` values.
+    pub fn with_limits(
+        input: &str,
+        limits: Limits,
+    ) -> Result<Self, <Decoder as crate::de::Decoder>::Error> {
         let root = jzon::parse(input).map_err(|e| {
             DecodeError::parser_fail(
                 alloc::format!("Error parsing JER JSON {e:?}"),
                 crate::Codec::Jer,
             )
         })?;
-        Ok(Self {
-            stack: alloc::vec![root],
-        })
+        let mut decoder = Self::from(root);
+        decoder.limits = limits;
+        Ok(decoder)
+    }
+
+    /// Runs `f` on this decoder one level deeper than the current value.
+    fn nest<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, DecodeError>,
+    ) -> Result<T, DecodeError> {
+        let depth = self.depth;
+        self.depth = self.limits.check_depth(depth + 1, crate::Codec::Jer)?;
+        let result = (f)(self);
+        self.depth = depth;
+        result
+    }
+
+    /// Returns `length` if neither it, nor the total length of the values
+    /// decoded so far, exceed the decoder's limits.
+    fn check_length(&mut self, length: usize) -> Result<usize, DecodeError> {
+        self.limits
+            .check_length_within_total(length, &mut self.total_length, crate::Codec::Jer)
+    }
+
+    fn decode_string(&mut self) -> Result<alloc::string::String, DecodeError> {
+        let string = decode_jer_value!(Self::string_from_value, self.stack)?;
+        self.check_length(string.chars().count())?;
+        Ok(string)
     }
 }
 
@@ -39,6 +81,9 @@ impl From<JsonValue> for Decoder {
     fn from(value: JsonValue) -> Self {
         Self {
             stack: alloc::vec![value],
+            limits: Limits::DEFAULT,
+            depth: 0,
+            total_length: 0,
         }
     }
 }
@@ -47,7 +92,9 @@ impl crate::Decoder for Decoder {
     type Error = DecodeError;
 
     fn decode_any(&mut self) -> Result<Any, Self::Error> {
-        decode_jer_value!(Self::any_from_value, self.stack)
+        let any = decode_jer_value!(Self::any_from_value, self.stack)?;
+        self.check_length(any.contents.len())?;
+        Ok(any)
     }
 
     fn decode_bit_string(
@@ -55,7 +102,9 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<BitString, Self::Error> {
-        decode_jer_value!(Self::bit_string_from_value, self.stack)
+        let bit_string = decode_jer_value!(Self::bit_string_from_value, self.stack)?;
+        self.check_length(bit_string.len())?;
+        Ok(bit_string)
     }
 
     fn decode_bool(&mut self, _t: crate::Tag) -> Result<bool, Self::Error> {
@@ -70,6 +119,18 @@ impl crate::Decoder for Decoder {
         decode_jer_value!(Self::integer_from_value, self.stack)
     }
 
+    fn decode_primitive_integer(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<i128, Self::Error> {
+        decode_jer_value!(Self::primitive_integer_from_value, self.stack)
+    }
+
+    fn decode_real(&mut self, _t: crate::Tag, _c: Constraints) -> Result<Real, Self::Error> {
+        decode_jer_value!(Self::real_from_value, self.stack)
+    }
+
     fn decode_null(&mut self, _t: crate::Tag) -> Result<(), Self::Error> {
         decode_jer_value!(Self::null_from_value, self.stack)
     }
@@ -81,6 +142,18 @@ impl crate::Decoder for Decoder {
         decode_jer_value!(Self::object_identifier_from_value, self.stack)
     }
 
+    fn decode_relative_oid(&mut self, _t: crate::Tag) -> Result<RelativeOid, Self::Error> {
+        decode_jer_value!(Self::relative_oid_from_value, self.stack)
+    }
+
+    fn decode_oid_iri(&mut self, _t: crate::Tag) -> Result<OidIri, Self::Error> {
+        decode_jer_value!(Self::oid_iri_from_value, self.stack)
+    }
+
+    fn decode_relative_oid_iri(&mut self, _t: crate::Tag) -> Result<RelativeOidIri, Self::Error> {
+        decode_jer_value!(Self::relative_oid_iri_from_value, self.stack)
+    }
+
     fn decode_sequence<D, DF, F>(
         &mut self,
         _: crate::Tag,
@@ -109,7 +182,7 @@ impl crate::Decoder for Decoder {
                 .push(value_map.remove(name).unwrap_or(JsonValue::Null));
         }
 
-        (decode_fn)(self)
+        self.nest(decode_fn)
     }
 
     fn decode_sequence_of<D: crate::Decode>(
@@ -117,7 +190,8 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<SequenceOf<D>, Self::Error> {
-        decode_jer_value!(|v| self.sequence_of_from_value(v), self.stack)
+        let value = self.stack.pop().ok_or_else(JerDecodeErrorKind::eoi)?;
+        self.nest(|decoder| decoder.sequence_of_from_value(value))
     }
 
     fn decode_set_of<D: crate::Decode + Ord>(
@@ -125,7 +199,8 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<SetOf<D>, Self::Error> {
-        decode_jer_value!(|v| self.set_of_from_value(v), self.stack)
+        let value = self.stack.pop().ok_or_else(JerDecodeErrorKind::eoi)?;
+        self.nest(|decoder| decoder.set_of_from_value(value))
     }
 
     fn decode_octet_string(
@@ -133,7 +208,9 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<alloc::vec::Vec<u8>, Self::Error> {
-        decode_jer_value!(Self::octet_string_from_value, self.stack)
+        let octet_string = decode_jer_value!(Self::octet_string_from_value, self.stack)?;
+        self.check_length(octet_string.len())?;
+        Ok(octet_string)
     }
 
     fn decode_utf8_string(
@@ -141,7 +218,7 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<Utf8String, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)
+        self.decode_string()
     }
 
     fn decode_visible_string(
@@ -149,15 +226,13 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<VisibleString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
-            .try_into()
-            .map_err(|e| {
-                DecodeError::string_conversion_failed(
-                    Tag::VISIBLE_STRING,
-                    alloc::format!("Error transforming VisibleString: {e:?}"),
-                    crate::Codec::Jer,
-                )
-            })
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::VISIBLE_STRING,
+                alloc::format!("Error transforming VisibleString: {e:?}"),
+                crate::Codec::Jer,
+            )
+        })
     }
 
     fn decode_general_string(
@@ -165,15 +240,35 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<GeneralString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
-            .try_into()
-            .map_err(|e| {
-                DecodeError::string_conversion_failed(
-                    Tag::GENERAL_STRING,
-                    alloc::format!("Error transforming GeneralString: {e:?}"),
-                    crate::Codec::Jer,
-                )
-            })
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::GENERAL_STRING,
+                alloc::format!("Error transforming GeneralString: {e:?}"),
+                crate::Codec::Jer,
+            )
+        })
+    }
+
+    fn decode_graphic_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<GraphicString, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::GRAPHIC_STRING,
+                alloc::format!("Error transforming GraphicString: {e:?}"),
+                crate::Codec::Jer,
+            )
+        })
+    }
+
+    fn decode_videotex_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<VideotexString, Self::Error> {
+        self.decode_string().map(VideotexString::from)
     }
 
     fn decode_ia5_string(
@@ -181,15 +276,13 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<Ia5String, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
-            .try_into()
-            .map_err(|e| {
-                DecodeError::string_conversion_failed(
-                    Tag::IA5_STRING,
-                    alloc::format!("Error transforming IA5String: {e:?}"),
-                    crate::Codec::Jer,
-                )
-            })
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::IA5_STRING,
+                alloc::format!("Error transforming IA5String: {e:?}"),
+                crate::Codec::Jer,
+            )
+        })
     }
 
     fn decode_printable_string(
@@ -197,15 +290,13 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<PrintableString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
-            .try_into()
-            .map_err(|e| {
-                DecodeError::string_conversion_failed(
-                    Tag::PRINTABLE_STRING,
-                    alloc::format!("Error transforming PrintableString: {e:?}"),
-                    crate::Codec::Jer,
-                )
-            })
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::PRINTABLE_STRING,
+                alloc::format!("Error transforming PrintableString: {e:?}"),
+                crate::Codec::Jer,
+            )
+        })
     }
 
     fn decode_numeric_string(
@@ -213,15 +304,13 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<NumericString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
-            .try_into()
-            .map_err(|e| {
-                DecodeError::string_conversion_failed(
-                    Tag::NUMERIC_STRING,
-                    alloc::format!("Error transforming NumericString: {e:?}"),
-                    crate::Codec::Jer,
-                )
-            })
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::NUMERIC_STRING,
+                alloc::format!("Error transforming NumericString: {e:?}"),
+                crate::Codec::Jer,
+            )
+        })
     }
 
     fn decode_teletex_string(
@@ -229,7 +318,13 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<TeletexString, Self::Error> {
-        todo!()
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::TELETEX_STRING,
+                alloc::format!("Error transforming TeletexString: {e:?}"),
+                crate::Codec::Jer,
+            )
+        })
     }
 
     fn decode_bmp_string(
@@ -237,15 +332,21 @@ impl crate::Decoder for Decoder {
         _t: crate::Tag,
         _c: Constraints,
     ) -> Result<BmpString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
-            .try_into()
-            .map_err(|e| {
-                DecodeError::string_conversion_failed(
-                    Tag::BMP_STRING,
-                    alloc::format!("Error transforming BMPString: {e:?}"),
-                    crate::Codec::Jer,
-                )
-            })
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::BMP_STRING,
+                alloc::format!("Error transforming BMPString: {e:?}"),
+                crate::Codec::Jer,
+            )
+        })
+    }
+
+    fn decode_universal_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<UniversalString, Self::Error> {
+        self.decode_string().map(UniversalString::from)
     }
 
     fn decode_explicit_prefix<D: crate::Decode>(
@@ -263,6 +364,33 @@ impl crate::Decoder for Decoder {
         decode_jer_value!(Self::general_time_from_value, self.stack)
     }
 
+    fn decode_date(&mut self, _t: crate::Tag) -> Result<Date, Self::Error> {
+        decode_jer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_time_of_day(&mut self, _t: crate::Tag) -> Result<TimeOfDay, Self::Error> {
+        decode_jer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_date_time(&mut self, _t: crate::Tag) -> Result<DateTime, Self::Error> {
+        decode_jer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_duration(&mut self, _t: crate::Tag) -> Result<Duration, Self::Error> {
+        decode_jer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_time(&mut self, _t: crate::Tag) -> Result<TimeValue, Self::Error> {
+        decode_jer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_lossless_generalized_time(
+        &mut self,
+        _t: crate::Tag,
+    ) -> Result<LosslessGeneralizedTime, Self::Error> {
+        decode_jer_value!(Self::lossless_generalized_time_from_value, self.stack)
+    }
+
     fn decode_set<FIELDS, SET, D, F>(
         &mut self,
         _t: crate::Tag,
@@ -289,21 +417,27 @@ impl crate::Decoder for Decoder {
         let mut fields = alloc::vec![];
         field_indices
             .sort_by(|(_, a), (_, b)| a.tag_tree.smallest_tag().cmp(&b.tag_tree.smallest_tag()));
-        for (index, field) in field_indices.into_iter() {
-            self.stack
-                .push(value_map.remove(field.name).unwrap_or(JsonValue::Null));
-            fields.push((decode_fn)(self, index, field.tag)?);
-        }
+        self.nest(|decoder| {
+            for (index, field) in field_indices.into_iter() {
+                decoder
+                    .stack
+                    .push(value_map.remove(field.name).unwrap_or(JsonValue::Null));
+                fields.push((decode_fn)(decoder, index, field.tag)?);
+            }
 
-        for (index, field) in SET::EXTENDED_FIELDS
-            .iter()
-            .flat_map(|fields| fields.iter())
-            .enumerate()
-        {
-            self.stack
-                .push(value_map.remove(field.name).unwrap_or(JsonValue::Null));
-            fields.push((decode_fn)(self, index + SET::FIELDS.len(), field.tag)?)
-        }
+            for (index, field) in SET::EXTENDED_FIELDS
+                .iter()
+                .flat_map(|fields| fields.iter())
+                .enumerate()
+            {
+                decoder
+                    .stack
+                    .push(value_map.remove(field.name).unwrap_or(JsonValue::Null));
+                fields.push((decode_fn)(decoder, index + SET::FIELDS.len(), field.tag)?)
+            }
+
+            Ok(())
+        })?;
 
         (field_fn)(fields)
     }
@@ -312,7 +446,8 @@ impl crate::Decoder for Decoder {
     where
         D: DecodeChoice,
     {
-        decode_jer_value!(|v| self.choice_from_value::<D>(v), self.stack)
+        let value = self.stack.pop().ok_or_else(JerDecodeErrorKind::eoi)?;
+        self.nest(|decoder| decoder.choice_from_value::<D>(value))
     }
 
     fn decode_optional<D: crate::Decode>(&mut self) -> Result<Option<D>, Self::Error> {
@@ -425,13 +560,41 @@ impl Decoder {
     }
 
     fn integer_from_value(value: JsonValue) -> Result<Integer, DecodeError> {
+        Self::primitive_integer_from_value(value).map(Integer::from)
+    }
+
+    fn primitive_integer_from_value(value: JsonValue) -> Result<i128, DecodeError> {
         Ok(value
             .as_i64()
             .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
                 needed: "number (supported range -2^63..2^63)",
                 found: alloc::format!("{value}"),
             })
-            .map(|n| n.into())?)
+            .map(i128::from)?)
+    }
+
+    fn real_from_value(value: JsonValue) -> Result<Real, DecodeError> {
+        if let Some(number) = value.as_number().filter(|number| !number.is_nan()) {
+            let (positive, mantissa, exponent) = number.as_parts();
+            return Ok(match (mantissa, positive) {
+                (0, true) => Real::zero(),
+                (0, false) => Real::MinusZero,
+                (_, true) => Real::decimal(mantissa, exponent.into()),
+                (_, false) => Real::decimal(-Integer::from(mantissa), exponent.into()),
+            });
+        }
+
+        match value.as_str() {
+            Some("INF") => Ok(Real::PlusInfinity),
+            Some("-INF") => Ok(Real::MinusInfinity),
+            Some("NaN") => Ok(Real::NotANumber),
+            Some("-0") => Ok(Real::MinusZero),
+            _ => Err(JerDecodeErrorKind::TypeMismatch {
+                needed: "number, or one of \"INF\", \"-INF\", \"NaN\", \"-0\"",
+                found: alloc::format!("{value}"),
+            }
+            .into()),
+        }
     }
 
     fn null_from_value(value: JsonValue) -> Result<(), DecodeError> {
@@ -445,6 +608,20 @@ impl Decoder {
     }
 
     fn object_identifier_from_value(value: JsonValue) -> Result<ObjectIdentifier, DecodeError> {
+        Ok(Self::arcs_from_value(&value)?
+            .and_then(|arcs| Oid::new(&arcs).map(|oid| ObjectIdentifier::from(oid)))
+            .ok_or_else(|| JerDecodeErrorKind::InvalidOIDString { value })?)
+    }
+
+    fn relative_oid_from_value(value: JsonValue) -> Result<RelativeOid, DecodeError> {
+        Ok(Self::arcs_from_value(&value)?
+            .and_then(RelativeOid::new)
+            .ok_or_else(|| JerDecodeErrorKind::InvalidOIDString { value })?)
+    }
+
+    /// Parses the dot separated arcs of an `OBJECT IDENTIFIER` or
+    /// `RELATIVE-OID`, returning `None` if an arc isn't a number.
+    fn arcs_from_value(value: &JsonValue) -> Result<Option<alloc::vec::Vec<u32>>, DecodeError> {
         Ok(value
             .as_str()
             .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
@@ -452,7 +629,6 @@ impl Decoder {
                 found: alloc::format!("{value}"),
             })?
             .split(".")
-            .into_iter()
             .map(|arc| {
                 u32::from_str_radix(arc, 10).map_err(|_| JerDecodeErrorKind::TypeMismatch {
                     needed: "OID arc number",
@@ -460,21 +636,42 @@ impl Decoder {
                 })
             })
             .collect::<Result<alloc::vec::Vec<u32>, _>>()
-            .ok()
-            .and_then(|arcs| Oid::new(&arcs).map(|oid| ObjectIdentifier::from(oid)))
-            .ok_or_else(|| JerDecodeErrorKind::InvalidOIDString { value })?)
+            .ok())
+    }
+
+    fn oid_iri_from_value(value: JsonValue) -> Result<OidIri, DecodeError> {
+        let iri = Self::iri_from_value(value)?;
+        OidIri::new(iri.clone()).ok_or_else(|| DecodeError::invalid_oid_iri(iri, crate::Codec::Jer))
+    }
+
+    fn relative_oid_iri_from_value(value: JsonValue) -> Result<RelativeOidIri, DecodeError> {
+        let iri = Self::iri_from_value(value)?;
+        RelativeOidIri::new(iri.clone())
+            .ok_or_else(|| DecodeError::invalid_oid_iri(iri, crate::Codec::Jer))
+    }
+
+    fn iri_from_value(value: JsonValue) -> Result<alloc::string::String, DecodeError> {
+        Ok(value
+            .as_str()
+            .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                needed: "IRI string",
+                found: alloc::format!("{value}"),
+            })?
+            .into())
     }
 
     fn sequence_of_from_value<D: Decode>(
         &mut self,
         value: JsonValue,
     ) -> Result<SequenceOf<D>, DecodeError> {
-        value
+        let items = value
             .as_array()
             .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
                 needed: "array",
                 found: alloc::format!("{value}"),
-            })?
+            })?;
+        self.limits.check_elements(items.len(), crate::Codec::Jer)?;
+        items
             .clone()
             .into_iter()
             .map(|v| {
@@ -488,12 +685,14 @@ impl Decoder {
         &mut self,
         value: JsonValue,
     ) -> Result<SetOf<D>, DecodeError> {
-        value
+        let items = value
             .as_array()
             .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
                 needed: "array",
                 found: alloc::format!("{value}"),
-            })?
+            })?;
+        self.limits.check_elements(items.len(), crate::Codec::Jer)?;
+        items
             .clone()
             .into_iter()
             .try_fold(SetOf::new(), |mut acc, v| {
@@ -548,6 +747,32 @@ impl Decoder {
         D::from_tag(self, tag)
     }
 
+    fn lossless_generalized_time_from_value(
+        value: JsonValue,
+    ) -> Result<LosslessGeneralizedTime, DecodeError> {
+        let string = value
+            .as_str()
+            .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                needed: "time string",
+                found: alloc::format!("{value}"),
+            })?;
+        string
+            .parse()
+            .map_err(|reason| DecodeError::invalid_time(reason, crate::Codec::Jer))
+    }
+
+    fn time_type_from_value<T: TimeType>(value: JsonValue) -> Result<T, DecodeError> {
+        T::from_extended(
+            value
+                .as_str()
+                .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                    needed: "time string",
+                    found: alloc::format!("{value}"),
+                })?,
+        )
+        .map_err(|reason| DecodeError::invalid_time(reason, crate::Codec::Jer))
+    }
+
     fn octet_string_from_value(value: JsonValue) -> Result<alloc::vec::Vec<u8>, DecodeError> {
         let octet_string = value
             .as_str()
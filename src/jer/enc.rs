@@ -1,13 +1,37 @@
 //! # Encoding JER.
 
+use alloc::string::ToString;
+
 use jzon::{object::Object, JsonValue};
 
 use crate::{
-    error::{EncodeError, JerEncodeErrorKind},
-    types::{fields::Fields, variants},
+    error::{strings::PermittedAlphabetError, EncodeError, JerEncodeErrorKind},
+    types::{fields::Fields, strings::StaticPermittedAlphabet, variants},
+    validate,
 };
 
+/// Options for configuring the [`Encoder`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EncoderOptions {
+    validate_constraints: bool,
+}
+
+impl EncoderOptions {
+    /// Returns these options set to check the size, value, and permitted
+    /// alphabet constraints of each value as it's encoded, failing on the
+    /// first one that isn't satisfied.
+    ///
+    /// Use [`Validate`][crate::Validate] instead to find every constraint
+    /// that a value doesn't satisfy.
+    #[must_use]
+    pub const fn with_constraint_validation(mut self, validate_constraints: bool) -> Self {
+        self.validate_constraints = validate_constraints;
+        self
+    }
+}
+
 pub struct Encoder {
+    options: EncoderOptions,
     stack: alloc::vec::Vec<&'static str>,
     constructed_stack: alloc::vec::Vec<Object>,
     root_value: Option<JsonValue>,
@@ -15,7 +39,12 @@ pub struct Encoder {
 
 impl Encoder {
     pub fn new() -> Self {
+        Self::with_options(EncoderOptions::default())
+    }
+
+    pub fn with_options(options: EncoderOptions) -> Self {
         Self {
+            options,
             stack: alloc::vec![],
             constructed_stack: alloc::vec![],
             root_value: None,
@@ -32,6 +61,18 @@ impl Encoder {
         self.root_value.map_or(<_>::default(), |v| v.dump())
     }
 
+    /// Runs `check` when the encoder is configured to validate constraints.
+    fn check_constraints(
+        &self,
+        check: impl FnOnce() -> Result<(), crate::error::ViolationKind>,
+    ) -> Result<(), EncodeError> {
+        if self.options.validate_constraints {
+            check().map_err(|kind| EncodeError::constraint_violation(kind, crate::Codec::Jer))
+        } else {
+            Ok(())
+        }
+    }
+
     fn update_root_or_constructed(&mut self, value: JsonValue) -> Result<(), EncodeError> {
         match self.stack.pop() {
             Some(id) => {
@@ -48,6 +89,16 @@ impl Encoder {
         };
         Ok(())
     }
+
+    fn encode_time_type<T: crate::types::TimeType>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        let string = value
+            .to_extended()
+            .map_err(|reason| EncodeError::invalid_time(reason, crate::Codec::Jer))?;
+        self.update_root_or_constructed(JsonValue::String(string))
+    }
 }
 
 impl crate::Encoder for Encoder {
@@ -70,9 +121,10 @@ impl crate::Encoder for Encoder {
     fn encode_bit_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::BitStr,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         self.update_root_or_constructed(JsonValue::String(value.iter().fold(
             alloc::string::String::new(),
             |mut acc, bit| {
@@ -104,12 +156,37 @@ impl crate::Encoder for Encoder {
         ))
     }
 
+    fn encode_relative_oid(
+        &mut self,
+        t: crate::Tag,
+        value: &[u32],
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_object_identifier(t, value)
+    }
+
+    fn encode_oid_iri(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::OidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(JsonValue::String(value.to_string()))
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::RelativeOidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(JsonValue::String(value.to_string()))
+    }
+
     fn encode_integer(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &num_bigint::BigInt,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_value(value, &constraints))?;
         let as_i64: i64 =
             value
                 .try_into()
@@ -119,6 +196,50 @@ impl crate::Encoder for Encoder {
         self.update_root_or_constructed(JsonValue::Number(as_i64.into()))
     }
 
+    fn encode_primitive_integer(
+        &mut self,
+        _t: crate::Tag,
+        constraints: crate::types::Constraints,
+        value: i128,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_value(&value.into(), &constraints))?;
+        let as_i64 =
+            i64::try_from(value).map_err(|_| JerEncodeErrorKind::ExceedsSupportedIntSize {
+                value: value.into(),
+            })?;
+        self.update_root_or_constructed(JsonValue::Number(as_i64.into()))
+    }
+
+    fn encode_real(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::Real,
+    ) -> Result<Self::Ok, Self::Error> {
+        use crate::types::Real;
+        let value = match value {
+            Real::PlusInfinity => JsonValue::String("INF".into()),
+            Real::MinusInfinity => JsonValue::String("-INF".into()),
+            Real::NotANumber => JsonValue::String("NaN".into()),
+            Real::MinusZero => JsonValue::String("-0".into()),
+            finite => JsonValue::Number(
+                finite
+                    .to_decimal()
+                    .and_then(|(mantissa, exponent)| {
+                        Some(jzon::number::Number::from_parts(
+                            mantissa.sign() != num_bigint::Sign::Minus,
+                            num_traits::ToPrimitive::to_u64(mantissa.magnitude())?,
+                            exponent.try_into().ok()?,
+                        ))
+                    })
+                    .ok_or_else(|| JerEncodeErrorKind::ExceedsSupportedRealRange {
+                        value: finite.clone(),
+                    })?,
+            ),
+        };
+        self.update_root_or_constructed(value)
+    }
+
     fn encode_null(&mut self, _: crate::Tag) -> Result<Self::Ok, Self::Error> {
         self.update_root_or_constructed(JsonValue::Null)
     }
@@ -126,9 +247,10 @@ impl crate::Encoder for Encoder {
     fn encode_octet_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &[u8],
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         self.update_root_or_constructed(JsonValue::String(value.iter().fold(
             alloc::string::String::new(),
             |mut acc, bit| {
@@ -141,9 +263,36 @@ impl crate::Encoder for Encoder {
     fn encode_general_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::GeneralString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
+        self.update_root_or_constructed(JsonValue::String(
+            alloc::string::String::from_utf8(value.to_vec())
+                .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
+        ))
+    }
+
+    fn encode_graphic_string(
+        &mut self,
+        _t: crate::Tag,
+        constraints: crate::types::Constraints,
+        value: &crate::types::GraphicString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
+        self.update_root_or_constructed(JsonValue::String(
+            alloc::string::String::from_utf8(value.to_vec())
+                .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
+        ))
+    }
+
+    fn encode_videotex_string(
+        &mut self,
+        _t: crate::Tag,
+        constraints: crate::types::Constraints,
+        value: &crate::types::VideotexString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         self.update_root_or_constructed(JsonValue::String(
             alloc::string::String::from_utf8(value.to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -153,18 +302,28 @@ impl crate::Encoder for Encoder {
     fn encode_utf8_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &str,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(
+                value.chars().count(),
+                value.chars().map(u32::from),
+                &constraints,
+            )
+        })?;
         self.update_root_or_constructed(JsonValue::String(value.into()))
     }
 
     fn encode_visible_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::VisibleString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.update_root_or_constructed(JsonValue::String(
             alloc::string::String::from_utf8(value.as_iso646_bytes().to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -174,9 +333,12 @@ impl crate::Encoder for Encoder {
     fn encode_ia5_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::Ia5String,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.update_root_or_constructed(JsonValue::String(
             alloc::string::String::from_utf8(value.as_iso646_bytes().to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -186,9 +348,12 @@ impl crate::Encoder for Encoder {
     fn encode_printable_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::PrintableString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.update_root_or_constructed(JsonValue::String(
             alloc::string::String::from_utf8(value.as_bytes().to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -198,9 +363,12 @@ impl crate::Encoder for Encoder {
     fn encode_numeric_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::NumericString,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
         self.update_root_or_constructed(JsonValue::String(
             alloc::string::String::from_utf8(value.as_bytes().to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -210,22 +378,51 @@ impl crate::Encoder for Encoder {
     fn encode_teletex_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
-        _value: &crate::types::TeletexString,
+        constraints: crate::types::Constraints,
+        value: &crate::types::TeletexString,
     ) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
+        self.update_root_or_constructed(JsonValue::String(
+            value
+                .to_unicode()
+                .map_err(|e| EncodeError::teletex_conversion_failed(e, crate::Codec::Jer))?,
+        ))
     }
 
     fn encode_bmp_string(
         &mut self,
         _t: crate::Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::BmpString,
     ) -> Result<Self::Ok, Self::Error> {
-        self.update_root_or_constructed(JsonValue::String(
-            alloc::string::String::from_utf8(value.to_bytes())
-                .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
-        ))
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
+        // BMP strings can hold lone surrogates, which JSON strings can't.
+        let string = value
+            .chars()
+            .map(|ch| {
+                char::from_u32(ch).ok_or_else(|| {
+                    EncodeError::alphabet_constraint_not_satisfied(
+                        PermittedAlphabetError::CharacterNotFound { character: ch },
+                        crate::Codec::Jer,
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        self.update_root_or_constructed(JsonValue::String(string))
+    }
+
+    fn encode_universal_string(
+        &mut self,
+        _t: crate::Tag,
+        constraints: crate::types::Constraints,
+        value: &crate::types::UniversalString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| {
+            validate::check_string(value.chars().count(), value.chars(), &constraints)
+        })?;
+        self.update_root_or_constructed(JsonValue::String(value.to_string()))
     }
 
     fn encode_generalized_time(
@@ -241,6 +438,14 @@ impl crate::Encoder for Encoder {
         ))
     }
 
+    fn encode_lossless_generalized_time(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::LosslessGeneralizedTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(JsonValue::String(value.to_string()))
+    }
+
     fn encode_utc_time(
         &mut self,
         _t: crate::Tag,
@@ -254,6 +459,46 @@ impl crate::Encoder for Encoder {
         ))
     }
 
+    fn encode_date(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::Date,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(value)
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::TimeOfDay,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(value)
+    }
+
+    fn encode_date_time(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::DateTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(value)
+    }
+
+    fn encode_duration(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::Duration,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(value)
+    }
+
+    fn encode_time(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::TimeValue,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(value)
+    }
+
     fn encode_explicit_prefix<V: crate::Encode>(
         &mut self,
         _: crate::Tag,
@@ -295,12 +540,13 @@ impl crate::Encoder for Encoder {
         &mut self,
         _t: crate::Tag,
         value: &[E],
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         self.update_root_or_constructed(JsonValue::Array(value.iter().try_fold(
             alloc::vec![],
             |mut acc, v| {
-                let mut item_encoder = Self::new();
+                let mut item_encoder = Self::with_options(self.options);
                 v.encode(&mut item_encoder).and(
                     item_encoder
                         .root_value()
@@ -323,12 +569,13 @@ impl crate::Encoder for Encoder {
         &mut self,
         _t: crate::Tag,
         value: &crate::types::SetOf<E>,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
     ) -> Result<Self::Ok, Self::Error> {
+        self.check_constraints(|| validate::check_size(value.len(), &constraints))?;
         self.update_root_or_constructed(JsonValue::Array(value.iter().try_fold(
             alloc::vec![],
             |mut acc, v| {
-                let mut item_encoder = Self::new();
+                let mut item_encoder = Self::with_options(self.options);
                 v.encode(&mut item_encoder).and(
                     item_encoder
                         .root_value()
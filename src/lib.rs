@@ -1,6 +1,8 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(test), no_std)]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(test)]
 macro_rules! round_trip {
@@ -37,6 +39,8 @@ pub mod codec;
 pub mod de;
 pub mod enc;
 pub mod types;
+pub mod validate;
+pub mod value;
 
 // Data Formats
 
@@ -46,27 +50,32 @@ pub mod aper;
 pub mod ber;
 mod bits;
 pub mod cer;
+pub mod coer;
 pub mod der;
 pub mod error;
 pub mod jer;
 mod num;
+pub mod oer;
 pub mod uper;
+pub mod xer;
 
 #[doc(inline)]
 pub use self::{
     codec::Codec,
-    de::{Decode, Decoder},
+    de::{BorrowDecoder, Decode, DecodeBorrowed, Decoder},
     enc::{Encode, Encoder},
     types::{AsnType, Tag, TagTree},
+    validate::Validate,
 };
 
 /// A prelude containing the codec traits and all types defined in the [`types`]
 /// module.
 pub mod prelude {
     pub use crate::{
-        de::{Decode, Decoder},
+        de::{BorrowDecoder, Decode, DecodeBorrowed, Decoder},
         enc::{Encode, Encoder},
         types::*,
+        validate::Validate,
     };
 }
 
@@ -95,7 +104,152 @@ mod tests {
             }
         }
 
-        codecs!(uper, aper);
+        codecs!(uper, aper, oer, coer, xer);
+    }
+
+    #[test]
+    fn decode_iter() {
+        let values = [Integer::from(-1), Integer::from(256), Integer::from(0)];
+
+        macro_rules! codecs {
+            ($($codec:ident),+ $(,)?) => {
+                $(
+                    let encoded: alloc::vec::Vec<u8> = values
+                        .iter()
+                        .flat_map(|value| crate::$codec::encode(value).unwrap())
+                        .collect();
+                    let decoded = crate::$codec::decode_iter::<Integer>(&encoded)
+                        .collect::<Result<alloc::vec::Vec<_>, _>>()
+                        .unwrap();
+                    pretty_assertions::assert_eq!(&values[..], &decoded[..], stringify!($codec));
+                )+
+            }
+        }
+
+        codecs!(ber, cer, der, uper, aper, oer, coer);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io() {
+        let value = alloc::vec![Integer::from(-1), Integer::from(256)];
+
+        macro_rules! codecs {
+            ($($codec:ident),+ $(,)?) => {
+                $(
+                    let mut output = alloc::vec::Vec::new();
+                    crate::$codec::encode_to_writer(&value, &mut output).unwrap();
+                    let decoded: alloc::vec::Vec<Integer> =
+                        crate::$codec::decode_from_reader(&*output).unwrap();
+                    pretty_assertions::assert_eq!(value, decoded, stringify!($codec));
+                )+
+            }
+        }
+
+        codecs!(ber, cer, der, uper, aper, oer, coer, jer, xer);
+
+        // BER values are read one at a time, leaving the rest of the input.
+        let mut output = alloc::vec::Vec::new();
+        crate::ber::encode_to_writer(&true, &mut output).unwrap();
+        crate::ber::encode_to_writer(&value, &mut output).unwrap();
+        let mut reader = std::io::Cursor::new(&output);
+        assert!(crate::ber::decode_from_reader::<bool>(&mut reader).unwrap());
+        assert_eq!(reader.position(), 3);
+        assert_eq!(
+            crate::ber::decode_from_reader::<alloc::vec::Vec<Integer>>(&mut reader).unwrap(),
+            value
+        );
+        assert!(matches!(
+            *crate::ber::decode_from_reader::<bool>(&mut reader)
+                .unwrap_err()
+                .kind,
+            crate::error::DecodeErrorKind::Io { .. }
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn encode_to_writer_streams() {
+        /// Records the size of the largest single write.
+        #[derive(Default)]
+        struct Writer {
+            output: alloc::vec::Vec<u8>,
+            largest_write: usize,
+        }
+
+        impl std::io::Write for Writer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.largest_write = self.largest_write.max(buf.len());
+                self.output.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Entry {
+            data: OctetString,
+            flags: SetOf<bool>,
+        }
+
+        let value: alloc::vec::Vec<Entry> = (0..100u8)
+            .map(|i| Entry {
+                data: OctetString::from(alloc::vec![i; 2500]),
+                flags: [true, false].into_iter().collect(),
+            })
+            .collect();
+
+        let mut writer = Writer::default();
+        crate::cer::encode_to_writer(&value, &mut writer).unwrap();
+        assert_eq!(writer.output, crate::cer::encode(&value).unwrap());
+        assert!(writer.largest_write < 16 * 1024, "{}", writer.largest_write);
+
+        let mut writer = Writer::default();
+        crate::ber::encode_to_writer(&value, &mut writer).unwrap();
+        assert_eq!(&writer.output[..2], &[0x30, 0x80]);
+        assert!(writer.largest_write < 16 * 1024, "{}", writer.largest_write);
+        assert_eq!(
+            crate::ber::decode::<alloc::vec::Vec<Entry>>(&writer.output).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn decode_with_remainder() {
+        assert_eq!(
+            crate::ber::decode_with_remainder::<bool>(&[0x01, 0x01, 0xff, 0x05, 0x00]).unwrap(),
+            (true, &[0x05, 0x00][..])
+        );
+        assert_eq!(
+            crate::oer::decode_with_remainder::<u8>(&[0x01, 0x02]).unwrap(),
+            (1, &[0x02][..])
+        );
+        // PER reports the offset in bits, here after a single bit.
+        assert_eq!(
+            crate::uper::decode_with_remainder::<bool>(&[0x80, 0x80]).unwrap(),
+            (true, 1)
+        );
+        // A complete PER encoding is padded to the next octet.
+        assert_eq!(
+            crate::uper::decode_iter::<bool>(&[0x80, 0x00])
+                .collect::<Result<alloc::vec::Vec<_>, _>>()
+                .unwrap(),
+            [true, false]
+        );
+        // Values which take up no input can't be iterated over, and leave the
+        // input visible.
+        let mut iter = crate::oer::decode_iter::<()>(&[0x00]);
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+        assert_eq!(iter.remaining(), [0x00]);
+        // PER padding must be zero.
+        let mut iter = crate::uper::decode_iter::<bool>(&[0x81, 0x00]);
+        assert!(iter.next().unwrap().is_err());
+        assert_eq!(iter.remaining(), [0x81, 0x00]);
     }
 
     #[test]
@@ -196,6 +350,20 @@ mod tests {
         round_trip(&CustomInt(i32::MAX));
     }
 
+    #[test]
+    fn real() {
+        round_trip(&0.0f64);
+        round_trip(&-1.5f64);
+        round_trip(&core::f64::consts::PI);
+        round_trip(&f64::MAX);
+        round_trip(&f64::MIN_POSITIVE);
+        round_trip(&f64::NEG_INFINITY);
+        round_trip(&0.1f32);
+        round_trip(&Real::decimal(-314, -2));
+        round_trip(&Real::MinusZero);
+        round_trip(&Real::NotANumber);
+    }
+
     #[test]
     fn bit_string() {
         round_trip(&BitString::from_slice(&[1u8, 2, 3, 4, 5]));
@@ -2,3 +2,134 @@
 pub(crate) const fn log2(x: i128) -> u32 {
     i128::BITS - (x - 1).leading_zeros()
 }
+
+/// The big endian octets of a primitive integer, with its redundant leading
+/// octets removed, stored inline so that encoding doesn't allocate.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct IntegerBytes {
+    bytes: [u8; 16],
+    start: usize,
+}
+
+impl IntegerBytes {
+    /// The fewest two's complement octets of `value`, the same as
+    /// `BigInt::to_signed_bytes_be`.
+    pub(crate) fn signed(value: i128) -> Self {
+        let bytes = value.to_be_bytes();
+        let mut start = 0;
+        while start < bytes.len() - 1
+            && matches!(
+                (bytes[start], bytes[start + 1] & 0x80),
+                (0, 0) | (0xFF, 0x80)
+            )
+        {
+            start += 1;
+        }
+
+        Self { bytes, start }
+    }
+
+    /// The fewest octets of `value`, the same as `BigUint::to_bytes_be`.
+    pub(crate) fn unsigned(value: u128) -> Self {
+        let bytes = value.to_be_bytes();
+        let start = (value.leading_zeros() as usize / 8).min(bytes.len() - 1);
+
+        Self { bytes, start }
+    }
+}
+
+impl core::ops::Deref for IntegerBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes[self.start..]
+    }
+}
+
+/// Reads big endian two's complement octets as an `i128`, or `None` if the
+/// value doesn't fit.
+pub(crate) fn i128_from_signed_bytes_be(bytes: &[u8]) -> Option<i128> {
+    let fill = match bytes.first() {
+        Some(first) if first & 0x80 != 0 => 0xFF,
+        _ => 0,
+    };
+    let (padding, bytes) = bytes.split_at(bytes.len().saturating_sub(16));
+    if padding.iter().any(|byte| *byte != fill)
+        || (!padding.is_empty() && (bytes[0] & 0x80 != 0) != (fill == 0xFF))
+    {
+        return None;
+    }
+
+    let mut buffer = [fill; 16];
+    buffer[16 - bytes.len()..].copy_from_slice(bytes);
+    Some(i128::from_be_bytes(buffer))
+}
+
+/// Reads big endian unsigned octets as an `i128`, or `None` if the value
+/// doesn't fit.
+pub(crate) fn i128_from_unsigned_bytes_be(bytes: &[u8]) -> Option<i128> {
+    let (padding, bytes) = bytes.split_at(bytes.len().saturating_sub(16));
+    if padding.iter().any(|byte| *byte != 0) {
+        return None;
+    }
+
+    let mut buffer = [0; 16];
+    buffer[16 - bytes.len()..].copy_from_slice(bytes);
+    i128::try_from(u128::from_be_bytes(buffer)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_bytes() {
+        for value in [
+            0,
+            1,
+            -1,
+            127,
+            128,
+            -128,
+            -129,
+            255,
+            256,
+            i64::MAX.into(),
+            i64::MIN.into(),
+            u64::MAX.into(),
+            i128::MAX,
+            i128::MIN,
+        ] {
+            let bytes = IntegerBytes::signed(value);
+            assert_eq!(
+                num_bigint::BigInt::from(value).to_signed_bytes_be(),
+                &*bytes
+            );
+            assert_eq!(Some(value), i128_from_signed_bytes_be(&bytes));
+
+            if let Ok(value) = u128::try_from(value) {
+                let bytes = IntegerBytes::unsigned(value);
+                assert_eq!(num_bigint::BigUint::from(value).to_bytes_be(), &*bytes);
+                assert_eq!(
+                    i128::try_from(value).ok(),
+                    i128_from_unsigned_bytes_be(&bytes)
+                );
+            }
+        }
+
+        assert_eq!(Some(-1), i128_from_signed_bytes_be(&[0xFF; 20]));
+        assert_eq!(
+            Some(1),
+            i128_from_unsigned_bytes_be(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+        );
+        assert_eq!(
+            None,
+            i128_from_signed_bytes_be(&[0, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            None,
+            i128_from_unsigned_bytes_be(&[0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(Some(0), i128_from_signed_bytes_be(&[]));
+    }
+}
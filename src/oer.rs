@@ -0,0 +1,719 @@
+//! # Octet Encoding Rules
+//!
+//! Codec functions for OER, rasn provides a "basic" decoder, and canonical encoder.
+//! This means that users are able decode any valid OER value, and that rasn's
+//! encoding will always produce the same output for the same value.
+pub mod de;
+pub mod enc;
+mod rules;
+
+use crate::types::{constraints::Bounded, Constraints};
+
+pub use self::{de::Decoder, enc::Encoder};
+pub(crate) use rules::EncodingRules;
+
+/// Attempts to decode `T` from `input` using OER.
+/// # Errors
+/// Returns error specific to OER decoder if decoding is not possible.
+pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeError> {
+    T::decode(&mut Decoder::new(input, de::DecoderOptions::oer()))
+}
+
+/// Attempts to decode `T` from the start of `input` using OER, returning it
+/// together with the input following it.
+/// # Errors
+/// Returns error specific to OER decoder if decoding is not possible.
+pub fn decode_with_remainder<T: crate::Decode>(
+    input: &[u8],
+) -> Result<(T, &[u8]), crate::error::DecodeError> {
+    let mut decoder = Decoder::new(input, de::DecoderOptions::oer());
+    let value = T::decode(&mut decoder)?;
+    Ok((value, decoder.input()))
+}
+
+/// Returns an iterator decoding successive values of `T` from `input` using
+/// OER.
+pub fn decode_iter<T: crate::Decode>(input: &[u8]) -> crate::de::DecodeIter<'_, T> {
+    crate::de::DecodeIter::new(input, crate::Codec::Oer, decode_with_remainder)
+}
+
+/// Attempts to encode `value` to OER.
+/// # Errors
+/// Returns error specific to OER encoder if encoding is not possible.
+pub fn encode<T: crate::Encode>(
+    value: &T,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let mut enc = Encoder::new(enc::EncoderOptions::oer());
+
+    value.encode(&mut enc)?;
+
+    Ok(enc.output())
+}
+
+/// Attempts to encode `value` to OER, writing the encoding to `writer`.
+/// # Errors
+/// Returns error specific to OER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    mut writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    writer
+        .write_all(&encode(value)?)
+        .map_err(|error| crate::error::EncodeError::io(error, crate::Codec::Oer))
+}
+
+/// Attempts to decode `T` from the rest of `reader` using OER.
+/// # Errors
+/// Returns error specific to OER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    mut reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    let mut input = alloc::vec::Vec::new();
+    reader
+        .read_to_end(&mut input)
+        .map_err(|error| crate::error::DecodeError::io(error, crate::Codec::Oer))?;
+    decode(&input)
+}
+
+/// Attempts to decode `T` from `input` using OER with `constraints`.
+/// # Errors
+/// Returns error specific to OER decoder if decoding is not possible.
+pub fn decode_with_constraints<T: crate::Decode>(
+    constraints: Constraints,
+    input: &[u8],
+) -> Result<T, crate::error::DecodeError> {
+    T::decode_with_constraints(
+        &mut Decoder::new(input, de::DecoderOptions::oer()),
+        constraints,
+    )
+}
+
+/// Attempts to encode `value` to OER with `constraints`.
+/// # Errors
+/// Returns error specific to OER encoder if encoding is not possible.
+pub fn encode_with_constraints<T: crate::Encode>(
+    constraints: Constraints,
+    value: &T,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let mut enc = Encoder::new(enc::EncoderOptions::oer());
+
+    value.encode_with_constraints(&mut enc, constraints)?;
+
+    Ok(enc.output())
+}
+
+/// How an `INTEGER` is laid out on the wire, decided by its OER-visible
+/// value constraint (ITU-T X.696 (02/2021) §10).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum IntegerWidth {
+    /// A fixed number of octets, without a length determinant.
+    Fixed { octets: usize, signed: bool },
+    /// A length determinant followed by the minimal number of octets.
+    Variable { signed: bool },
+}
+
+impl IntegerWidth {
+    /// Extensible constraints are not OER-visible (X.696 §8.2.1), so those
+    /// integers are always encoded as if they were unconstrained.
+    fn from_constraints(constraints: &Constraints) -> Self {
+        let bounds = constraints
+            .value()
+            .filter(|value| value.extensible.is_none())
+            .map(|value| *value.constraint);
+
+        let (lower, upper) = match bounds {
+            Some(Bounded::Single(value)) => (Some(value), Some(value)),
+            Some(Bounded::Range { start, end }) => (start, end),
+            _ => (None, None),
+        };
+
+        match (lower, upper) {
+            (Some(lower), upper) if lower >= 0 => match upper {
+                Some(upper) if upper <= u8::MAX.into() => Self::Fixed {
+                    octets: 1,
+                    signed: false,
+                },
+                Some(upper) if upper <= u16::MAX.into() => Self::Fixed {
+                    octets: 2,
+                    signed: false,
+                },
+                Some(upper) if upper <= u32::MAX.into() => Self::Fixed {
+                    octets: 4,
+                    signed: false,
+                },
+                Some(upper) if upper <= u64::MAX.into() => Self::Fixed {
+                    octets: 8,
+                    signed: false,
+                },
+                _ => Self::Variable { signed: false },
+            },
+            (Some(lower), Some(upper)) if lower >= i8::MIN.into() && upper <= i8::MAX.into() => {
+                Self::Fixed {
+                    octets: 1,
+                    signed: true,
+                }
+            }
+            (Some(lower), Some(upper)) if lower >= i16::MIN.into() && upper <= i16::MAX.into() => {
+                Self::Fixed {
+                    octets: 2,
+                    signed: true,
+                }
+            }
+            (Some(lower), Some(upper)) if lower >= i32::MIN.into() && upper <= i32::MAX.into() => {
+                Self::Fixed {
+                    octets: 4,
+                    signed: true,
+                }
+            }
+            (Some(lower), Some(upper)) if lower >= i64::MIN.into() && upper <= i64::MAX.into() => {
+                Self::Fixed {
+                    octets: 8,
+                    signed: true,
+                }
+            }
+            _ => Self::Variable { signed: true },
+        }
+    }
+}
+
+/// Returns the fixed length of a string type, if its OER-visible size
+/// constraint permits exactly one length. Such strings are encoded without a
+/// length determinant.
+fn fixed_size(constraints: &Constraints) -> Option<usize> {
+    constraints
+        .size()
+        .filter(|size| size.extensible.is_none())
+        .and_then(|size| match *size.constraint {
+            Bounded::Single(length) => Some(length),
+            Bounded::Range {
+                start: Some(start),
+                end: Some(end),
+            } if start == end => Some(start),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as rasn;
+    use crate::{
+        prelude::*,
+        types::{constraints::*, *},
+    };
+
+    #[test]
+    fn bool() {
+        round_trip!(oer, bool, true, &[0xff]);
+        round_trip!(oer, bool, false, &[0]);
+    }
+
+    #[test]
+    fn integer() {
+        round_trip!(oer, Integer, 0.into(), &[0x01, 0x00]);
+        round_trip!(oer, Integer, 127.into(), &[0x01, 0x7f]);
+        round_trip!(oer, Integer, 128.into(), &[0x02, 0x00, 0x80]);
+        round_trip!(oer, Integer, 256.into(), &[0x02, 0x01, 0x00]);
+        round_trip!(oer, Integer, (-1).into(), &[0x01, 0xff]);
+        round_trip!(oer, Integer, (-129).into(), &[0x02, 0xff, 0x7f]);
+
+        round_trip!(oer, u8, 5, &[0x05]);
+        round_trip!(oer, u16, 256, &[0x01, 0x00]);
+        round_trip!(oer, u32, 1, &[0x00, 0x00, 0x00, 0x01]);
+        round_trip!(oer, u64, 1, &[0, 0, 0, 0, 0, 0, 0, 0x01]);
+        round_trip!(oer, i8, -1, &[0xff]);
+        round_trip!(oer, i16, -2, &[0xff, 0xfe]);
+        round_trip!(oer, i32, -1, &[0xff, 0xff, 0xff, 0xff]);
+
+        type B = ConstrainedInteger<5, 99>;
+        type C = ConstrainedInteger<-10, 10>;
+        type D = ConstrainedInteger<0, 65535>;
+        type E = ConstrainedInteger<-1, 4_294_967_295>;
+
+        round_trip!(oer, B, 5.into(), &[0x05]);
+        round_trip!(oer, B, 99.into(), &[0x63]);
+        round_trip!(oer, C, (-10).into(), &[0xf6]);
+        round_trip!(oer, D, 256.into(), &[0x01, 0x00]);
+        round_trip!(oer, E, 1.into(), &[0, 0, 0, 0, 0, 0, 0, 0x01]);
+
+        round_trip_with_constraints!(
+            oer,
+            Integer,
+            Constraints::new(&[Constraint::Value(Value::new(Bounded::start_from(0)).into())]),
+            256.into(),
+            &[0x02, 0x01, 0x00]
+        );
+        round_trip_with_constraints!(
+            oer,
+            Integer,
+            Constraints::new(&[Constraint::Value(
+                Extensible::new(Value::new(Bounded::new(0, 255))).set_extensible(true)
+            )]),
+            255.into(),
+            &[0x02, 0x00, 0xff]
+        );
+    }
+
+    #[test]
+    fn real() {
+        round_trip!(oer, f64, 1.0, &[0x03, 0x80, 0x00, 0x01]);
+        round_trip!(oer, f64, 0.0, &[0x00]);
+        round_trip!(oer, Real, Real::MinusZero, &[0x01, 0x43]);
+        let base_eight = [0x03, 0x90, 0x01, 0x01];
+        assert_eq!(8.0, crate::oer::decode::<f64>(&base_eight).unwrap());
+        assert!(crate::coer::decode::<f64>(&base_eight).is_err());
+    }
+
+    #[test]
+    fn integer_outside_of_constraints() {
+        type B = ConstrainedInteger<5, 99>;
+        assert!(crate::oer::encode(&B::from(100)).is_err());
+    }
+
+    #[test]
+    fn canonical_decoding() {
+        #[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+        #[rasn(enumerated, crate_root = "crate")]
+        enum Enum {
+            Zero = 0,
+            Large = 128,
+        }
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, crate_root = "crate")]
+        #[non_exhaustive]
+        struct Extensible {
+            a: u8,
+            #[rasn(extension_addition)]
+            b: Option<bool>,
+        }
+
+        // Non-minimal length determinant.
+        let long_length = &[0x81, 0x01, 0x05];
+        assert_eq!(
+            crate::oer::decode::<OctetString>(long_length).unwrap(),
+            OctetString::from_static(&[0x05])
+        );
+        assert!(crate::coer::decode::<OctetString>(long_length).is_err());
+
+        // Non-minimal integer.
+        let padded_integer = &[0x02, 0x00, 0x05];
+        assert_eq!(
+            crate::oer::decode::<Integer>(padded_integer).unwrap(),
+            5.into()
+        );
+        assert!(crate::coer::decode::<Integer>(padded_integer).is_err());
+        assert!(crate::coer::decode::<Integer>(&[0x02, 0xff, 0xff]).is_err());
+        assert!(crate::coer::decode::<Integer>(&[0x00]).is_err());
+        assert_eq!(
+            crate::coer::decode::<Integer>(&[0x02, 0x00, 0x80]).unwrap(),
+            128.into()
+        );
+
+        // Long form enumerated for a short form value.
+        assert_eq!(
+            crate::oer::decode::<Enum>(&[0x81, 0x00]).unwrap(),
+            Enum::Zero
+        );
+        assert!(crate::coer::decode::<Enum>(&[0x81, 0x00]).is_err());
+        assert!(crate::coer::decode::<Enum>(&[0x82, 0x00, 0x00]).is_err());
+        assert_eq!(
+            crate::coer::decode::<Enum>(&[0x82, 0x00, 0x80]).unwrap(),
+            Enum::Large
+        );
+
+        // Boolean values other than `0x00` and `0xFF`.
+        assert!(crate::oer::decode::<bool>(&[0x01]).unwrap());
+        assert!(crate::coer::decode::<bool>(&[0x01]).is_err());
+
+        // Non-zero padding bits.
+        assert!(crate::oer::decode::<BitString>(&[0x02, 0x05, 0xa1]).is_ok());
+        assert!(crate::coer::decode::<BitString>(&[0x02, 0x05, 0xa1]).is_err());
+
+        // Extension bit set without any extension additions present.
+        let empty_extensions = &[0x80, 0x05, 0x02, 0x07, 0x00];
+        assert_eq!(
+            crate::oer::decode::<Extensible>(empty_extensions).unwrap(),
+            Extensible { a: 5, b: None }
+        );
+        assert!(crate::coer::decode::<Extensible>(empty_extensions).is_err());
+        round_trip!(
+            coer,
+            Extensible,
+            Extensible {
+                a: 5,
+                b: Some(true)
+            },
+            &[0x80, 0x05, 0x02, 0x07, 0x80, 0x01, 0xff]
+        );
+    }
+
+    #[test]
+    fn enumerated() {
+        #[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+        #[rasn(enumerated, crate_root = "crate")]
+        enum Enum {
+            Negative = -1,
+            Zero = 0,
+            Small = 127,
+            Large = 128,
+        }
+
+        round_trip!(oer, Enum, Enum::Zero, &[0x00]);
+        round_trip!(oer, Enum, Enum::Small, &[0x7f]);
+        round_trip!(oer, Enum, Enum::Large, &[0x82, 0x00, 0x80]);
+        round_trip!(oer, Enum, Enum::Negative, &[0x81, 0xff]);
+    }
+
+    #[test]
+    fn octet_string() {
+        round_trip!(
+            oer,
+            OctetString,
+            OctetString::from_static(&[1, 2, 3]),
+            &[0x03, 0x01, 0x02, 0x03]
+        );
+        round_trip!(
+            oer,
+            FixedOctetString<3>,
+            [1, 2, 3].into(),
+            &[0x01, 0x02, 0x03]
+        );
+
+        let long = OctetString::from(vec![0xAB; 200]);
+        let mut expected = vec![0x81, 200];
+        expected.extend_from_slice(&[0xAB; 200]);
+        round_trip!(oer, OctetString, long, &expected);
+    }
+
+    #[test]
+    fn bit_string() {
+        use bitvec::prelude::*;
+        round_trip!(
+            oer,
+            BitString,
+            bitvec::bitvec![u8, Msb0; 1, 0, 1],
+            &[0x02, 0x05, 0xa0]
+        );
+        round_trip!(oer, BitString, BitString::new(), &[0x01, 0x00]);
+
+        round_trip_with_constraints!(
+            oer,
+            BitString,
+            Constraints::new(&[Constraint::Size(Size::fixed(3).into())]),
+            bitvec::bitvec![u8, Msb0; 1, 0, 1],
+            &[0xa0]
+        );
+    }
+
+    #[test]
+    fn strings() {
+        round_trip!(
+            oer,
+            Utf8String,
+            "Jones".into(),
+            &[0x05, 0x4a, 0x6f, 0x6e, 0x65, 0x73]
+        );
+        round_trip!(
+            oer,
+            VisibleString,
+            VisibleString::try_from("Jones").unwrap(),
+            &[0x05, 0x4a, 0x6f, 0x6e, 0x65, 0x73]
+        );
+        round_trip!(
+            oer,
+            Ia5String,
+            Ia5String::try_from("Jo").unwrap(),
+            &[0x02, 0x4a, 0x6f]
+        );
+        round_trip!(
+            oer,
+            NumericString,
+            NumericString::try_from("123").unwrap(),
+            &[0x03, 0x31, 0x32, 0x33]
+        );
+        round_trip!(
+            oer,
+            PrintableString,
+            PrintableString::try_from("Hi").unwrap(),
+            &[0x02, 0x48, 0x69]
+        );
+        round_trip!(
+            oer,
+            BmpString,
+            BmpString::try_from("Hi").unwrap(),
+            &[0x04, 0x00, 0x48, 0x00, 0x69]
+        );
+        round_trip!(
+            oer,
+            TeletexString,
+            TeletexString::try_from("Grüße").unwrap(),
+            &[0x06, 0x47, 0x72, 0xC8, 0x75, 0xFB, 0x65]
+        );
+
+        round_trip_with_constraints!(
+            oer,
+            VisibleString,
+            Constraints::new(&[Constraint::Size(Size::fixed(5).into())]),
+            VisibleString::try_from("Jones").unwrap(),
+            &[0x4a, 0x6f, 0x6e, 0x65, 0x73]
+        );
+    }
+
+    #[test]
+    fn object_identifier() {
+        round_trip!(
+            oer,
+            ObjectIdentifier,
+            ObjectIdentifier::new(vec![1, 2, 840, 113549]).unwrap(),
+            &[0x06, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d]
+        );
+        round_trip!(
+            oer,
+            RelativeOid,
+            RelativeOid::new(vec![8571, 3, 2]).unwrap(),
+            &[0x04, 0xc2, 0x7b, 0x03, 0x02]
+        );
+    }
+
+    #[test]
+    fn sequence() {
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, crate_root = "crate")]
+        struct Sequence {
+            a: u8,
+            b: Option<bool>,
+            #[rasn(default = "default_c")]
+            c: u8,
+        }
+
+        fn default_c() -> u8 {
+            7
+        }
+
+        round_trip!(
+            oer,
+            Sequence,
+            Sequence {
+                a: 5,
+                b: None,
+                c: 7
+            },
+            &[0x00, 0x05]
+        );
+        round_trip!(
+            oer,
+            Sequence,
+            Sequence {
+                a: 5,
+                b: Some(true),
+                c: 1
+            },
+            &[0xc0, 0x05, 0xff, 0x01]
+        );
+    }
+
+    #[test]
+    fn extensible_sequence() {
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, crate_root = "crate")]
+        #[non_exhaustive]
+        struct Extensible {
+            a: u8,
+            #[rasn(extension_addition)]
+            b: Option<bool>,
+        }
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(automatic_tags, crate_root = "crate")]
+        #[non_exhaustive]
+        struct Root {
+            a: u8,
+        }
+
+        round_trip!(oer, Extensible, Extensible { a: 5, b: None }, &[0x00, 0x05]);
+        round_trip!(
+            oer,
+            Extensible,
+            Extensible {
+                a: 5,
+                b: Some(true)
+            },
+            &[0x80, 0x05, 0x02, 0x07, 0x80, 0x01, 0xff]
+        );
+
+        // A decoder without knowledge of the addition skips over it.
+        let encoded = crate::oer::encode(&Extensible {
+            a: 5,
+            b: Some(true),
+        })
+        .unwrap();
+        assert_eq!(Root { a: 5 }, crate::oer::decode::<Root>(&encoded).unwrap());
+    }
+
+    #[test]
+    fn choice() {
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags, crate_root = "crate")]
+        enum Choice {
+            A(u8),
+            B(bool),
+        }
+
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags, crate_root = "crate")]
+        #[non_exhaustive]
+        enum ExtensibleChoice {
+            A(u8),
+            #[rasn(extension_addition)]
+            B(bool),
+        }
+
+        round_trip!(oer, Choice, Choice::A(5), &[0x80, 0x05]);
+        round_trip!(oer, Choice, Choice::B(true), &[0x81, 0xff]);
+        round_trip!(oer, ExtensibleChoice, ExtensibleChoice::A(5), &[0x80, 0x05]);
+        round_trip!(
+            oer,
+            ExtensibleChoice,
+            ExtensibleChoice::B(true),
+            &[0x81, 0x01, 0xff]
+        );
+    }
+
+    #[test]
+    fn large_choice_tag() {
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(choice, crate_root = "crate")]
+        enum Choice {
+            #[rasn(tag(context, 100))]
+            A(u8),
+            #[rasn(tag(application, 1))]
+            B(bool),
+        }
+
+        round_trip!(oer, Choice, Choice::A(5), &[0xbf, 0x64, 0x05]);
+        round_trip!(oer, Choice, Choice::B(false), &[0x41, 0x00]);
+    }
+
+    #[test]
+    fn sequence_of() {
+        round_trip!(oer, Vec<u8>, vec![1, 2], &[0x01, 0x02, 0x01, 0x02]);
+        round_trip!(oer, Vec<u8>, vec![], &[0x01, 0x00]);
+    }
+
+    #[test]
+    fn set() {
+        #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+        #[rasn(set, crate_root = "crate")]
+        struct Set {
+            #[rasn(tag(context, 1))]
+            b: bool,
+            #[rasn(tag(context, 0))]
+            a: u8,
+            #[rasn(tag(context, 2))]
+            c: Option<u8>,
+        }
+
+        round_trip!(
+            oer,
+            Set,
+            Set {
+                a: 5,
+                b: true,
+                c: Some(1)
+            },
+            &[0x80, 0x05, 0xff, 0x01]
+        );
+    }
+
+    #[test]
+    fn generalized_time() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone};
+        let time = FixedOffset::east_opt(0)
+            .unwrap()
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(2024, 1, 2)
+                    .unwrap()
+                    .and_hms_opt(3, 4, 5)
+                    .unwrap(),
+            )
+            .unwrap();
+        let mut expected = vec![15];
+        expected.extend_from_slice(b"20240102030405Z");
+        round_trip!(oer, GeneralizedTime, time, &expected);
+    }
+
+    #[test]
+    fn time_types() {
+        let date = Date::from_ymd_opt(2024, 2, 29).unwrap();
+
+        round_trip!(oer, Date, date, &[&[0x08][..], b"20240229"].concat());
+        round_trip!(
+            oer,
+            Duration,
+            "P3W".parse().unwrap(),
+            &[&[0x03][..], b"P3W"].concat()
+        );
+    }
+
+    #[test]
+    fn limits() {
+        use crate::{
+            de::{Limit, Limits},
+            error::DecodeErrorKind,
+        };
+
+        fn decode<T: crate::Decode>(input: &[u8], limits: Limits) -> Result<T, Limit> {
+            let options = crate::oer::de::DecoderOptions::oer().with_limits(limits);
+            T::decode(&mut crate::oer::Decoder::new(input, options)).map_err(|error| {
+                match *error.kind {
+                    DecodeErrorKind::LimitExceeded { limit, .. } => limit,
+                    kind => panic!("unexpected error: {kind}"),
+                }
+            })
+        }
+
+        let nested = [0x01, 0x01, 0x01, 0x01, 0x01, 0x00];
+        assert!(decode::<Vec<Vec<Vec<()>>>>(&nested, Limits::default()).is_ok());
+        assert_eq!(
+            Err(Limit::Depth),
+            decode::<Vec<Vec<Vec<()>>>>(
+                &nested,
+                Limits {
+                    max_depth: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        // A huge number of elements that take no space at all.
+        assert_eq!(
+            Err(Limit::Elements),
+            decode::<Vec<()>>(&[0x04, 0xFF, 0xFF, 0xFF, 0xFF], Limits::default())
+        );
+        assert_eq!(
+            Err(Limit::Length),
+            decode::<OctetString>(
+                &[0x03, 0x01, 0x02, 0x03],
+                Limits {
+                    max_length: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        // The length of the quantity counts towards the total, as well as
+        // the lengths of the strings.
+        let strings = [0x01, 0x02, 0x02, 0x01, 0x02, 0x02, 0x03, 0x04];
+        let total_length = |max_total_length| Limits {
+            max_total_length,
+            ..Limits::default()
+        };
+        assert!(decode::<Vec<OctetString>>(&strings, total_length(5)).is_ok());
+        assert_eq!(
+            Err(Limit::TotalLength),
+            decode::<Vec<OctetString>>(&strings, total_length(4))
+        );
+    }
+}
@@ -0,0 +1,972 @@
+use alloc::{collections::VecDeque, string::ToString, vec::Vec};
+
+use super::{fixed_size, EncodingRules, IntegerWidth};
+use crate::{
+    de::{Error as _, Limits},
+    error::{CoerDecodeErrorKind, DecodeErrorKind, OerDecodeErrorKind},
+    types::{
+        self,
+        fields::{Field, Fields},
+        strings::StaticPermittedAlphabet,
+        Constraints, Enumerated, Tag,
+    },
+    Decode,
+};
+
+pub use crate::error::DecodeError;
+pub type Result<T, E = DecodeError> = core::result::Result<T, E>;
+
+/// The options for the OER [`Decoder`].
+#[derive(Clone, Copy, Debug)]
+pub struct DecoderOptions {
+    encoding_rules: EncodingRules,
+    limits: Limits,
+}
+
+impl DecoderOptions {
+    /// Return the default configuration for OER.
+    #[must_use]
+    pub const fn oer() -> Self {
+        Self {
+            encoding_rules: EncodingRules::Oer,
+            limits: Limits::DEFAULT,
+        }
+    }
+
+    /// Return the default configuration for C-OER, which rejects any
+    /// encoding that the canonical encoder would not have produced, such as
+    /// length determinants and integers that aren't in their shortest form,
+    /// or extension bitmaps with unexpected bits.
+    #[must_use]
+    pub const fn coer() -> Self {
+        Self {
+            encoding_rules: EncodingRules::Coer,
+            limits: Limits::DEFAULT,
+        }
+    }
+
+    /// Returns these options with the given resource `limits`.
+    #[must_use]
+    pub const fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the resource limits of these options.
+    #[must_use]
+    pub const fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    #[must_use]
+    pub fn current_codec(self) -> crate::Codec {
+        match self.encoding_rules {
+            EncodingRules::Oer => crate::Codec::Oer,
+            EncodingRules::Coer => crate::Codec::Coer,
+        }
+    }
+}
+
+pub struct Decoder<'input> {
+    input: &'input [u8],
+    options: DecoderOptions,
+    /// When the decoder contains fields, we check against optional or default
+    /// fields to know the presence of those fields.
+    fields: VecDeque<(Field, bool)>,
+    extension_fields: Option<Fields>,
+    extensions_present: Option<Option<VecDeque<bool>>>,
+    /// How many constructed values the current value is nested in.
+    depth: usize,
+    /// The sum of the lengths decoded so far, checked against the maximum
+    /// total length.
+    total_length: usize,
+}
+
+impl<'input> Decoder<'input> {
+    pub fn codec(&self) -> crate::Codec {
+        self.options.current_codec()
+    }
+
+    pub fn new(input: &'input [u8], options: DecoderOptions) -> Self {
+        Self {
+            input,
+            options,
+            fields: <_>::default(),
+            extension_fields: <_>::default(),
+            extensions_present: <_>::default(),
+            depth: 0,
+            total_length: 0,
+        }
+    }
+
+    /// Returns the depth of a value nested in the current one, checking it
+    /// against the maximum nesting depth.
+    fn nested_depth(&self) -> Result<usize> {
+        self.options
+            .limits
+            .check_depth(self.depth + 1, self.codec())
+    }
+
+    /// Creates a decoder for a value nested one level deeper than the
+    /// current one. The lengths it decodes count towards the current
+    /// decoder's total once its `total_length` is copied back.
+    fn nested<'a>(&self, input: &'a [u8]) -> Result<Decoder<'a>> {
+        let mut decoder = Decoder::new(input, self.options);
+        decoder.depth = self.nested_depth()?;
+        decoder.total_length = self.total_length;
+        Ok(decoder)
+    }
+
+    /// Runs `f` on this decoder one level deeper than the current value.
+    fn nest<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let depth = self.depth;
+        self.depth = self.nested_depth()?;
+        let result = (f)(self);
+        self.depth = depth;
+        result
+    }
+
+    /// Returns the remaining input, if any.
+    pub fn input(&self) -> &'input [u8] {
+        self.input
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'input [u8]> {
+        let codec = self.codec();
+        let (input, output) = nom::bytes::streaming::take(length)(self.input)
+            .map_err(|e| DecodeError::map_nom_err(e, codec))?;
+        self.input = input;
+        Ok(output)
+    }
+
+    fn parse_one_octet(&mut self) -> Result<u8> {
+        self.take(1).map(|octets| octets[0])
+    }
+
+    #[track_caller]
+    fn require_field(&mut self, tag: Tag) -> Result<bool> {
+        if self
+            .fields
+            .front()
+            .map(|field| field.0.tag_tree.smallest_tag() == tag)
+            .unwrap_or_default()
+        {
+            Ok(self.fields.pop_front().unwrap().1)
+        } else {
+            Err(DecodeError::missing_tag_class_or_value_in_sequence_or_set(
+                tag.class,
+                tag.value,
+                self.codec(),
+            ))
+        }
+    }
+
+    /// Decodes a length determinant (ITU-T X.696 (02/2021) §8.6).
+    fn decode_length(&mut self) -> Result<usize> {
+        let initial = self.parse_one_octet()?;
+        if initial & 0x80 == 0 {
+            return self.check_length(usize::from(initial));
+        }
+
+        let octets = usize::from(initial & 0x7F);
+        if octets == 0 {
+            return Err(OerDecodeErrorKind::InvalidLengthDeterminant { initial }.into());
+        }
+
+        let bytes = self.take(octets)?;
+        let significant = &bytes[bytes.iter().take_while(|byte| **byte == 0).count()..];
+        if significant.len() > core::mem::size_of::<usize>() {
+            return Err(DecodeError::range_exceeds_platform_width(
+                usize::BITS,
+                (significant.len() * 8) as u32,
+                self.codec(),
+            ));
+        }
+
+        let length = significant
+            .iter()
+            .fold(0, |length, byte| (length << 8) | usize::from(*byte));
+
+        if self.options.encoding_rules.is_coer() && (length < 128 || significant.len() != octets) {
+            return Err(CoerDecodeErrorKind::NonCanonicalLengthDeterminant { length }.into());
+        }
+
+        self.check_length(length)
+    }
+
+    fn check_length(&mut self, length: usize) -> Result<usize> {
+        let codec = self.codec();
+        self.options
+            .limits
+            .check_length_within_total(length, &mut self.total_length, codec)
+    }
+
+    /// Checks that a variable width integer uses the fewest octets possible,
+    /// which is only required by C-OER.
+    fn check_minimal_integer(&self, octets: &[u8], signed: bool) -> Result<()> {
+        if !self.options.encoding_rules.is_coer() {
+            return Ok(());
+        }
+
+        let is_minimal = match octets {
+            [] => false,
+            [_] => true,
+            [first, second, ..] if signed => {
+                !matches!((first, second & 0x80), (0x00, 0) | (0xFF, 0x80))
+            }
+            [first, ..] => *first != 0,
+        };
+
+        if is_minimal {
+            Ok(())
+        } else {
+            Err(CoerDecodeErrorKind::NonMinimalInteger.into())
+        }
+    }
+
+    /// Checks that the unused trailing bits of `octets` are zero, which is
+    /// only required by C-OER.
+    fn check_padding(&self, octets: &[u8], bits: usize) -> Result<()> {
+        if self.options.encoding_rules.is_coer()
+            && bitvec::slice::BitSlice::<u8, bitvec::order::Msb0>::from_slice(octets)[bits..].any()
+        {
+            return Err(CoerDecodeErrorKind::NonZeroPadding.into());
+        }
+
+        Ok(())
+    }
+
+    fn decode_octets_with_length(&mut self) -> Result<&'input [u8]> {
+        let length = self.decode_length()?;
+        self.take(length)
+    }
+
+    /// Decodes one of the time types from a length prefixed string of its
+    /// ISO 8601 basic format representation.
+    fn decode_time_type<T: types::TimeType>(&mut self) -> Result<T> {
+        let octets = self.decode_octets_with_length()?;
+        T::from_basic_bytes(octets)
+            .map_err(|reason| DecodeError::invalid_time(reason, self.codec()))
+    }
+
+    /// Decodes the tag of a `CHOICE` alternative (ITU-T X.696 (02/2021) §8.7),
+    /// returning the tag along with the remaining input.
+    fn peek_tag(&self) -> Result<(Tag, &'input [u8])> {
+        let mut decoder = Self::new(self.input, self.options);
+        let initial = decoder.parse_one_octet()?;
+        let class = types::Class::from_u8(initial >> 6);
+        let mut value = u32::from(initial & 0x3F);
+
+        if value == 0x3F {
+            value = 0;
+            loop {
+                let octet = decoder.parse_one_octet()?;
+                value = value
+                    .checked_mul(128)
+                    .ok_or_else(|| DecodeError::integer_overflow(u32::BITS, self.codec()))?
+                    | u32::from(octet & 0x7F);
+                if octet & 0x80 == 0 {
+                    break;
+                }
+            }
+        }
+
+        Ok((Tag::new(class, value), decoder.input))
+    }
+
+    fn decode_string<S, F>(
+        &mut self,
+        tag: Tag,
+        constraints: &Constraints,
+        width: usize,
+        f: F,
+    ) -> Result<S>
+    where
+        F: FnOnce(Vec<u8>) -> Result<S>,
+    {
+        let octets = match fixed_size(constraints) {
+            Some(size) => self.take(size * width)?,
+            None => self.decode_octets_with_length()?,
+        };
+
+        if octets.len() % width != 0 {
+            return Err(DecodeError::string_conversion_failed(
+                tag,
+                alloc::format!("{} octets is not a multiple of {width}", octets.len()),
+                self.codec(),
+            ));
+        }
+
+        (f)(octets.to_vec())
+    }
+
+    fn decode_known_multiplier_string<S: StaticPermittedAlphabet>(
+        &mut self,
+        tag: Tag,
+        constraints: &Constraints,
+        width: usize,
+    ) -> Result<S> {
+        let codec = self.codec();
+        self.decode_string(tag, constraints, width, |octets| {
+            let mut string = S::default();
+            for chunk in octets.chunks(width) {
+                let ch = chunk
+                    .iter()
+                    .fold(0u32, |ch, byte| (ch << 8) | u32::from(*byte));
+                if !S::contains_char(ch) {
+                    return Err(DecodeError::string_conversion_failed(
+                        tag,
+                        alloc::format!("{ch:#x} is not in the permitted alphabet"),
+                        codec,
+                    ));
+                }
+                string.push_char(ch);
+            }
+            Ok(string)
+        })
+    }
+
+    fn parse_integer(&mut self, constraints: &Constraints) -> Result<types::Integer> {
+        Ok(match IntegerWidth::from_constraints(constraints) {
+            IntegerWidth::Fixed {
+                octets,
+                signed: true,
+            } => types::Integer::from_signed_bytes_be(self.take(octets)?),
+            IntegerWidth::Fixed {
+                octets,
+                signed: false,
+            } => types::Integer::from_bytes_be(num_bigint::Sign::Plus, self.take(octets)?),
+            IntegerWidth::Variable { signed } => {
+                let octets = self.decode_octets_with_length()?;
+                self.check_minimal_integer(octets, signed)?;
+                if signed {
+                    types::Integer::from_signed_bytes_be(octets)
+                } else {
+                    types::Integer::from_bytes_be(num_bigint::Sign::Plus, octets)
+                }
+            }
+        })
+    }
+
+    /// Parses the extension presence bitmap of a `SEQUENCE` or `SET`, if it
+    /// hasn't already been parsed, returning whether any extensions are present.
+    fn parse_extension_header(&mut self) -> Result<bool> {
+        match self.extensions_present {
+            Some(Some(_)) => return Ok(true),
+            Some(None) => (),
+            None => return Ok(false),
+        }
+
+        let octets = self.decode_octets_with_length()?;
+        let Some((unused, bitmap)) = octets.split_first() else {
+            return Err(DecodeError::invalid_bit_string(0, self.codec()));
+        };
+
+        if *unused > 7 || (bitmap.is_empty() && *unused != 0) {
+            return Err(DecodeError::invalid_bit_string(*unused, self.codec()));
+        }
+
+        let bits = bitmap.len() * 8 - usize::from(*unused);
+        self.check_padding(bitmap, bits)?;
+        let bitmap = bitvec::slice::BitSlice::<u8, bitvec::order::Msb0>::from_slice(bitmap);
+        // ITU-T X.696 (02/2021) §16.2.2: The extension bit is only set when
+        // at least one extension addition is present.
+        if self.options.encoding_rules.is_coer() && !bitmap.any() {
+            return Err(CoerDecodeErrorKind::EmptyExtensionBitmap.into());
+        }
+
+        self.extensions_present = Some(Some(bitmap[..bits].iter().map(|b| *b).collect()));
+
+        Ok(true)
+    }
+
+    fn extension_is_present(&mut self) -> Result<bool> {
+        let codec = self.codec();
+        Ok(self
+            .extensions_present
+            .as_mut()
+            .ok_or_else(|| DecodeError::type_not_extensible(codec))?
+            .as_mut()
+            .ok_or_else(|| DecodeError::type_not_extensible(codec))?
+            .pop_front()
+            .unwrap_or_default())
+    }
+
+    /// Skips over any extension additions that are unknown to this decoder.
+    fn skip_unknown_extensions(&mut self) -> Result<()> {
+        if !self.parse_extension_header()? {
+            return Ok(());
+        }
+
+        while let Some(is_present) = self
+            .extensions_present
+            .as_mut()
+            .and_then(Option::as_mut)
+            .and_then(VecDeque::pop_front)
+        {
+            if is_present {
+                self.decode_octets_with_length()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_preamble(
+        &mut self,
+        is_extensible: bool,
+        fields: &Fields,
+    ) -> Result<(bool, Vec<bool>)> {
+        let optional_fields = fields.optional_and_default_fields().count();
+        let length = usize::from(is_extensible) + optional_fields;
+        let octets = self.take(length.div_ceil(8))?;
+        self.check_padding(octets, length)?;
+        let bits = bitvec::slice::BitSlice::<u8, bitvec::order::Msb0>::from_slice(octets);
+        let (extension_bit, presence) = if is_extensible {
+            (bits[0], &bits[1..length])
+        } else {
+            (false, &bits[..length])
+        };
+
+        Ok((extension_bit, presence.iter().map(|b| *b).collect()))
+    }
+
+    fn new_constructed_decoder<D: crate::types::Constructed>(
+        &self,
+        is_extended: bool,
+    ) -> Result<Self> {
+        let mut decoder = self.nested(self.input)?;
+        decoder.extension_fields = D::EXTENDED_FIELDS;
+        decoder.extensions_present = is_extended.then_some(None);
+        Ok(decoder)
+    }
+}
+
+impl<'input> crate::Decoder for Decoder<'input> {
+    type Error = DecodeError;
+
+    fn codec(&self) -> crate::Codec {
+        Self::codec(self)
+    }
+
+    fn decode_any(&mut self) -> Result<types::Any> {
+        Ok(types::Any::new(self.decode_octets_with_length()?.to_vec()))
+    }
+
+    fn decode_bool(&mut self, _: Tag) -> Result<bool> {
+        match self.parse_one_octet()? {
+            0 => Ok(false),
+            0xFF => Ok(true),
+            _ if self.options.encoding_rules.is_oer() => Ok(true),
+            value => Err(DecodeError::from_kind(
+                DecodeErrorKind::InvalidBool { value },
+                self.codec(),
+            )),
+        }
+    }
+
+    fn decode_enumerated<E: Enumerated>(&mut self, _: Tag) -> Result<E> {
+        let initial = self.parse_one_octet()?;
+        let discriminant = if initial & 0x80 == 0 {
+            isize::from(initial)
+        } else {
+            let octets = self.take(usize::from(initial & 0x7F))?;
+            self.check_minimal_integer(octets, true)?;
+            let integer = types::Integer::from_signed_bytes_be(octets);
+            let discriminant = isize::try_from(&integer).map_err(|e| {
+                DecodeError::integer_type_conversion_failed(e.to_string(), self.codec())
+            })?;
+
+            if self.options.encoding_rules.is_coer() && (0..=127).contains(&discriminant) {
+                return Err(CoerDecodeErrorKind::NonCanonicalEnumerated { discriminant }.into());
+            }
+
+            discriminant
+        };
+
+        E::from_discriminant(discriminant).ok_or_else(|| {
+            DecodeError::enumeration_index_not_found(
+                discriminant as usize,
+                E::EXTENDED_VARIANTS.is_some(),
+                self.codec(),
+            )
+        })
+    }
+
+    fn decode_integer(&mut self, _: Tag, constraints: Constraints) -> Result<types::Integer> {
+        self.parse_integer(&constraints)
+    }
+
+    fn decode_octet_string(&mut self, tag: Tag, constraints: Constraints) -> Result<Vec<u8>> {
+        self.decode_string(tag, &constraints, 1, Ok)
+    }
+
+    fn decode_null(&mut self, _: Tag) -> Result<()> {
+        Ok(())
+    }
+
+    fn decode_real(&mut self, _: Tag, _: Constraints) -> Result<types::Real> {
+        let octets = self.decode_octets_with_length()?;
+        let options = if self.options.encoding_rules.is_coer() {
+            crate::ber::de::DecoderOptions::der()
+        } else {
+            crate::ber::de::DecoderOptions::ber()
+        };
+        crate::ber::de::Decoder::new(octets, options).decode_real_from_bytes(octets)
+    }
+
+    fn decode_object_identifier(&mut self, _: Tag) -> Result<crate::types::ObjectIdentifier> {
+        let octets = self.decode_octets_with_length()?;
+        let ber_decoder =
+            crate::ber::de::Decoder::new(octets, crate::ber::de::DecoderOptions::ber());
+        ber_decoder.decode_object_identifier_from_bytes(octets)
+    }
+
+    fn decode_relative_oid(&mut self, _: Tag) -> Result<types::RelativeOid> {
+        let octets = self.decode_octets_with_length()?;
+        let ber_decoder =
+            crate::ber::de::Decoder::new(octets, crate::ber::de::DecoderOptions::ber());
+        ber_decoder.decode_relative_oid_from_bytes(octets)
+    }
+
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri> {
+        let iri = self.decode_utf8_string(tag, <_>::default())?;
+        types::OidIri::new(iri.clone())
+            .ok_or_else(|| DecodeError::invalid_oid_iri(iri, self.codec()))
+    }
+
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri> {
+        let iri = self.decode_utf8_string(tag, <_>::default())?;
+        types::RelativeOidIri::new(iri.clone())
+            .ok_or_else(|| DecodeError::invalid_oid_iri(iri, self.codec()))
+    }
+
+    fn decode_bit_string(&mut self, _: Tag, constraints: Constraints) -> Result<types::BitString> {
+        if let Some(size) = fixed_size(&constraints) {
+            let octets = self.take(size.div_ceil(8))?;
+            self.check_padding(octets, size)?;
+            let mut bits = types::BitString::from_slice(octets);
+            bits.truncate(size);
+            return Ok(bits);
+        }
+
+        let octets = self.decode_octets_with_length()?;
+        let Some((unused, octets)) = octets.split_first() else {
+            return Err(DecodeError::invalid_bit_string(0, self.codec()));
+        };
+
+        if *unused > 7 || (octets.is_empty() && *unused != 0) {
+            return Err(DecodeError::invalid_bit_string(*unused, self.codec()));
+        }
+
+        self.check_padding(octets, octets.len() * 8 - usize::from(*unused))?;
+        let mut bits = types::BitString::from_slice(octets);
+        bits.truncate(octets.len() * 8 - usize::from(*unused));
+        Ok(bits)
+    }
+
+    fn decode_visible_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::VisibleString, Self::Error> {
+        self.decode_known_multiplier_string(tag, &constraints, 1)
+    }
+
+    fn decode_ia5_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::Ia5String> {
+        self.decode_known_multiplier_string(tag, &constraints, 1)
+    }
+
+    fn decode_printable_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::PrintableString> {
+        self.decode_known_multiplier_string(tag, &constraints, 1)
+    }
+
+    fn decode_numeric_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::NumericString> {
+        self.decode_known_multiplier_string(tag, &constraints, 1)
+    }
+
+    fn decode_teletex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::TeletexString> {
+        self.decode_string(tag, &constraints, 1, |octets| {
+            Ok(types::TeletexString::from(octets))
+        })
+    }
+
+    fn decode_bmp_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::BmpString> {
+        self.decode_known_multiplier_string(tag, &constraints, 2)
+    }
+
+    fn decode_universal_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::UniversalString> {
+        self.decode_known_multiplier_string(tag, &constraints, 4)
+    }
+
+    fn decode_utf8_string(&mut self, _: Tag, _: Constraints) -> Result<types::Utf8String> {
+        let octets = self.decode_octets_with_length()?.to_vec();
+        types::Utf8String::from_utf8(octets).map_err(|e| {
+            DecodeError::string_conversion_failed(
+                types::Tag::UTF8_STRING,
+                e.to_string(),
+                self.codec(),
+            )
+        })
+    }
+
+    fn decode_general_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::GeneralString> {
+        let codec = self.codec();
+        self.decode_string(tag, &constraints, 1, |octets| {
+            types::GeneralString::try_from(octets).map_err(|e| {
+                DecodeError::string_conversion_failed(
+                    types::Tag::GENERAL_STRING,
+                    e.to_string(),
+                    codec,
+                )
+            })
+        })
+    }
+
+    fn decode_graphic_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::GraphicString> {
+        let codec = self.codec();
+        self.decode_string(tag, &constraints, 1, |octets| {
+            types::GraphicString::try_from(octets).map_err(|e| {
+                DecodeError::string_conversion_failed(
+                    types::Tag::GRAPHIC_STRING,
+                    e.to_string(),
+                    codec,
+                )
+            })
+        })
+    }
+
+    fn decode_videotex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::VideotexString> {
+        self.decode_string(tag, &constraints, 1, |octets| {
+            Ok(types::VideotexString::from(octets))
+        })
+    }
+
+    fn decode_generalized_time(&mut self, _: Tag) -> Result<types::GeneralizedTime> {
+        let octets = self.decode_octets_with_length()?;
+        let string = core::str::from_utf8(octets).map_err(|e| {
+            DecodeError::string_conversion_failed(
+                types::Tag::GENERALIZED_TIME,
+                e.to_string(),
+                self.codec(),
+            )
+        })?;
+        crate::ber::de::Decoder::parse_any_generalized_time_string(string.into())
+    }
+
+    fn decode_lossless_generalized_time(
+        &mut self,
+        _: Tag,
+    ) -> Result<types::LosslessGeneralizedTime> {
+        let octets = self.decode_octets_with_length()?;
+        let string = core::str::from_utf8(octets).map_err(|e| {
+            DecodeError::string_conversion_failed(
+                types::Tag::GENERALIZED_TIME,
+                e.to_string(),
+                self.codec(),
+            )
+        })?;
+        string
+            .parse()
+            .map_err(|reason| DecodeError::invalid_time(reason, self.codec()))
+    }
+
+    fn decode_utc_time(&mut self, _: Tag) -> Result<types::UtcTime> {
+        let octets = self.decode_octets_with_length()?;
+        let string = core::str::from_utf8(octets).map_err(|e| {
+            DecodeError::string_conversion_failed(types::Tag::UTC_TIME, e.to_string(), self.codec())
+        })?;
+        crate::ber::de::Decoder::parse_any_utc_time_string(string.into())
+    }
+
+    fn decode_date(&mut self, _: Tag) -> Result<types::Date> {
+        self.decode_time_type()
+    }
+
+    fn decode_time_of_day(&mut self, _: Tag) -> Result<types::TimeOfDay> {
+        self.decode_time_type()
+    }
+
+    fn decode_date_time(&mut self, _: Tag) -> Result<types::DateTime> {
+        self.decode_time_type()
+    }
+
+    fn decode_duration(&mut self, _: Tag) -> Result<types::Duration> {
+        self.decode_time_type()
+    }
+
+    fn decode_time(&mut self, _: Tag) -> Result<types::TimeValue> {
+        self.decode_time_type()
+    }
+
+    fn decode_sequence_of<D: Decode>(
+        &mut self,
+        _: Tag,
+        _: Constraints,
+    ) -> Result<Vec<D>, Self::Error> {
+        let quantity = types::Integer::from_bytes_be(
+            num_bigint::Sign::Plus,
+            self.decode_octets_with_length()?,
+        );
+        let quantity = usize::try_from(&quantity).map_err(|_| {
+            DecodeError::exceeds_max_length(quantity.magnitude().clone(), self.codec())
+        })?;
+        self.options.limits.check_elements(quantity, self.codec())?;
+
+        let depth = self.nested_depth()?;
+        let mut sequence_of = Vec::new();
+        for index in 0..quantity {
+            let mut decoder = Self::new(self.input, self.options);
+            decoder.depth = depth;
+            decoder.total_length = self.total_length;
+            sequence_of.push(D::decode(&mut decoder).map_err(|error| error.at_index(index))?);
+            self.input = decoder.input;
+            self.total_length = decoder.total_length;
+        }
+
+        Ok(sequence_of)
+    }
+
+    fn decode_set_of<D: Decode + Ord>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::SetOf<D>, Self::Error> {
+        self.decode_sequence_of(tag, constraints)
+            .map(|seq| seq.into_iter().collect())
+    }
+
+    fn decode_sequence<D, DF, F>(
+        &mut self,
+        _: Tag,
+        _: Option<DF>,
+        decode_fn: F,
+    ) -> Result<D, Self::Error>
+    where
+        D: crate::types::Constructed,
+        DF: FnOnce() -> D,
+        F: FnOnce(&mut Self) -> Result<D, Self::Error>,
+    {
+        let (is_extended, bitmap) =
+            self.parse_preamble(D::EXTENDED_FIELDS.is_some(), &D::FIELDS)?;
+
+        let mut sequence_decoder = self.new_constructed_decoder::<D>(is_extended)?;
+        sequence_decoder.fields = D::FIELDS
+            .optional_and_default_fields()
+            .zip(bitmap)
+            .collect();
+        let value = (decode_fn)(&mut sequence_decoder)?;
+        sequence_decoder.skip_unknown_extensions()?;
+
+        self.input = sequence_decoder.input;
+        self.total_length = sequence_decoder.total_length;
+        Ok(value)
+    }
+
+    fn decode_explicit_prefix<D: Decode>(&mut self, tag: Tag) -> Result<D> {
+        // Explicit tags aren't encoded, so the only thing to check is whether
+        // an optional or default field was marked as present in the preamble.
+        if self
+            .fields
+            .front()
+            .is_some_and(|field| field.0.tag_tree.smallest_tag() == tag)
+            && !self.require_field(tag)?
+        {
+            return Err(DecodeError::missing_tag_class_or_value_in_sequence_or_set(
+                tag.class,
+                tag.value,
+                self.codec(),
+            ));
+        }
+
+        D::decode(self)
+    }
+
+    fn decode_set<FIELDS, SET, D, F>(
+        &mut self,
+        _: Tag,
+        decode_fn: D,
+        field_fn: F,
+    ) -> Result<SET, Self::Error>
+    where
+        SET: Decode + crate::types::Constructed,
+        FIELDS: Decode,
+        D: Fn(&mut Self, usize, Tag) -> Result<FIELDS, Self::Error>,
+        F: FnOnce(Vec<FIELDS>) -> Result<SET, Self::Error>,
+    {
+        let canonical_fields = SET::FIELDS.canonised();
+        let (is_extended, bitmap) =
+            self.parse_preamble(SET::EXTENDED_FIELDS.is_some(), &canonical_fields)?;
+        let field_map = canonical_fields
+            .optional_and_default_fields()
+            .zip(bitmap)
+            .collect::<alloc::collections::BTreeMap<_, _>>();
+
+        let mut fields = Vec::new();
+        let mut set_decoder = self.new_constructed_decoder::<SET>(is_extended)?;
+
+        let mut field_indices = SET::FIELDS.iter().enumerate().collect::<Vec<_>>();
+        field_indices.sort_by_key(|(_, field)| field.tag_tree.smallest_tag());
+        for (indice, field) in field_indices {
+            match field_map.get(&field).copied() {
+                Some(true) | None => fields.push((decode_fn)(&mut set_decoder, indice, field.tag)?),
+                Some(false) => {}
+            }
+        }
+
+        for (indice, field) in SET::EXTENDED_FIELDS
+            .iter()
+            .flat_map(|fields| fields.iter())
+            .enumerate()
+        {
+            fields.push((decode_fn)(
+                &mut set_decoder,
+                indice + SET::FIELDS.len(),
+                field.tag,
+            )?)
+        }
+        set_decoder.skip_unknown_extensions()?;
+
+        self.input = set_decoder.input;
+        self.total_length = set_decoder.total_length;
+        (field_fn)(fields)
+    }
+
+    fn decode_optional<D: Decode>(&mut self) -> Result<Option<D>, Self::Error> {
+        self.decode_optional_with_tag(D::TAG_TREE.smallest_tag())
+    }
+
+    /// Decode an the optional value in a `SEQUENCE` or `SET` with `tag`.
+    /// Passing the correct tag is required even when used with codecs where
+    /// the tag is not present.
+    fn decode_optional_with_tag<D: Decode>(&mut self, tag: Tag) -> Result<Option<D>, Self::Error> {
+        let is_present = self.require_field(tag)?;
+
+        if is_present {
+            D::decode_with_tag(self, tag).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn decode_optional_with_constraints<D: Decode>(
+        &mut self,
+        constraints: Constraints,
+    ) -> Result<Option<D>, Self::Error> {
+        let is_present = self.require_field(D::TAG_TREE.smallest_tag())?;
+
+        if is_present {
+            D::decode_with_constraints(self, constraints).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn decode_optional_with_tag_and_constraints<D: Decode>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Option<D>, Self::Error> {
+        let is_present = self.require_field(tag)?;
+
+        if is_present {
+            D::decode_with_tag_and_constraints(self, tag, constraints).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn decode_choice<D>(&mut self, _: Constraints) -> Result<D, Self::Error>
+    where
+        D: crate::types::DecodeChoice,
+    {
+        let (tag, input) = self.peek_tag()?;
+        let is_root_leaf = D::VARIANTS.contains(&crate::TagTree::Leaf(tag));
+
+        if is_root_leaf {
+            self.input = input;
+            self.nest(|decoder| D::from_tag(decoder, tag))
+        } else if crate::TagTree::tag_contains(&tag, D::VARIANTS) {
+            // The tag belongs to a nested untagged `CHOICE`, which decodes
+            // its own tag.
+            self.nest(|decoder| D::from_tag(decoder, tag))
+        } else if crate::TagTree::tag_contains(&tag, D::EXTENDED_VARIANTS.unwrap_or(&[])) {
+            self.input = input;
+            let octets = self.decode_octets_with_length()?;
+            let mut decoder = self.nested(octets)?;
+            let value = D::from_tag(&mut decoder, tag)?;
+            self.total_length = decoder.total_length;
+            Ok(value)
+        } else {
+            Err(OerDecodeErrorKind::UnknownChoiceTag { tag }.into())
+        }
+    }
+
+    fn decode_extension_addition_group<D: Decode + crate::types::Constructed>(
+        &mut self,
+    ) -> Result<Option<D>, Self::Error> {
+        if !self.parse_extension_header()? || !self.extension_is_present()? {
+            return Ok(None);
+        }
+
+        let octets = self.decode_octets_with_length()?;
+        let mut decoder = self.nested(octets)?;
+        let value = D::decode(&mut decoder)?;
+        self.total_length = decoder.total_length;
+        Ok(Some(value))
+    }
+
+    fn decode_extension_addition_with_constraints<D>(
+        &mut self,
+        constraints: Constraints,
+    ) -> core::result::Result<Option<D>, Self::Error>
+    where
+        D: Decode,
+    {
+        if !self.parse_extension_header()? || !self.extension_is_present()? {
+            return Ok(None);
+        }
+
+        let octets = self.decode_octets_with_length()?;
+        let mut decoder = self.nested(octets)?;
+        let value = D::decode_with_constraints(&mut decoder, constraints)?;
+        self.total_length = decoder.total_length;
+        Ok(Some(value))
+    }
+}
@@ -0,0 +1,808 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+use bitvec::prelude::*;
+
+use super::{fixed_size, EncodingRules, IntegerWidth};
+use crate::{
+    types::{
+        self, fields::FieldPresence, strings::StaticPermittedAlphabet, BitStr, Constraints,
+        Enumerated, Tag,
+    },
+    Encode,
+};
+
+pub use crate::error::EncodeError as Error;
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct EncoderOptions {
+    encoding_rules: EncodingRules,
+    set_encoding: bool,
+}
+
+impl EncoderOptions {
+    /// Return the default configuration for OER.
+    #[must_use]
+    pub const fn oer() -> Self {
+        Self {
+            encoding_rules: EncodingRules::Oer,
+            set_encoding: false,
+        }
+    }
+
+    /// Return the default configuration for C-OER.
+    #[must_use]
+    pub const fn coer() -> Self {
+        Self {
+            encoding_rules: EncodingRules::Coer,
+            set_encoding: false,
+        }
+    }
+
+    #[must_use]
+    fn without_set_encoding(mut self) -> Self {
+        self.set_encoding = false;
+        self
+    }
+
+    #[must_use]
+    pub fn current_codec(self) -> crate::Codec {
+        match self.encoding_rules {
+            EncodingRules::Oer => crate::Codec::Oer,
+            EncodingRules::Coer => crate::Codec::Coer,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Encoder {
+    options: EncoderOptions,
+    output: Vec<u8>,
+    set_output: BTreeMap<Tag, Vec<u8>>,
+    field_bitfield: BTreeMap<Tag, (FieldPresence, bool)>,
+    extension_fields: Vec<Option<Vec<u8>>>,
+}
+
+impl Encoder {
+    pub fn new(options: EncoderOptions) -> Self {
+        Self {
+            options,
+            output: <_>::default(),
+            set_output: <_>::default(),
+            field_bitfield: <_>::default(),
+            extension_fields: <_>::default(),
+        }
+    }
+
+    fn codec(&self) -> crate::Codec {
+        self.options.current_codec()
+    }
+
+    fn new_constructed_encoder(&self, fields: &types::fields::Fields, set_encoding: bool) -> Self {
+        let mut options = self.options;
+        options.set_encoding = set_encoding;
+        let mut encoder = Self::new(options);
+        encoder.field_bitfield = fields
+            .iter()
+            .map(|field| (field.tag_tree.smallest_tag(), (field.presence, false)))
+            .collect();
+        encoder
+    }
+
+    pub fn output(self) -> Vec<u8> {
+        if self.options.set_encoding {
+            self.set_output.into_values().flatten().collect()
+        } else {
+            self.output
+        }
+    }
+
+    pub fn set_bit(&mut self, tag: Tag, bit: bool) -> Result<()> {
+        self.field_bitfield.entry(tag).and_modify(|(_, b)| *b = bit);
+        Ok(())
+    }
+
+    fn extend(&mut self, tag: Tag, bytes: &[u8]) {
+        if self.options.set_encoding {
+            self.set_output.insert(tag, bytes.to_vec());
+        } else {
+            self.output.extend_from_slice(bytes);
+        }
+    }
+
+    /// Encodes a length determinant (ITU-T X.696 (02/2021) §8.6), always in
+    /// its shortest form.
+    fn encode_length(buffer: &mut Vec<u8>, length: usize) {
+        if length < 128 {
+            buffer.push(length as u8);
+        } else {
+            let bytes = length.to_be_bytes();
+            let leading_zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+            buffer.push(0x80 | (bytes.len() - leading_zeros) as u8);
+            buffer.extend_from_slice(&bytes[leading_zeros..]);
+        }
+    }
+
+    fn encode_octets_with_length(buffer: &mut Vec<u8>, octets: &[u8]) {
+        Self::encode_length(buffer, octets.len());
+        buffer.extend_from_slice(octets);
+    }
+
+    /// Encodes the tag of a `CHOICE` alternative (ITU-T X.696 (02/2021) §8.7).
+    fn encode_tag(buffer: &mut Vec<u8>, tag: Tag) {
+        let class = (tag.class as u8) << 6;
+        if tag.value < 63 {
+            buffer.push(class | tag.value as u8);
+        } else {
+            buffer.push(class | 0x3F);
+            let mut octets = Vec::new();
+            let mut value = tag.value;
+            loop {
+                octets.push((value & 0x7F) as u8 | if octets.is_empty() { 0 } else { 0x80 });
+                value >>= 7;
+                if value == 0 {
+                    break;
+                }
+            }
+            octets.reverse();
+            buffer.extend(octets);
+        }
+    }
+
+    /// Encodes a string whose length is counted in `length` units, omitting
+    /// the length determinant when the size constraint is fixed.
+    fn encode_string(
+        &mut self,
+        tag: Tag,
+        constraints: &Constraints,
+        length: usize,
+        octets: &[u8],
+    ) -> Result<()> {
+        let mut buffer = Vec::new();
+        if let Some(size) = constraints.size().filter(|size| size.extensible.is_none()) {
+            Error::check_length(length, &size.constraint, self.codec())?;
+        }
+
+        if fixed_size(constraints).is_some() {
+            buffer.extend_from_slice(octets);
+        } else {
+            Self::encode_octets_with_length(&mut buffer, octets);
+        }
+
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    /// Encodes one of the time types as a length prefixed string of its
+    /// ISO 8601 basic format representation, as BER does.
+    fn encode_time_type<T: types::TimeType>(&mut self, tag: Tag, value: &T) -> Result<()> {
+        self.set_bit(tag, true)?;
+        let string = value
+            .to_basic()
+            .map_err(|reason| Error::invalid_time(reason, self.codec()))?;
+        self.encode_string(
+            tag,
+            &Constraints::default(),
+            string.len(),
+            string.as_bytes(),
+        )
+    }
+
+    fn encode_known_multiplier_string<S: StaticPermittedAlphabet>(
+        &mut self,
+        tag: Tag,
+        constraints: &Constraints,
+        value: &S,
+        width: usize,
+    ) -> Result<()> {
+        let mut octets = Vec::with_capacity(value.len() * width);
+        for ch in value.chars() {
+            octets.extend_from_slice(&ch.to_be_bytes()[4 - width..]);
+        }
+        self.encode_string(tag, constraints, value.len(), &octets)
+    }
+
+    fn encode_integer_into_buffer(
+        &self,
+        constraints: &Constraints,
+        value: &num_bigint::BigInt,
+        buffer: &mut Vec<u8>,
+    ) -> Result<()> {
+        if let Some(bounds) = constraints
+            .value()
+            .filter(|value| value.extensible.is_none())
+        {
+            if !bounds.constraint.bigint_contains(value) {
+                return Err(Error::value_constraint_not_satisfied(
+                    value.clone(),
+                    *bounds.constraint,
+                    self.codec(),
+                ));
+            }
+        }
+
+        match IntegerWidth::from_constraints(constraints) {
+            IntegerWidth::Fixed { octets, signed } => {
+                let bytes = if signed {
+                    value.to_signed_bytes_be()
+                } else {
+                    value.magnitude().to_bytes_be()
+                };
+                if bytes.len() > octets {
+                    return Err(Error::integer_type_conversion_failed(
+                        alloc::format!("{value} does not fit in {octets} octets"),
+                        self.codec(),
+                    ));
+                }
+                let padding = if signed && value.sign() == num_bigint::Sign::Minus {
+                    0xFF
+                } else {
+                    0
+                };
+                buffer.extend(core::iter::repeat_n(padding, octets - bytes.len()));
+                buffer.extend(bytes);
+            }
+            IntegerWidth::Variable { signed: true } => {
+                Self::encode_octets_with_length(buffer, &value.to_signed_bytes_be());
+            }
+            IntegerWidth::Variable { signed: false } => {
+                Self::encode_octets_with_length(buffer, &value.magnitude().to_bytes_be());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the preamble, root components and extension additions of a
+    /// `SEQUENCE` or `SET`, with `fields` in the order they appear on the wire.
+    fn encode_constructed<C: crate::types::Constructed>(
+        &mut self,
+        tag: Tag,
+        fields: &types::fields::Fields,
+        encoder: Self,
+    ) -> Result<()> {
+        self.set_bit(tag, true)?;
+        let mut preamble = BitString::new();
+        let extension_fields = encoder.extension_fields.clone();
+        let has_extensions = extension_fields.iter().any(Option::is_some);
+
+        if C::EXTENDED_FIELDS.is_some() {
+            preamble.push(has_extensions);
+        }
+
+        for field in fields.optional_and_default_fields() {
+            preamble.push(
+                encoder
+                    .field_bitfield
+                    .get(&field.tag_tree.smallest_tag())
+                    .is_some_and(|(_, is_present)| *is_present),
+            );
+        }
+
+        let mut buffer = bits_to_octets(&preamble);
+        buffer.extend(encoder.output());
+
+        if has_extensions {
+            // ITU-T X.696 (02/2021) §16.4: The presence bitmap is encoded
+            // like a `BIT STRING` with a length determinant.
+            let bitmap = extension_fields
+                .iter()
+                .map(Option::is_some)
+                .collect::<BitString>();
+            let octets = bits_to_octets(&bitmap);
+            Self::encode_length(&mut buffer, octets.len() + 1);
+            buffer.push((octets.len() * 8 - bitmap.len()) as u8);
+            buffer.extend(octets);
+
+            for field in extension_fields.into_iter().flatten() {
+                Self::encode_octets_with_length(&mut buffer, &field);
+            }
+        }
+
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+}
+
+type BitString = bitvec::vec::BitVec<u8, Msb0>;
+
+/// Packs `bits` into octets, padding the trailing octet with zeroes.
+fn bits_to_octets(bits: &BitStr) -> Vec<u8> {
+    let mut octets = alloc::vec![0u8; bits.len().div_ceil(8)];
+    octets.view_bits_mut::<Msb0>()[..bits.len()].copy_from_bitslice(bits);
+    octets
+}
+
+impl crate::Encoder for Encoder {
+    type Ok = ();
+    type Error = Error;
+
+    fn codec(&self) -> crate::Codec {
+        Self::codec(self)
+    }
+
+    fn encode_any(&mut self, tag: Tag, value: &types::Any) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let mut buffer = Vec::new();
+        Self::encode_octets_with_length(&mut buffer, &value.contents);
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_bool(&mut self, tag: Tag, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.extend(tag, &[if value { 0xFF } else { 0x00 }]);
+        Ok(())
+    }
+
+    fn encode_bit_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &BitStr,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        if let Some(size) = constraints.size().filter(|size| size.extensible.is_none()) {
+            Error::check_length(value.len(), &size.constraint, self.codec())?;
+        }
+
+        let octets = bits_to_octets(value);
+        let mut buffer = Vec::new();
+        if fixed_size(&constraints).is_some() {
+            buffer.extend(octets);
+        } else {
+            Self::encode_length(&mut buffer, octets.len() + 1);
+            buffer.push((octets.len() * 8 - value.len()) as u8);
+            buffer.extend(octets);
+        }
+
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_enumerated<E: Enumerated>(
+        &mut self,
+        tag: Tag,
+        value: &E,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let discriminant = value.discriminant();
+        let mut buffer = Vec::new();
+        // ITU-T X.696 (02/2021) §11: Short form for values in `0..=127`,
+        // otherwise the length of the value in octets with the high bit set.
+        if (0..=127).contains(&discriminant) {
+            buffer.push(discriminant as u8);
+        } else {
+            let bytes = num_bigint::BigInt::from(discriminant).to_signed_bytes_be();
+            buffer.push(0x80 | bytes.len() as u8);
+            buffer.extend(bytes);
+        }
+
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_real(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+        value: &types::Real,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let octets = crate::ber::enc::Encoder::real_to_canonical_bytes(value);
+        let mut buffer = Vec::new();
+        Self::encode_octets_with_length(&mut buffer, &octets);
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_object_identifier(&mut self, tag: Tag, oid: &[u32]) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let mut encoder = crate::der::enc::Encoder::new(crate::der::enc::EncoderOptions::der());
+        let octets = encoder.object_identifier_as_bytes(oid)?;
+        let mut buffer = Vec::new();
+        Self::encode_octets_with_length(&mut buffer, &octets);
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_relative_oid(&mut self, tag: Tag, oid: &[u32]) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let encoder = crate::der::enc::Encoder::new(crate::der::enc::EncoderOptions::der());
+        let octets = encoder.relative_oid_as_bytes(oid);
+        let mut buffer = Vec::new();
+        Self::encode_octets_with_length(&mut buffer, &octets);
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_oid_iri(&mut self, tag: Tag, value: &types::OidIri) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(tag, <_>::default(), value.as_str())
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(tag, <_>::default(), value.as_str())
+    }
+
+    fn encode_integer(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &num_bigint::BigInt,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let mut buffer = Vec::new();
+        self.encode_integer_into_buffer(&constraints, value, &mut buffer)?;
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_null(&mut self, tag: Tag) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.extend(tag, &[]);
+        Ok(())
+    }
+
+    fn encode_octet_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &[u8],
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_string(tag, &constraints, value.len(), value)
+    }
+
+    fn encode_general_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::GeneralString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_string(tag, &constraints, value.len(), value)
+    }
+
+    fn encode_graphic_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::GraphicString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_string(tag, &constraints, value.len(), value)
+    }
+
+    fn encode_videotex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::VideotexString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_string(tag, &constraints, value.len(), value)
+    }
+
+    fn encode_utf8_string(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+        value: &str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        // Size constraints of `UTF8String` count characters, not octets, so
+        // they never remove the length determinant.
+        self.encode_string(tag, &Constraints::default(), value.len(), value.as_bytes())
+    }
+
+    fn encode_visible_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::VisibleString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_known_multiplier_string(tag, &constraints, value, 1)
+    }
+
+    fn encode_ia5_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::Ia5String,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_known_multiplier_string(tag, &constraints, value, 1)
+    }
+
+    fn encode_printable_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::PrintableString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_known_multiplier_string(tag, &constraints, value, 1)
+    }
+
+    fn encode_numeric_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::NumericString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_known_multiplier_string(tag, &constraints, value, 1)
+    }
+
+    fn encode_teletex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::TeletexString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_string(tag, &constraints, value.len(), value)
+    }
+
+    fn encode_bmp_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::BmpString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_known_multiplier_string(tag, &constraints, value, 2)
+    }
+
+    fn encode_universal_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::UniversalString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_known_multiplier_string(tag, &constraints, value, 4)
+    }
+
+    fn encode_generalized_time(
+        &mut self,
+        tag: Tag,
+        value: &types::GeneralizedTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let octets = crate::ber::enc::Encoder::datetime_to_canonical_generalized_time_bytes(value);
+        self.encode_string(tag, &Constraints::default(), octets.len(), &octets)
+    }
+
+    fn encode_lossless_generalized_time(
+        &mut self,
+        tag: Tag,
+        value: &types::LosslessGeneralizedTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let string = value.to_string();
+        self.encode_string(
+            tag,
+            &Constraints::default(),
+            string.len(),
+            string.as_bytes(),
+        )
+    }
+
+    fn encode_utc_time(
+        &mut self,
+        tag: Tag,
+        value: &types::UtcTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let octets = crate::ber::enc::Encoder::datetime_to_canonical_utc_time_bytes(value);
+        self.encode_string(tag, &Constraints::default(), octets.len(), &octets)
+    }
+
+    fn encode_date(&mut self, tag: Tag, value: &types::Date) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &types::TimeOfDay,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &types::DateTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &types::Duration,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_time(&mut self, tag: Tag, value: &types::TimeValue) -> Result<Self::Ok, Self::Error> {
+        self.encode_time_type(tag, value)
+    }
+
+    fn encode_explicit_prefix<V: Encode>(
+        &mut self,
+        tag: Tag,
+        value: &V,
+    ) -> Result<Self::Ok, Self::Error> {
+        // Tags are not part of the encoding, so the prefix is transparent.
+        self.set_bit(tag, true)?;
+        let mut encoder = Self::new(self.options.without_set_encoding());
+        value.encode(&mut encoder)?;
+        self.extend(tag, &encoder.output);
+        Ok(())
+    }
+
+    fn encode_sequence<C, F>(&mut self, tag: Tag, encoder_scope: F) -> Result<Self::Ok, Self::Error>
+    where
+        C: crate::types::Constructed,
+        F: FnOnce(&mut Self) -> Result<Self::Ok, Self::Error>,
+    {
+        let mut encoder = self.new_constructed_encoder(&C::FIELDS, false);
+        (encoder_scope)(&mut encoder)?;
+        self.encode_constructed::<C>(tag, &C::FIELDS, encoder)
+    }
+
+    fn encode_sequence_of<E: Encode>(
+        &mut self,
+        tag: Tag,
+        values: &[E],
+        _: Constraints,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        // ITU-T X.696 (02/2021) §17: The quantity is a length determinant
+        // followed by the number of components as an unsigned integer.
+        let mut buffer = Vec::new();
+        let quantity = num_bigint::BigUint::from(values.len()).to_bytes_be();
+        Self::encode_octets_with_length(&mut buffer, &quantity);
+
+        for value in values {
+            let mut encoder = Self::new(self.options.without_set_encoding());
+            value.encode(&mut encoder)?;
+            buffer.extend(encoder.output);
+        }
+
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_set<C, F>(&mut self, tag: Tag, encoder_scope: F) -> Result<Self::Ok, Self::Error>
+    where
+        C: crate::types::Constructed,
+        F: FnOnce(&mut Self) -> Result<Self::Ok, Self::Error>,
+    {
+        let fields = C::FIELDS.canonised();
+        let mut encoder = self.new_constructed_encoder(&fields, true);
+        (encoder_scope)(&mut encoder)?;
+        self.encode_constructed::<C>(tag, &fields, encoder)
+    }
+
+    fn encode_set_of<E: Encode>(
+        &mut self,
+        tag: Tag,
+        values: &types::SetOf<E>,
+        constraints: Constraints,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_sequence_of(tag, &values.iter().collect::<Vec<_>>(), constraints)
+    }
+
+    fn encode_some<E: Encode>(&mut self, value: &E) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(E::TAG_TREE.smallest_tag(), true)?;
+        value.encode(self)
+    }
+
+    fn encode_some_with_tag<E: Encode>(
+        &mut self,
+        tag: Tag,
+        value: &E,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        value.encode_with_tag(self, tag)
+    }
+
+    fn encode_some_with_tag_and_constraints<E: Encode>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &E,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        value.encode_with_tag_and_constraints(self, tag, constraints)
+    }
+
+    fn encode_none<E: Encode>(&mut self) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(E::TAG_TREE.smallest_tag(), false)
+    }
+
+    fn encode_none_with_tag(&mut self, tag: Tag) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, false)
+    }
+
+    fn encode_choice<E: Encode + crate::types::Choice>(
+        &mut self,
+        _: Constraints,
+        _: Tag,
+        _: &str,
+        encode_fn: impl FnOnce(&mut Self) -> Result<Tag, Self::Error>,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut encoder = Self::new(self.options.without_set_encoding());
+        let tag = (encode_fn)(&mut encoder)?;
+        let is_root_variant = crate::TagTree::tag_contains(&tag, E::VARIANTS);
+        let is_extended_variant =
+            crate::TagTree::tag_contains(&tag, E::EXTENDED_VARIANTS.unwrap_or(&[]));
+        let mut buffer = Vec::new();
+
+        if tag == Tag::CHOICE {
+            // An untagged `CHOICE` alternative already encodes its own tag.
+            buffer.extend(encoder.output);
+        } else if is_root_variant {
+            Self::encode_tag(&mut buffer, tag);
+            buffer.extend(encoder.output);
+        } else if is_extended_variant {
+            // ITU-T X.696 (02/2021) §20.2: Extension alternatives are
+            // encoded as open types.
+            Self::encode_tag(&mut buffer, tag);
+            Self::encode_octets_with_length(&mut buffer, &encoder.output);
+        } else {
+            return Err(Error::variant_not_in_choice(self.codec()));
+        }
+
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
+    fn encode_extension_addition<E: Encode>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: E,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut encoder = Self::new(self.options.without_set_encoding());
+        encoder.field_bitfield = <_>::from([(tag, (FieldPresence::Optional, false))]);
+        E::encode_with_tag_and_constraints(&value, &mut encoder, tag, constraints)?;
+
+        if encoder.field_bitfield.get(&tag).is_some_and(|(_, b)| *b) {
+            self.set_bit(tag, true)?;
+            self.extension_fields.push(Some(encoder.output));
+        } else {
+            self.set_bit(tag, false)?;
+            self.extension_fields.push(None);
+        }
+
+        Ok(())
+    }
+
+    fn encode_extension_addition_group<E>(
+        &mut self,
+        value: Option<&E>,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        E: Encode + crate::types::Constructed,
+    {
+        let Some(value) = value else {
+            self.extension_fields.push(None);
+            return Ok(());
+        };
+
+        let mut encoder = Self::new(self.options.without_set_encoding());
+        value.encode(&mut encoder)?;
+        self.extension_fields.push(Some(encoder.output));
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EncodingRules {
+    Oer,
+    Coer,
+}
+
+impl EncodingRules {
+    pub fn is_oer(self) -> bool {
+        matches!(self, Self::Oer)
+    }
+
+    pub fn is_coer(self) -> bool {
+        matches!(self, Self::Coer)
+    }
+}
@@ -1,6 +1,8 @@
 pub mod de;
 pub mod enc;
 
+mod time;
+
 use crate::types::Constraints;
 
 pub use self::{de::Decoder, enc::Encoder};
@@ -15,10 +17,41 @@ pub(crate) fn decode<T: crate::Decode>(
     options: de::DecoderOptions,
     input: &[u8],
 ) -> Result<T, crate::error::DecodeError> {
-    T::decode(&mut crate::per::de::Decoder::new(
-        crate::types::BitStr::from_slice(input),
-        options,
-    ))
+    crate::per::de::Decoder::new(crate::types::BitStr::from_slice(input), options)
+        .decode_with_offset(None)
+}
+
+/// Attempts to decode `T` from the start of `input` using PER, returning it
+/// together with the offset in bits of the input following it.
+pub(crate) fn decode_with_remainder<T: crate::Decode>(
+    options: de::DecoderOptions,
+    input: &[u8],
+) -> Result<(T, usize), crate::error::DecodeError> {
+    let input = crate::types::BitStr::from_slice(input);
+    let mut decoder = crate::per::de::Decoder::new(input, options);
+    let value = decoder.decode_with_offset(None)?;
+    Ok((value, input.len() - decoder.input().len()))
+}
+
+/// Returns the input following a complete PER encoding ending at the bit
+/// `offset`. Complete encodings are padded with zero bits to a whole number of
+/// octets, and take up at least one octet even when the value is encoded in no
+/// bits.
+pub(crate) fn remainder_after(
+    input: &[u8],
+    offset: usize,
+    codec: crate::Codec,
+) -> Result<&[u8], crate::error::DecodeError> {
+    let end = num_integer::div_ceil(offset, 8).max(1).min(input.len());
+    let padding = &crate::types::BitStr::from_slice(input)[offset.min(end * 8)..end * 8];
+    if padding.any() {
+        return Err(crate::error::DecodeError::parser_fail(
+            alloc::format!("padding bits after the encoding ending at bit {offset} are not zero"),
+            codec,
+        ));
+    }
+
+    Ok(&input[end..])
 }
 
 /// Attempts to encode `value` to PER.
@@ -39,10 +72,8 @@ pub(crate) fn decode_with_constraints<T: crate::Decode>(
     constraints: Constraints,
     input: &[u8],
 ) -> Result<T, crate::error::DecodeError> {
-    T::decode_with_constraints(
-        &mut crate::per::de::Decoder::new(crate::types::BitStr::from_slice(input), options),
-        constraints,
-    )
+    crate::per::de::Decoder::new(crate::types::BitStr::from_slice(input), options)
+        .decode_with_offset(Some(constraints))
 }
 
 /// Attempts to encode `value` to PER.
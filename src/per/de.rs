@@ -4,7 +4,8 @@ use bitvec::field::BitField;
 use super::{FOURTY_EIGHT_K, SIXTEEN_K, SIXTY_FOUR_K, THIRTY_TWO_K};
 use crate::bits::{to_left_padded_vec, to_vec};
 use crate::{
-    de::Error as _,
+    de::{Error as _, Limits},
+    error::Offset,
     types::{
         self,
         constraints::{self, Extensible},
@@ -20,20 +21,136 @@ pub type Result<T, E = DecodeError> = core::result::Result<T, E>;
 
 type InputSlice<'input> = nom_bitvec::BSlice<'input, u8, bitvec::order::Msb0>;
 
+/// The integer representations that PER integers are decoded into, so that
+/// primitive integers are decoded without allocating a [`types::Integer`].
+trait DecodedInteger: Sized {
+    fn from_i128(value: i128) -> Self;
+    /// `None` when the value doesn't fit in `Self`.
+    fn from_unsigned_bits(bits: &types::BitStr) -> Option<Self>;
+    fn from_unsigned_bytes_be(bytes: &[u8]) -> Option<Self>;
+    fn from_signed_bytes_be(bytes: &[u8]) -> Option<Self>;
+    fn checked_add(self, value: i128) -> Option<Self>;
+}
+
+impl DecodedInteger for types::Integer {
+    fn from_i128(value: i128) -> Self {
+        value.into()
+    }
+
+    fn from_unsigned_bits(bits: &types::BitStr) -> Option<Self> {
+        let bits = if bits.len() < 8 {
+            let mut buffer = types::BitString::repeat(false, 8 - bits.len());
+            buffer.extend_from_bitslice(bits);
+            buffer
+        } else {
+            bits.to_bitvec()
+        };
+
+        Some(num_bigint::BigUint::from_bytes_be(&to_left_padded_vec(&bits)).into())
+    }
+
+    fn from_unsigned_bytes_be(bytes: &[u8]) -> Option<Self> {
+        Some(num_bigint::BigUint::from_bytes_be(bytes).into())
+    }
+
+    fn from_signed_bytes_be(bytes: &[u8]) -> Option<Self> {
+        Some(num_bigint::BigInt::from_signed_bytes_be(bytes))
+    }
+
+    fn checked_add(self, value: i128) -> Option<Self> {
+        Some(self + value)
+    }
+}
+
+impl DecodedInteger for i128 {
+    fn from_i128(value: i128) -> Self {
+        value
+    }
+
+    fn from_unsigned_bits(bits: &types::BitStr) -> Option<Self> {
+        let mut value = 0u128;
+        for chunk in bits.rchunks(8).rev() {
+            if value.leading_zeros() < chunk.len() as u32 {
+                return None;
+            }
+            value = (value << chunk.len()) | u128::from(chunk.load_be::<u8>());
+        }
+
+        value.try_into().ok()
+    }
+
+    fn from_unsigned_bytes_be(bytes: &[u8]) -> Option<Self> {
+        crate::num::i128_from_unsigned_bytes_be(bytes)
+    }
+
+    fn from_signed_bytes_be(bytes: &[u8]) -> Option<Self> {
+        crate::num::i128_from_signed_bytes_be(bytes)
+    }
+
+    fn checked_add(self, value: i128) -> Option<Self> {
+        i128::checked_add(self, value)
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct DecoderOptions {
     #[allow(unused)]
     aligned: bool,
+    canonical: bool,
+    limits: Limits,
 }
 
 impl DecoderOptions {
     pub fn aligned() -> Self {
-        Self { aligned: true }
+        Self {
+            aligned: true,
+            canonical: false,
+            limits: Limits::DEFAULT,
+        }
     }
 
     pub fn unaligned() -> Self {
-        Self { aligned: false }
+        Self {
+            aligned: false,
+            canonical: false,
+            limits: Limits::DEFAULT,
+        }
     }
+
+    /// Options for CANONICAL-PER (aligned). Input that any other valid PER
+    /// encoder could have produced, but a canonical encoder would not, is
+    /// rejected with [`DecodeErrorKind::NonCanonicalEncoding`].
+    ///
+    /// [`DecodeErrorKind::NonCanonicalEncoding`]: crate::error::DecodeErrorKind::NonCanonicalEncoding
+    pub fn canonical_aligned() -> Self {
+        Self {
+            aligned: true,
+            canonical: true,
+            limits: Limits::DEFAULT,
+        }
+    }
+
+    /// Options for CANONICAL-PER (unaligned), see [`Self::canonical_aligned`].
+    pub fn canonical_unaligned() -> Self {
+        Self {
+            aligned: false,
+            canonical: true,
+            limits: Limits::DEFAULT,
+        }
+    }
+    /// Returns these options with the given resource `limits`.
+    #[must_use]
+    pub const fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the resource limits of these options.
+    #[must_use]
+    pub const fn limits(&self) -> Limits {
+        self.limits
+    }
+
     #[must_use]
     fn current_codec(self) -> crate::Codec {
         if self.aligned {
@@ -52,6 +169,13 @@ pub struct Decoder<'input> {
     fields: VecDeque<(Field, bool)>,
     extension_fields: Option<Fields>,
     extensions_present: Option<Option<VecDeque<(Field, bool)>>>,
+    /// How many constructed values the current value is nested in.
+    depth: usize,
+    /// The sum of the lengths decoded so far, checked against the maximum
+    /// total length.
+    total_length: usize,
+    /// The offset of the end of `input` in the outermost input, in bits.
+    end: usize,
 }
 
 impl<'input> Decoder<'input> {
@@ -65,7 +189,61 @@ impl<'input> Decoder<'input> {
             fields: <_>::default(),
             extension_fields: <_>::default(),
             extensions_present: <_>::default(),
+            depth: 0,
+            total_length: 0,
+            end: input.len(),
+        }
+    }
+
+    /// Decodes a `T`, recording where in the input decoding failed in any
+    /// error.
+    pub(crate) fn decode_with_offset<T: Decode>(
+        &mut self,
+        constraints: Option<Constraints>,
+    ) -> Result<T> {
+        match constraints {
+            Some(constraints) => T::decode_with_constraints(self, constraints),
+            None => T::decode(self),
         }
+        .map_err(|error| error.at_offset(self.offset()))
+    }
+
+    /// Returns the offset of the remaining input in the outermost input.
+    fn position(&self) -> usize {
+        self.end - self.input.len()
+    }
+
+    fn offset(&self) -> Offset {
+        Offset::Bit(self.position())
+    }
+
+    /// Returns the depth of a value nested in the current one, checking it
+    /// against the maximum nesting depth.
+    fn nested_depth(&self) -> Result<usize> {
+        self.options
+            .limits
+            .check_depth(self.depth + 1, self.codec())
+    }
+
+    /// Creates a decoder for a value nested one level deeper than the
+    /// current one, whose `input` ends at `end` in the outermost input. The
+    /// lengths it decodes count towards the current decoder's total once
+    /// its `total_length` is copied back.
+    fn nested<'a>(&self, input: &'a crate::types::BitStr, end: usize) -> Result<Decoder<'a>> {
+        let mut decoder = Decoder::new(input, self.options);
+        decoder.depth = self.nested_depth()?;
+        decoder.total_length = self.total_length;
+        decoder.end = end;
+        Ok(decoder)
+    }
+
+    /// Runs `f` on this decoder one level deeper than the current value.
+    fn nest<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let depth = self.depth;
+        self.depth = self.nested_depth()?;
+        let result = (f)(self);
+        self.depth = depth;
+        result
     }
 
     /// Returns the remaining input, if any.
@@ -122,12 +300,22 @@ impl<'input> Decoder<'input> {
         if input.len() % 8 == 0 {
             Ok(input)
         } else {
-            let (input, _) = nom::bytes::streaming::take(input.len() % 8)(input)
+            let (input, padding) = nom::bytes::streaming::take(input.len() % 8)(input)
                 .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
+            self.require_canonical(padding.not_any(), "padding bits are not zero")?;
             Ok(input)
         }
     }
 
+    /// Returns an error when decoding CANONICAL-PER and `condition` is false.
+    fn require_canonical(&self, condition: bool, reason: &'static str) -> Result<()> {
+        if self.options.canonical && !condition {
+            Err(DecodeError::non_canonical_encoding(reason, self.codec()))
+        } else {
+            Ok(())
+        }
+    }
+
     fn parse_optional_and_default_field_bitmap(
         &mut self,
         fields: &Fields,
@@ -166,6 +354,49 @@ impl<'input> Decoder<'input> {
         Ok(())
     }
 
+    /// Decodes the components of a SEQUENCE OF or SET OF value, passing the
+    /// bits that each one was decoded from to `inspect_encoding`.
+    fn decode_components<D: Decode>(
+        &mut self,
+        constraints: Constraints,
+        mut inspect_encoding: impl FnMut(&types::BitStr),
+    ) -> Result<Vec<D>> {
+        let mut components = Vec::new();
+        let options = self.options;
+        let codec = self.codec();
+        let depth = self.nested_depth()?;
+        let end = self.end;
+        let initial_total_length = self.total_length;
+        let mut total_length = self.total_length;
+        self.decode_extensible_container(constraints, |mut input, length| {
+            options
+                .limits
+                .check_elements(components.len() + length, codec)?;
+            let start = components.len();
+            components.append(
+                &mut (start..start + length)
+                    .map(|index| {
+                        let mut decoder = Self::new(input.0, options);
+                        decoder.depth = depth;
+                        decoder.total_length = total_length;
+                        decoder.end = end;
+                        let value = D::decode(&mut decoder)
+                            .map_err(|error| error.at_offset(decoder.offset()).at_index(index))?;
+                        inspect_encoding(&input.0[..input.len() - decoder.input.len()]);
+                        input = decoder.input;
+                        total_length = decoder.total_length;
+                        Ok(value)
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            );
+
+            Ok(input)
+        })?;
+        self.add_nested_total_length(total_length - initial_total_length)?;
+
+        Ok(components)
+    }
+
     fn decode_octets(&mut self) -> Result<types::BitString> {
         let mut buffer = types::BitString::default();
         let codec = self.codec();
@@ -194,7 +425,8 @@ impl<'input> Decoder<'input> {
             let (input, length) = nom::bytes::streaming::take(7u8)(input)
                 .map(|(i, bs)| (i, bs.to_bitvec()))
                 .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
-            (decode_fn)(input, length.load_be::<usize>())
+            let length = self.check_length(length.load_be::<usize>())?;
+            (decode_fn)(input, length)
         } else {
             let (input, mask) = nom::bytes::streaming::take(1u8)(input)
                 .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
@@ -203,7 +435,9 @@ impl<'input> Decoder<'input> {
                 let (input, length) = nom::bytes::streaming::take(14u8)(input)
                     .map(|(i, bs)| (i, bs.to_bitvec()))
                     .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
-                (decode_fn)(input, length.load_be::<usize>())
+                let length = self.check_length(length.load_be::<usize>())?;
+                self.require_canonical(length > 127, "length determinant is not minimal")?;
+                (decode_fn)(input, length)
             } else {
                 let (input, mask) = nom::bytes::streaming::take(6u8)(input)
                     .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
@@ -219,6 +453,7 @@ impl<'input> Decoder<'input> {
                         ));
                     }
                 };
+                let length = self.check_length(length)?;
 
                 let mut input = (decode_fn)(input, length)?;
 
@@ -237,6 +472,23 @@ impl<'input> Decoder<'input> {
         }
     }
 
+    /// Adds `length`, decoded by nested decoders that didn't start from the
+    /// current total, to the total.
+    fn add_nested_total_length(&mut self, length: usize) -> Result<()> {
+        self.total_length = self
+            .options
+            .limits
+            .check_total_length(self.total_length.saturating_add(length), self.codec())?;
+        Ok(())
+    }
+
+    fn check_length(&mut self, length: usize) -> Result<usize> {
+        let codec = self.codec();
+        self.options
+            .limits
+            .check_length_within_total(length, &mut self.total_length, codec)
+    }
+
     pub fn decode_string_length(
         &mut self,
         mut input: InputSlice<'input>,
@@ -357,37 +609,33 @@ impl<'input> Decoder<'input> {
         self.parse_integer(Constraints::new(&[constraints]))
     }
 
-    fn parse_non_negative_binary_integer(&mut self, range: i128) -> Result<types::Integer> {
+    fn parse_non_negative_binary_integer<I: DecodedInteger>(&mut self, range: i128) -> Result<I> {
         let bits = crate::num::log2(range);
         let (input, data) = nom::bytes::streaming::take(bits)(self.input)
             .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
         self.input = input;
-        let data = if data.len() < 8 {
-            let mut buffer = types::BitString::repeat(false, 8 - data.len());
-            buffer.extend_from_bitslice(&data);
-            buffer
-        } else {
-            data.to_bitvec()
-        };
 
-        Ok(num_bigint::BigUint::from_bytes_be(&to_left_padded_vec(&data)).into())
+        I::from_unsigned_bits(&data)
+            .ok_or_else(|| DecodeError::integer_overflow(i128::BITS, self.codec()))
     }
 
-    fn parse_integer(&mut self, constraints: Constraints) -> Result<types::Integer> {
+    fn parse_integer<I: DecodedInteger>(&mut self, constraints: Constraints) -> Result<I> {
         let extensible = self.parse_extensible_bit(&constraints)?;
         let value_constraint = constraints.value();
 
         let Some(value_constraint) = value_constraint.filter(|_| !extensible) else {
             let bytes = to_vec(&self.decode_octets()?);
-            return Ok(num_bigint::BigInt::from_signed_bytes_be(&bytes));
+            self.require_minimal_integer(&bytes, true)?;
+            return I::from_signed_bytes_be(&bytes)
+                .ok_or_else(|| DecodeError::integer_overflow(i128::BITS, self.codec()));
         };
 
         const K64: i128 = SIXTY_FOUR_K as i128;
         const OVER_K64: i128 = K64 + 1;
 
-        let number = if let Some(range) = value_constraint.constraint.range() {
+        let number: I = if let Some(range) = value_constraint.constraint.range() {
             match (self.options.aligned, range) {
-                (_, 0) => return Ok(value_constraint.constraint.minimum().into()),
+                (_, 0) => return Ok(I::from_i128(value_constraint.constraint.minimum())),
                 (true, 256) => {
                     self.input = self.parse_padding(self.input)?;
                     self.parse_non_negative_binary_integer(range)?
@@ -400,9 +648,9 @@ impl<'input> Decoder<'input> {
                     let range_len_in_bytes =
                         num_integer::div_ceil(crate::num::log2(range), 8) as i128;
                     let length: u32 = self
-                        .parse_non_negative_binary_integer(range_len_in_bytes)?
+                        .parse_non_negative_binary_integer::<i128>(range_len_in_bytes)?
                         .try_into()
-                        .map_err(|e: num_bigint::TryFromBigIntError<types::Integer>| {
+                        .map_err(|e: core::num::TryFromIntError| {
                             DecodeError::integer_type_conversion_failed(e.to_string(), self.codec())
                         })?;
                     self.input = self.parse_padding(self.input)?;
@@ -421,14 +669,31 @@ impl<'input> Decoder<'input> {
             }
         } else {
             let bytes = to_vec(&self.decode_octets()?);
+            self.require_minimal_integer(&bytes, value_constraint.constraint.as_start().is_none())?;
             value_constraint
                 .constraint
                 .as_start()
-                .map(|_| num_bigint::BigUint::from_bytes_be(&bytes).into())
-                .unwrap_or_else(|| num_bigint::BigInt::from_signed_bytes_be(&bytes))
+                .map(|_| I::from_unsigned_bytes_be(&bytes))
+                .unwrap_or_else(|| I::from_signed_bytes_be(&bytes))
+                .ok_or_else(|| DecodeError::integer_overflow(i128::BITS, self.codec()))?
         };
 
-        Ok(value_constraint.constraint.minimum() + number)
+        number
+            .checked_add(value_constraint.constraint.minimum())
+            .ok_or_else(|| DecodeError::integer_overflow(i128::BITS, self.codec()))
+    }
+
+    /// Checks that a length-prefixed integer uses the fewest octets possible.
+    fn require_minimal_integer(&self, bytes: &[u8], signed: bool) -> Result<()> {
+        let is_minimal = match bytes {
+            [] => false,
+            [first, second, ..] if signed => {
+                !((*first == 0 && second & 0x80 == 0) || (*first == 0xFF && second & 0x80 != 0))
+            }
+            [0, _, ..] => false,
+            _ => true,
+        };
+        self.require_canonical(is_minimal, "integer is not encoded in the fewest octets")
     }
 
     fn parse_extension_header(&mut self) -> Result<bool> {
@@ -448,6 +713,10 @@ impl<'input> Decoder<'input> {
             )?)(self.input)
             .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
         self.input = input;
+        self.require_canonical(
+            bitfield.any(),
+            "extension bit is set, but no extension additions are present",
+        )?;
 
         let extensions_present: VecDeque<_> = self
             .extension_fields
@@ -649,9 +918,9 @@ impl<'input> crate::Decoder for Decoder<'input> {
                 .ok_or_else(|| DecodeError::enumeration_index_not_found(index, true, self.codec()))
         } else {
             let index = self
-                .parse_non_negative_binary_integer(E::variance() as i128)?
+                .parse_non_negative_binary_integer::<i128>(E::variance() as i128)?
                 .try_into()
-                .map_err(|e: num_bigint::TryFromBigIntError<types::Integer>| {
+                .map_err(|e: core::num::TryFromIntError| {
                     DecodeError::integer_type_conversion_failed(e.to_string(), self.codec())
                 })?;
             E::from_enumeration_index(index)
@@ -663,6 +932,10 @@ impl<'input> crate::Decoder for Decoder<'input> {
         self.parse_integer(constraints)
     }
 
+    fn decode_primitive_integer(&mut self, _: Tag, constraints: Constraints) -> Result<i128> {
+        self.parse_integer(constraints)
+    }
+
     fn decode_octet_string(&mut self, _: Tag, constraints: Constraints) -> Result<Vec<u8>> {
         let mut octet_string = types::BitString::default();
         let codec = self.codec();
@@ -682,12 +955,41 @@ impl<'input> crate::Decoder for Decoder<'input> {
         Ok(())
     }
 
+    fn decode_real(&mut self, _: Tag, _: Constraints) -> Result<types::Real> {
+        let octets = self.decode_octets()?.into_vec();
+        let options = if self.options.canonical {
+            crate::ber::de::DecoderOptions::der()
+        } else {
+            crate::ber::de::DecoderOptions::ber()
+        };
+        let decoder = crate::ber::de::Decoder::new(&octets, options);
+        decoder.decode_real_from_bytes(&octets)
+    }
+
     fn decode_object_identifier(&mut self, _: Tag) -> Result<crate::types::ObjectIdentifier> {
         let octets = self.decode_octets()?.into_vec();
         let decoder = crate::ber::de::Decoder::new(&octets, crate::ber::de::DecoderOptions::ber());
         decoder.decode_object_identifier_from_bytes(&octets)
     }
 
+    fn decode_relative_oid(&mut self, _: Tag) -> Result<types::RelativeOid> {
+        let octets = self.decode_octets()?.into_vec();
+        let decoder = crate::ber::de::Decoder::new(&octets, crate::ber::de::DecoderOptions::ber());
+        decoder.decode_relative_oid_from_bytes(&octets)
+    }
+
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri> {
+        let iri = self.decode_utf8_string(tag, <_>::default())?;
+        types::OidIri::new(iri.clone())
+            .ok_or_else(|| DecodeError::invalid_oid_iri(iri, self.codec()))
+    }
+
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri> {
+        let iri = self.decode_utf8_string(tag, <_>::default())?;
+        types::RelativeOidIri::new(iri.clone())
+            .ok_or_else(|| DecodeError::invalid_oid_iri(iri, self.codec()))
+    }
+
     fn decode_bit_string(&mut self, _: Tag, constraints: Constraints) -> Result<types::BitString> {
         let mut bit_string = types::BitString::default();
         let codec = self.codec();
@@ -730,16 +1032,29 @@ impl<'input> crate::Decoder for Decoder<'input> {
         self.parse_fixed_width_string(constraints)
     }
 
-    fn decode_teletex_string(
-        &mut self,
-        _: Tag,
-        _constraints: Constraints,
-    ) -> Result<types::TeletexString> {
-        todo!()
+    fn decode_teletex_string(&mut self, tag: Tag, _: Constraints) -> Result<types::TeletexString> {
+        self.decode_octet_string(tag, <_>::default())
+            .map(types::TeletexString::from)
     }
 
-    fn decode_bmp_string(&mut self, _: Tag, _constraints: Constraints) -> Result<types::BmpString> {
-        todo!()
+    fn decode_bmp_string(&mut self, _: Tag, constraints: Constraints) -> Result<types::BmpString> {
+        self.parse_fixed_width_string(constraints)
+    }
+
+    fn decode_universal_string(
+        &mut self,
+        _: Tag,
+        constraints: Constraints,
+    ) -> Result<types::UniversalString> {
+        self.parse_fixed_width_string::<types::UniversalString>(constraints)?
+            .validate()
+            .map_err(|e| {
+                DecodeError::string_conversion_failed(
+                    Tag::UNIVERSAL_STRING,
+                    e.to_string(),
+                    self.codec(),
+                )
+            })
     }
 
     fn decode_utf8_string(
@@ -769,50 +1084,119 @@ impl<'input> crate::Decoder for Decoder<'input> {
         })
     }
 
+    fn decode_graphic_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::GraphicString> {
+        <types::GraphicString>::try_from(self.decode_octet_string(tag, constraints)?).map_err(|e| {
+            DecodeError::string_conversion_failed(Tag::GRAPHIC_STRING, e.to_string(), self.codec())
+        })
+    }
+
+    fn decode_videotex_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::VideotexString> {
+        self.decode_octet_string(tag, constraints)
+            .map(types::VideotexString::from)
+    }
+
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime> {
         let bytes = self.decode_octet_string(tag, <_>::default())?;
 
         crate::ber::decode(&bytes)
     }
 
+    fn decode_lossless_generalized_time(
+        &mut self,
+        tag: Tag,
+    ) -> Result<types::LosslessGeneralizedTime> {
+        let bytes = self.decode_octet_string(tag, <_>::default())?;
+
+        crate::ber::decode(&bytes)
+    }
+
     fn decode_utc_time(&mut self, tag: Tag) -> Result<types::UtcTime> {
         let bytes = self.decode_octet_string(tag, <_>::default())?;
 
         crate::ber::decode(&bytes)
     }
 
+    fn decode_date(&mut self, tag: Tag) -> Result<types::Date> {
+        super::time::YearMonthDay::decode_with_tag(self, tag)?
+            .try_into()
+            .map_err(|reason| DecodeError::invalid_time(reason, self.codec()))
+    }
+
+    fn decode_time_of_day(&mut self, tag: Tag) -> Result<types::TimeOfDay> {
+        super::time::HoursMinutesSeconds::decode_with_tag(self, tag)?
+            .try_into()
+            .map_err(|reason| DecodeError::invalid_time(reason, self.codec()))
+    }
+
+    fn decode_date_time(&mut self, tag: Tag) -> Result<types::DateTime> {
+        super::time::DateTime::decode_with_tag(self, tag)?
+            .try_into()
+            .map_err(|reason| DecodeError::invalid_time(reason, self.codec()))
+    }
+
+    fn decode_duration(&mut self, tag: Tag) -> Result<types::Duration> {
+        super::time::DurationInterval::decode_with_tag(self, tag)?
+            .try_into()
+            .map_err(|reason| DecodeError::invalid_time(reason, self.codec()))
+    }
+
+    fn decode_time(&mut self, tag: Tag) -> Result<types::TimeValue> {
+        // Unconstrained `VisibleString` characters are a full octet each in
+        // the aligned variant, so they're read as octets and checked by
+        // `TimeValue` rather than by the string decoder.
+        let octets = if self.options.aligned {
+            self.decode_octet_string(tag, Constraints::default())?
+        } else {
+            self.decode_visible_string(tag, Constraints::default())?
+                .as_iso646_bytes()
+                .to_vec()
+        };
+
+        core::str::from_utf8(&octets)
+            .map_err(|_| types::InvalidTimeValue::new("TIME"))
+            .and_then(types::TimeValue::new)
+            .map_err(|reason| DecodeError::invalid_time(reason, self.codec()))
+    }
+
     fn decode_sequence_of<D: Decode>(
         &mut self,
         _: Tag,
         constraints: Constraints,
     ) -> Result<Vec<D>, Self::Error> {
-        let mut sequence_of = Vec::new();
-        let options = self.options;
-        self.decode_extensible_container(constraints, |mut input, length| {
-            sequence_of.append(
-                &mut (0..length)
-                    .map(|_| {
-                        let mut decoder = Self::new(input.0, options);
-                        let value = D::decode(&mut decoder)?;
-                        input = decoder.input;
-                        Ok(value)
-                    })
-                    .collect::<Result<Vec<_>>>()?,
-            );
-
-            Ok(input)
-        })?;
-
-        Ok(sequence_of)
+        self.decode_components(constraints, |_| ())
     }
 
     fn decode_set_of<D: Decode + Ord>(
         &mut self,
-        tag: Tag,
+        _: Tag,
         constraints: Constraints,
     ) -> Result<types::SetOf<D>, Self::Error> {
-        self.decode_sequence_of(tag, constraints)
-            .map(|seq| seq.into_iter().collect())
+        let canonical = self.options.canonical;
+        let mut encodings = Vec::new();
+        let set_of = self.decode_components(constraints, |encoding| {
+            if canonical {
+                encodings.push(to_vec(encoding));
+            }
+        })?;
+
+        // CANONICAL-PER requires the components to be in ascending order of
+        // their encodings, padded to an octet boundary.
+        if encodings.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(DecodeError::non_canonical_encoding(
+                "SET OF components are not in canonical order",
+                self.codec(),
+            ));
+        }
+
+        Ok(set_of.into_iter().collect())
     }
 
     fn decode_sequence<D, DF, F>(
@@ -834,16 +1218,18 @@ impl<'input> crate::Decoder for Decoder<'input> {
         let bitmap = self.parse_optional_and_default_field_bitmap(&D::FIELDS)?;
 
         let value = {
-            let mut sequence_decoder = Self::new(self.input(), self.options);
+            let mut sequence_decoder = self.nested(self.input(), self.end)?;
             sequence_decoder.extension_fields = D::EXTENDED_FIELDS;
             sequence_decoder.extensions_present = is_extensible.then_some(None);
             sequence_decoder.fields = D::FIELDS
                 .optional_and_default_fields()
                 .zip(bitmap.into_iter().map(|b| *b))
                 .collect();
-            let value = (decode_fn)(&mut sequence_decoder)?;
+            let value = (decode_fn)(&mut sequence_decoder)
+                .map_err(|error| error.at_offset(sequence_decoder.offset()))?;
 
             self.input = sequence_decoder.input;
+            self.total_length = sequence_decoder.total_length;
             value
         };
 
@@ -880,7 +1266,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
 
         let fields = {
             let mut fields = Vec::new();
-            let mut set_decoder = Self::new(self.input(), self.options);
+            let mut set_decoder = self.nested(self.input(), self.end)?;
             set_decoder.extension_fields = SET::EXTENDED_FIELDS;
             set_decoder.extensions_present = is_extensible.then_some(None);
             set_decoder.fields = SET::FIELDS
@@ -894,9 +1280,10 @@ impl<'input> crate::Decoder for Decoder<'input> {
             });
             for (indice, field) in field_indices.into_iter() {
                 match field_map.get(&field).copied() {
-                    Some(true) | None => {
-                        fields.push((decode_fn)(&mut set_decoder, indice, field.tag)?)
-                    }
+                    Some(true) | None => fields.push(
+                        (decode_fn)(&mut set_decoder, indice, field.tag)
+                            .map_err(|error| error.at_offset(set_decoder.offset()))?,
+                    ),
                     Some(false) => {}
                 }
             }
@@ -906,14 +1293,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
                 .flat_map(|fields| fields.iter())
                 .enumerate()
             {
-                fields.push((decode_fn)(
-                    &mut set_decoder,
-                    indice + SET::FIELDS.len(),
-                    field.tag,
-                )?)
+                fields.push(
+                    (decode_fn)(&mut set_decoder, indice + SET::FIELDS.len(), field.tag)
+                        .map_err(|error| error.at_offset(set_decoder.offset()))?,
+                )
             }
 
             self.input = set_decoder.input;
+            self.total_length = set_decoder.total_length;
             fields
         };
 
@@ -1005,10 +1392,13 @@ impl<'input> crate::Decoder for Decoder<'input> {
 
         if is_extensible {
             let bytes = self.decode_octets()?;
-            let mut decoder = Decoder::new(&bytes, self.options);
-            D::from_tag(&mut decoder, *tag)
+            let mut decoder = self.nested(&bytes, self.position())?;
+            let value = D::from_tag(&mut decoder, *tag)
+                .map_err(|error| error.at_offset(decoder.offset()))?;
+            self.total_length = decoder.total_length;
+            Ok(value)
         } else {
-            D::from_tag(self, *tag)
+            self.nest(|decoder| D::from_tag(decoder, *tag))
         }
     }
 
@@ -1029,9 +1419,18 @@ impl<'input> crate::Decoder for Decoder<'input> {
         }
 
         let bytes = self.decode_octets()?;
-        let mut decoder = Decoder::new(&bytes, self.options);
+        if D::FIELDS.iter().all(|field| field.is_optional_or_default()) {
+            let preamble_length = usize::from(D::EXTENDED_FIELDS.is_some()) + D::FIELDS.len();
+            self.require_canonical(
+                bytes.iter().take(preamble_length).any(|bit| *bit),
+                "extension addition group is present, but has no present components",
+            )?;
+        }
+        let mut decoder = self.nested(&bytes, self.position())?;
+        let value = D::decode(&mut decoder).map_err(|error| error.at_offset(decoder.offset()))?;
+        self.total_length = decoder.total_length;
 
-        D::decode(&mut decoder).map(Some)
+        Ok(Some(value))
     }
 
     fn decode_extension_addition_with_constraints<D>(
@@ -1055,9 +1454,12 @@ impl<'input> crate::Decoder for Decoder<'input> {
         }
 
         let bytes = self.decode_octets()?;
-        let mut decoder = Decoder::new(&bytes, self.options);
+        let mut decoder = self.nested(&bytes, self.position())?;
+        let value = D::decode_with_constraints(&mut decoder, constraints)
+            .map_err(|error| error.at_offset(decoder.offset()))?;
+        self.total_length = decoder.total_length;
 
-        D::decode_with_constraints(&mut decoder, constraints).map(Some)
+        Ok(Some(value))
     }
 }
 
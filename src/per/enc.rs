@@ -22,6 +22,7 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 #[derive(Debug, Clone, Copy, Default)]
 pub struct EncoderOptions {
     aligned: bool,
+    canonical: bool,
     set_encoding: bool,
 }
 
@@ -42,6 +43,29 @@ impl EncoderOptions {
         }
     }
 
+    /// Options for CANONICAL-PER (aligned). In addition to the canonical
+    /// ordering of `SET` components used by every PER variant, `SET OF`
+    /// components are sorted by their encodings and extension addition groups
+    /// without any present components are encoded as absent.
+    #[must_use]
+    pub fn canonical_aligned() -> Self {
+        Self {
+            aligned: true,
+            canonical: true,
+            ..<_>::default()
+        }
+    }
+
+    /// Options for CANONICAL-PER (unaligned), see [`Self::canonical_aligned`].
+    #[must_use]
+    pub fn canonical_unaligned() -> Self {
+        Self {
+            aligned: false,
+            canonical: true,
+            ..<_>::default()
+        }
+    }
+
     #[must_use]
     fn without_set_encoding(mut self) -> Self {
         self.set_encoding = false;
@@ -286,6 +310,7 @@ impl Encoder {
                 let value = value.to_index_or_value_bitstring();
 
                 let octet_aligned_value = &octet_aligned_value;
+                let octets_per_char = self.character_width(S::CHARACTER_WIDTH) as usize / 8;
                 self.encode_string_length(
                     &mut buffer,
                     is_large_string,
@@ -296,7 +321,9 @@ impl Encoder {
                         .or(constraints.size()),
                     |range| {
                         Ok(match octet_aligned_value {
-                            Some(value) => types::BitString::from_slice(&value[range]),
+                            Some(value) => types::BitString::from_slice(
+                                &value[range.start * octets_per_char..range.end * octets_per_char],
+                            ),
                             None => value[S::char_range_to_bit_range(range)].to_bitvec(),
                         })
                     },
@@ -336,15 +363,21 @@ impl Encoder {
             buffer.push(Self::encoded_extension_addition(&encoder.extension_fields));
         }
 
-        for bit in encoder
-            .field_bitfield
-            .values()
-            .filter_map(|(presence, is_present)| {
-                presence.is_optional_or_default().then_some(is_present)
-            })
-            .copied()
-        {
-            buffer.push(bit);
+        // The bitmap follows the order of the fields, which for a `SET` is
+        // the canonical order of their tags.
+        let fields = if encoder.options.set_encoding {
+            C::FIELDS.canonised()
+        } else {
+            C::FIELDS
+        };
+        for field in fields.optional_and_default_fields() {
+            let tag = field.tag_tree.smallest_tag();
+            buffer.push(
+                encoder
+                    .field_bitfield
+                    .get(&tag)
+                    .is_some_and(|(_, is_present)| *is_present),
+            );
         }
 
         let extension_fields = core::mem::take(&mut encoder.extension_fields);
@@ -407,9 +440,9 @@ impl Encoder {
             constraints::Value::new(constraints::Bounded::new(0, 63)).into()
         };
 
-        self.encode_integer_into_buffer(
+        self.encode_primitive_integer_into_buffer(
             Constraints::new(&[size_constraints]),
-            &value.into(),
+            value as i128,
             buffer,
         )
     }
@@ -622,14 +655,46 @@ impl Encoder {
         value: &num_bigint::BigInt,
         buffer: &mut BitString,
     ) -> Result<()> {
+        let error = match i128::try_from(value) {
+            Ok(value) => {
+                return self.encode_primitive_integer_into_buffer(constraints, value, buffer)
+            }
+            Err(error) => error,
+        };
+
         let is_extended_value = self.encode_extensible_bit(&constraints, buffer, || {
-            constraints.value().map_or(false, |value_range| {
+            constraints.value().is_some_and(|value_range| {
                 value_range.extensible.is_some() && value_range.constraint.bigint_contains(value)
             })
         });
 
-        let value_range = if is_extended_value || constraints.value().is_none() {
+        if is_extended_value || constraints.value().is_none() {
             let bytes = value.to_signed_bytes_be();
+            self.encode_length(buffer, bytes.len(), constraints.size(), |range| {
+                Ok(BitString::from_slice(&bytes[range]))
+            })
+        } else {
+            Err(Error::integer_type_conversion_failed(
+                error.to_string(),
+                self.codec(),
+            ))
+        }
+    }
+
+    fn encode_primitive_integer_into_buffer(
+        &mut self,
+        constraints: Constraints,
+        value: i128,
+        buffer: &mut BitString,
+    ) -> Result<()> {
+        let is_extended_value = self.encode_extensible_bit(&constraints, buffer, || {
+            constraints.value().is_some_and(|value_range| {
+                value_range.extensible.is_some() && value_range.constraint.contains(&value)
+            })
+        });
+
+        let value_range = if is_extended_value || constraints.value().is_none() {
+            let bytes = crate::num::IntegerBytes::signed(value);
             self.encode_length(buffer, bytes.len(), constraints.size(), |range| {
                 Ok(BitString::from_slice(&bytes[range]))
             })?;
@@ -638,20 +703,16 @@ impl Encoder {
             constraints.value().unwrap()
         };
 
-        let bytes = match value_range.constraint.effective_bigint_value(value.clone()) {
-            either::Left(offset) => offset.to_biguint().unwrap().to_bytes_be(),
-            either::Right(value) => value.to_signed_bytes_be(),
+        let effective_value = value_range.constraint.effective_value(value);
+        let bytes = match effective_value {
+            either::Left(offset) => {
+                crate::num::IntegerBytes::unsigned(u128::try_from(offset).map_err(|e| {
+                    Error::integer_type_conversion_failed(e.to_string(), self.codec())
+                })?)
+            }
+            either::Right(value) => crate::num::IntegerBytes::signed(value),
         };
-
-        let effective_value: i128 =
-            value_range
-                .constraint
-                .effective_value(value.try_into().map_err(
-                    |e: num_bigint::TryFromBigIntError<()>| {
-                        Error::integer_type_conversion_failed(e.to_string(), self.codec())
-                    },
-                )?)
-                .either_into();
+        let effective_value: i128 = effective_value.either_into();
 
         const K64: i128 = SIXTY_FOUR_K as i128;
         const OVER_K64: i128 = K64 + 1;
@@ -849,11 +910,35 @@ impl crate::Encoder for Encoder {
         Ok(())
     }
 
+    fn encode_primitive_integer(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: i128,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let mut buffer = BitString::new();
+        self.encode_primitive_integer_into_buffer(constraints, value, &mut buffer)?;
+        self.extend(tag, &buffer);
+        Ok(())
+    }
+
     fn encode_null(&mut self, tag: Tag) -> Result<Self::Ok, Self::Error> {
         self.set_bit(tag, true)?;
         Ok(())
     }
 
+    fn encode_real(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+        value: &types::Real,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let octets = crate::ber::enc::Encoder::real_to_canonical_bytes(value);
+        self.encode_octet_string(tag, <_>::default(), &octets)
+    }
+
     fn encode_object_identifier(&mut self, tag: Tag, oid: &[u32]) -> Result<Self::Ok, Self::Error> {
         self.set_bit(tag, true)?;
         let mut encoder = crate::der::enc::Encoder::new(crate::der::enc::EncoderOptions::der());
@@ -861,6 +946,25 @@ impl crate::Encoder for Encoder {
         self.encode_octet_string(tag, <_>::default(), &der)
     }
 
+    fn encode_relative_oid(&mut self, tag: Tag, oid: &[u32]) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        let encoder = crate::der::enc::Encoder::new(crate::der::enc::EncoderOptions::der());
+        let der = encoder.relative_oid_as_bytes(oid);
+        self.encode_octet_string(tag, <_>::default(), &der)
+    }
+
+    fn encode_oid_iri(&mut self, tag: Tag, value: &types::OidIri) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(tag, <_>::default(), value.as_str())
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(tag, <_>::default(), value.as_str())
+    }
+
     fn encode_octet_string(
         &mut self,
         tag: Tag,
@@ -904,6 +1008,26 @@ impl crate::Encoder for Encoder {
         self.encode_octet_string(tag, <_>::default(), value)
     }
 
+    fn encode_graphic_string(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+        value: &types::GraphicString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_octet_string(tag, <_>::default(), value)
+    }
+
+    fn encode_videotex_string(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+        value: &types::VideotexString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_octet_string(tag, <_>::default(), value)
+    }
+
     fn encode_printable_string(
         &mut self,
         tag: Tag,
@@ -944,6 +1068,16 @@ impl crate::Encoder for Encoder {
         self.encode_known_multiplier_string(tag, &constraints, value)
     }
 
+    fn encode_universal_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+        value: &types::UniversalString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_known_multiplier_string(tag, &constraints, value)
+    }
+
     fn encode_utf8_string(
         &mut self,
         tag: Tag,
@@ -972,6 +1106,51 @@ impl crate::Encoder for Encoder {
         self.encode_octet_string(tag, <_>::default(), &crate::der::encode(value)?)
     }
 
+    fn encode_lossless_generalized_time(
+        &mut self,
+        tag: Tag,
+        value: &types::LosslessGeneralizedTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.set_bit(tag, true)?;
+        self.encode_octet_string(tag, <_>::default(), &crate::ber::encode(value)?)
+    }
+
+    fn encode_date(&mut self, tag: Tag, value: &types::Date) -> Result<Self::Ok, Self::Error> {
+        super::time::YearMonthDay::from(value).encode_with_tag(self, tag)
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &types::TimeOfDay,
+    ) -> Result<Self::Ok, Self::Error> {
+        super::time::HoursMinutesSeconds::from(value).encode_with_tag(self, tag)
+    }
+
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &types::DateTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        super::time::DateTime::from(value).encode_with_tag(self, tag)
+    }
+
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &types::Duration,
+    ) -> Result<Self::Ok, Self::Error> {
+        super::time::DurationInterval::try_from(value)
+            .map_err(|reason| Error::invalid_time(reason, self.codec()))?
+            .encode_with_tag(self, tag)
+    }
+
+    fn encode_time(&mut self, tag: Tag, value: &types::TimeValue) -> Result<Self::Ok, Self::Error> {
+        let string = types::VisibleString::try_from(value.as_str())
+            .map_err(|_| Error::invalid_time(types::InvalidTimeValue::new("TIME"), self.codec()))?;
+        self.encode_visible_string(tag, Constraints::default(), &string)
+    }
+
     fn encode_sequence_of<E: Encode>(
         &mut self,
         tag: Tag,
@@ -1016,7 +1195,25 @@ impl crate::Encoder for Encoder {
         values: &types::SetOf<E>,
         constraints: Constraints,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_sequence_of(tag, &values.iter().collect::<Vec<_>>(), constraints)
+        let mut values = values.iter().collect::<Vec<_>>();
+
+        if self.options.canonical {
+            // X.691 CANONICAL-PER orders `SET OF` components by their
+            // encodings, padded to an octet boundary and compared as octets.
+            let options = self.options.without_set_encoding();
+            let mut encoded = values
+                .into_iter()
+                .map(|value| {
+                    let mut encoder = Self::new(options);
+                    value.encode(&mut encoder)?;
+                    Ok((encoder.output(), value))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            encoded.sort_by(|(a, _), (b, _)| a.cmp(b));
+            values = encoded.into_iter().map(|(_, value)| value).collect();
+        }
+
+        self.encode_sequence_of(tag, &values, constraints)
     }
 
     fn encode_explicit_prefix<V: Encode>(
@@ -1151,9 +1348,9 @@ impl crate::Encoder for Encoder {
                     (variance - 1) as i128,
                 ))
                 .into()];
-                self.encode_integer_into_buffer(
+                self.encode_primitive_integer_into_buffer(
                     Constraints::from(choice_range),
-                    &index.into(),
+                    index as i128,
                     &mut buffer,
                 )?;
 
@@ -1211,11 +1408,25 @@ impl crate::Encoder for Encoder {
             return Ok(());
         };
 
-        self.set_bit(E::TAG, true)?;
         let mut encoder = self.new_sequence_encoder::<E>();
         encoder.is_extension_sequence = true;
         value.encode(&mut encoder)?;
 
+        if self.options.canonical
+            && E::FIELDS.iter().all(|field| field.is_optional_or_default())
+            && !encoder
+                .field_bitfield
+                .values()
+                .any(|(_, is_present)| *is_present)
+        {
+            // A group without any mandatory or present components is encoded
+            // as absent.
+            self.set_bit(E::TAG, false)?;
+            self.extension_fields.push(Vec::new());
+            return Ok(());
+        }
+
+        self.set_bit(E::TAG, true)?;
         let output = encoder.output();
         self.extension_fields.push(output);
         Ok(())
@@ -0,0 +1,220 @@
+//! The encodings of the useful time types from ITU-T X.691 (02/2021) §32,
+//! which PER uses in place of their ISO 8601 representation.
+
+use chrono::{Datelike, Timelike};
+
+use crate::{
+    types::{self, AsnType, Integer, InvalidTimeValue, TimeType},
+    Decode, Encode,
+};
+
+/// `YEAR-ENCODING`
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", choice, automatic_tags)]
+enum Year {
+    #[rasn(value("2005..=2020"))]
+    Immediate(i32),
+    #[rasn(value("2021..=2276"))]
+    NearFuture(i32),
+    #[rasn(value("1749..=2004"))]
+    NearPast(i32),
+    Remainder(Integer),
+}
+
+/// `YEAR-MONTH-DAY-ENCODING`, used for `DATE`.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", automatic_tags)]
+pub(super) struct YearMonthDay {
+    year: Year,
+    #[rasn(value("1..=12"))]
+    month: u8,
+    #[rasn(value("1..=31"))]
+    day: u8,
+}
+
+/// `HOURS-MINUTES-SECONDS-ENCODING`, used for `TIME-OF-DAY`.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", automatic_tags)]
+pub(super) struct HoursMinutesSeconds {
+    #[rasn(value("0..=24"))]
+    hours: u8,
+    #[rasn(value("0..=59"))]
+    minutes: u8,
+    #[rasn(value("0..=60"))]
+    seconds: u8,
+}
+
+/// `DATE-TIME-ENCODING`, used for `DATE-TIME`.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", automatic_tags)]
+pub(super) struct DateTime {
+    date: YearMonthDay,
+    time: HoursMinutesSeconds,
+}
+
+/// `DURATION-INTERVAL-ENCODING`, used for `DURATION`.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", automatic_tags)]
+pub(super) struct DurationInterval {
+    #[rasn(value("0.."))]
+    years: Option<Integer>,
+    #[rasn(value("0.."))]
+    months: Option<Integer>,
+    #[rasn(value("0.."))]
+    weeks: Option<Integer>,
+    #[rasn(value("0.."))]
+    days: Option<Integer>,
+    #[rasn(value("0.."))]
+    hours: Option<Integer>,
+    #[rasn(value("0.."))]
+    minutes: Option<Integer>,
+    #[rasn(value("0.."))]
+    seconds: Option<Integer>,
+    fractional_part: Option<FractionalPart>,
+}
+
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", automatic_tags)]
+struct FractionalPart {
+    #[rasn(value("1.."))]
+    number_of_digits: Integer,
+    #[rasn(value("0.."))]
+    fractional_value: Integer,
+}
+
+impl From<&types::Date> for YearMonthDay {
+    fn from(date: &types::Date) -> Self {
+        let year = match date.year() {
+            year @ 2005..=2020 => Year::Immediate(year),
+            year @ 2021..=2276 => Year::NearFuture(year),
+            year @ 1749..=2004 => Year::NearPast(year),
+            year => Year::Remainder(year.into()),
+        };
+
+        Self {
+            year,
+            month: date.month() as u8,
+            day: date.day() as u8,
+        }
+    }
+}
+
+impl TryFrom<YearMonthDay> for types::Date {
+    type Error = InvalidTimeValue;
+
+    fn try_from(value: YearMonthDay) -> Result<Self, Self::Error> {
+        let year = match value.year {
+            Year::Immediate(year) | Year::NearFuture(year) | Year::NearPast(year) => Some(year),
+            Year::Remainder(year) => year.try_into().ok(),
+        };
+
+        year.and_then(|year| Self::from_ymd_opt(year, value.month.into(), value.day.into()))
+            .ok_or(InvalidTimeValue::new(Self::NAME))
+    }
+}
+
+impl From<&types::TimeOfDay> for HoursMinutesSeconds {
+    fn from(time: &types::TimeOfDay) -> Self {
+        Self {
+            hours: time.hour() as u8,
+            minutes: time.minute() as u8,
+            seconds: types::time::second(time) as u8,
+        }
+    }
+}
+
+impl TryFrom<HoursMinutesSeconds> for types::TimeOfDay {
+    type Error = InvalidTimeValue;
+
+    fn try_from(value: HoursMinutesSeconds) -> Result<Self, Self::Error> {
+        types::time::time_of_day(
+            value.hours.into(),
+            value.minutes.into(),
+            value.seconds.into(),
+        )
+        .ok_or(InvalidTimeValue::new(Self::NAME))
+    }
+}
+
+impl From<&types::DateTime> for DateTime {
+    fn from(date_time: &types::DateTime) -> Self {
+        Self {
+            date: (&date_time.date()).into(),
+            time: (&date_time.time()).into(),
+        }
+    }
+}
+
+impl TryFrom<DateTime> for types::DateTime {
+    type Error = InvalidTimeValue;
+
+    fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+        let error = |_| InvalidTimeValue::new(Self::NAME);
+        Ok(types::Date::try_from(value.date)
+            .map_err(error)?
+            .and_time(value.time.try_into().map_err(error)?))
+    }
+}
+
+impl TryFrom<&types::Duration> for DurationInterval {
+    type Error = InvalidTimeValue;
+
+    fn try_from(duration: &types::Duration) -> Result<Self, Self::Error> {
+        if !duration.is_valid() {
+            return Err(InvalidTimeValue::new(types::Duration::NAME));
+        }
+
+        Ok(Self {
+            years: duration.years.map(Integer::from),
+            months: duration.months.map(Integer::from),
+            weeks: duration.weeks.map(Integer::from),
+            days: duration.days.map(Integer::from),
+            hours: duration.hours.map(Integer::from),
+            minutes: duration.minutes.map(Integer::from),
+            seconds: duration.seconds.map(Integer::from),
+            fractional_part: duration.fraction.map(|fraction| FractionalPart {
+                number_of_digits: fraction.digits.into(),
+                fractional_value: fraction.value.into(),
+            }),
+        })
+    }
+}
+
+impl TryFrom<DurationInterval> for types::Duration {
+    type Error = InvalidTimeValue;
+
+    fn try_from(value: DurationInterval) -> Result<Self, Self::Error> {
+        let error = InvalidTimeValue::new(Self::NAME);
+        let component = |value: Option<Integer>| {
+            value
+                .map(|value| u32::try_from(value).map_err(|_| error))
+                .transpose()
+        };
+        let fraction = value
+            .fractional_part
+            .map(|fraction| {
+                Ok::<_, InvalidTimeValue>(types::DurationFraction {
+                    digits: fraction.number_of_digits.try_into().map_err(|_| error)?,
+                    value: fraction.fractional_value.try_into().map_err(|_| error)?,
+                })
+            })
+            .transpose()?;
+
+        let duration = Self {
+            years: component(value.years)?,
+            months: component(value.months)?,
+            weeks: component(value.weeks)?,
+            days: component(value.days)?,
+            hours: component(value.hours)?,
+            minutes: component(value.minutes)?,
+            seconds: component(value.seconds)?,
+            fraction,
+        };
+
+        if duration.is_valid() {
+            Ok(duration)
+        } else {
+            Err(error)
+        }
+    }
+}
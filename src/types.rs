@@ -4,9 +4,14 @@
 //! ASN.1's terminology.
 
 mod any;
+mod containing;
+mod external;
+mod generalized_time;
+mod information_object;
 mod instance;
 mod open;
 mod prefix;
+mod real;
 mod tag;
 
 pub mod constraints;
@@ -15,32 +20,46 @@ pub mod variants;
 
 pub(crate) mod oid;
 pub(crate) mod strings;
+pub(crate) mod time;
 
 use alloc::boxed::Box;
 
 pub use {
     self::{
-        any::Any,
+        any::{Any, AnyRef},
         constraints::{Constraint, Constraints, Extensible},
+        containing::{encoded_by, Containing, ContentsEncoding, ContentsString, LazyContaining},
+        external::{
+            CharacterString, ContextNegotiation, EmbeddedPdv, External, ExternalEncoding,
+            Identification, Syntaxes,
+        },
+        generalized_time::{LosslessGeneralizedTime, TimePrecision, TimeZoneDesignator},
+        information_object::InformationObjectSet,
         instance::InstanceOf,
-        oid::{ObjectIdentifier, Oid},
+        oid::{ObjectIdentifier, Oid, OidIri, RelativeOid, RelativeOidIri, RelativeOidRef},
         open::Open,
         prefix::{Explicit, Implicit},
+        real::Real,
         strings::{
             BitStr, BitString, BmpString, FixedBitString, FixedOctetString, GeneralString,
-            Ia5String, NumericString, OctetString, PrintableString, TeletexString, Utf8String,
-            VisibleString,
+            GraphicString, Ia5String, NumericString, OctetString, PrintableString, TeletexString,
+            UniversalString, Utf8String, VideotexString, VisibleString,
         },
         tag::{Class, Tag, TagTree},
+        time::{
+            Date, DateTime, Duration, DurationFraction, InvalidTimeValue, TimeOfDay, TimeValue,
+        },
     },
     num_bigint::BigInt as Integer,
     rasn_derive::AsnType,
 };
 
+pub(crate) use self::time::TimeType;
+
 ///  The `SET OF` type.
 pub type SetOf<T> = alloc::collections::BTreeSet<T>;
-///  The `UniversalString` type.
-pub type UniversalString = Implicit<tag::UNIVERSAL_STRING, Utf8String>;
+///  The `ObjectDescriptor` type.
+pub type ObjectDescriptor = Implicit<tag::OBJECT_DESCRIPTOR, GraphicString>;
 ///  The `UTCTime` type.
 pub type UtcTime = chrono::DateTime<chrono::Utc>;
 ///  The `GeneralizedTime` type.
@@ -61,6 +80,11 @@ pub trait AsnType {
     const TAG_TREE: TagTree = TagTree::Leaf(Self::TAG);
 
     const CONSTRAINTS: Constraints<'static> = Constraints::NONE;
+
+    /// The ASN.1 identifier of the type, used to name values in text-based
+    /// encoding rules. Builtin types use the names from X.693 (e.g.
+    /// `INTEGER`, `BIT_STRING`), derived types use the name of the Rust type.
+    const IDENTIFIER: Option<&'static str> = None;
 }
 
 /// A `SET` or `SEQUENCE` value.
@@ -100,6 +124,10 @@ pub trait Enumerated: Sized + 'static + PartialEq + Copy + core::fmt::Debug {
     /// present.
     const EXTENDED_DISCRIMINANTS: Option<&'static [(Self, isize)]>;
 
+    /// Variant identifiers for text-based encoding rules, listed in the same
+    /// order as `VARIANTS` followed by `EXTENDED_VARIANTS`.
+    const IDENTIFIERS: &'static [&'static str] = &[];
+
     /// Returns the number of "root" variants for a given type.
     fn variance() -> usize {
         Self::VARIANTS.len()
@@ -162,6 +190,32 @@ pub trait Enumerated: Sized + 'static + PartialEq + Copy + core::fmt::Debug {
             .find_map(|(variant, discriminant)| (value == *discriminant).then_some(*variant))
     }
 
+    /// Returns the identifier of `self`, if `Self::IDENTIFIERS` is defined.
+    fn identifier(&self) -> Option<&'static str> {
+        Self::VARIANTS
+            .iter()
+            .chain(
+                Self::EXTENDED_VARIANTS
+                    .iter()
+                    .flat_map(|array| array.iter()),
+            )
+            .zip(Self::IDENTIFIERS)
+            .find_map(|(variant, identifier)| (variant == self).then_some(*identifier))
+    }
+
+    /// Returns a variant, if the provided identifier matches any variant.
+    fn from_identifier(identifier: &str) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .chain(
+                Self::EXTENDED_VARIANTS
+                    .iter()
+                    .flat_map(|array| array.iter()),
+            )
+            .zip(Self::IDENTIFIERS)
+            .find_map(|(variant, id)| id.eq_ignore_ascii_case(identifier).then_some(*variant))
+    }
+
     /// Returns a variant, if the index matches any "root" variant.
     fn from_enumeration_index(index: usize) -> Option<Self> {
         Self::VARIANTS.get(index).copied()
@@ -179,6 +233,7 @@ pub struct ConstrainedInteger<const START: i128, const END: i128>(pub(crate) Int
 
 impl<const START: i128, const END: i128> AsnType for ConstrainedInteger<START, END> {
     const TAG: Tag = Tag::INTEGER;
+    const IDENTIFIER: Option<&'static str> = Some("INTEGER");
     const CONSTRAINTS: Constraints<'static> =
         Constraints::new(&[constraints::Constraint::Value(Extensible::new(
             constraints::Value::new(constraints::Bounded::const_new(START, END)),
@@ -202,26 +257,40 @@ impl<T: Into<Integer>, const START: i128, const END: i128> From<T>
 }
 
 macro_rules! asn_type {
-    ($($name:ty: $value:ident),+) => {
+    ($($name:ty: $value:ident = $identifier:literal),+) => {
         $(
             impl AsnType for $name {
                 const TAG: Tag = Tag::$value;
+                const IDENTIFIER: Option<&'static str> = Some($identifier);
             }
         )+
     }
 }
 
 asn_type! {
-    bool: BOOL,
-    Integer: INTEGER,
-    OctetString: OCTET_STRING,
-    ObjectIdentifier: OBJECT_IDENTIFIER,
-    Oid: OBJECT_IDENTIFIER,
-    Utf8String: UTF8_STRING,
-    UtcTime: UTC_TIME,
-    GeneralizedTime: GENERALIZED_TIME,
-    (): NULL,
-    &'_ str: UTF8_STRING
+    bool: BOOL = "BOOLEAN",
+    Integer: INTEGER = "INTEGER",
+    OctetString: OCTET_STRING = "OCTET_STRING",
+    ObjectIdentifier: OBJECT_IDENTIFIER = "OBJECT_IDENTIFIER",
+    Oid: OBJECT_IDENTIFIER = "OBJECT_IDENTIFIER",
+    RelativeOid: RELATIVE_OID = "RELATIVE_OID",
+    RelativeOidRef: RELATIVE_OID = "RELATIVE_OID",
+    OidIri: OID_IRI = "OID_IRI",
+    RelativeOidIri: RELATIVE_OID_IRI = "RELATIVE_OID_IRI",
+    Utf8String: UTF8_STRING = "UTF8String",
+    UtcTime: UTC_TIME = "UTCTime",
+    GeneralizedTime: GENERALIZED_TIME = "GeneralizedTime",
+    LosslessGeneralizedTime: GENERALIZED_TIME = "GeneralizedTime",
+    Date: DATE = "DATE",
+    TimeOfDay: TIME_OF_DAY = "TIME_OF_DAY",
+    DateTime: DATE_TIME = "DATE_TIME",
+    Duration: DURATION = "DURATION",
+    TimeValue: TIME = "TIME",
+    Real: REAL = "REAL",
+    f32: REAL = "REAL",
+    f64: REAL = "REAL",
+    (): NULL = "NULL",
+    &'_ str: UTF8_STRING = "UTF8String"
 
 }
 
@@ -230,6 +299,7 @@ macro_rules! asn_integer_type {
         $(
             impl AsnType for $int {
                 const TAG: Tag = Tag::INTEGER;
+                const IDENTIFIER: Option<&'static str> = Some("INTEGER");
                 const CONSTRAINTS: Constraints<'static> = Constraints::new(&[
                     constraints::Constraint::Value(Extensible::new(constraints::Value::new(constraints::Bounded::const_new(<$int>::MIN as i128, <$int>::MAX as i128)))),
                 ]);
@@ -255,44 +325,57 @@ asn_integer_type! {
 
 impl AsnType for str {
     const TAG: Tag = Tag::UTF8_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("UTF8String");
 }
 
 impl<T: AsnType> AsnType for &'_ T {
     const TAG: Tag = T::TAG;
     const TAG_TREE: TagTree = T::TAG_TREE;
+    const IDENTIFIER: Option<&'static str> = T::IDENTIFIER;
 }
 
 impl<T: AsnType> AsnType for Box<T> {
     const TAG: Tag = T::TAG;
     const TAG_TREE: TagTree = T::TAG_TREE;
+    const IDENTIFIER: Option<&'static str> = T::IDENTIFIER;
 }
 
 impl<T: AsnType> AsnType for alloc::vec::Vec<T> {
     const TAG: Tag = Tag::SEQUENCE;
+    const IDENTIFIER: Option<&'static str> = Some("SEQUENCE_OF");
 }
 
 impl<T: AsnType> AsnType for Option<T> {
     const TAG: Tag = T::TAG;
     const TAG_TREE: TagTree = T::TAG_TREE;
+    const IDENTIFIER: Option<&'static str> = T::IDENTIFIER;
 }
 
 impl<T> AsnType for alloc::collections::BTreeSet<T> {
     const TAG: Tag = Tag::SET;
+    const IDENTIFIER: Option<&'static str> = Some("SET_OF");
 }
 
 impl<T: AsnType, const N: usize> AsnType for [T; N] {
     const TAG: Tag = Tag::SEQUENCE;
+    const IDENTIFIER: Option<&'static str> = Some("SEQUENCE_OF");
     const CONSTRAINTS: Constraints<'static> =
         Constraints::new(&[Constraint::Size(Extensible::new(constraints::Size::new(
             constraints::Bounded::single_value(N),
         )))]);
 }
 
-impl<T> AsnType for &'_ [T] {
-    const TAG: Tag = Tag::SEQUENCE;
+impl AsnType for &'_ [u8] {
+    const TAG: Tag = Tag::OCTET_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("OCTET_STRING");
 }
 
 impl AsnType for Any {
     const TAG: Tag = Tag::EOC;
     const TAG_TREE: TagTree = TagTree::Choice(&[]);
 }
+
+impl AsnType for AnyRef<'_> {
+    const TAG: Tag = Tag::EOC;
+    const TAG_TREE: TagTree = TagTree::Choice(&[]);
+}
@@ -35,3 +35,51 @@ impl From<Vec<u8>> for Any {
         Any::new(value)
     }
 }
+
+/// A borrowed [`Any`], referencing a complete encoded ASN.1 value in the
+/// decoder's input instead of copying it.
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct AnyRef<'a> {
+    pub(crate) contents: &'a [u8],
+}
+
+impl<'a> AnyRef<'a> {
+    /// Creates a new wrapper around the borrowed opaque value.
+    pub fn new(contents: &'a [u8]) -> Self {
+        Self { contents }
+    }
+
+    /// Provides the raw representation of the value as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.contents
+    }
+
+    /// Copies the borrowed value into an owned [`Any`].
+    pub fn to_any(&self) -> Any {
+        Any::new(self.contents.to_vec())
+    }
+}
+
+impl AsRef<[u8]> for AnyRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.contents
+    }
+}
+
+impl<'a> From<&'a [u8]> for AnyRef<'a> {
+    fn from(value: &'a [u8]) -> Self {
+        AnyRef::new(value)
+    }
+}
+
+impl<'a> From<&'a Any> for AnyRef<'a> {
+    fn from(value: &'a Any) -> Self {
+        AnyRef::new(&value.contents)
+    }
+}
+
+impl From<AnyRef<'_>> for Any {
+    fn from(value: AnyRef<'_>) -> Self {
+        value.to_any()
+    }
+}
@@ -0,0 +1,323 @@
+use alloc::vec::Vec;
+
+use super::{AsnType, BitString, Constraints, OctetString, Tag};
+use crate::{
+    error::{DecodeError, EncodeError},
+    Codec, Decode, Decoder, Encode, Encoder,
+};
+
+/// The encoding rules of the contents of a [`Containing`] value, the
+/// `ENCODED BY` part of its contents constraint.
+pub trait ContentsEncoding {
+    /// Returns the codec used for the contents when the containing value is
+    /// encoded with `outer`.
+    fn codec(outer: Codec) -> Codec;
+}
+
+/// The encoding rules that can be named by a contents constraint.
+pub mod encoded_by {
+    use super::ContentsEncoding;
+    use crate::Codec;
+
+    /// The contents are encoded with the same encoding rules as the value
+    /// containing them, which is the case when a contents constraint has no
+    /// `ENCODED BY`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Outer;
+
+    impl ContentsEncoding for Outer {
+        fn codec(outer: Codec) -> Codec {
+            outer
+        }
+    }
+
+    macro_rules! encodings {
+        ($($name:ident),+ $(,)?) => {
+            $(
+                #[doc = concat!("The contents are always encoded with [`Codec::", stringify!($name), "`].")]
+                #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+                pub struct $name;
+
+                impl ContentsEncoding for $name {
+                    fn codec(_: Codec) -> Codec {
+                        Codec::$name
+                    }
+                }
+            )+
+        }
+    }
+
+    encodings!(Aper, Ber, Cer, Der, Oer, Coer, Uper);
+}
+
+/// The string type that holds the contents of a [`Containing`] value, either
+/// an [`OctetString`] or a [`BitString`].
+pub trait ContentsString: AsnType {
+    /// Encodes `contents` as this string type.
+    fn encode_contents<E: Encoder>(
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        contents: &[u8],
+    ) -> Result<(), E::Error>;
+
+    /// Decodes the contents from this string type.
+    fn decode_contents<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Vec<u8>, D::Error>;
+}
+
+impl ContentsString for OctetString {
+    fn encode_contents<E: Encoder>(
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        contents: &[u8],
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_octet_string(tag, constraints, contents)
+            .map(drop)
+    }
+
+    fn decode_contents<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Vec<u8>, D::Error> {
+        decoder.decode_octet_string(tag, constraints)
+    }
+}
+
+impl ContentsString for BitString {
+    fn encode_contents<E: Encoder>(
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        contents: &[u8],
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_bit_string(tag, constraints, super::BitStr::from_slice(contents))
+            .map(drop)
+    }
+
+    fn decode_contents<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Vec<u8>, D::Error> {
+        decoder
+            .decode_bit_string(tag, constraints)
+            .map(BitString::into_vec)
+    }
+}
+
+/// A value of `T` held in an `OCTET STRING` or `BIT STRING` with a contents
+/// constraint, as in `OCTET STRING (CONTAINING T ENCODED BY der)`.
+///
+/// The value is encoded with the encoding rules named by `E`, by default the
+/// same encoding rules as the containing value, and the encoding is held in
+/// the string type `S`. The value is decoded along with the containing value,
+/// use [`LazyContaining`] to decode it later instead. Fields of type `T`
+/// can also be marked with `#[rasn(containing)]`, optionally with
+/// `encoded_by = "der"` and `bit_string`, rather than using the wrapper.
+///
+/// ```rust
+/// use rasn::{prelude::*, types::encoded_by};
+///
+/// #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+/// struct Inner {
+///     id: u8,
+/// }
+///
+/// #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+/// struct Outer {
+///     // OCTET STRING (CONTAINING Inner)
+///     contents: Containing<Inner>,
+///     // OCTET STRING (CONTAINING Inner ENCODED BY der)
+///     #[rasn(containing(encoded_by = "der"))]
+///     der_contents: Inner,
+/// }
+///
+/// let outer = Outer {
+///     contents: Containing::new(Inner { id: 1 }),
+///     der_contents: Inner { id: 2 },
+/// };
+/// let encoded = rasn::uper::encode(&outer).unwrap();
+/// assert_eq!(outer, rasn::uper::decode(&encoded).unwrap());
+///
+/// let contents: Containing<Inner, encoded_by::Der> = Containing::new(Inner { id: 2 });
+/// assert_eq!(
+///     rasn::uper::encode(&contents).unwrap(),
+///     rasn::uper::encode(&OctetString::from(rasn::der::encode(&Inner { id: 2 }).unwrap())).unwrap(),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Containing<T, E = encoded_by::Outer, S = OctetString> {
+    /// The contained value.
+    pub value: T,
+    _encoding: core::marker::PhantomData<(E, S)>,
+}
+
+impl<T, E, S> Containing<T, E, S> {
+    /// Creates a wrapper from `value`.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            _encoding: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the contained value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Default, E, S> Default for Containing<T, E, S> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T, E, S> From<T> for Containing<T, E, S> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, E, S> core::ops::Deref for Containing<T, E, S> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, E, S> core::ops::DerefMut for Containing<T, E, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T, E, S: ContentsString> AsnType for Containing<T, E, S> {
+    const TAG: Tag = S::TAG;
+    const IDENTIFIER: Option<&'static str> = S::IDENTIFIER;
+}
+
+impl<T: Encode, E: ContentsEncoding, S: ContentsString> Encode for Containing<T, E, S> {
+    fn encode_with_tag_and_constraints<EN: Encoder>(
+        &self,
+        encoder: &mut EN,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), EN::Error> {
+        let contents = E::codec(encoder.codec()).encode_to_binary(&self.value)?;
+        S::encode_contents(encoder, tag, constraints, &contents)
+    }
+}
+
+impl<T: Decode, E: ContentsEncoding, S: ContentsString> Decode for Containing<T, E, S> {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let contents = S::decode_contents(decoder, tag, constraints)?;
+        let value = E::codec(decoder.codec()).decode_from_binary(&contents)?;
+
+        Ok(Self::new(value))
+    }
+}
+
+/// A value of `T` held in an `OCTET STRING` or `BIT STRING` with a contents
+/// constraint, which is only decoded when it's needed.
+///
+/// This is the lazy form of [`Containing`], which keeps the encoding of the
+/// value as it was decoded, so that containers that are only passed along,
+/// such as NAS messages in NGAP, don't need to be decoded at all. It's
+/// encoded as it was decoded, unless it's encoded with different encoding
+/// rules, in which case it's decoded and encoded again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LazyContaining<T, E = encoded_by::Outer, S = OctetString> {
+    codec: Codec,
+    contents: Vec<u8>,
+    _value: core::marker::PhantomData<(T, E, S)>,
+}
+
+impl<T, E, S> LazyContaining<T, E, S> {
+    /// Creates a wrapper from the `contents` of a value encoded with `codec`.
+    pub fn from_contents(codec: Codec, contents: Vec<u8>) -> Self {
+        Self {
+            codec,
+            contents,
+            _value: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the codec that the contents are encoded with.
+    pub fn codec(&self) -> Codec {
+        self.codec
+    }
+
+    /// Returns the encoded value.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.contents
+    }
+}
+
+impl<T: Encode, E, S> LazyContaining<T, E, S> {
+    /// Creates a wrapper from `value` by encoding it with `codec`.
+    pub fn encode(value: &T, codec: Codec) -> Result<Self, EncodeError> {
+        codec
+            .encode_to_binary(value)
+            .map(|contents| Self::from_contents(codec, contents))
+    }
+}
+
+impl<T: Decode, E, S> LazyContaining<T, E, S> {
+    /// Decodes the contained value.
+    pub fn decode(&self) -> Result<T, DecodeError> {
+        self.codec.decode_from_binary(&self.contents)
+    }
+}
+
+impl<T, E, S: ContentsString> AsnType for LazyContaining<T, E, S> {
+    const TAG: Tag = S::TAG;
+    const IDENTIFIER: Option<&'static str> = S::IDENTIFIER;
+}
+
+impl<T: Decode + Encode, E: ContentsEncoding, S: ContentsString> Encode
+    for LazyContaining<T, E, S>
+{
+    fn encode_with_tag_and_constraints<EN: Encoder>(
+        &self,
+        encoder: &mut EN,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), EN::Error> {
+        let codec = E::codec(encoder.codec());
+        if codec == self.codec {
+            S::encode_contents(encoder, tag, constraints, &self.contents)
+        } else {
+            let value = self
+                .decode()
+                .map_err(|error| crate::enc::Error::custom(error, encoder.codec()))?;
+            let contents = codec.encode_to_binary(&value)?;
+            S::encode_contents(encoder, tag, constraints, &contents)
+        }
+    }
+}
+
+impl<T, E: ContentsEncoding, S: ContentsString> Decode for LazyContaining<T, E, S> {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let contents = S::decode_contents(decoder, tag, constraints)?;
+
+        Ok(Self::from_contents(E::codec(decoder.codec()), contents))
+    }
+}
@@ -0,0 +1,190 @@
+use super::{
+    Any, AsnType, BitString, Constraints, Integer, ObjectDescriptor, ObjectIdentifier, OctetString,
+    Tag,
+};
+use crate::{Decode, Decoder, Encode, Encoder, Validate};
+
+/// The `EXTERNAL` type, a value of a type that isn't defined in the current
+/// specification, along with its encoding.
+///
+/// This is the X.208 compatible form of the type that X.690 §8.18 and X.691
+/// §29 use for encoding.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash, Validate)]
+#[rasn(crate_root = "crate", tag(universal, 8))]
+pub struct External {
+    /// The object identifier of the value's abstract syntax, or of its
+    /// abstract and transfer syntax.
+    pub direct_reference: Option<ObjectIdentifier>,
+    /// The presentation context identifier of the value, negotiated by the
+    /// presentation layer.
+    pub indirect_reference: Option<Integer>,
+    /// A human readable description of the value.
+    pub data_value_descriptor: Option<ObjectDescriptor>,
+    /// The value itself.
+    pub encoding: ExternalEncoding,
+}
+
+/// How the value of an [`External`] is encoded.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash, Validate)]
+#[rasn(crate_root = "crate", choice)]
+pub enum ExternalEncoding {
+    /// An encoding of an ASN.1 type, using the same encoding rules as the
+    /// `EXTERNAL` value itself.
+    #[rasn(tag(explicit(0)))]
+    SingleAsn1Type(Any),
+    /// An encoding that is a whole number of octets.
+    #[rasn(tag(1))]
+    OctetAligned(OctetString),
+    /// Any other encoding.
+    #[rasn(tag(2))]
+    Arbitrary(BitString),
+}
+
+/// The `EMBEDDED PDV` type, a value of a type that isn't defined in the
+/// current specification, encoded as a string of octets.
+///
+/// Encoded as its associated type from X.680 §36.5, in PER as well, so the
+/// encodings X.691 §30 predefines for fixed identifications aren't used.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EmbeddedPdv {
+    /// The abstract and transfer syntax of the value.
+    pub identification: Identification,
+    /// The encoded value.
+    pub data_value: OctetString,
+}
+
+/// The `CHARACTER STRING` type, a string of characters from a character
+/// abstract syntax that isn't defined in the current specification.
+///
+/// Encoded as its associated type from X.680 §44.5.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharacterString {
+    /// The character abstract and transfer syntax of the string.
+    pub identification: Identification,
+    /// The encoded string.
+    pub string_value: OctetString,
+}
+
+/// Identifies the abstract and transfer syntax of an [`EmbeddedPdv`] or a
+/// [`CharacterString`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash, Validate)]
+#[rasn(crate_root = "crate", choice, automatic_tags)]
+pub enum Identification {
+    /// The object identifiers of the abstract and the transfer syntax.
+    Syntaxes(Syntaxes),
+    /// The object identifier of both the abstract and the transfer syntax.
+    Syntax(ObjectIdentifier),
+    /// The presentation context negotiated by the presentation layer.
+    PresentationContextId(Integer),
+    /// A presentation context that is still being negotiated.
+    ContextNegotiation(ContextNegotiation),
+    /// The object identifier of the transfer syntax, with the abstract
+    /// syntax known from context.
+    TransferSyntax(ObjectIdentifier),
+    /// Both syntaxes are known from context.
+    Fixed,
+}
+
+/// The `syntaxes` alternative of an [`Identification`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash, Validate)]
+#[rasn(crate_root = "crate", automatic_tags)]
+pub struct Syntaxes {
+    pub abstract_syntax: ObjectIdentifier,
+    pub transfer_syntax: ObjectIdentifier,
+}
+
+/// The `context-negotiation` alternative of an [`Identification`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash, Validate)]
+#[rasn(crate_root = "crate", automatic_tags)]
+pub struct ContextNegotiation {
+    pub presentation_context_id: Integer,
+    pub transfer_syntax: ObjectIdentifier,
+}
+
+/// The associated type of `EMBEDDED PDV` and `CHARACTER STRING`, whose
+/// `data-value-descriptor` is always absent.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", automatic_tags)]
+struct Associated {
+    identification: Identification,
+    data_value_descriptor: Option<ObjectDescriptor>,
+    data_value: OctetString,
+}
+
+impl Associated {
+    fn new(identification: &Identification, data_value: &OctetString) -> Self {
+        Self {
+            identification: identification.clone(),
+            data_value_descriptor: None,
+            data_value: data_value.clone(),
+        }
+    }
+}
+
+impl AsnType for EmbeddedPdv {
+    const TAG: Tag = Tag::EMBEDDED_PDV;
+    const IDENTIFIER: Option<&'static str> = Some("SEQUENCE");
+}
+
+impl Encode for EmbeddedPdv {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        Associated::new(&self.identification, &self.data_value).encode_with_tag_and_constraints(
+            encoder,
+            tag,
+            constraints,
+        )
+    }
+}
+
+impl Decode for EmbeddedPdv {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let associated = Associated::decode_with_tag_and_constraints(decoder, tag, constraints)?;
+        Ok(Self {
+            identification: associated.identification,
+            data_value: associated.data_value,
+        })
+    }
+}
+
+impl AsnType for CharacterString {
+    const TAG: Tag = Tag::CHARACTER_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("SEQUENCE");
+}
+
+impl Encode for CharacterString {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        Associated::new(&self.identification, &self.string_value).encode_with_tag_and_constraints(
+            encoder,
+            tag,
+            constraints,
+        )
+    }
+}
+
+impl Decode for CharacterString {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        let associated = Associated::decode_with_tag_and_constraints(decoder, tag, constraints)?;
+        Ok(Self {
+            identification: associated.identification,
+            string_value: associated.data_value,
+        })
+    }
+}
@@ -0,0 +1,295 @@
+use alloc::string::{String, ToString};
+use core::{fmt, str::FromStr};
+
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+
+use super::{GeneralizedTime, InvalidTimeValue};
+
+/// A `GeneralizedTime` value that keeps the exact form it was written in.
+///
+/// [`GeneralizedTime`] is a `chrono` date and time, so decoding into it
+/// forgets whether the value was a local time, which UTC offset it had, how
+/// many digits its fraction had, and whether its minutes and seconds were
+/// written, and encoding it always produces the canonical form. This type
+/// keeps all of those components instead, so that a decoded value encodes
+/// back to the same bytes, as re-encoding signed structures such as
+/// timestamp tokens requires.
+///
+/// Two values are only equal when they're written the same way, use
+/// [`LosslessGeneralizedTime::to_date_time`] to compare the points in time.
+/// ```
+/// use rasn::types::{LosslessGeneralizedTime, TimePrecision};
+///
+/// let time: LosslessGeneralizedTime = "202401020304,50+0100".parse().unwrap();
+/// assert_eq!(time.precision(), TimePrecision::Minute);
+/// assert_eq!(time.fraction(), Some("50"));
+/// assert_eq!(time.to_string(), "202401020304,50+0100");
+/// assert_eq!(time.to_date_time().to_rfc3339(), "2024-01-02T03:04:30+01:00");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LosslessGeneralizedTime {
+    date_time: NaiveDateTime,
+    precision: TimePrecision,
+    fraction: Option<Fraction>,
+    time_zone: TimeZoneDesignator,
+}
+
+/// The smallest time component written in a [`LosslessGeneralizedTime`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TimePrecision {
+    Hour,
+    Minute,
+    Second,
+}
+
+/// How the time zone of a [`LosslessGeneralizedTime`] was written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeZoneDesignator {
+    /// No designator, the value is a local time.
+    Local,
+    /// `Z`, the value is in UTC.
+    Utc,
+    /// A UTC offset such as `+01` or `-0530`.
+    Offset {
+        negative: bool,
+        hours: u8,
+        /// The minutes of the offset, if they were written.
+        minutes: Option<u8>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Fraction {
+    digits: String,
+    comma: bool,
+}
+
+impl LosslessGeneralizedTime {
+    /// Returns the date and time of day as written, without the fraction,
+    /// and with any omitted minutes and seconds set to zero.
+    #[must_use]
+    pub fn naive_date_time(&self) -> NaiveDateTime {
+        self.date_time
+    }
+
+    /// Returns the smallest time component that was written.
+    #[must_use]
+    pub fn precision(&self) -> TimePrecision {
+        self.precision
+    }
+
+    /// Returns the digits of the fraction of the smallest time component,
+    /// including any trailing zeros.
+    #[must_use]
+    pub fn fraction(&self) -> Option<&str> {
+        self.fraction.as_ref().map(|fraction| &*fraction.digits)
+    }
+
+    /// Returns how the time zone was written.
+    #[must_use]
+    pub fn time_zone(&self) -> TimeZoneDesignator {
+        self.time_zone
+    }
+
+    /// Whether the value is in the only form CER and DER allow, with seconds,
+    /// in UTC, and with a fraction that uses a full stop and has no trailing
+    /// zeros.
+    #[must_use]
+    pub fn is_canonical(&self) -> bool {
+        self.precision == TimePrecision::Second
+            && self.time_zone == TimeZoneDesignator::Utc
+            && self
+                .fraction
+                .as_ref()
+                .filter(|fraction| fraction.comma || fraction.digits.ends_with('0'))
+                .is_none()
+    }
+
+    /// Converts the value into a point in time, treating local times as
+    /// being in UTC.
+    #[must_use]
+    pub fn to_date_time(&self) -> GeneralizedTime {
+        let offset = match self.time_zone {
+            TimeZoneDesignator::Local | TimeZoneDesignator::Utc => 0,
+            TimeZoneDesignator::Offset {
+                negative,
+                hours,
+                minutes,
+            } => {
+                let seconds = i32::from(hours) * 3600 + i32::from(minutes.unwrap_or(0)) * 60;
+                if negative {
+                    -seconds
+                } else {
+                    seconds
+                }
+            }
+        };
+        let unit: u128 = match self.precision {
+            TimePrecision::Hour => 3_600_000_000_000,
+            TimePrecision::Minute => 60_000_000_000,
+            TimePrecision::Second => 1_000_000_000,
+        };
+        // Nanoseconds beyond the precision of `chrono` are truncated.
+        let nanoseconds = self.fraction.as_ref().map_or(0, |fraction| {
+            let digits = &fraction.digits[..fraction.digits.len().min(18)];
+            digits.parse::<u128>().unwrap_or(0) * unit / 10u128.pow(digits.len() as u32)
+        });
+
+        let date_time = self.date_time + chrono::Duration::nanoseconds(nanoseconds as i64);
+        FixedOffset::east_opt(offset)
+            .unwrap()
+            .from_local_datetime(&date_time)
+            .unwrap()
+    }
+}
+
+impl From<GeneralizedTime> for LosslessGeneralizedTime {
+    /// Returns the canonical form of `value`, in UTC.
+    fn from(value: GeneralizedTime) -> Self {
+        let value = value.naive_utc();
+        let nanosecond = value.nanosecond() % 1_000_000_000;
+        let mut digits = alloc::format!("{nanosecond:09}");
+        while digits.ends_with('0') {
+            digits.pop();
+        }
+
+        Self {
+            date_time: value
+                .with_nanosecond(value.nanosecond() - nanosecond)
+                .unwrap(),
+            precision: TimePrecision::Second,
+            fraction: (!digits.is_empty()).then_some(Fraction {
+                digits,
+                comma: false,
+            }),
+            time_zone: TimeZoneDesignator::Utc,
+        }
+    }
+}
+
+impl From<LosslessGeneralizedTime> for GeneralizedTime {
+    fn from(value: LosslessGeneralizedTime) -> Self {
+        value.to_date_time()
+    }
+}
+
+impl fmt::Display for LosslessGeneralizedTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let date_time = &self.date_time;
+        write!(
+            f,
+            "{:04}{:02}{:02}{:02}",
+            date_time.year(),
+            date_time.month(),
+            date_time.day(),
+            date_time.hour()
+        )?;
+        if self.precision >= TimePrecision::Minute {
+            write!(f, "{:02}", date_time.minute())?;
+        }
+        if self.precision == TimePrecision::Second {
+            write!(f, "{:02}", super::time::second(&date_time.time()))?;
+        }
+        if let Some(fraction) = &self.fraction {
+            let mark = if fraction.comma { ',' } else { '.' };
+            write!(f, "{mark}{}", fraction.digits)?;
+        }
+        match self.time_zone {
+            TimeZoneDesignator::Local => Ok(()),
+            TimeZoneDesignator::Utc => f.write_str("Z"),
+            TimeZoneDesignator::Offset {
+                negative,
+                hours,
+                minutes,
+            } => {
+                write!(f, "{}{hours:02}", if negative { '-' } else { '+' })?;
+                match minutes {
+                    Some(minutes) => write!(f, "{minutes:02}"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for LosslessGeneralizedTime {
+    type Err = InvalidTimeValue;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        parse(string).ok_or(InvalidTimeValue::new("GeneralizedTime"))
+    }
+}
+
+fn parse(string: &str) -> Option<LosslessGeneralizedTime> {
+    if !string.is_ascii() {
+        return None;
+    }
+
+    let (rest, time_zone) = if let Some(rest) = string.strip_suffix('Z') {
+        (rest, TimeZoneDesignator::Utc)
+    } else if let Some(index) = string.rfind(['+', '-']) {
+        let (rest, offset) = string.split_at(index);
+        let minutes = match offset.len() {
+            3 => None,
+            5 => Some(number(&offset[3..]).filter(|minutes| *minutes < 60)? as u8),
+            _ => return None,
+        };
+        let time_zone = TimeZoneDesignator::Offset {
+            negative: offset.starts_with('-'),
+            hours: number(&offset[1..3]).filter(|hours| *hours < 24)? as u8,
+            minutes,
+        };
+        (rest, time_zone)
+    } else {
+        (string, TimeZoneDesignator::Local)
+    };
+
+    let (rest, fraction) = match rest.find(['.', ',']) {
+        Some(index) => {
+            let digits = &rest[index + 1..];
+            if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            let fraction = Fraction {
+                digits: digits.to_string(),
+                comma: rest.as_bytes()[index] == b',',
+            };
+            (&rest[..index], Some(fraction))
+        }
+        None => (rest, None),
+    };
+
+    let precision = match rest.len() {
+        10 => TimePrecision::Hour,
+        12 => TimePrecision::Minute,
+        14 => TimePrecision::Second,
+        _ => return None,
+    };
+    let component = |range: core::ops::Range<usize>| rest.get(range).map_or(Some(0), number);
+    let date = NaiveDate::from_ymd_opt(
+        number(&rest[..4])?.into(),
+        number(&rest[4..6])?.into(),
+        number(&rest[6..8])?.into(),
+    )?;
+    let time = super::time::time_of_day(
+        number(&rest[8..10])?.into(),
+        component(10..12)?.into(),
+        component(12..14)?.into(),
+    )?;
+
+    Some(LosslessGeneralizedTime {
+        date_time: date.and_time(time),
+        precision,
+        fraction,
+        time_zone,
+    })
+}
+
+/// Parses a string of ASCII digits that fits in a `u16`.
+fn number(string: &str) -> Option<u16> {
+    if string.bytes().all(|byte| byte.is_ascii_digit()) {
+        string.parse().ok()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+
+use super::Any;
+use crate::{
+    error::{DecodeError, EncodeError},
+    Codec,
+};
+
+/// The values of a set of information objects, such as the extensions that
+/// can appear in an X.509 certificate, selected by the value of an
+/// identifying field.
+///
+/// This is how a table constraint like
+/// `EXTENSION.&ExtnType({ExtensionSet}{@extnID})` is represented. Marking a
+/// `SEQUENCE` field with `#[rasn(identified_by = "extn_id")]` makes the
+/// derived `Decode` implementation decode it as an open type, and pass the
+/// value of the earlier `extn_id` field to [`Self::decode_value`] to decode
+/// its contents. The derived `Encode` implementation encodes the field with
+/// [`Self::encode_value`], wrapped as an open type for the encoding rules in
+/// use.
+///
+/// ```rust
+/// use rasn::{prelude::*, Codec};
+///
+/// const BASIC_CONSTRAINTS: &Oid = Oid::const_new(&[2, 5, 29, 19]);
+///
+/// #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+/// struct BasicConstraints {
+///     #[rasn(default)]
+///     ca: bool,
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum ExtensionValue {
+///     BasicConstraints(BasicConstraints),
+///     /// An extension that isn't in the set, left encoded.
+///     Unknown(Any),
+/// }
+///
+/// impl InformationObjectSet for ExtensionValue {
+///     type Id = ObjectIdentifier;
+///
+///     fn encode_value(&self, codec: Codec) -> Result<Vec<u8>, rasn::error::EncodeError> {
+///         match self {
+///             Self::BasicConstraints(value) => codec.encode_to_binary(value),
+///             Self::Unknown(value) => Ok(value.as_bytes().to_vec()),
+///         }
+///     }
+///
+///     fn decode_value(
+///         id: &ObjectIdentifier,
+///         codec: Codec,
+///         input: &[u8],
+///     ) -> Result<Self, rasn::error::DecodeError> {
+///         if **id == *BASIC_CONSTRAINTS {
+///             codec.decode_from_binary(input).map(Self::BasicConstraints)
+///         } else {
+///             Ok(Self::Unknown(Any::new(input.to_vec())))
+///         }
+///     }
+/// }
+///
+/// #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+/// struct Extension {
+///     extn_id: ObjectIdentifier,
+///     #[rasn(identified_by = "extn_id")]
+///     extn_value: ExtensionValue,
+/// }
+///
+/// let extension = Extension {
+///     extn_id: BASIC_CONSTRAINTS.to_owned(),
+///     extn_value: ExtensionValue::BasicConstraints(BasicConstraints { ca: true }),
+/// };
+/// let encoded = rasn::uper::encode(&extension).unwrap();
+/// assert_eq!(extension, rasn::uper::decode(&encoded).unwrap());
+/// ```
+pub trait InformationObjectSet: Sized {
+    /// The type of the identifying field, such as an `ObjectIdentifier` or an
+    /// integer.
+    type Id;
+
+    /// Returns the complete encoding of `self` with `codec`.
+    fn encode_value(&self, codec: Codec) -> Result<Vec<u8>, EncodeError>;
+
+    /// Decodes the value of the object identified by `id` from its complete
+    /// encoding with `codec`. Sets that can't represent unknown objects should
+    /// return [`DecodeError::unknown_information_object`].
+    fn decode_value(id: &Self::Id, codec: Codec, input: &[u8]) -> Result<Self, DecodeError>;
+
+    /// Encodes `self` as the contents of an open type.
+    fn to_open_type(&self, codec: Codec) -> Result<Any, EncodeError> {
+        let mut contents = self.encode_value(codec)?;
+
+        // PER replaces an empty complete encoding with a single zero octet.
+        if contents.is_empty() && matches!(codec, Codec::Aper | Codec::Uper) {
+            contents.push(0);
+        }
+
+        Ok(Any::new(contents))
+    }
+
+    /// Decodes the value of the object identified by `id` from the contents of
+    /// an open type.
+    fn from_open_type(id: &Self::Id, codec: Codec, value: &Any) -> Result<Self, DecodeError> {
+        Self::decode_value(id, codec, value.as_bytes())
+    }
+}
@@ -12,6 +12,7 @@ pub struct InstanceOf<T> {
 
 impl<T> AsnType for InstanceOf<T> {
     const TAG: Tag = Tag::EXTERNAL;
+    const IDENTIFIER: Option<&'static str> = Some("SEQUENCE");
 }
 
 impl<T: crate::Decode> crate::Decode for InstanceOf<T> {
@@ -1,3 +1,4 @@
+use alloc::string::String;
 use core::ops;
 
 pub(crate) const MAX_OID_FIRST_OCTET: u32 = 2;
@@ -229,18 +230,290 @@ impl PartialEq<[u32]> for ObjectIdentifier {
     }
 }
 
+impl Oid {
+    /// Appends the arcs of `relative` to this object identifier.
+    /// ```
+    /// use rasn::types::{Oid, RelativeOidRef};
+    ///
+    /// let relative = RelativeOidRef::new(&[113549, 1]).unwrap();
+    /// assert_eq!([1, 2, 840, 113549, 1], Oid::ISO_MEMBER_BODY_US.join(relative));
+    /// ```
+    #[must_use]
+    pub fn join(&self, relative: &RelativeOidRef) -> ObjectIdentifier {
+        let mut arcs = self.0.to_vec();
+        arcs.extend_from_slice(relative);
+        ObjectIdentifier::new_unchecked(arcs.into())
+    }
+
+    /// Returns the arcs of this object identifier that follow `base`.
+    ///
+    /// Returns `None` if this object identifier doesn't start with `base`, or
+    /// is `base` itself.
+    /// ```
+    /// use rasn::types::Oid;
+    ///
+    /// let rsa = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA;
+    /// let relative = rsa.relative_to(Oid::ISO_MEMBER_BODY_US_RSADSI).unwrap();
+    /// assert_eq!(*relative, [1, 1, 1]);
+    /// assert!(Oid::ISO.relative_to(rsa).is_none());
+    /// ```
+    #[must_use]
+    pub fn relative_to(&self, base: &Oid) -> Option<&RelativeOidRef> {
+        self.0
+            .strip_prefix(&base.0)
+            .and_then(|arcs| RelativeOidRef::new(arcs))
+    }
+}
+
+/// A reference to a `RELATIVE-OID`, the arcs of an object identifier that
+/// follow some base object identifier known from context.
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct RelativeOidRef([u32]);
+
+impl RelativeOidRef {
+    /// Creates a new reference to a relative object identifier from `slice`.
+    ///
+    /// Returns `None` if `slice` is empty.
+    /// ```
+    /// use rasn::types::RelativeOidRef;
+    ///
+    /// let relative = RelativeOidRef::new(&[8571, 3, 2]).unwrap();
+    /// ```
+    pub const fn new(slice: &[u32]) -> Option<&Self> {
+        if slice.is_empty() {
+            None
+        } else {
+            Some(Self::new_unchecked(slice))
+        }
+    }
+
+    /// Creates a new reference to a relative object identifier from `slice`.
+    ///
+    /// # Safety
+    /// This allows you to create potentially invalid relative object
+    /// identifiers which may affect encoding validity.
+    pub const fn new_unchecked(slice: &[u32]) -> &Self {
+        unsafe { &*(slice as *const [u32] as *const Self) }
+    }
+}
+
+impl alloc::borrow::ToOwned for RelativeOidRef {
+    type Owned = RelativeOid;
+
+    fn to_owned(&self) -> Self::Owned {
+        Self::Owned::new_unchecked(self.0.to_owned().into())
+    }
+}
+
+impl AsRef<[u32]> for RelativeOidRef {
+    fn as_ref(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl PartialEq<[u32]> for RelativeOidRef {
+    fn eq(&self, rhs: &[u32]) -> bool {
+        &self.0 == rhs
+    }
+}
+
+impl<const N: usize> PartialEq<[u32; N]> for RelativeOidRef {
+    fn eq(&self, rhs: &[u32; N]) -> bool {
+        &self.0 == rhs
+    }
+}
+
+impl PartialEq<RelativeOidRef> for RelativeOid {
+    fn eq(&self, rhs: &RelativeOidRef) -> bool {
+        *self.0 == rhs.0
+    }
+}
+
+impl PartialEq<RelativeOid> for RelativeOidRef {
+    fn eq(&self, rhs: &RelativeOid) -> bool {
+        self.0 == *rhs.0
+    }
+}
+
+impl ops::Deref for RelativeOidRef {
+    type Target = [u32];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A `RELATIVE-OID`. The "owned" version of [`RelativeOidRef`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RelativeOid(alloc::borrow::Cow<'static, [u32]>);
+
+impl RelativeOid {
+    /// Creates a new relative object identifier from `arcs`.
+    ///
+    /// Returns `None` if `arcs` is empty.
+    pub fn new(arcs: impl Into<alloc::borrow::Cow<'static, [u32]>>) -> Option<Self> {
+        let arcs = arcs.into();
+        (!arcs.is_empty()).then_some(Self(arcs))
+    }
+
+    /// Creates a new relative object identifier from `vec`.
+    ///
+    /// # Safety
+    /// This allows you to create potentially invalid relative object
+    /// identifiers which may affect encoding validity.
+    pub const fn new_unchecked(vec: alloc::borrow::Cow<'static, [u32]>) -> Self {
+        Self(vec)
+    }
+}
+
+impl AsRef<[u32]> for RelativeOid {
+    fn as_ref(&self) -> &[u32] {
+        self.0.as_ref()
+    }
+}
+
+impl alloc::borrow::Borrow<RelativeOidRef> for RelativeOid {
+    fn borrow(&self) -> &RelativeOidRef {
+        self
+    }
+}
+
+impl<'a> From<&'a RelativeOidRef> for RelativeOid {
+    fn from(oid: &'a RelativeOidRef) -> Self {
+        alloc::borrow::ToOwned::to_owned(oid)
+    }
+}
+
+impl ops::Deref for RelativeOid {
+    type Target = RelativeOidRef;
+
+    fn deref(&self) -> &Self::Target {
+        RelativeOidRef::new_unchecked(&self.0)
+    }
+}
+
+impl<const N: usize> PartialEq<[u32; N]> for RelativeOid {
+    fn eq(&self, rhs: &[u32; N]) -> bool {
+        *self.0 == *rhs
+    }
+}
+
+/// An `OID-IRI`, an object identifier written as the Unicode labels of its
+/// arcs, such as `/ISO/Registration_Authority/19785.CBEFF`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct OidIri(String);
+
+impl OidIri {
+    /// Creates a new OID-IRI from `iri`.
+    ///
+    /// Returns `None` if `iri` isn't a `/` followed by `/` separated arc
+    /// labels.
+    /// ```
+    /// use rasn::types::OidIri;
+    ///
+    /// assert!(OidIri::new("/Joint-ISO-ITU-T/Example").is_some());
+    /// assert!(OidIri::new("Joint-ISO-ITU-T/Example").is_none());
+    /// ```
+    pub fn new(iri: impl Into<String>) -> Option<Self> {
+        let iri = iri.into();
+        iri.strip_prefix('/')
+            .is_some_and(is_valid_iri_labels)
+            .then_some(Self(iri))
+    }
+
+    /// Returns the IRI as a string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A `RELATIVE-OID-IRI`, the arc labels of an [`OidIri`] that follow some
+/// base OID-IRI known from context, such as `Registration_Authority/19785`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RelativeOidIri(String);
+
+impl RelativeOidIri {
+    /// Creates a new relative OID-IRI from `iri`.
+    ///
+    /// Returns `None` if `iri` isn't a `/` separated list of arc labels.
+    pub fn new(iri: impl Into<String>) -> Option<Self> {
+        let iri = iri.into();
+        is_valid_iri_labels(&iri).then_some(Self(iri))
+    }
+
+    /// Returns the IRI as a string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl OidIri {
+    /// Appends the arc labels of `relative` to this OID-IRI.
+    #[must_use]
+    pub fn join(&self, relative: &RelativeOidIri) -> OidIri {
+        Self(alloc::format!("{}/{}", self.0, relative.0))
+    }
+}
+
+impl core::fmt::Display for OidIri {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl core::fmt::Display for RelativeOidIri {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Whether `labels` is a `/` separated list of integer or non-integer
+/// Unicode labels, as described in ITU-T X.660 §7.5.
+fn is_valid_iri_labels(labels: &str) -> bool {
+    labels.split('/').all(|label| {
+        if label.bytes().all(|byte| byte.is_ascii_digit()) {
+            // Integer labels have no leading zeros.
+            !label.is_empty() && (label == "0" || !label.starts_with('0'))
+        } else {
+            !label.starts_with('-')
+                && !label.ends_with('-')
+                && !label.contains("--")
+                && label
+                    .chars()
+                    .all(|ch| ch.is_alphanumeric() || matches!(ch, '-' | '.' | '_' | '~'))
+        }
+    })
+}
+
 macro_rules! oids {
-    ($($name:ident => $($num:literal),+ $(,)?);+ $(;)?) => {
+    ($table:ident; $($name:ident => $($num:literal),+ $(,)?);+ $(;)?) => {
         impl Oid {
             $(
                 pub const $name: &'static Oid = Oid::const_new(&[$($num),+]);
             )+
         }
+
+        const $table: &[(&str, &Oid)] = &[$((stringify!($name), Oid::$name)),+];
+    }
+}
+
+impl Oid {
+    /// The name of the constant defined for this object identifier, if any.
+    pub(crate) fn known_name(&self) -> Option<&'static str> {
+        [ITU_T_NAMES, ISO_NAMES, JOINT_ISO_ITU_T_NAMES]
+            .into_iter()
+            .flatten()
+            .find(|(_, oid)| *oid == self)
+            .map(|(name, _)| *name)
     }
 }
 
 // ITU-T object identifiers
 oids! {
+    ITU_T_NAMES;
     ITU_T => 0;
     ITU_T_DATA_PSS_UCL_PILOT => 0, 9, 2342, 19200300, 100;
     ITU_T_DATA_PSS_UCL_PILOT_ATTRIBUTE_TYPE => 0, 9, 2342, 19200300, 100, 1;
@@ -249,6 +522,7 @@ oids! {
 
 // ISO object identifiers
 oids! {
+    ISO_NAMES;
     ISO => 1;
 
     ISO_MEMBER_BODY => 1, 2;
@@ -451,6 +725,7 @@ oids! {
 
 // Joint ISO-ITU-T object identifiers
 oids! {
+    JOINT_ISO_ITU_T_NAMES;
     JOINT_ISO_ITU_T => 2;
 
     JOINT_ISO_ITU_T_MEMBER_BODY => 2, 2;
@@ -622,8 +897,7 @@ oids! {
 
 #[cfg(test)]
 mod test {
-    use super::ObjectIdentifier;
-    use super::Oid;
+    use super::{ObjectIdentifier, Oid, OidIri, RelativeOid, RelativeOidIri};
 
     #[test]
     fn transmute() {
@@ -655,4 +929,41 @@ mod test {
             ObjectIdentifier::new(vec![1, 2]).unwrap()
         );
     }
+
+    #[test]
+    fn known_name() {
+        assert_eq!(
+            Oid::new(&[1, 2, 840, 113549, 1, 1, 1])
+                .unwrap()
+                .known_name(),
+            Some("ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA")
+        );
+        assert_eq!(Oid::new(&[1, 2, 3, 4, 5]).unwrap().known_name(), None);
+    }
+
+    #[test]
+    fn relative() {
+        let relative = RelativeOid::new(vec![113549, 1, 1]).unwrap();
+        let oid = Oid::ISO_MEMBER_BODY_US.join(&relative);
+        assert_eq!([1, 2, 840, 113549, 1, 1], oid);
+        assert_eq!(relative, *oid.relative_to(Oid::ISO_MEMBER_BODY_US).unwrap());
+        assert!(oid.relative_to(&oid).is_none());
+        assert!(RelativeOid::new(vec![]).is_none());
+    }
+
+    #[test]
+    fn iri() {
+        let iri = OidIri::new("/ISO/Registration_Authority").unwrap();
+        let relative = RelativeOidIri::new("19785.CBEFF/4").unwrap();
+        assert_eq!(
+            iri.join(&relative).as_str(),
+            "/ISO/Registration_Authority/19785.CBEFF/4"
+        );
+        assert!(OidIri::new("/").is_none());
+        assert!(OidIri::new("/ISO//Example").is_none());
+        assert!(RelativeOidIri::new("/ISO").is_none());
+        assert!(RelativeOidIri::new("07").is_none());
+        assert!(RelativeOidIri::new("-ISO").is_none());
+        assert!(RelativeOidIri::new("Registration Authority").is_none());
+    }
 }
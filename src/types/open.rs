@@ -1,8 +1,8 @@
 use super::*;
-use crate::{Decode, Encode};
+use crate::{Decode, Encode, Validate};
 
 /// An "open" type representing any valid ASN.1 type.
-#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Decode, Encode)]
+#[derive(AsnType, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, Validate)]
 #[rasn(crate_root = "crate")]
 #[rasn(choice)]
 pub enum Open {
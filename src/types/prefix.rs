@@ -55,10 +55,12 @@ macro_rules! tag_kind {
 
 tag_kind!(Implicit, Explicit);
 
-impl<T: AsnType, V> AsnType for Implicit<T, V> {
+impl<T: AsnType, V: AsnType> AsnType for Implicit<T, V> {
     const TAG: Tag = T::TAG;
+    const IDENTIFIER: Option<&'static str> = V::IDENTIFIER;
 }
 
-impl<T: AsnType, V> AsnType for Explicit<T, V> {
+impl<T: AsnType, V: AsnType> AsnType for Explicit<T, V> {
     const TAG: Tag = T::TAG;
+    const IDENTIFIER: Option<&'static str> = V::IDENTIFIER;
 }
@@ -0,0 +1,574 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use num_bigint::{BigUint, Sign};
+use num_integer::Integer as _;
+use num_traits::{ToPrimitive, Zero};
+
+use super::Integer;
+
+/// The largest binary exponent that will be expanded into an exact decimal
+/// representation for text-based encoding rules.
+const MAX_DECIMAL_EXPANSION: u64 = 1 << 14;
+
+/// An ASN.1 `REAL` value.
+///
+/// Finite values are stored exactly as `mantissa × base^exponent` in either
+/// base 2 or base 10, so moving a value between codecs never rounds it.
+/// Native floating point numbers convert into a [`Real`] without loss using
+/// `From`, and back using [`Real::to_f64`] or [`Real::to_f32`], which round to
+/// the nearest representable value.
+///
+/// Equality compares the mathematical value, so `Real::binary(1, -1)` is equal
+/// to `Real::decimal(5, -1)`.
+/// ```
+/// use rasn::types::Real;
+///
+/// assert_eq!(Real::from(0.5), Real::decimal(5, -1));
+/// assert_eq!(Real::decimal(314, -2).to_f64(), 3.14);
+/// ```
+#[derive(Clone, Debug)]
+pub enum Real {
+    /// The finite value `mantissa × 2^exponent`.
+    Binary { mantissa: Integer, exponent: i64 },
+    /// The finite value `mantissa × 10^exponent`.
+    Decimal { mantissa: Integer, exponent: i64 },
+    /// `PLUS-INFINITY`
+    PlusInfinity,
+    /// `MINUS-INFINITY`
+    MinusInfinity,
+    /// `NOT-A-NUMBER`
+    NotANumber,
+    /// Negative zero, which ASN.1 distinguishes from zero.
+    MinusZero,
+}
+
+/// The layout of an IEEE 754 binary interchange format.
+struct FloatFormat {
+    /// Total width of the format in bits.
+    width: u32,
+    /// Significand precision in bits, including the implicit leading bit.
+    precision: u32,
+    /// The weight of the least significant bit of the smallest subnormal.
+    min_unit: i64,
+    /// The weight of the least significant bit of the largest finite value.
+    max_unit: i64,
+}
+
+const BINARY32: FloatFormat = FloatFormat {
+    width: 32,
+    precision: 24,
+    min_unit: -149,
+    max_unit: 104,
+};
+
+const BINARY64: FloatFormat = FloatFormat {
+    width: 64,
+    precision: 53,
+    min_unit: -1074,
+    max_unit: 971,
+};
+
+impl Real {
+    /// Creates the finite value `mantissa × 2^exponent`.
+    pub fn binary(mantissa: impl Into<Integer>, exponent: i64) -> Self {
+        Self::Binary {
+            mantissa: mantissa.into(),
+            exponent,
+        }
+    }
+
+    /// Creates the finite value `mantissa × 10^exponent`.
+    pub fn decimal(mantissa: impl Into<Integer>, exponent: i64) -> Self {
+        Self::Decimal {
+            mantissa: mantissa.into(),
+            exponent,
+        }
+    }
+
+    /// Returns the value zero.
+    #[must_use]
+    pub fn zero() -> Self {
+        Self::binary(0, 0)
+    }
+
+    /// Whether the value is zero. Negative zero is not considered zero.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Self::Binary { mantissa, .. } | Self::Decimal { mantissa, .. } => mantissa.is_zero(),
+            _ => false,
+        }
+    }
+
+    /// Returns the same value with a mantissa that has no trailing zero
+    /// digits in its base. Zero is always returned with an exponent of zero.
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        match self {
+            Self::Binary { mantissa, exponent } => {
+                let Some(zeros) = mantissa.trailing_zeros() else {
+                    return Self::zero();
+                };
+                match i64::try_from(zeros)
+                    .ok()
+                    .and_then(|zeros| exponent.checked_add(zeros))
+                {
+                    Some(exponent) => Self::Binary {
+                        mantissa: mantissa >> zeros,
+                        exponent,
+                    },
+                    None => self.clone(),
+                }
+            }
+            Self::Decimal { mantissa, exponent } => {
+                if mantissa.is_zero() {
+                    return Self::zero();
+                }
+                let ten = Integer::from(10);
+                let mut mantissa = mantissa.clone();
+                let mut exponent = *exponent;
+                loop {
+                    let (quotient, remainder) = mantissa.div_rem(&ten);
+                    match exponent.checked_add(1) {
+                        Some(next) if remainder.is_zero() => {
+                            mantissa = quotient;
+                            exponent = next;
+                        }
+                        _ => break,
+                    }
+                }
+                Self::Decimal { mantissa, exponent }
+            }
+            special => special.clone(),
+        }
+    }
+
+    /// Returns the nearest `f64` to this value, rounding ties to even.
+    #[must_use]
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            Self::Binary { mantissa, exponent } => {
+                f64::from_bits(binary_to_float_bits(mantissa, *exponent, &BINARY64))
+            }
+            Self::Decimal { mantissa, exponent } => alloc::format!("{mantissa}e{exponent}")
+                .parse()
+                .unwrap_or(f64::NAN),
+            Self::PlusInfinity => f64::INFINITY,
+            Self::MinusInfinity => f64::NEG_INFINITY,
+            Self::NotANumber => f64::NAN,
+            Self::MinusZero => -0.0,
+        }
+    }
+
+    /// Returns the nearest `f32` to this value, rounding ties to even.
+    #[must_use]
+    pub fn to_f32(&self) -> f32 {
+        match self {
+            Self::Binary { mantissa, exponent } => {
+                f32::from_bits(binary_to_float_bits(mantissa, *exponent, &BINARY32) as u32)
+            }
+            Self::Decimal { mantissa, exponent } => alloc::format!("{mantissa}e{exponent}")
+                .parse()
+                .unwrap_or(f32::NAN),
+            Self::PlusInfinity => f32::INFINITY,
+            Self::MinusInfinity => f32::NEG_INFINITY,
+            Self::NotANumber => f32::NAN,
+            Self::MinusZero => -0.0,
+        }
+    }
+
+    /// Parses a number written in any of the ISO 6093 NR1, NR2, or NR3
+    /// forms, such as `12`, `-1,5`, or `1.25E-3`.
+    pub(crate) fn from_iso6093(text: &str) -> Option<Self> {
+        let text = text.trim_start_matches(' ');
+        let (negative, text) = match text.as_bytes().first()? {
+            b'-' => (true, &text[1..]),
+            b'+' => (false, &text[1..]),
+            _ => (false, text),
+        };
+        let (significand, exponent) = match text.find(['e', 'E']) {
+            Some(index) => (&text[..index], text[index + 1..].parse::<i64>().ok()?),
+            None => (text, 0),
+        };
+        let (integer, fraction) = match significand.find(['.', ',']) {
+            Some(index) => (&significand[..index], &significand[index + 1..]),
+            None => (significand, ""),
+        };
+
+        let digits = [integer, fraction].concat();
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+
+        let mantissa = digits.parse::<Integer>().ok()?;
+        let exponent = exponent.checked_sub(i64::try_from(fraction.len()).ok()?)?;
+        Some(match (mantissa.is_zero(), negative) {
+            (true, true) => Self::MinusZero,
+            (true, false) => Self::zero(),
+            (false, true) => Self::Decimal {
+                mantissa: -mantissa,
+                exponent,
+            },
+            (false, false) => Self::Decimal { mantissa, exponent },
+        })
+    }
+
+    /// Returns a finite value as `(mantissa, exponent)` in base ten, with no
+    /// trailing zeros in the mantissa.
+    ///
+    /// Binary values that are exactly representable as an `f64` use the
+    /// shortest decimal that rounds back to the same `f64`; other binary values
+    /// are expanded exactly. Returns `None` for special values, and for binary
+    /// values too large to expand.
+    pub(crate) fn to_decimal(&self) -> Option<(Integer, i64)> {
+        match self.normalize() {
+            Self::Decimal { mantissa, exponent } => Some((mantissa, exponent)),
+            Self::Binary { mantissa, exponent } if mantissa.is_zero() => Some((mantissa, exponent)),
+            binary @ Self::Binary { .. } => {
+                let float = binary.to_f64();
+                if Self::from(float) == binary {
+                    return match Self::from_iso6093(&alloc::format!("{float:e}"))?.normalize() {
+                        Self::Decimal { mantissa, exponent } => Some((mantissa, exponent)),
+                        _ => None,
+                    };
+                }
+
+                let Self::Binary { mantissa, exponent } = binary else {
+                    unreachable!()
+                };
+                let power = u32::try_from(exponent.unsigned_abs())
+                    .ok()
+                    .filter(|power| u64::from(*power) <= MAX_DECIMAL_EXPANSION)?;
+                let decimal = if exponent >= 0 {
+                    Self::Decimal {
+                        mantissa: mantissa << power,
+                        exponent: 0,
+                    }
+                } else {
+                    Self::Decimal {
+                        mantissa: mantissa * Integer::from(5).pow(power),
+                        exponent,
+                    }
+                };
+
+                match decimal.normalize() {
+                    Self::Decimal { mantissa, exponent } => Some((mantissa, exponent)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value in the text form used by text-based encoding rules,
+    /// with a single digit before the decimal point, e.g. `-1.5E3`. Returns
+    /// `None` when [`Self::to_decimal`] does.
+    pub(crate) fn to_decimal_string(&self) -> Option<String> {
+        if matches!(self, Self::MinusZero) {
+            return Some("-0".into());
+        }
+
+        let (mantissa, exponent) = self.to_decimal()?;
+        if mantissa.is_zero() {
+            return Some("0".into());
+        }
+
+        let sign = if mantissa.sign() == Sign::Minus {
+            "-"
+        } else {
+            ""
+        };
+        let digits = mantissa.magnitude().to_string();
+        let (first, rest) = digits.split_at(1);
+        let exponent = i128::from(exponent) + i128::try_from(rest.len()).ok()?;
+        Some(if rest.is_empty() {
+            alloc::format!("{sign}{first}E{exponent}")
+        } else {
+            alloc::format!("{sign}{first}.{rest}E{exponent}")
+        })
+    }
+}
+
+/// Converts `mantissa × 2^exponent` into the bits of the nearest value in
+/// `format`, rounding ties to even.
+fn binary_to_float_bits(mantissa: &Integer, exponent: i64, format: &FloatFormat) -> u64 {
+    let sign = if mantissa.sign() == Sign::Minus {
+        1 << (format.width - 1)
+    } else {
+        0
+    };
+    let magnitude = mantissa.magnitude();
+    if magnitude.is_zero() {
+        return sign;
+    }
+
+    let exponent = i128::from(exponent);
+    let top = exponent + i128::from(magnitude.bits()) - 1;
+    let mut unit = (top - i128::from(format.precision - 1)).max(i128::from(format.min_unit));
+    let shift = unit - exponent;
+    let mut significand: BigUint = if shift <= 0 {
+        magnitude << usize::try_from(-shift).unwrap_or_default()
+    } else {
+        let shift = u64::try_from(shift).unwrap_or(u64::MAX);
+        let truncated = magnitude >> shift;
+        let half = magnitude.bit(shift - 1);
+        let sticky = magnitude
+            .trailing_zeros()
+            .is_some_and(|zeros| zeros < shift - 1);
+        if half && (sticky || truncated.bit(0)) {
+            truncated + 1u8
+        } else {
+            truncated
+        }
+    };
+
+    // Rounding up may carry into a new bit.
+    if significand.bits() > u64::from(format.precision) {
+        significand >>= 1;
+        unit += 1;
+    }
+
+    let exponent_shift = format.precision - 1;
+    let biased_exponent = |unit: i128| (unit - i128::from(format.min_unit) + 1) as u64;
+    if unit > i128::from(format.max_unit) {
+        return sign | biased_exponent(i128::from(format.max_unit) + 1) << exponent_shift;
+    }
+
+    let significand = significand.to_u64().unwrap_or_default();
+    let hidden_bit = 1 << exponent_shift;
+    if significand < hidden_bit {
+        sign | significand
+    } else {
+        sign | biased_exponent(unit) << exponent_shift | (significand - hidden_bit)
+    }
+}
+
+/// Converts the decimal `mantissa × 10^exponent` into a normalised binary
+/// `(mantissa, exponent)`, if it is exactly representable in base two.
+///
+/// A decimal exponent larger than `limit` bits can't be equal to a binary
+/// mantissa of `limit` bits, so those are rejected without being computed.
+fn decimal_to_binary(mantissa: &Integer, exponent: i64, limit: u64) -> Option<(Integer, i64)> {
+    let power = u32::try_from(exponent.unsigned_abs())
+        .ok()
+        .filter(|power| u64::from(*power) <= limit.max(mantissa.bits()))?;
+    let five = Integer::from(5).pow(power);
+    let mantissa = if exponent >= 0 {
+        mantissa * five
+    } else {
+        let (quotient, remainder) = mantissa.div_rem(&five);
+        if !remainder.is_zero() {
+            return None;
+        }
+        quotient
+    };
+
+    match Real::binary(mantissa, exponent).normalize() {
+        Real::Binary { mantissa, exponent } => Some((mantissa, exponent)),
+        _ => None,
+    }
+}
+
+impl Default for Real {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl PartialEq for Real {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.normalize(), other.normalize()) {
+            (
+                Self::Binary {
+                    mantissa: lhs,
+                    exponent: lhs_exponent,
+                },
+                Self::Binary {
+                    mantissa: rhs,
+                    exponent: rhs_exponent,
+                },
+            )
+            | (
+                Self::Decimal {
+                    mantissa: lhs,
+                    exponent: lhs_exponent,
+                },
+                Self::Decimal {
+                    mantissa: rhs,
+                    exponent: rhs_exponent,
+                },
+            ) => lhs == rhs && lhs_exponent == rhs_exponent,
+            (
+                Self::Binary { mantissa, exponent },
+                Self::Decimal {
+                    mantissa: decimal,
+                    exponent: decimal_exponent,
+                },
+            )
+            | (
+                Self::Decimal {
+                    mantissa: decimal,
+                    exponent: decimal_exponent,
+                },
+                Self::Binary { mantissa, exponent },
+            ) => decimal_to_binary(&decimal, decimal_exponent, mantissa.bits())
+                .is_some_and(|binary| binary == (mantissa, exponent)),
+            (lhs, rhs) => core::mem::discriminant(&lhs) == core::mem::discriminant(&rhs),
+        }
+    }
+}
+
+impl Eq for Real {}
+
+impl fmt::Display for Real {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlusInfinity => f.write_str("PLUS-INFINITY"),
+            Self::MinusInfinity => f.write_str("MINUS-INFINITY"),
+            Self::NotANumber => f.write_str("NOT-A-NUMBER"),
+            finite => match finite.to_decimal_string() {
+                Some(text) => f.write_str(&text),
+                None => match finite {
+                    Self::Binary { mantissa, exponent } => {
+                        write!(f, "{{ mantissa {mantissa}, base 2, exponent {exponent} }}")
+                    }
+                    Self::Decimal { mantissa, exponent } => {
+                        write!(f, "{{ mantissa {mantissa}, base 10, exponent {exponent} }}")
+                    }
+                    _ => unreachable!(),
+                },
+            },
+        }
+    }
+}
+
+impl From<f64> for Real {
+    fn from(value: f64) -> Self {
+        if value.is_nan() {
+            Self::NotANumber
+        } else if value.is_infinite() {
+            if value.is_sign_positive() {
+                Self::PlusInfinity
+            } else {
+                Self::MinusInfinity
+            }
+        } else if value == 0.0 {
+            if value.is_sign_negative() {
+                Self::MinusZero
+            } else {
+                Self::zero()
+            }
+        } else {
+            let bits = value.to_bits();
+            let biased_exponent = ((bits >> 52) & 0x7FF) as i64;
+            let fraction = bits & ((1 << 52) - 1);
+            let (significand, exponent) = if biased_exponent == 0 {
+                (fraction, BINARY64.min_unit)
+            } else {
+                (fraction | 1 << 52, biased_exponent + BINARY64.min_unit - 1)
+            };
+            let mantissa = Integer::from(significand);
+            Self::Binary {
+                mantissa: if value.is_sign_negative() {
+                    -mantissa
+                } else {
+                    mantissa
+                },
+                exponent,
+            }
+            .normalize()
+        }
+    }
+}
+
+impl From<f32> for Real {
+    fn from(value: f32) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_round_trip() {
+        for value in [
+            0.0,
+            1.0,
+            -1.5,
+            0.1,
+            core::f64::consts::PI,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            -2.5e-310,
+            1e300,
+        ] {
+            assert_eq!(value.to_bits(), Real::from(value).to_f64().to_bits());
+        }
+
+        for value in [0.0f32, 1.0, -1.5, 0.1, f32::MAX, 1e-45, f32::MIN_POSITIVE] {
+            assert_eq!(value.to_bits(), Real::from(value).to_f32().to_bits());
+        }
+
+        assert!(Real::from(f64::NAN).to_f64().is_nan());
+        assert!(Real::from(-0.0).to_f64().is_sign_negative());
+        assert_eq!(f64::INFINITY, Real::from(f64::INFINITY).to_f64());
+    }
+
+    #[test]
+    fn rounding() {
+        // 2^53 + 1 is a tie between 2^53 and 2^53 + 2, and rounds to even.
+        assert_eq!(
+            9_007_199_254_740_992.0,
+            Real::binary((1u64 << 53) + 1, 0).to_f64()
+        );
+        assert_eq!(
+            9_007_199_254_740_996.0,
+            Real::binary((1u64 << 53) + 3, 0).to_f64()
+        );
+        assert_eq!(f64::INFINITY, Real::binary(1, 1024).to_f64());
+        assert_eq!(0.0, Real::binary(1, -1076).to_f64());
+        assert_eq!(5e-324, Real::binary(3, -1076).to_f64());
+        assert_eq!(2.75, Real::decimal(275, -2).to_f64());
+        assert_eq!(0.3f32, Real::decimal(3, -1).to_f32());
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Real::binary(4, 0), Real::binary(1, 2));
+        assert_eq!(Real::decimal(100, -2), Real::binary(1, 0));
+        assert_eq!(Real::binary(3, -2), Real::decimal(75, -2));
+        assert_ne!(Real::decimal(1, -1), Real::from(0.1));
+        assert_eq!(Real::zero(), Real::decimal(0, 5));
+        assert_ne!(Real::zero(), Real::MinusZero);
+        assert_eq!(Real::NotANumber, Real::NotANumber);
+    }
+
+    #[test]
+    fn iso6093() {
+        assert_eq!(Some(Real::decimal(12, 0)), Real::from_iso6093("  12"));
+        assert_eq!(Some(Real::decimal(-15, -1)), Real::from_iso6093("-1,5"));
+        assert_eq!(Some(Real::decimal(125, -5)), Real::from_iso6093("1.25E-3"));
+        assert_eq!(Some(Real::decimal(5, 0)), Real::from_iso6093("+.5e1"));
+        assert_eq!(Some(Real::MinusZero), Real::from_iso6093("-0.0"));
+        assert_eq!(None, Real::from_iso6093("."));
+        assert_eq!(None, Real::from_iso6093("1E"));
+        assert_eq!(None, Real::from_iso6093("0x10"));
+    }
+
+    #[test]
+    fn decimal_string() {
+        let text = |real: Real| real.to_decimal_string().unwrap();
+        assert_eq!("1.5E0", text(Real::from(1.5)));
+        assert_eq!("1E-1", text(Real::from(0.1)));
+        assert_eq!("-1.25E2", text(Real::decimal(-12500, -2)));
+        assert_eq!("0", text(Real::zero()));
+        assert_eq!("-0", text(Real::MinusZero));
+        assert_eq!("1.1920928955078125E-7", text(Real::binary(1, -23)));
+        assert_eq!(None, Real::binary(1, 1 << 20).to_decimal_string());
+        assert_eq!(None, Real::PlusInfinity.to_decimal_string());
+    }
+}
@@ -2,11 +2,14 @@ mod bit;
 mod bmp;
 mod constrained;
 mod general;
+mod graphic;
 mod ia5;
 mod numeric;
 mod octet;
 mod printable;
 mod teletex;
+mod universal;
+mod videotex;
 mod visible;
 
 use crate::prelude::*;
@@ -16,11 +19,14 @@ pub use {
     bit::{BitStr, BitString, FixedBitString},
     bmp::BmpString,
     general::GeneralString,
+    graphic::GraphicString,
     ia5::Ia5String,
     numeric::NumericString,
     octet::{FixedOctetString, OctetString},
     printable::PrintableString,
     teletex::TeletexString,
+    universal::UniversalString,
+    videotex::VideotexString,
     visible::VisibleString,
 };
 
@@ -9,6 +9,7 @@ pub type BitStr = bitvec::slice::BitSlice<u8, bitvec::order::Msb0>;
 
 impl AsnType for BitString {
     const TAG: Tag = Tag::BIT_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("BIT_STRING");
 }
 
 impl Decode for BitString {
@@ -34,6 +35,7 @@ impl Encode for BitString {
 
 impl AsnType for BitStr {
     const TAG: Tag = Tag::BIT_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("BIT_STRING");
 }
 
 impl Encode for BitStr {
@@ -49,6 +51,7 @@ impl Encode for BitStr {
 
 impl<const N: usize> AsnType for FixedBitString<N> {
     const TAG: Tag = Tag::BIT_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("BIT_STRING");
 }
 
 impl<const N: usize> Decode for FixedBitString<N> {
@@ -15,6 +15,36 @@ impl BmpString {
     }
 }
 
+impl TryFrom<&'_ [u8]> for BmpString {
+    type Error = InvalidBmpString;
+
+    /// Converts a set of big endian bytes into a string.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let mut vec = Vec::with_capacity(bytes.len() / 2);
+        for chunk in bytes.chunks(2) {
+            let ch = match chunk {
+                [high, low] => u16::from_be_bytes([*high, *low]),
+                [byte] => {
+                    return Err(InvalidBmpString {
+                        character: (*byte).into(),
+                    })
+                }
+                _ => unreachable!(),
+            };
+
+            if ch >= 0xFFFE {
+                return Err(InvalidBmpString {
+                    character: ch.into(),
+                });
+            }
+
+            vec.push(ch);
+        }
+
+        Ok(Self(vec))
+    }
+}
+
 impl StaticPermittedAlphabet for BmpString {
     const CHARACTER_SET: &'static [u32] = &{
         let mut array = [0u32; 0xFFFE];
@@ -53,12 +83,13 @@ impl TryFrom<&'_ str> for BmpString {
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut vec = Vec::with_capacity(value.len());
         for ch in value.chars() {
-            if matches!(ch as u16, 0x0..=0xFFFF) {
-                vec.push(ch as u16);
-            } else {
-                return Err(InvalidBmpString {
-                    character: ch as u16,
-                });
+            match u16::try_from(u32::from(ch)) {
+                Ok(ch) if ch < 0xFFFE => vec.push(ch),
+                _ => {
+                    return Err(InvalidBmpString {
+                        character: ch.into(),
+                    })
+                }
             }
         }
 
@@ -68,6 +99,7 @@ impl TryFrom<&'_ str> for BmpString {
 
 impl AsnType for BmpString {
     const TAG: Tag = Tag::BMP_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("BMPString");
 }
 
 impl Encode for BmpString {
@@ -3,7 +3,6 @@ use alloc::collections::BTreeMap;
 use crate::error::strings::PermittedAlphabetError;
 use alloc::{boxed::Box, vec::Vec};
 use bitvec::prelude::*;
-use once_cell::race::OnceBox;
 
 use crate::types;
 
@@ -11,6 +10,10 @@ pub(crate) trait StaticPermittedAlphabet: Sized + Default {
     const CHARACTER_SET: &'static [u32];
     const CHARACTER_WIDTH: u32 = crate::num::log2(Self::CHARACTER_SET.len() as i128);
 
+    /// Whether `ch` is in the character set of the type.
+    fn contains_char(ch: u32) -> bool {
+        Self::CHARACTER_SET.contains(&ch)
+    }
     fn push_char(&mut self, ch: u32);
     fn chars(&self) -> Box<dyn Iterator<Item = u32> + '_>;
     fn char_range_to_bit_range(mut range: core::ops::Range<usize>) -> core::ops::Range<usize> {
@@ -29,11 +32,10 @@ pub(crate) trait StaticPermittedAlphabet: Sized + Default {
     }
 
     fn to_index_string(&self) -> types::BitString {
-        let index_map = Self::index_map();
         let mut index_string = types::BitString::new();
         let width = Self::CHARACTER_WIDTH;
         for ch in self.chars() {
-            let index = index_map[&ch];
+            let index = Self::index_of(ch);
             index_string
                 .extend_from_bitslice(&index.view_bits::<Msb0>()[(u32::BITS - width) as usize..]);
         }
@@ -41,13 +43,13 @@ pub(crate) trait StaticPermittedAlphabet: Sized + Default {
     }
 
     fn to_octet_aligned_index_string(&self) -> Vec<u8> {
-        let index_map = Self::index_map();
         let mut index_string = types::BitString::new();
         let width = Self::CHARACTER_WIDTH;
         let new_width = self.octet_aligned_char_width() as usize;
 
         for ch in self.chars() {
-            let ch = &index_map[&ch].view_bits::<Msb0>()[(u32::BITS - width) as usize..];
+            let index = Self::index_of(ch);
+            let ch = &index.view_bits::<Msb0>()[(u32::BITS - width) as usize..];
             let mut padding = types::BitString::new();
             for _ in 0..(new_width - width as usize) {
                 padding.push(false);
@@ -89,49 +91,49 @@ pub(crate) trait StaticPermittedAlphabet: Sized + Default {
     }
 
     fn character_width() -> u32 {
-        crate::num::log2(Self::CHARACTER_SET.len() as i128)
+        Self::CHARACTER_WIDTH
     }
 
     fn len(&self) -> usize {
         self.chars().count()
     }
 
-    fn index_map() -> &'static alloc::collections::BTreeMap<u32, u32> {
-        static MAP: OnceBox<BTreeMap<u32, u32>> = OnceBox::new();
-
-        MAP.get_or_init(|| {
-            Box::new(
-                Self::CHARACTER_SET
-                    .iter()
-                    .copied()
-                    .enumerate()
-                    .map(|(i, e)| (e, i as u32))
-                    .collect(),
-            )
-        })
-    }
-
-    fn character_map() -> &'static alloc::collections::BTreeMap<u32, u32> {
-        static MAP: OnceBox<BTreeMap<u32, u32>> = OnceBox::new();
-
-        MAP.get_or_init(|| {
-            Box::new(
-                Self::CHARACTER_SET
-                    .iter()
-                    .copied()
-                    .enumerate()
-                    .map(|(i, e)| (i as u32, e))
-                    .collect(),
-            )
-        })
+    /// The index of `ch` in the character set of the type.
+    ///
+    /// A `static` inside a default trait method is shared between every
+    /// implementor, so the index is looked up in `CHARACTER_SET` directly
+    /// rather than cached.
+    #[track_caller]
+    fn index_of(ch: u32) -> u32 {
+        Self::CHARACTER_SET
+            .iter()
+            .position(|c| *c == ch)
+            .unwrap_or_else(|| panic!("{ch} not in character set")) as u32
     }
 
     fn try_from_permitted_alphabet(
         input: &types::BitStr,
         alphabet: Option<&BTreeMap<u32, u32>>,
     ) -> Result<Self, PermittedAlphabetError> {
-        let alphabet = alphabet.unwrap_or_else(|| Self::character_map());
-        try_from_permitted_alphabet(input, alphabet)
+        if let Some(alphabet) = alphabet {
+            return try_from_permitted_alphabet(input, alphabet);
+        }
+
+        let width = Self::CHARACTER_WIDTH as usize;
+        if should_be_indexed(Self::CHARACTER_WIDTH, Self::CHARACTER_SET) {
+            let mut string = Self::default();
+            for ch in input.chunks_exact(width) {
+                let index = ch.load_be();
+                string.push_char(
+                    *Self::CHARACTER_SET
+                        .get(index as usize)
+                        .ok_or(PermittedAlphabetError::IndexNotFound { index })?,
+                );
+            }
+            Ok(string)
+        } else {
+            Self::try_from_bits(input.to_bitvec(), width)
+        }
     }
 
     #[track_caller]
@@ -148,7 +150,11 @@ pub(crate) trait StaticPermittedAlphabet: Sized + Default {
         }
 
         for ch in bits.chunks_exact(character_width) {
-            string.push_char(ch.load_be());
+            let ch = ch.load_be();
+            if !Self::contains_char(ch) {
+                return Err(PermittedAlphabetError::CharacterNotFound { character: ch });
+            }
+            string.push_char(ch);
         }
 
         Ok(string)
@@ -183,7 +189,7 @@ pub(crate) fn try_from_permitted_alphabet<S: StaticPermittedAlphabet>(
 }
 pub(crate) fn should_be_indexed(width: u32, character_set: &[u32]) -> bool {
     let largest_value = character_set.iter().copied().max().unwrap_or(0);
-    if 2u32.pow(width) > largest_value {
+    if 2u64.pow(width) > u64::from(largest_value) {
         false
     } else {
         true
@@ -67,6 +67,7 @@ impl core::ops::DerefMut for GeneralString {
 
 impl AsnType for GeneralString {
     const TAG: Tag = Tag::GENERAL_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("GeneralString");
 }
 
 impl Decode for GeneralString {
@@ -0,0 +1,87 @@
+use super::*;
+
+use crate::error::strings::InvalidGraphicString;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+/// A "graphic" string containing the `SPACE`, Basic Latin, and Latin-1
+/// Supplement characters, which is [`GeneralString`] without its control
+/// characters.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GraphicString(Vec<u8>);
+
+impl GraphicString {
+    fn is_valid(bytes: &[u8]) -> Result<(), InvalidGraphicString> {
+        for byte in bytes {
+            let is_in_set = matches!(
+                byte,
+                | 0x20        // SPACE
+                | 0x21..=0x7E // Basic Latin (G set)
+                | 0xA1..=0xFF // Latin-1 Supplement (G set)
+            );
+
+            if !is_in_set {
+                return Err(InvalidGraphicString { character: *byte });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidGraphicString> {
+        Self::is_valid(bytes)?;
+        Ok(Self(bytes.to_owned()))
+    }
+}
+
+impl TryFrom<Vec<u8>> for GraphicString {
+    type Error = InvalidGraphicString;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::is_valid(&value)?;
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<String> for GraphicString {
+    type Error = InvalidGraphicString;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::is_valid(value.as_bytes())?;
+        Ok(Self(value.into_bytes()))
+    }
+}
+
+impl core::ops::Deref for GraphicString {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsnType for GraphicString {
+    const TAG: Tag = Tag::GRAPHIC_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("GraphicString");
+}
+
+impl Decode for GraphicString {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_graphic_string(tag, constraints)
+    }
+}
+
+impl Encode for GraphicString {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_graphic_string(tag, constraints, self)
+            .map(drop)
+    }
+}
@@ -33,6 +33,7 @@ impl core::fmt::Display for Ia5String {
 
 impl AsnType for Ia5String {
     const TAG: Tag = Tag::IA5_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("IA5String");
 }
 
 impl Encode for Ia5String {
@@ -59,6 +59,7 @@ impl TryFrom<Vec<u8>> for NumericString {
 
 impl AsnType for NumericString {
     const TAG: Tag = Tag::NUMERIC_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("NumericString");
 }
 
 impl Encode for NumericString {
@@ -59,6 +59,7 @@ impl<const N: usize> core::ops::DerefMut for FixedOctetString<N> {
 
 impl<const N: usize> AsnType for FixedOctetString<N> {
     const TAG: Tag = Tag::OCTET_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("OCTET_STRING");
     const CONSTRAINTS: Constraints<'static> = Constraints::new(&[Constraint::Size(
         Extensible::new(constraints::Size::fixed(N)),
     )]);
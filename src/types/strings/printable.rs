@@ -90,6 +90,7 @@ impl TryFrom<&'_ str> for PrintableString {
 
 impl AsnType for PrintableString {
     const TAG: Tag = Tag::PRINTABLE_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("PrintableString");
 }
 
 impl Encode for PrintableString {
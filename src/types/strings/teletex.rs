@@ -1,8 +1,15 @@
 use super::*;
 
-use alloc::vec::Vec;
+use crate::error::strings::InvalidTeletexString;
+use alloc::{string::String, vec::Vec};
 
 /// A string, which contains the characters defined in T.61 standard.
+///
+/// The string is stored as its raw T.61 octets. [`TeletexString::to_unicode`]
+/// and the `TryFrom<&str>` implementation convert between those octets and
+/// Unicode, using the T.61 supplementary set for the upper half of the code
+/// table and composing the non-spacing diacritical marks (`0xC1..=0xCF`) with
+/// the character that follows them.
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TeletexString(Vec<u8>);
 
@@ -10,6 +17,323 @@ impl TeletexString {
     pub fn new(vec: Vec<u8>) -> Self {
         Self(vec)
     }
+
+    /// Converts the T.61 octets of the string into a Unicode string.
+    ///
+    /// A diacritical mark followed by a character with a precomposed Unicode
+    /// form is converted to that form, otherwise the character is followed by
+    /// the matching combining mark.
+    pub fn to_unicode(&self) -> Result<String, InvalidTeletexString> {
+        let mut string = String::with_capacity(self.0.len());
+        let mut octets = self.0.iter().copied();
+
+        while let Some(octet) = octets.next() {
+            if let Some(mark) = combining_mark(octet) {
+                let base = octets
+                    .next()
+                    .filter(|base| matches!(base, 0x20..=0x7E))
+                    .ok_or(InvalidTeletexString {
+                        character: octet.into(),
+                    })?;
+
+                match COMPOSED
+                    .iter()
+                    .find(|(diacritic, b, _)| *diacritic == normalise(octet) && *b == base)
+                {
+                    Some((_, _, ch)) => string.push(*ch),
+                    None => {
+                        string.push(char::from(base));
+                        string.push(mark);
+                    }
+                }
+            } else {
+                string.push(supplementary_to_char(octet).ok_or(InvalidTeletexString {
+                    character: octet.into(),
+                })?);
+            }
+        }
+
+        Ok(string)
+    }
+}
+
+/// Non-spacing diacritical marks of the T.61 supplementary set, and their
+/// Unicode combining equivalents.
+const DIACRITICS: &[(u8, char)] = &[
+    (0xC1, '\u{0300}'),
+    (0xC2, '\u{0301}'),
+    (0xC3, '\u{0302}'),
+    (0xC4, '\u{0303}'),
+    (0xC5, '\u{0304}'),
+    (0xC6, '\u{0306}'),
+    (0xC7, '\u{0307}'),
+    (0xC8, '\u{0308}'),
+    (0xC9, '\u{0308}'),
+    (0xCA, '\u{030A}'),
+    (0xCB, '\u{0327}'),
+    (0xCD, '\u{030B}'),
+    (0xCE, '\u{0328}'),
+    (0xCF, '\u{030C}'),
+];
+
+/// Characters of the T.61 supplementary set that are not diacritical marks.
+const SUPPLEMENTARY: &[(u8, char)] = &[
+    (0xA0, '\u{00A0}'),
+    (0xA1, '¡'),
+    (0xA2, '¢'),
+    (0xA3, '£'),
+    (0xA4, '$'),
+    (0xA5, '¥'),
+    (0xA6, '#'),
+    (0xA7, '§'),
+    (0xA8, '¤'),
+    (0xAB, '«'),
+    (0xB0, '°'),
+    (0xB1, '±'),
+    (0xB2, '²'),
+    (0xB3, '³'),
+    (0xB4, '×'),
+    (0xB5, 'µ'),
+    (0xB6, '¶'),
+    (0xB7, '·'),
+    (0xB8, '÷'),
+    (0xBB, '»'),
+    (0xBC, '¼'),
+    (0xBD, '½'),
+    (0xBE, '¾'),
+    (0xBF, '¿'),
+    (0xE0, 'Ω'),
+    (0xE1, 'Æ'),
+    (0xE2, 'Đ'),
+    (0xE3, 'ª'),
+    (0xE4, 'Ħ'),
+    (0xE6, 'Ĳ'),
+    (0xE7, 'Ŀ'),
+    (0xE8, 'Ł'),
+    (0xE9, 'Ø'),
+    (0xEA, 'Œ'),
+    (0xEB, 'º'),
+    (0xEC, 'Þ'),
+    (0xED, 'Ŧ'),
+    (0xEE, 'Ŋ'),
+    (0xEF, 'ŉ'),
+    (0xF0, 'ĸ'),
+    (0xF1, 'æ'),
+    (0xF2, 'đ'),
+    (0xF3, 'ð'),
+    (0xF4, 'ħ'),
+    (0xF5, 'ı'),
+    (0xF6, 'ĳ'),
+    (0xF7, 'ŀ'),
+    (0xF8, 'ł'),
+    (0xF9, 'ø'),
+    (0xFA, 'œ'),
+    (0xFB, 'ß'),
+    (0xFC, 'þ'),
+    (0xFD, 'ŧ'),
+    (0xFE, 'ŋ'),
+    (0xFF, '\u{00AD}'),
+];
+
+/// Precomposed Latin characters, as a diacritical mark and base character.
+const COMPOSED: &[(u8, u8, char)] = &[
+    (0xC1, b'A', '\u{00C0}'),
+    (0xC1, b'E', '\u{00C8}'),
+    (0xC1, b'I', '\u{00CC}'),
+    (0xC1, b'O', '\u{00D2}'),
+    (0xC1, b'U', '\u{00D9}'),
+    (0xC1, b'a', '\u{00E0}'),
+    (0xC1, b'e', '\u{00E8}'),
+    (0xC1, b'i', '\u{00EC}'),
+    (0xC1, b'o', '\u{00F2}'),
+    (0xC1, b'u', '\u{00F9}'),
+    (0xC2, b'A', '\u{00C1}'),
+    (0xC2, b'C', '\u{0106}'),
+    (0xC2, b'E', '\u{00C9}'),
+    (0xC2, b'I', '\u{00CD}'),
+    (0xC2, b'L', '\u{0139}'),
+    (0xC2, b'N', '\u{0143}'),
+    (0xC2, b'O', '\u{00D3}'),
+    (0xC2, b'R', '\u{0154}'),
+    (0xC2, b'S', '\u{015A}'),
+    (0xC2, b'U', '\u{00DA}'),
+    (0xC2, b'Y', '\u{00DD}'),
+    (0xC2, b'Z', '\u{0179}'),
+    (0xC2, b'a', '\u{00E1}'),
+    (0xC2, b'c', '\u{0107}'),
+    (0xC2, b'e', '\u{00E9}'),
+    (0xC2, b'i', '\u{00ED}'),
+    (0xC2, b'l', '\u{013A}'),
+    (0xC2, b'n', '\u{0144}'),
+    (0xC2, b'o', '\u{00F3}'),
+    (0xC2, b'r', '\u{0155}'),
+    (0xC2, b's', '\u{015B}'),
+    (0xC2, b'u', '\u{00FA}'),
+    (0xC2, b'y', '\u{00FD}'),
+    (0xC2, b'z', '\u{017A}'),
+    (0xC3, b'A', '\u{00C2}'),
+    (0xC3, b'C', '\u{0108}'),
+    (0xC3, b'E', '\u{00CA}'),
+    (0xC3, b'G', '\u{011C}'),
+    (0xC3, b'H', '\u{0124}'),
+    (0xC3, b'I', '\u{00CE}'),
+    (0xC3, b'J', '\u{0134}'),
+    (0xC3, b'O', '\u{00D4}'),
+    (0xC3, b'S', '\u{015C}'),
+    (0xC3, b'U', '\u{00DB}'),
+    (0xC3, b'W', '\u{0174}'),
+    (0xC3, b'Y', '\u{0176}'),
+    (0xC3, b'a', '\u{00E2}'),
+    (0xC3, b'c', '\u{0109}'),
+    (0xC3, b'e', '\u{00EA}'),
+    (0xC3, b'g', '\u{011D}'),
+    (0xC3, b'h', '\u{0125}'),
+    (0xC3, b'i', '\u{00EE}'),
+    (0xC3, b'j', '\u{0135}'),
+    (0xC3, b'o', '\u{00F4}'),
+    (0xC3, b's', '\u{015D}'),
+    (0xC3, b'u', '\u{00FB}'),
+    (0xC3, b'w', '\u{0175}'),
+    (0xC3, b'y', '\u{0177}'),
+    (0xC4, b'A', '\u{00C3}'),
+    (0xC4, b'I', '\u{0128}'),
+    (0xC4, b'N', '\u{00D1}'),
+    (0xC4, b'O', '\u{00D5}'),
+    (0xC4, b'U', '\u{0168}'),
+    (0xC4, b'a', '\u{00E3}'),
+    (0xC4, b'i', '\u{0129}'),
+    (0xC4, b'n', '\u{00F1}'),
+    (0xC4, b'o', '\u{00F5}'),
+    (0xC4, b'u', '\u{0169}'),
+    (0xC5, b'A', '\u{0100}'),
+    (0xC5, b'E', '\u{0112}'),
+    (0xC5, b'I', '\u{012A}'),
+    (0xC5, b'O', '\u{014C}'),
+    (0xC5, b'U', '\u{016A}'),
+    (0xC5, b'a', '\u{0101}'),
+    (0xC5, b'e', '\u{0113}'),
+    (0xC5, b'i', '\u{012B}'),
+    (0xC5, b'o', '\u{014D}'),
+    (0xC5, b'u', '\u{016B}'),
+    (0xC6, b'A', '\u{0102}'),
+    (0xC6, b'E', '\u{0114}'),
+    (0xC6, b'G', '\u{011E}'),
+    (0xC6, b'I', '\u{012C}'),
+    (0xC6, b'O', '\u{014E}'),
+    (0xC6, b'U', '\u{016C}'),
+    (0xC6, b'a', '\u{0103}'),
+    (0xC6, b'e', '\u{0115}'),
+    (0xC6, b'g', '\u{011F}'),
+    (0xC6, b'i', '\u{012D}'),
+    (0xC6, b'o', '\u{014F}'),
+    (0xC6, b'u', '\u{016D}'),
+    (0xC7, b'C', '\u{010A}'),
+    (0xC7, b'E', '\u{0116}'),
+    (0xC7, b'G', '\u{0120}'),
+    (0xC7, b'I', '\u{0130}'),
+    (0xC7, b'Z', '\u{017B}'),
+    (0xC7, b'c', '\u{010B}'),
+    (0xC7, b'e', '\u{0117}'),
+    (0xC7, b'g', '\u{0121}'),
+    (0xC7, b'z', '\u{017C}'),
+    (0xC8, b'A', '\u{00C4}'),
+    (0xC8, b'E', '\u{00CB}'),
+    (0xC8, b'I', '\u{00CF}'),
+    (0xC8, b'O', '\u{00D6}'),
+    (0xC8, b'U', '\u{00DC}'),
+    (0xC8, b'Y', '\u{0178}'),
+    (0xC8, b'a', '\u{00E4}'),
+    (0xC8, b'e', '\u{00EB}'),
+    (0xC8, b'i', '\u{00EF}'),
+    (0xC8, b'o', '\u{00F6}'),
+    (0xC8, b'u', '\u{00FC}'),
+    (0xC8, b'y', '\u{00FF}'),
+    (0xCA, b'A', '\u{00C5}'),
+    (0xCA, b'U', '\u{016E}'),
+    (0xCA, b'a', '\u{00E5}'),
+    (0xCA, b'u', '\u{016F}'),
+    (0xCB, b'C', '\u{00C7}'),
+    (0xCB, b'G', '\u{0122}'),
+    (0xCB, b'K', '\u{0136}'),
+    (0xCB, b'L', '\u{013B}'),
+    (0xCB, b'N', '\u{0145}'),
+    (0xCB, b'R', '\u{0156}'),
+    (0xCB, b'S', '\u{015E}'),
+    (0xCB, b'T', '\u{0162}'),
+    (0xCB, b'c', '\u{00E7}'),
+    (0xCB, b'g', '\u{0123}'),
+    (0xCB, b'k', '\u{0137}'),
+    (0xCB, b'l', '\u{013C}'),
+    (0xCB, b'n', '\u{0146}'),
+    (0xCB, b'r', '\u{0157}'),
+    (0xCB, b's', '\u{015F}'),
+    (0xCB, b't', '\u{0163}'),
+    (0xCD, b'O', '\u{0150}'),
+    (0xCD, b'U', '\u{0170}'),
+    (0xCD, b'o', '\u{0151}'),
+    (0xCD, b'u', '\u{0171}'),
+    (0xCE, b'A', '\u{0104}'),
+    (0xCE, b'E', '\u{0118}'),
+    (0xCE, b'I', '\u{012E}'),
+    (0xCE, b'U', '\u{0172}'),
+    (0xCE, b'a', '\u{0105}'),
+    (0xCE, b'e', '\u{0119}'),
+    (0xCE, b'i', '\u{012F}'),
+    (0xCE, b'u', '\u{0173}'),
+    (0xCF, b'C', '\u{010C}'),
+    (0xCF, b'D', '\u{010E}'),
+    (0xCF, b'E', '\u{011A}'),
+    (0xCF, b'L', '\u{013D}'),
+    (0xCF, b'N', '\u{0147}'),
+    (0xCF, b'R', '\u{0158}'),
+    (0xCF, b'S', '\u{0160}'),
+    (0xCF, b'T', '\u{0164}'),
+    (0xCF, b'Z', '\u{017D}'),
+    (0xCF, b'c', '\u{010D}'),
+    (0xCF, b'd', '\u{010F}'),
+    (0xCF, b'e', '\u{011B}'),
+    (0xCF, b'l', '\u{013E}'),
+    (0xCF, b'n', '\u{0148}'),
+    (0xCF, b'r', '\u{0159}'),
+    (0xCF, b's', '\u{0161}'),
+    (0xCF, b't', '\u{0165}'),
+    (0xCF, b'z', '\u{017E}'),
+];
+
+fn combining_mark(octet: u8) -> Option<char> {
+    DIACRITICS
+        .iter()
+        .find(|(diacritic, _)| *diacritic == octet)
+        .map(|(_, mark)| *mark)
+}
+
+/// `0xC9` is the legacy umlaut mark, which is encoded as the diaeresis.
+fn normalise(diacritic: u8) -> u8 {
+    if diacritic == 0xC9 {
+        0xC8
+    } else {
+        diacritic
+    }
+}
+
+fn supplementary_to_char(octet: u8) -> Option<char> {
+    match octet {
+        0x00..=0x9F => Some(char::from(octet)),
+        _ => SUPPLEMENTARY
+            .iter()
+            .find(|(o, _)| *o == octet)
+            .map(|(_, ch)| *ch),
+    }
+}
+
+fn char_to_supplementary(ch: char) -> Option<u8> {
+    match u32::from(ch) {
+        0x00..=0x9F => Some(ch as u8),
+        _ => SUPPLEMENTARY
+            .iter()
+            .find(|(_, c)| *c == ch)
+            .map(|(octet, _)| *octet),
+    }
 }
 
 impl From<Vec<u8>> for TeletexString {
@@ -18,6 +342,50 @@ impl From<Vec<u8>> for TeletexString {
     }
 }
 
+impl TryFrom<String> for TeletexString {
+    type Error = InvalidTeletexString;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(&*value)
+    }
+}
+
+impl TryFrom<&'_ str> for TeletexString {
+    type Error = InvalidTeletexString;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut vec = Vec::with_capacity(value.len());
+        let mut chars = value.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if let Some((diacritic, base, _)) = COMPOSED.iter().find(|(_, _, c)| *c == ch) {
+                vec.extend([*diacritic, *base]);
+                continue;
+            }
+
+            let octet = char_to_supplementary(ch).ok_or(InvalidTeletexString {
+                character: ch.into(),
+            })?;
+            let diacritic = chars.peek().and_then(|mark| {
+                DIACRITICS
+                    .iter()
+                    .find(|(_, m)| m == mark)
+                    .map(|(diacritic, _)| *diacritic)
+            });
+
+            match diacritic {
+                Some(diacritic) if matches!(octet, 0x20..=0x7E) => {
+                    chars.next();
+                    vec.extend([diacritic, octet]);
+                }
+                _ => vec.push(octet),
+            }
+        }
+
+        Ok(Self(vec))
+    }
+}
+
 impl core::ops::Deref for TeletexString {
     type Target = [u8];
 
@@ -28,6 +396,7 @@ impl core::ops::Deref for TeletexString {
 
 impl AsnType for TeletexString {
     const TAG: Tag = Tag::TELETEX_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("TeletexString");
 }
 
 impl Encode for TeletexString {
@@ -52,3 +421,42 @@ impl Decode for TeletexString {
         decoder.decode_teletex_string(tag, constraints)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_conversion() {
+        let cases: &[(&[u8], &str)] = &[
+            (b"abc", "abc"),
+            (&[0xE8, 0xC2, b'o', b'd', 0xC2, b'z'], "Łódź"),
+            (&[0xC8, b'u', 0xFB], "üß"),
+            (&[0xC3, b'x'], "x\u{0302}"),
+        ];
+
+        for (octets, string) in cases {
+            let teletex = TeletexString::from(octets.to_vec());
+            assert_eq!(teletex.to_unicode().unwrap(), *string);
+            assert_eq!(TeletexString::try_from(*string).unwrap(), teletex);
+        }
+    }
+
+    #[test]
+    fn alternative_encodings() {
+        let teletex = TeletexString::from(alloc::vec![0xA4, 0x31, 0xA6]);
+        assert_eq!(teletex.to_unicode().unwrap(), "$1#");
+        let teletex = TeletexString::from(alloc::vec![0xC9, b'a']);
+        assert_eq!(teletex.to_unicode().unwrap(), "ä");
+    }
+
+    #[test]
+    fn invalid_characters() {
+        assert!(TeletexString::from(alloc::vec![0xC2]).to_unicode().is_err());
+        assert!(TeletexString::from(alloc::vec![0xC2, 0xC3, b'a'])
+            .to_unicode()
+            .is_err());
+        assert!(TeletexString::from(alloc::vec![0xD0]).to_unicode().is_err());
+        assert!(TeletexString::try_from("€").is_err());
+    }
+}
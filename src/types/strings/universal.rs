@@ -0,0 +1,120 @@
+use super::*;
+
+use crate::error::strings::InvalidUniversalString;
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+/// A string of any Unicode characters, which is encoded as UCS-4, four
+/// octets per character.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UniversalString(Vec<u32>);
+
+impl UniversalString {
+    /// Converts the string into a set of big endian bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.iter().flat_map(|ch| ch.to_be_bytes()).collect()
+    }
+}
+
+impl UniversalString {
+    /// Checks that every character is a Unicode scalar value, as decoders
+    /// accept any 32-bit value through `push_char`.
+    pub(crate) fn validate(self) -> Result<Self, InvalidUniversalString> {
+        match self.0.iter().find(|ch| !Self::contains_char(**ch)) {
+            Some(ch) => Err(InvalidUniversalString { character: *ch }),
+            None => Ok(self),
+        }
+    }
+}
+
+impl TryFrom<&'_ [u8]> for UniversalString {
+    type Error = InvalidUniversalString;
+
+    /// Converts a set of big endian bytes into a string.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let mut vec = Vec::with_capacity(bytes.len() / 4);
+        for chunk in bytes.chunks(4) {
+            let ch = chunk
+                .iter()
+                .fold(0u32, |ch, byte| (ch << 8) | u32::from(*byte));
+
+            if chunk.len() != 4 || !Self::contains_char(ch) {
+                return Err(InvalidUniversalString { character: ch });
+            }
+
+            vec.push(ch);
+        }
+
+        Ok(Self(vec))
+    }
+}
+
+impl StaticPermittedAlphabet for UniversalString {
+    /// Every Unicode scalar value is permitted, which is too many to list, so
+    /// `contains_char` is used instead.
+    const CHARACTER_SET: &'static [u32] = &[];
+    const CHARACTER_WIDTH: u32 = u32::BITS;
+
+    fn contains_char(ch: u32) -> bool {
+        char::from_u32(ch).is_some()
+    }
+
+    fn push_char(&mut self, ch: u32) {
+        self.0.push(ch);
+    }
+
+    fn chars(&self) -> Box<dyn Iterator<Item = u32> + '_> {
+        Box::from(self.0.iter().copied())
+    }
+}
+
+impl From<&'_ str> for UniversalString {
+    fn from(value: &str) -> Self {
+        Self(value.chars().map(u32::from).collect())
+    }
+}
+
+impl From<String> for UniversalString {
+    fn from(value: String) -> Self {
+        Self::from(&*value)
+    }
+}
+
+impl core::fmt::Display for UniversalString {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        for ch in &self.0 {
+            core::fmt::Write::write_char(
+                f,
+                char::from_u32(*ch).unwrap_or(char::REPLACEMENT_CHARACTER),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl AsnType for UniversalString {
+    const TAG: Tag = Tag::UNIVERSAL_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("UniversalString");
+}
+
+impl Encode for UniversalString {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_universal_string(tag, constraints, self)
+            .map(drop)
+    }
+}
+
+impl Decode for UniversalString {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_universal_string(tag, constraints)
+    }
+}
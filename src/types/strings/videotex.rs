@@ -0,0 +1,71 @@
+use super::*;
+
+use alloc::{string::String, vec::Vec};
+
+/// A string, which contains the characters defined in the T.100 and T.101
+/// standards.
+///
+/// The string is stored as its raw octets, as there is no conversion
+/// between the videotex character sets and Unicode.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VideotexString(Vec<u8>);
+
+impl VideotexString {
+    pub fn new(vec: Vec<u8>) -> Self {
+        Self(vec)
+    }
+}
+
+impl From<Vec<u8>> for VideotexString {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&'_ [u8]> for VideotexString {
+    fn from(value: &[u8]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl From<String> for VideotexString {
+    fn from(value: String) -> Self {
+        Self(value.into_bytes())
+    }
+}
+
+impl core::ops::Deref for VideotexString {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsnType for VideotexString {
+    const TAG: Tag = Tag::VIDEOTEX_STRING;
+    const IDENTIFIER: Option<&'static str> = Some("VideotexString");
+}
+
+impl Decode for VideotexString {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_videotex_string(tag, constraints)
+    }
+}
+
+impl Encode for VideotexString {
+    fn encode_with_tag_and_constraints<E: Encoder>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_videotex_string(tag, constraints, self)
+            .map(drop)
+    }
+}
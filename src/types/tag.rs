@@ -113,6 +113,7 @@ consts! {
     EMBEDDED_PDV = 11,
     UTF8_STRING = 12,
     RELATIVE_OID = 13,
+    TIME = 14,
     SEQUENCE = 16,
     SET = 17,
     NUMERIC_STRING = 18,
@@ -127,7 +128,13 @@ consts! {
     GENERAL_STRING = 27,
     UNIVERSAL_STRING = 28,
     CHARACTER_STRING = 29,
-    BMP_STRING = 30
+    BMP_STRING = 30,
+    DATE = 31,
+    TIME_OF_DAY = 32,
+    DATE_TIME = 33,
+    DURATION = 34,
+    OID_IRI = 35,
+    RELATIVE_OID_IRI = 36
 }
 
 impl Tag {
@@ -0,0 +1,517 @@
+use alloc::string::{String, ToString};
+use core::{fmt, str::FromStr};
+
+use chrono::{Datelike, Timelike};
+
+/// The `DATE` type, a calendar date such as `2024-02-29`.
+pub type Date = chrono::NaiveDate;
+/// The `TIME-OF-DAY` type, a local time of day such as `13:30:00`.
+///
+/// `TIME-OF-DAY` has a precision of one second, so any fraction of a second
+/// is dropped when encoding.
+pub type TimeOfDay = chrono::NaiveTime;
+/// The `DATE-TIME` type, a local date and time of day such as
+/// `2024-02-29T13:30:00`.
+///
+/// `DATE-TIME` has a precision of one second, so any fraction of a second is
+/// dropped when encoding.
+pub type DateTime = chrono::NaiveDateTime;
+
+/// The `DURATION` type, an ISO 8601 duration such as `P1Y2M10DT2H30M`.
+///
+/// Each component is optional, but at least one of them has to be present.
+/// The `fraction` applies to the last present component, so `PT1.5S` is
+/// written as:
+/// ```
+/// use rasn::types::{Duration, DurationFraction};
+///
+/// let duration = Duration {
+///     seconds: Some(1),
+///     fraction: Some(DurationFraction { digits: 1, value: 5 }),
+///     ..Duration::default()
+/// };
+///
+/// assert_eq!(duration.to_string(), "PT1.5S");
+/// assert_eq!("PT1.5S".parse(), Ok(duration));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Duration {
+    pub years: Option<u32>,
+    pub months: Option<u32>,
+    pub weeks: Option<u32>,
+    pub days: Option<u32>,
+    pub hours: Option<u32>,
+    pub minutes: Option<u32>,
+    pub seconds: Option<u32>,
+    /// The fractional part of the last present component.
+    pub fraction: Option<DurationFraction>,
+}
+
+/// The decimal fraction of the last component of a [`Duration`], such as the
+/// `.25` of `PT1.25S`, which has two `digits` and a `value` of `25`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DurationFraction {
+    /// The number of digits after the decimal mark, including leading zeros.
+    pub digits: u32,
+    pub value: u32,
+}
+
+impl Duration {
+    /// The components of the duration with their ISO 8601 designators, in
+    /// the order they're written.
+    fn components(&self) -> [(Option<u32>, char); 7] {
+        [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+            (self.hours, 'H'),
+            (self.minutes, 'M'),
+            (self.seconds, 'S'),
+        ]
+    }
+
+    pub(crate) fn is_valid(&self) -> bool {
+        let has_component = self.components().iter().any(|(value, _)| value.is_some());
+        match self.fraction {
+            // The value has to fit in the number of digits.
+            Some(fraction) => {
+                has_component
+                    && fraction.digits != 0
+                    && (fraction.digits >= 10 || fraction.value < 10u32.pow(fraction.digits))
+            }
+            None => has_component,
+        }
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let components = self.components();
+        let last = components.iter().rposition(|(value, _)| value.is_some());
+
+        f.write_str("P")?;
+        for (index, (value, designator)) in components.into_iter().enumerate() {
+            let Some(value) = value else { continue };
+            if index >= 4 && !components[4..index].iter().any(|(v, _)| v.is_some()) {
+                f.write_str("T")?;
+            }
+            write!(f, "{value}")?;
+            if let Some(fraction) = self.fraction.filter(|_| Some(index) == last) {
+                write!(
+                    f,
+                    ".{:0width$}",
+                    fraction.value,
+                    width = fraction.digits as usize
+                )?;
+            }
+            write!(f, "{designator}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Duration {
+    type Err = InvalidTimeValue;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let error = InvalidTimeValue::new("DURATION");
+        let mut rest = string.strip_prefix('P').ok_or(error)?;
+        let mut duration = Self::default();
+        let mut in_time = false;
+        // The index of the next component that may appear.
+        let mut next = 0;
+
+        while !rest.is_empty() {
+            if duration.fraction.is_some() {
+                return Err(error);
+            }
+            if let Some(time) = rest.strip_prefix('T') {
+                if in_time || time.is_empty() {
+                    return Err(error);
+                }
+                in_time = true;
+                next = next.max(4);
+                rest = time;
+                continue;
+            }
+
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+                .ok_or(error)?;
+            let (number, designator) = (&rest[..end], rest[end..].chars().next().unwrap());
+            rest = &rest[end + designator.len_utf8()..];
+            let (integer, fraction) = match number.split_once(['.', ',']) {
+                Some((integer, fraction)) => (integer, Some(fraction)),
+                None => (number, None),
+            };
+
+            let index = match (designator, in_time) {
+                ('Y', false) => 0,
+                ('M', false) => 1,
+                ('W', false) => 2,
+                ('D', false) => 3,
+                ('H', true) => 4,
+                ('M', true) => 5,
+                ('S', true) => 6,
+                _ => return Err(error),
+            };
+            if index < next {
+                return Err(error);
+            }
+            next = index + 1;
+
+            let value = Some(digits(integer).ok_or(error)?);
+            match index {
+                0 => duration.years = value,
+                1 => duration.months = value,
+                2 => duration.weeks = value,
+                3 => duration.days = value,
+                4 => duration.hours = value,
+                5 => duration.minutes = value,
+                _ => duration.seconds = value,
+            }
+            if let Some(fraction) = fraction {
+                duration.fraction = Some(DurationFraction {
+                    digits: fraction.len() as u32,
+                    value: digits(fraction).ok_or(error)?,
+                });
+            }
+        }
+
+        if duration.is_valid() {
+            Ok(duration)
+        } else {
+            Err(error)
+        }
+    }
+}
+
+/// The generic `TIME` type, holding any ISO 8601 time value, such as a date,
+/// a time with a UTC offset, an interval, or a recurrence.
+///
+/// The value is kept as written, and is only checked to be made up of the
+/// characters ISO 8601 uses.
+/// ```
+/// use rasn::types::TimeValue;
+///
+/// let time: TimeValue = "2024-02-29T13:30:00Z/PT1H".parse().unwrap();
+/// assert_eq!(time.as_str(), "2024-02-29T13:30:00Z/PT1H");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeValue(String);
+
+impl TimeValue {
+    /// Creates a new `TIME` value from its ISO 8601 representation.
+    pub fn new(value: impl Into<String>) -> Result<Self, InvalidTimeValue> {
+        let value = value.into();
+        let is_valid = !value.is_empty()
+            && value
+                .chars()
+                .all(|c| c.is_ascii_digit() || "+-:.,/CDHLMPRSTWYZ".contains(c));
+
+        if is_valid {
+            Ok(Self(value))
+        } else {
+            Err(InvalidTimeValue::new("TIME"))
+        }
+    }
+
+    /// Returns the ISO 8601 representation of the value.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for TimeValue {
+    type Err = InvalidTimeValue;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::new(string)
+    }
+}
+
+/// The error returned when a value isn't valid for one of the time types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTimeValue {
+    type_name: &'static str,
+}
+
+impl InvalidTimeValue {
+    pub(crate) fn new(type_name: &'static str) -> Self {
+        Self { type_name }
+    }
+}
+
+impl fmt::Display for InvalidTimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "not a valid {} value", self.type_name)
+    }
+}
+
+/// Conversions between the time types and their string representations.
+///
+/// The text based codecs use the ISO 8601 extended format of the ASN.1 value
+/// notation (e.g. `2024-02-29`), while BER and OER encode the useful time
+/// types in the basic format required by X.690 (e.g. `20240229`).
+pub(crate) trait TimeType: Sized {
+    const NAME: &'static str;
+
+    fn to_extended(&self) -> Result<String, InvalidTimeValue>;
+
+    fn from_extended(string: &str) -> Result<Self, InvalidTimeValue>;
+
+    fn to_basic(&self) -> Result<String, InvalidTimeValue> {
+        self.to_extended()
+    }
+
+    fn from_basic(string: &str) -> Result<Self, InvalidTimeValue> {
+        Self::from_extended(string)
+    }
+
+    fn from_basic_bytes(bytes: &[u8]) -> Result<Self, InvalidTimeValue> {
+        core::str::from_utf8(bytes)
+            .map_err(|_| InvalidTimeValue::new(Self::NAME))
+            .and_then(Self::from_basic)
+    }
+}
+
+impl TimeType for Date {
+    const NAME: &'static str = "DATE";
+
+    fn to_extended(&self) -> Result<String, InvalidTimeValue> {
+        Ok(alloc::format!(
+            "{}-{:02}-{:02}",
+            year(self)?,
+            self.month(),
+            self.day()
+        ))
+    }
+
+    fn from_extended(string: &str) -> Result<Self, InvalidTimeValue> {
+        match string.as_bytes() {
+            [_, _, _, _, b'-', _, _, b'-', _, _] => {
+                parse_date(&string[..4], &string[5..7], &string[8..])
+            }
+            _ => None,
+        }
+        .ok_or(InvalidTimeValue::new(Self::NAME))
+    }
+
+    fn to_basic(&self) -> Result<String, InvalidTimeValue> {
+        Ok(alloc::format!(
+            "{}{:02}{:02}",
+            year(self)?,
+            self.month(),
+            self.day()
+        ))
+    }
+
+    fn from_basic(string: &str) -> Result<Self, InvalidTimeValue> {
+        (string.len() == 8 && string.is_ascii())
+            .then(|| parse_date(&string[..4], &string[4..6], &string[6..]))
+            .flatten()
+            .ok_or(InvalidTimeValue::new(Self::NAME))
+    }
+}
+
+impl TimeType for TimeOfDay {
+    const NAME: &'static str = "TIME-OF-DAY";
+
+    fn to_extended(&self) -> Result<String, InvalidTimeValue> {
+        Ok(alloc::format!(
+            "{:02}:{:02}:{:02}",
+            self.hour(),
+            self.minute(),
+            second(self)
+        ))
+    }
+
+    fn from_extended(string: &str) -> Result<Self, InvalidTimeValue> {
+        match string.as_bytes() {
+            [_, _, b':', _, _, b':', _, _] => parse_time(&string[..2], &string[3..5], &string[6..]),
+            _ => None,
+        }
+        .ok_or(InvalidTimeValue::new(Self::NAME))
+    }
+
+    fn to_basic(&self) -> Result<String, InvalidTimeValue> {
+        Ok(alloc::format!(
+            "{:02}{:02}{:02}",
+            self.hour(),
+            self.minute(),
+            second(self)
+        ))
+    }
+
+    fn from_basic(string: &str) -> Result<Self, InvalidTimeValue> {
+        (string.len() == 6 && string.is_ascii())
+            .then(|| parse_time(&string[..2], &string[2..4], &string[4..]))
+            .flatten()
+            .ok_or(InvalidTimeValue::new(Self::NAME))
+    }
+}
+
+impl TimeType for DateTime {
+    const NAME: &'static str = "DATE-TIME";
+
+    fn to_extended(&self) -> Result<String, InvalidTimeValue> {
+        let error = |_| InvalidTimeValue::new(Self::NAME);
+        Ok(alloc::format!(
+            "{}T{}",
+            self.date().to_extended().map_err(error)?,
+            self.time().to_extended().map_err(error)?
+        ))
+    }
+
+    fn from_extended(string: &str) -> Result<Self, InvalidTimeValue> {
+        let error = |_| InvalidTimeValue::new(Self::NAME);
+        let (date, time) = string
+            .split_once('T')
+            .ok_or(InvalidTimeValue::new(Self::NAME))?;
+        Ok(Date::from_extended(date)
+            .map_err(error)?
+            .and_time(TimeOfDay::from_extended(time).map_err(error)?))
+    }
+
+    fn to_basic(&self) -> Result<String, InvalidTimeValue> {
+        let error = |_| InvalidTimeValue::new(Self::NAME);
+        Ok(self.date().to_basic().map_err(error)? + &self.time().to_basic().map_err(error)?)
+    }
+
+    fn from_basic(string: &str) -> Result<Self, InvalidTimeValue> {
+        let error = |_| InvalidTimeValue::new(Self::NAME);
+        if string.len() != 14 || !string.is_ascii() {
+            return Err(InvalidTimeValue::new(Self::NAME));
+        }
+        Ok(Date::from_basic(&string[..8])
+            .map_err(error)?
+            .and_time(TimeOfDay::from_basic(&string[8..]).map_err(error)?))
+    }
+}
+
+impl TimeType for Duration {
+    const NAME: &'static str = "DURATION";
+
+    fn to_extended(&self) -> Result<String, InvalidTimeValue> {
+        if self.is_valid() {
+            Ok(self.to_string())
+        } else {
+            Err(InvalidTimeValue::new(Self::NAME))
+        }
+    }
+
+    fn from_extended(string: &str) -> Result<Self, InvalidTimeValue> {
+        string.parse()
+    }
+}
+
+impl TimeType for TimeValue {
+    const NAME: &'static str = "TIME";
+
+    fn to_extended(&self) -> Result<String, InvalidTimeValue> {
+        Ok(self.0.clone())
+    }
+
+    fn from_extended(string: &str) -> Result<Self, InvalidTimeValue> {
+        Self::new(string)
+    }
+}
+
+/// Formats the year of `date`, which has to fit in four digits.
+fn year(date: &Date) -> Result<String, InvalidTimeValue> {
+    (0..=9999)
+        .contains(&date.year())
+        .then(|| alloc::format!("{:04}", date.year()))
+        .ok_or(InvalidTimeValue::new(Date::NAME))
+}
+
+/// Returns the second of `time`, which is `60` during a leap second.
+pub(crate) fn second(time: &TimeOfDay) -> u32 {
+    if time.nanosecond() >= 1_000_000_000 {
+        60
+    } else {
+        time.second()
+    }
+}
+
+fn parse_date(year: &str, month: &str, day: &str) -> Option<Date> {
+    Date::from_ymd_opt(digits(year)?.try_into().ok()?, digits(month)?, digits(day)?)
+}
+
+fn parse_time(hour: &str, minute: &str, second: &str) -> Option<TimeOfDay> {
+    time_of_day(digits(hour)?, digits(minute)?, digits(second)?)
+}
+
+/// Returns the time of day, where a `second` of `60` is a leap second.
+pub(crate) fn time_of_day(hour: u32, minute: u32, second: u32) -> Option<TimeOfDay> {
+    if second == 60 {
+        TimeOfDay::from_hms_nano_opt(hour, minute, 59, 1_000_000_000)
+    } else {
+        TimeOfDay::from_hms_opt(hour, minute, second)
+    }
+}
+
+/// Parses a non-empty string of ASCII digits.
+fn digits(string: &str) -> Option<u32> {
+    if string.is_empty() || !string.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    string.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration() {
+        for string in [
+            "P1Y2M10DT2H30M",
+            "P3W",
+            "PT0S",
+            "P1DT0.500S",
+            "PT36H",
+            "P0,5Y",
+        ] {
+            let duration: Duration = string.parse().unwrap();
+            assert_eq!(duration.to_string(), string.replace(',', "."));
+        }
+        for string in [
+            "P", "PT", "P1S", "P1H", "PT1D", "P1M1Y", "P1.5Y2M", "P1YT", "1Y", "P1.Y",
+        ] {
+            assert!(string.parse::<Duration>().is_err(), "{string}");
+        }
+    }
+
+    #[test]
+    fn date_and_time_forms() {
+        let date_time = Date::from_ymd_opt(2024, 2, 29)
+            .unwrap()
+            .and_hms_opt(13, 30, 5)
+            .unwrap();
+
+        assert_eq!(date_time.to_extended().unwrap(), "2024-02-29T13:30:05");
+        assert_eq!(date_time.to_basic().unwrap(), "20240229133005");
+        assert_eq!(
+            DateTime::from_extended("2024-02-29T13:30:05"),
+            Ok(date_time)
+        );
+        assert_eq!(DateTime::from_basic("20240229133005"), Ok(date_time));
+        assert!(Date::from_extended("2023-02-29").is_err());
+        assert!(Date::from_basic("2024-2-9").is_err());
+        assert!(TimeOfDay::from_extended("24:00:00").is_err());
+        assert_eq!(
+            TimeOfDay::from_basic("235960").map(|time| time.to_basic()),
+            Ok(Ok("235960".into()))
+        );
+        assert!(Date::from_ymd_opt(10000, 1, 1).unwrap().to_basic().is_err());
+    }
+}
@@ -13,6 +13,27 @@ pub fn decode<T: crate::Decode>(input: &[u8]) -> Result<T, crate::error::DecodeE
     crate::per::decode(de::DecoderOptions::unaligned(), input)
 }
 
+/// Attempts to decode `T` from the start of `input` using UPER-BASIC, returning it
+/// together with the offset in bits of the input following it.
+pub fn decode_with_remainder<T: crate::Decode>(
+    input: &[u8],
+) -> Result<(T, usize), crate::error::DecodeError> {
+    crate::per::decode_with_remainder(de::DecoderOptions::unaligned(), input)
+}
+
+/// Returns an iterator decoding successive values of `T` from `input` using
+/// UPER-BASIC, where each value is a complete encoding padded to a whole number
+/// of octets.
+pub fn decode_iter<T: crate::Decode>(input: &[u8]) -> crate::de::DecodeIter<'_, T> {
+    crate::de::DecodeIter::new(input, crate::Codec::Uper, |input| {
+        let (value, offset) = decode_with_remainder(input)?;
+        Ok((
+            value,
+            crate::per::remainder_after(input, offset, crate::Codec::Uper)?,
+        ))
+    })
+}
+
 /// Attempts to encode `value` to UPER-CANONICAL.
 pub fn encode<T: crate::Encode>(
     value: &T,
@@ -20,6 +41,35 @@ pub fn encode<T: crate::Encode>(
     crate::per::encode(enc::EncoderOptions::unaligned(), value)
 }
 
+/// Attempts to encode `value` to UPER, writing the encoding to `writer`.
+/// # Errors
+/// Returns error specific to UPER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    mut writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    writer
+        .write_all(&encode(value)?)
+        .map_err(|error| crate::error::EncodeError::io(error, crate::Codec::Uper))
+}
+
+/// Attempts to decode `T` from the rest of `reader` using UPER.
+/// # Errors
+/// Returns error specific to UPER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    mut reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    let mut input = alloc::vec::Vec::new();
+    reader
+        .read_to_end(&mut input)
+        .map_err(|error| crate::error::DecodeError::io(error, crate::Codec::Uper))?;
+    decode(&input)
+}
+
 /// Attempts to decode `T` from `input` using UPER-BASIC.
 pub fn decode_with_constraints<T: crate::Decode>(
     constraints: Constraints,
@@ -82,6 +132,28 @@ mod tests {
         round_trip!(uper, E, Integer::from(1000).into(), &[]);
     }
 
+    #[test]
+    fn primitive_integers() {
+        macro_rules! assert_same_as_bigint {
+            ($($int:ty),+) => {$({
+                type Big = ConstrainedInteger<{ <$int>::MIN as i128 }, { <$int>::MAX as i128 }>;
+                for value in [<$int>::MIN, <$int>::MIN + 1, 0, 1, <$int>::MAX - 1, <$int>::MAX] {
+                    let uper = crate::uper::encode(&value).unwrap();
+                    assert_eq!(crate::uper::encode(&Big::from(value)).unwrap(), uper);
+                    assert_eq!(value, crate::uper::decode::<$int>(&uper).unwrap());
+                    assert_eq!(Big::from(value), crate::uper::decode::<Big>(&uper).unwrap());
+
+                    let aper = crate::aper::encode(&value).unwrap();
+                    assert_eq!(crate::aper::encode(&Big::from(value)).unwrap(), aper);
+                    assert_eq!(value, crate::aper::decode::<$int>(&aper).unwrap());
+                    assert_eq!(Big::from(value), crate::aper::decode::<Big>(&aper).unwrap());
+                }
+            })+};
+        }
+
+        assert_same_as_bigint!(u8, i8, u16, i32, u64, i64);
+    }
+
     #[test]
     fn sequence_of() {
         round_trip!(uper, Vec<u8>, vec![1; 5], &[0b00000101, 1, 1, 1, 1, 1]);
@@ -89,7 +161,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn numeric_string() {
         round_trip!(
             uper,
@@ -977,6 +1048,30 @@ mod tests {
             &[96, 8, 5, 52]
         );
     }
+    #[test]
+    fn external_types() {
+        round_trip!(
+            uper,
+            External,
+            External {
+                direct_reference: Some(ObjectIdentifier::new(vec![2, 1, 1]).unwrap()),
+                indirect_reference: None,
+                data_value_descriptor: None,
+                encoding: ExternalEncoding::SingleAsn1Type(Any::new(vec![0x02, 0x01, 0x05])),
+            },
+            &[0x80, 0x4A, 0x20, 0x20, 0x18, 0x10, 0x08, 0x28]
+        );
+        round_trip!(
+            uper,
+            EmbeddedPdv,
+            EmbeddedPdv {
+                identification: Identification::Fixed,
+                data_value: OctetString::from_static(&[0xAB]),
+            },
+            &[0x50, 0x1A, 0xB0]
+        );
+    }
+
     #[test]
     fn test_object_identifier() {
         round_trip!(
@@ -1006,5 +1101,321 @@ mod tests {
             },
             &[0x80, 0x95, 0x00]
         );
+        round_trip!(
+            uper,
+            RelativeOid,
+            RelativeOid::new(vec![8571, 3, 2]).unwrap(),
+            &[0x04, 0xC2, 0x7B, 0x03, 0x02]
+        );
+        round_trip!(
+            uper,
+            OidIri,
+            OidIri::new("/ISO/A").unwrap(),
+            &[0x06, 0x2F, 0x49, 0x53, 0x4F, 0x2F, 0x41]
+        );
+    }
+
+    #[test]
+    fn bmp_string() {
+        round_trip!(
+            uper,
+            BmpString,
+            "Hi".try_into().unwrap(),
+            &[0x02, 0x00, 0x48, 0x00, 0x69]
+        );
+
+        const ALPHABET: &[u32] = &[0x100, 0x101, 0x102, 0x103];
+        round_trip_with_constraints!(
+            uper,
+            BmpString,
+            Constraints::new(&[
+                Constraint::Size(Size::new(Bounded::Single(2)).into()),
+                Constraint::PermittedAlphabet(PermittedAlphabet::new(ALPHABET).into()),
+            ]),
+            "\u{101}\u{102}".try_into().unwrap(),
+            &[0x60]
+        );
+    }
+
+    #[test]
+    fn universal_string() {
+        round_trip!(
+            uper,
+            UniversalString,
+            "H😀".into(),
+            &[0x02, 0x00, 0x00, 0x00, 0x48, 0x00, 0x01, 0xF6, 0x00]
+        );
+        round_trip_with_constraints!(
+            uper,
+            UniversalString,
+            Constraints::new(&[Constraint::Size(Size::new(Bounded::Single(1)).into())]),
+            "H".into(),
+            &[0x00, 0x00, 0x00, 0x48]
+        );
+
+        assert!(crate::uper::decode::<UniversalString>(&[0x01, 0x00, 0x11, 0x00, 0x00]).is_err());
+    }
+
+    #[test]
+    fn graphic_and_videotex_strings() {
+        round_trip!(
+            uper,
+            GraphicString,
+            String::from("Hi").try_into().unwrap(),
+            &[0x02, 0x48, 0x69]
+        );
+        round_trip!(
+            uper,
+            VideotexString,
+            vec![0x48, 0x0A].into(),
+            &[0x02, 0x48, 0x0A]
+        );
+    }
+
+    #[test]
+    fn teletex_string() {
+        round_trip!(
+            uper,
+            TeletexString,
+            "Grüße".try_into().unwrap(),
+            &[0x06, 0x47, 0x72, 0xC8, 0x75, 0xFB, 0x65]
+        );
+    }
+
+    #[test]
+    fn time_types() {
+        let date = Date::from_ymd_opt(2024, 2, 29).unwrap();
+
+        round_trip!(uper, Date, date, &[0x40, 0xC7, 0x80]);
+        round_trip!(
+            uper,
+            Date,
+            Date::from_ymd_opt(1600, 1, 1).unwrap(),
+            &[0xC0, 0x81, 0x90, 0x00, 0x00]
+        );
+        round_trip!(
+            uper,
+            TimeOfDay,
+            TimeOfDay::from_hms_opt(13, 30, 5).unwrap(),
+            &[0x6B, 0xC2, 0x80]
+        );
+        round_trip!(
+            uper,
+            DateTime,
+            date.and_hms_opt(13, 30, 5).unwrap(),
+            &[0x40, 0xC7, 0x8D, 0x78, 0x50]
+        );
+        round_trip!(
+            uper,
+            Duration,
+            "P1Y2M10DT2H30M".parse().unwrap(),
+            &[0xDC, 0x01, 0x01, 0x01, 0x02, 0x01, 0x0A, 0x01, 0x02, 0x01, 0x1E]
+        );
+        round_trip!(
+            uper,
+            Duration,
+            "PT0.25S".parse().unwrap(),
+            &[0x03, 0x01, 0x00, 0x01, 0x01, 0x01, 0x19]
+        );
+        round_trip!(
+            uper,
+            TimeValue,
+            "R/P1D".parse().unwrap(),
+            &[0x05, 0xA4, 0xBE, 0x83, 0x18, 0x80]
+        );
+        round_trip!(
+            uper,
+            LosslessGeneralizedTime,
+            "2024010203,5+01".parse().unwrap(),
+            &[
+                0x11, 0x18, 0x0F, 0x32, 0x30, 0x32, 0x34, 0x30, 0x31, 0x30, 0x32, 0x30, 0x33, 0x2C,
+                0x35, 0x2B, 0x30, 0x31
+            ]
+        );
+    }
+
+    #[test]
+    fn real() {
+        round_trip!(uper, f64, 1.0, &[0x03, 0x80, 0x00, 0x01]);
+        round_trip!(uper, f32, -10.0, &[0x03, 0xC0, 0x01, 0x05]);
+        round_trip!(uper, Real, Real::PlusInfinity, &[0x01, 0x40]);
+    }
+
+    #[test]
+    fn canonical_decoding() {
+        use super::{de, enc};
+
+        let canonical = de::DecoderOptions::canonical_unaligned();
+        let basic = de::DecoderOptions::unaligned();
+
+        let set: SetOf<u8> = [3, 1, 2].into_iter().collect();
+        let encoded = crate::per::encode(enc::EncoderOptions::canonical_unaligned(), &set).unwrap();
+        assert_eq!(&[0x03, 0x01, 0x02, 0x03][..], &*encoded);
+        assert_eq!(
+            set,
+            crate::per::decode::<SetOf<u8>>(canonical, &encoded).unwrap()
+        );
+
+        let unordered = [0x03, 0x03, 0x01, 0x02];
+        assert_eq!(
+            set,
+            crate::per::decode::<SetOf<u8>>(basic, &unordered).unwrap()
+        );
+        assert!(crate::per::decode::<SetOf<u8>>(canonical, &unordered).is_err());
+
+        let padded_integer = [0x02, 0x00, 0x05];
+        assert_eq!(
+            Integer::from(5),
+            crate::per::decode::<Integer>(basic, &padded_integer).unwrap()
+        );
+        assert!(crate::per::decode::<Integer>(canonical, &padded_integer).is_err());
+        assert_eq!(
+            Integer::from(-1),
+            crate::per::decode::<Integer>(canonical, &[0x01, 0xFF]).unwrap()
+        );
+        assert!(crate::per::decode::<Integer>(canonical, &[0x02, 0xFF, 0xFF]).is_err());
+    }
+
+    #[test]
+    fn presence_bitmap_follows_field_order() {
+        #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Descending {
+            #[rasn(tag(context, 1))]
+            a: Option<bool>,
+            #[rasn(tag(context, 0))]
+            b: Option<bool>,
+        }
+
+        // The bitmap is `a` then `b`, even though `b` has the smaller tag.
+        round_trip!(
+            uper,
+            Descending,
+            Descending {
+                a: Some(true),
+                b: None
+            },
+            &[0b1010_0000]
+        );
+    }
+
+    #[test]
+    fn canonical_extension_addition_group() {
+        use super::{de, enc};
+
+        #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        #[non_exhaustive]
+        struct Outer {
+            x: bool,
+            #[rasn(extension_addition_group)]
+            g: Option<Group>,
+        }
+
+        #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Group {
+            a: u8,
+        }
+
+        // A group with a mandatory component is present even though none of
+        // its components are optional.
+        let value = Outer {
+            x: true,
+            g: Some(Group { a: 7 }),
+        };
+        let encoded =
+            crate::per::encode(enc::EncoderOptions::canonical_unaligned(), &value).unwrap();
+        assert_eq!(&[0xC0, 0x40, 0x41, 0xC0][..], &*encoded);
+        assert_eq!(encoded, crate::uper::encode(&value).unwrap());
+        assert_eq!(
+            value,
+            crate::per::decode::<Outer>(de::DecoderOptions::canonical_unaligned(), &encoded)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn limits() {
+        use crate::{
+            de::{Limit, Limits},
+            error::DecodeErrorKind,
+        };
+
+        fn decode<T: crate::Decode>(input: &[u8], limits: Limits) -> Result<T, Limit> {
+            let options = crate::uper::de::DecoderOptions::unaligned().with_limits(limits);
+            crate::per::decode(options, input).map_err(|error| match *error.kind {
+                DecodeErrorKind::LimitExceeded { limit, .. } => limit,
+                kind => panic!("unexpected error: {kind}"),
+            })
+        }
+
+        let nested = [0x01, 0x01, 0x00];
+        assert!(decode::<Vec<Vec<Vec<()>>>>(&nested, Limits::default()).is_ok());
+        assert_eq!(
+            Err(Limit::Depth),
+            decode::<Vec<Vec<Vec<()>>>>(
+                &nested,
+                Limits {
+                    max_depth: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        assert_eq!(
+            Err(Limit::Elements),
+            decode::<Vec<()>>(
+                &[0x03],
+                Limits {
+                    max_elements: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        assert_eq!(
+            Err(Limit::Length),
+            decode::<OctetString>(
+                &[0x03, 0x01, 0x02, 0x03],
+                Limits {
+                    max_length: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        // The number of strings counts towards the total, as well as their
+        // lengths.
+        let strings = [0x02, 0x02, 0x01, 0x02, 0x02, 0x03, 0x04];
+        let total_length = |max_total_length| Limits {
+            max_total_length,
+            ..Limits::default()
+        };
+        assert!(decode::<Vec<OctetString>>(&strings, total_length(6)).is_ok());
+        assert_eq!(
+            Err(Limit::TotalLength),
+            decode::<Vec<OctetString>>(&strings, total_length(5))
+        );
+    }
+
+    #[test]
+    fn error_offset_and_path() {
+        use crate::error::Offset;
+
+        #[derive(AsnType, Decode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Outer {
+            id: Integer,
+            inners: SequenceOf<Inner>,
+        }
+
+        #[derive(AsnType, Decode, Debug, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Inner {
+            value: Integer,
+        }
+
+        let input = [0x01, 0x01, 0x02, 0x01, 0x05, 0x02, 0x01];
+        let error = crate::uper::decode::<Outer>(&input).unwrap_err();
+        assert_eq!(Some(Offset::Bit(40)), error.offset);
+        assert_eq!("inners[1].value", error.path.to_string());
     }
 }
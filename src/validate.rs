@@ -0,0 +1,447 @@
+//! Checking values against their ASN.1 constraints without encoding them.
+
+use alloc::{boxed::Box, collections::BTreeSet, string::String, vec::Vec};
+
+use crate::error::{ConstraintViolation, FieldPath, PathSegment, ValidationError, ViolationKind};
+use crate::types::{self, constraints, AsnType, Constraints, Integer};
+
+pub use rasn_derive::Validate;
+
+/// A **data type** whose value can be checked against its ASN.1 constraints.
+///
+/// Encoding a value only checks the constraints that the codec needs to know
+/// about, and stops at the first one that isn't satisfied, while validating
+/// checks the size, value, and permitted alphabet constraints of every
+/// component of the value and reports all of the violations at once.
+pub trait Validate: AsnType {
+    /// Checks `self` and all of its components against their constraints,
+    /// returning every constraint that isn't satisfied.
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut validator = Validator::new();
+        self.validate_with_constraints(&mut validator, Self::CONSTRAINTS);
+        validator.finish()
+    }
+
+    /// Checks `self` against `constraints`, reporting any violations to
+    /// `validator`.
+    ///
+    /// **Note for implementors** Constructed types should validate each of
+    /// their components inside of [`Validator::field`] or
+    /// [`Validator::index`], so that violations record where they occurred.
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints);
+}
+
+/// Collects the constraint violations of a value, along with the path to the
+/// component that is currently being validated.
+#[derive(Debug, Default)]
+pub struct Validator {
+    path: FieldPath,
+    violations: Vec<ConstraintViolation>,
+}
+
+impl Validator {
+    /// Creates a validator with no violations.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates the field called `name` of a `SEQUENCE`, `SET`, or the
+    /// alternative called `name` of a `CHOICE`.
+    pub fn field(&mut self, name: &'static str, validate: impl FnOnce(&mut Self)) {
+        self.path.push(PathSegment::Field(name));
+        validate(self);
+        self.path.pop();
+    }
+
+    /// Validates the component at `index` of a `SEQUENCE OF` or `SET OF`.
+    pub fn index(&mut self, index: usize, validate: impl FnOnce(&mut Self)) {
+        self.path.push(PathSegment::Index(index));
+        validate(self);
+        self.path.pop();
+    }
+
+    /// Checks that a length of `length` satisfies the size constraint of
+    /// `constraints`, if it has one.
+    pub fn check_size(&mut self, length: usize, constraints: &Constraints) {
+        if let Err(kind) = check_size(length, constraints) {
+            self.report(kind);
+        }
+    }
+
+    /// Checks that `value` satisfies the value constraint of `constraints`, if
+    /// it has one.
+    pub fn check_value(&mut self, value: &Integer, constraints: &Constraints) {
+        if let Err(kind) = check_value(value, constraints) {
+            self.report(kind);
+        }
+    }
+
+    /// Checks that every character in `chars` satisfies the permitted
+    /// alphabet constraint of `constraints`, if it has one. Only the first
+    /// character that isn't permitted is reported.
+    pub fn check_permitted_alphabet(
+        &mut self,
+        chars: impl IntoIterator<Item = u32>,
+        constraints: &Constraints,
+    ) {
+        if let Err(kind) = check_permitted_alphabet(chars, constraints) {
+            self.report(kind);
+        }
+    }
+
+    /// Records a violation of the component that is currently being
+    /// validated.
+    pub fn report(&mut self, kind: ViolationKind) {
+        self.violations.push(ConstraintViolation {
+            path: self.path.clone(),
+            kind,
+        });
+    }
+
+    /// Returns the violations that were reported, if any.
+    pub fn finish(self) -> Result<(), ValidationError> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                violations: self.violations,
+            })
+        }
+    }
+}
+
+/// Whether a value outside of the root of an extensible constraint is
+/// permitted. When the constraint lists its extension additions the value
+/// must be within one of them, otherwise any value is permitted.
+fn is_extension<T>(constraint: &constraints::Extensible<T>, contains: impl Fn(&T) -> bool) -> bool {
+    constraint
+        .extensible
+        .is_some_and(|additions| additions.is_empty() || additions.iter().any(contains))
+}
+
+pub(crate) fn check_size(length: usize, constraints: &Constraints) -> Result<(), ViolationKind> {
+    let Some(size) = constraints.size() else {
+        return Ok(());
+    };
+
+    if size.constraint.contains(&length) || is_extension(size, |size| size.contains(&length)) {
+        Ok(())
+    } else {
+        Err(ViolationKind::Size {
+            length,
+            expected: *size.constraint,
+        })
+    }
+}
+
+pub(crate) fn check_value(value: &Integer, constraints: &Constraints) -> Result<(), ViolationKind> {
+    let Some(bounds) = constraints.value() else {
+        return Ok(());
+    };
+
+    if bounds.constraint.bigint_contains(value)
+        || is_extension(&bounds, |bounds| bounds.bigint_contains(value))
+    {
+        Ok(())
+    } else {
+        Err(ViolationKind::Value {
+            value: value.clone(),
+            expected: *bounds.constraint,
+        })
+    }
+}
+
+pub(crate) fn check_permitted_alphabet(
+    chars: impl IntoIterator<Item = u32>,
+    constraints: &Constraints,
+) -> Result<(), ViolationKind> {
+    let Some(alphabet) = constraints.permitted_alphabet() else {
+        return Ok(());
+    };
+
+    if alphabet.extensible.is_some() {
+        return Ok(());
+    }
+
+    match chars
+        .into_iter()
+        .find(|character| !alphabet.constraint.contains(character))
+    {
+        Some(character) => Err(ViolationKind::PermittedAlphabet { character }),
+        None => Ok(()),
+    }
+}
+
+/// Checks both the size, in characters, and the permitted alphabet of a
+/// string.
+pub(crate) fn check_string(
+    length: usize,
+    chars: impl IntoIterator<Item = u32>,
+    constraints: &Constraints,
+) -> Result<(), ViolationKind> {
+    check_size(length, constraints)?;
+    check_permitted_alphabet(chars, constraints)
+}
+
+/// Types without any constraints that can be checked independently of a
+/// codec.
+macro_rules! unconstrained {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Validate for $ty {
+                fn validate_with_constraints(&self, _: &mut Validator, _: Constraints) {}
+            }
+        )+
+    }
+}
+
+unconstrained! {
+    bool,
+    (),
+    f32,
+    f64,
+    types::Real,
+    types::Any,
+    types::ObjectIdentifier,
+    types::Oid,
+    types::RelativeOid,
+    types::RelativeOidRef,
+    types::OidIri,
+    types::RelativeOidIri,
+    types::UtcTime,
+    types::GeneralizedTime,
+    types::LosslessGeneralizedTime,
+    types::Date,
+    types::TimeOfDay,
+    types::DateTime,
+    types::Duration,
+    types::TimeValue,
+}
+
+impl<const N: usize> Validate for types::FixedOctetString<N> {
+    fn validate_with_constraints(&self, _: &mut Validator, _: Constraints) {}
+}
+
+impl<const N: usize> Validate for types::FixedBitString<N> {
+    fn validate_with_constraints(&self, _: &mut Validator, _: Constraints) {}
+}
+
+impl Validate for Integer {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        validator.check_value(self, &constraints);
+    }
+}
+
+impl<const START: i128, const END: i128> Validate for types::ConstrainedInteger<START, END> {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        validator.check_value(self, &constraints);
+    }
+}
+
+/// Primitive integers always satisfy the range of their own type, so they
+/// are only checked when they're given a narrower value constraint.
+macro_rules! primitive_integer {
+    ($($int:ty),+ $(,)?) => {
+        $(
+            impl Validate for $int {
+                fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+                    if constraints.value() != Self::CONSTRAINTS.value() {
+                        validator.check_value(&Integer::from(*self), &constraints);
+                    }
+                }
+            }
+        )+
+    }
+}
+
+primitive_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Validate for types::OctetString {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        validator.check_size(self.len(), &constraints);
+    }
+}
+
+impl Validate for &'_ [u8] {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        validator.check_size(self.len(), &constraints);
+    }
+}
+
+impl Validate for types::BitString {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        validator.check_size(self.len(), &constraints);
+    }
+}
+
+impl Validate for String {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        self.as_str()
+            .validate_with_constraints(validator, constraints);
+    }
+}
+
+impl Validate for &'_ str {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        validator.check_size(self.chars().count(), &constraints);
+        validator.check_permitted_alphabet(self.chars().map(u32::from), &constraints);
+    }
+}
+
+/// Strings whose characters are known, checked for both size and permitted
+/// alphabet.
+macro_rules! known_multiplier_string {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Validate for $ty {
+                fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+                    use types::strings::StaticPermittedAlphabet;
+
+                    validator.check_size(self.chars().count(), &constraints);
+                    validator.check_permitted_alphabet(self.chars(), &constraints);
+                }
+            }
+        )+
+    }
+}
+
+known_multiplier_string! {
+    types::Ia5String,
+    types::PrintableString,
+    types::NumericString,
+    types::VisibleString,
+    types::BmpString,
+    types::UniversalString,
+}
+
+/// Strings whose characters can't be determined without their escape
+/// sequences, which are only checked for size.
+macro_rules! octet_string_type {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Validate for $ty {
+                fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+                    validator.check_size(self.len(), &constraints);
+                }
+            }
+        )+
+    }
+}
+
+octet_string_type! {
+    types::GeneralString,
+    types::GraphicString,
+    types::TeletexString,
+    types::VideotexString,
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        validator.check_size(self.len(), &constraints);
+        for (index, value) in self.iter().enumerate() {
+            validator.index(index, |validator| {
+                value.validate_with_constraints(validator, T::CONSTRAINTS);
+            });
+        }
+    }
+}
+
+impl<T: Validate> Validate for BTreeSet<T> {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        validator.check_size(self.len(), &constraints);
+        for (index, value) in self.iter().enumerate() {
+            validator.index(index, |validator| {
+                value.validate_with_constraints(validator, T::CONSTRAINTS);
+            });
+        }
+    }
+}
+
+impl<T: Validate, const N: usize> Validate for [T; N] {
+    fn validate_with_constraints(&self, validator: &mut Validator, _: Constraints) {
+        for (index, value) in self.iter().enumerate() {
+            validator.index(index, |validator| {
+                value.validate_with_constraints(validator, T::CONSTRAINTS);
+            });
+        }
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        if let Some(value) = self {
+            value.validate_with_constraints(
+                validator,
+                T::CONSTRAINTS.override_constraints(constraints),
+            );
+        }
+    }
+}
+
+impl<T: Validate> Validate for Box<T> {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        (**self)
+            .validate_with_constraints(validator, T::CONSTRAINTS.override_constraints(constraints));
+    }
+}
+
+impl<T: Validate> Validate for &'_ T {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        (**self)
+            .validate_with_constraints(validator, T::CONSTRAINTS.override_constraints(constraints));
+    }
+}
+
+impl<T: AsnType, V: Validate> Validate for types::Implicit<T, V> {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        self.value
+            .validate_with_constraints(validator, V::CONSTRAINTS.override_constraints(constraints));
+    }
+}
+
+impl<T: AsnType, V: Validate> Validate for types::Explicit<T, V> {
+    fn validate_with_constraints(&self, validator: &mut Validator, constraints: Constraints) {
+        self.value
+            .validate_with_constraints(validator, V::CONSTRAINTS.override_constraints(constraints));
+    }
+}
+
+impl<T: Validate> Validate for types::InstanceOf<T> {
+    fn validate_with_constraints(&self, validator: &mut Validator, _: Constraints) {
+        validator.field("value", |validator| {
+            self.value
+                .validate_with_constraints(validator, T::CONSTRAINTS);
+        });
+    }
+}
+
+impl Validate for types::EmbeddedPdv {
+    fn validate_with_constraints(&self, validator: &mut Validator, _: Constraints) {
+        validator.field("identification", |validator| {
+            self.identification
+                .validate_with_constraints(validator, types::Identification::CONSTRAINTS);
+        });
+    }
+}
+
+impl Validate for types::CharacterString {
+    fn validate_with_constraints(&self, validator: &mut Validator, _: Constraints) {
+        validator.field("identification", |validator| {
+            self.identification
+                .validate_with_constraints(validator, types::Identification::CONSTRAINTS);
+        });
+    }
+}
+
+impl<T: Validate, E, S: types::ContentsString> Validate for types::Containing<T, E, S> {
+    fn validate_with_constraints(&self, validator: &mut Validator, _: Constraints) {
+        self.value
+            .validate_with_constraints(validator, T::CONSTRAINTS);
+    }
+}
+
+/// The contents are only checked when they're decoded.
+impl<T, E, S: types::ContentsString> Validate for types::LazyContaining<T, E, S> {
+    fn validate_with_constraints(&self, _: &mut Validator, _: Constraints) {}
+}
@@ -1 +1,534 @@
+//! # Dynamic Values
+//!
+//! A schema-less representation of BER, CER, and DER encoded data. An
+//! [`Element`] can hold any encoded value without a corresponding Rust type,
+//! exposing the universal types it recognises as typed [`Value`]s, and
+//! everything else as nested elements or raw contents.
+//!
+//! Decoded elements remember how they were encoded, so [`encode`] reproduces
+//! the original input byte for byte. Only the elements that were changed
+//! (and the lengths of the constructed elements containing them) are encoded
+//! again, using DER.
+//!
+//! ```rust
+//! use rasn::value::{self, Value};
+//!
+//! // SEQUENCE { INTEGER 5, [0] { BOOLEAN TRUE } }
+//! let input = [0x30, 0x08, 0x02, 0x01, 0x05, 0xA0, 0x03, 0x01, 0x01, 0xFF];
+//! let mut element = value::decode_der(&input).unwrap();
+//! assert_eq!(value::encode(&element).unwrap(), input);
+//!
+//! if let Value::Sequence(children) = &mut element.value {
+//!     children[0].value = Value::Integer(300.into());
+//! }
+//!
+//! assert_eq!(
+//!     value::encode(&element).unwrap(),
+//!     [0x30, 0x09, 0x02, 0x02, 0x01, 0x2C, 0xA0, 0x03, 0x01, 0x01, 0xFF]
+//! );
+//! ```
 
+use alloc::vec::Vec;
+
+use crate::{
+    ber::{
+        de::{parser, DecoderOptions},
+        enc::EncoderOptions,
+    },
+    error::{DecodeError, EncodeError},
+    types::{self, Class, Constraints, Tag},
+    Decode, Encoder as _,
+};
+
+const END_OF_CONTENTS: &[u8] = &[0, 0];
+
+/// Attempts to decode an [`Element`] from `input` using BER.
+/// # Errors
+/// Returns error specific to BER decoder if decoding is not possible.
+pub fn decode_ber(input: &[u8]) -> Result<Element, DecodeError> {
+    decode_with_options(input, DecoderOptions::ber())
+}
+
+/// Attempts to decode an [`Element`] from `input` using CER.
+/// # Errors
+/// Returns error specific to CER decoder if decoding is not possible.
+pub fn decode_cer(input: &[u8]) -> Result<Element, DecodeError> {
+    decode_with_options(input, DecoderOptions::cer())
+}
+
+/// Attempts to decode an [`Element`] from `input` using DER.
+/// # Errors
+/// Returns error specific to DER decoder if decoding is not possible.
+pub fn decode_der(input: &[u8]) -> Result<Element, DecodeError> {
+    decode_with_options(input, DecoderOptions::der())
+}
+
+/// Attempts to encode `element`, reusing the original encoding of every
+/// unmodified element and encoding everything else with DER.
+/// # Errors
+/// Returns error specific to DER encoder if encoding is not possible.
+pub fn encode(element: &Element) -> Result<Vec<u8>, EncodeError> {
+    let mut output = Vec::new();
+    element.encode_into(&mut output)?;
+    Ok(output)
+}
+
+fn decode_with_options(input: &[u8], options: DecoderOptions) -> Result<Element, DecodeError> {
+    Element::parse(input, &options, 0).map(|(_, element)| element)
+}
+
+/// A single encoded ASN.1 value, and the tag it was encoded with.
+#[derive(Clone, Debug)]
+pub struct Element {
+    /// The tag of the element.
+    pub tag: Tag,
+    /// The value of the element.
+    pub value: Value,
+    original: Option<Original>,
+}
+
+/// The decoded contents of an [`Element`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Value {
+    Boolean(bool),
+    Integer(types::Integer),
+    BitString(types::BitString),
+    OctetString(Vec<u8>),
+    Null,
+    ObjectIdentifier(types::ObjectIdentifier),
+    Real(types::Real),
+    Enumerated(types::Integer),
+    Utf8String(types::Utf8String),
+    NumericString(types::NumericString),
+    PrintableString(types::PrintableString),
+    TeletexString(types::TeletexString),
+    Ia5String(types::Ia5String),
+    UtcTime(types::UtcTime),
+    GeneralizedTime(types::GeneralizedTime),
+    VisibleString(types::VisibleString),
+    GraphicString(types::GraphicString),
+    GeneralString(types::GeneralString),
+    UniversalString(types::UniversalString),
+    BmpString(types::BmpString),
+    Sequence(Vec<Element>),
+    Set(Vec<Element>),
+    /// Constructed contents of any other tag, such as an explicitly tagged
+    /// value.
+    Constructed(Vec<Element>),
+    /// Primitive contents of any other tag, such as an implicitly tagged
+    /// value, a universal type without a typed representation, or a
+    /// universal value that isn't valid for its type.
+    Primitive(Vec<u8>),
+}
+
+/// How an element was originally encoded.
+#[derive(Clone, Debug)]
+enum Original {
+    /// The identifier and length octets of an element whose contents are
+    /// elements of their own.
+    Constructed { header: Vec<u8>, indefinite: bool },
+    /// Every octet of an element decoded as a single value.
+    Complete { encoding: Vec<u8> },
+}
+
+impl Element {
+    /// Creates a new element, which will be encoded using DER.
+    #[must_use]
+    pub fn new(tag: Tag, value: Value) -> Self {
+        Self {
+            tag,
+            value,
+            original: None,
+        }
+    }
+
+    /// Parses the element at the start of `input`, which is nested `depth`
+    /// constructed elements deep.
+    fn parse<'input>(
+        input: &'input [u8],
+        options: &DecoderOptions,
+        depth: usize,
+    ) -> Result<(&'input [u8], Self), DecodeError> {
+        let (after_header, (identifier, contents)) = parser::parse_value(options, input, None)?;
+        let child_depth = if identifier.is_constructed() {
+            options
+                .limits
+                .check_depth(depth + 1, options.current_codec())?
+        } else {
+            depth
+        };
+
+        let (rest, children, header_len) = match contents {
+            Some(contents) => {
+                let rest = after_header;
+                let children = if identifier.is_constructed() {
+                    Self::parse_all(contents, options, child_depth)?
+                } else {
+                    Vec::new()
+                };
+                let encoded_len = input.len() - rest.len();
+                (rest, children, encoded_len - contents.len())
+            }
+            None => {
+                let mut children = Vec::new();
+                let mut cursor = after_header;
+                while !cursor.starts_with(END_OF_CONTENTS) {
+                    let (rest, child) = Self::parse(cursor, options, child_depth)?;
+                    children.push(child);
+                    cursor = rest;
+                }
+                (
+                    &cursor[END_OF_CONTENTS.len()..],
+                    children,
+                    input.len() - after_header.len(),
+                )
+            }
+        };
+
+        let encoding = &input[..input.len() - rest.len()];
+        let tag = identifier.tag;
+        // A universal value that doesn't satisfy its type, such as a
+        // `PrintableString` holding `@`, is kept as its raw contents instead.
+        let (value, original) = match decode_universal(tag, encoding, options).and_then(Result::ok)
+        {
+            Some(value) => (
+                value,
+                Original::Complete {
+                    encoding: encoding.to_vec(),
+                },
+            ),
+            None if identifier.is_constructed() => (
+                match tag {
+                    Tag::SEQUENCE => Value::Sequence(children),
+                    Tag::SET => Value::Set(children),
+                    _ => Value::Constructed(children),
+                },
+                Original::Constructed {
+                    header: encoding[..header_len].to_vec(),
+                    indefinite: contents.is_none(),
+                },
+            ),
+            None => (
+                Value::Primitive(encoding[header_len..].to_vec()),
+                Original::Complete {
+                    encoding: encoding.to_vec(),
+                },
+            ),
+        };
+
+        Ok((
+            rest,
+            Self {
+                tag,
+                value,
+                original: Some(original),
+            },
+        ))
+    }
+
+    fn parse_all(
+        mut input: &[u8],
+        options: &DecoderOptions,
+        depth: usize,
+    ) -> Result<Vec<Self>, DecodeError> {
+        let mut elements = Vec::new();
+        while !input.is_empty() {
+            let (rest, element) = Self::parse(input, options, depth)?;
+            elements.push(element);
+            input = rest;
+        }
+
+        Ok(elements)
+    }
+
+    fn encode_into(&self, output: &mut Vec<u8>) -> Result<(), EncodeError> {
+        match (&self.original, &self.value) {
+            (Some(Original::Complete { encoding }), _) if self.is_encoded_by(encoding) => {
+                output.extend_from_slice(encoding);
+                return Ok(());
+            }
+            (
+                Some(Original::Constructed { header, indefinite }),
+                Value::Sequence(children) | Value::Set(children) | Value::Constructed(children),
+            ) => {
+                let contents = encode_all(children)?;
+                if header_matches(header, self.tag, *indefinite, contents.len()) {
+                    output.extend_from_slice(header);
+                    output.extend_from_slice(&contents);
+                    if *indefinite {
+                        output.extend_from_slice(END_OF_CONTENTS);
+                    }
+                    return Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        let mut encoder = crate::ber::enc::Encoder::new(EncoderOptions::der());
+        let tag = self.tag;
+        let constraints = Constraints::default();
+        match &self.value {
+            Value::Boolean(value) => encoder.encode_bool(tag, *value),
+            Value::Integer(value) | Value::Enumerated(value) => {
+                encoder.encode_integer(tag, constraints, value)
+            }
+            Value::BitString(value) => encoder.encode_bit_string(tag, constraints, value),
+            Value::OctetString(value) => encoder.encode_octet_string(tag, constraints, value),
+            Value::Null => encoder.encode_null(tag),
+            Value::ObjectIdentifier(value) => encoder.encode_object_identifier(tag, value),
+            Value::Real(value) => encoder.encode_real(tag, constraints, value),
+            Value::Utf8String(value) => encoder.encode_utf8_string(tag, constraints, value),
+            Value::NumericString(value) => encoder.encode_numeric_string(tag, constraints, value),
+            Value::PrintableString(value) => {
+                encoder.encode_printable_string(tag, constraints, value)
+            }
+            Value::TeletexString(value) => encoder.encode_teletex_string(tag, constraints, value),
+            Value::Ia5String(value) => encoder.encode_ia5_string(tag, constraints, value),
+            Value::UtcTime(value) => encoder.encode_utc_time(tag, value),
+            Value::GeneralizedTime(value) => encoder.encode_generalized_time(tag, value),
+            Value::VisibleString(value) => encoder.encode_visible_string(tag, constraints, value),
+            Value::GraphicString(value) => encoder.encode_graphic_string(tag, constraints, value),
+            Value::GeneralString(value) => encoder.encode_general_string(tag, constraints, value),
+            Value::UniversalString(value) => {
+                encoder.encode_universal_string(tag, constraints, value)
+            }
+            Value::BmpString(value) => encoder.encode_bmp_string(tag, constraints, value),
+            Value::Sequence(children) | Value::Set(children) | Value::Constructed(children) => {
+                encoder.encode_constructed(tag, &encode_all(children)?);
+                Ok(())
+            }
+            Value::Primitive(contents) => {
+                encoder.encode_primitive(tag, contents);
+                Ok(())
+            }
+        }?;
+
+        output.extend_from_slice(&encoder.output());
+        Ok(())
+    }
+
+    /// Whether `encoding` still decodes to the current tag and value.
+    fn is_encoded_by(&self, encoding: &[u8]) -> bool {
+        Self::parse(encoding, &DecoderOptions::ber(), 0)
+            .is_ok_and(|(rest, element)| rest.is_empty() && element == *self)
+    }
+}
+
+/// Elements are equal when their tags and values are equal, regardless of
+/// how they were originally encoded.
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag && self.value == other.value
+    }
+}
+
+fn encode_all(elements: &[Element]) -> Result<Vec<u8>, EncodeError> {
+    let mut output = Vec::new();
+    for element in elements {
+        element.encode_into(&mut output)?;
+    }
+
+    Ok(output)
+}
+
+/// Decodes the universal types that have a typed [`Value`], returning `None`
+/// for every other tag.
+fn decode_universal(
+    tag: Tag,
+    encoding: &[u8],
+    options: &DecoderOptions,
+) -> Option<Result<Value, DecodeError>> {
+    fn decode<T: Decode>(
+        encoding: &[u8],
+        options: &DecoderOptions,
+        tag: Tag,
+        variant: fn(T) -> Value,
+    ) -> Result<Value, DecodeError> {
+        T::decode_with_tag(&mut crate::ber::de::Decoder::new(encoding, *options), tag).map(variant)
+    }
+
+    if tag.class != Class::Universal {
+        return None;
+    }
+
+    Some(match tag {
+        Tag::BOOL => decode(encoding, options, tag, Value::Boolean),
+        Tag::INTEGER => decode(encoding, options, tag, Value::Integer),
+        Tag::BIT_STRING => decode(encoding, options, tag, Value::BitString),
+        Tag::OCTET_STRING => decode(encoding, options, tag, |value: types::OctetString| {
+            Value::OctetString(value.to_vec())
+        }),
+        Tag::NULL => decode(encoding, options, tag, |()| Value::Null),
+        Tag::OBJECT_IDENTIFIER => decode(encoding, options, tag, Value::ObjectIdentifier),
+        Tag::REAL => decode(encoding, options, tag, Value::Real),
+        Tag::ENUMERATED => decode(encoding, options, tag, Value::Enumerated),
+        Tag::UTF8_STRING => decode(encoding, options, tag, Value::Utf8String),
+        Tag::NUMERIC_STRING => decode(encoding, options, tag, Value::NumericString),
+        Tag::PRINTABLE_STRING => decode(encoding, options, tag, Value::PrintableString),
+        Tag::TELETEX_STRING => decode(encoding, options, tag, Value::TeletexString),
+        Tag::IA5_STRING => decode(encoding, options, tag, Value::Ia5String),
+        Tag::UTC_TIME => decode(encoding, options, tag, Value::UtcTime),
+        Tag::GENERALIZED_TIME => decode(encoding, options, tag, Value::GeneralizedTime),
+        Tag::VISIBLE_STRING => decode(encoding, options, tag, Value::VisibleString),
+        Tag::GRAPHIC_STRING => decode(encoding, options, tag, Value::GraphicString),
+        Tag::GENERAL_STRING => decode(encoding, options, tag, Value::GeneralString),
+        Tag::UNIVERSAL_STRING => decode(encoding, options, tag, Value::UniversalString),
+        Tag::BMP_STRING => decode(encoding, options, tag, Value::BmpString),
+        _ => return None,
+    })
+}
+
+/// Whether the identifier and length octets in `header` still describe a
+/// constructed element with `tag` and `length` octets of contents.
+fn header_matches(header: &[u8], tag: Tag, indefinite: bool, length: usize) -> bool {
+    let Ok((length_octets, identifier)) = parser::parse_identifier_octet(header) else {
+        return false;
+    };
+
+    if identifier.tag != tag || !identifier.is_constructed() {
+        return false;
+    }
+
+    match length_octets {
+        [0x80] => indefinite,
+        [short] if short & 0x80 == 0 => !indefinite && usize::from(*short) == length,
+        [long, octets @ ..] if usize::from(long & 0x7F) == octets.len() => {
+            !indefinite
+                && octets.len() <= core::mem::size_of::<usize>()
+                && octets
+                    .iter()
+                    .fold(0usize, |acc, octet| (acc << 8) | usize::from(*octet))
+                    == length
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn invalid_universal_value() {
+        // PrintableString "a@b", where `@` isn't a printable character.
+        let input = [0x30, 0x05, 0x13, 0x03, b'a', b'@', b'b'];
+        let element = decode_der(&input).unwrap();
+
+        let Value::Sequence(children) = &element.value else {
+            panic!("expected a sequence, found {:?}", element.value);
+        };
+        assert_eq!(Tag::PRINTABLE_STRING, children[0].tag);
+        assert_eq!(Value::Primitive(vec![b'a', b'@', b'b']), children[0].value);
+        assert_eq!(&input[..], &*encode(&element).unwrap());
+    }
+
+    #[test]
+    fn der() {
+        let input = [
+            0x30, 0x29, // SEQUENCE
+            0x06, 0x03, 0x55, 0x1D, 0x13, // OBJECT IDENTIFIER 2.5.29.19
+            0xA0, 0x03, 0x01, 0x01, 0xFF, // [0] EXPLICIT BOOLEAN TRUE
+            0x81, 0x02, 0xCA, 0xFE, // [1] IMPLICIT
+            0x0C, 0x02, 0x68, 0x69, // UTF8String "hi"
+            0x03, 0x02, 0x07, 0x80, // BIT STRING
+            0x31, 0x02, 0x05, 0x00, // SET { NULL }
+            0x17, 0x0D, 0x32, 0x34, 0x30, 0x31, 0x30, 0x32, 0x30, 0x33, 0x30, 0x34, 0x30, 0x35,
+            0x5A, // UTCTime
+        ];
+
+        let element = decode_der(&input).unwrap();
+        let Value::Sequence(children) = &element.value else {
+            panic!("expected a sequence, found {element:?}");
+        };
+
+        assert_eq!(
+            children[0].value,
+            Value::ObjectIdentifier(types::ObjectIdentifier::new(vec![2, 5, 29, 19]).unwrap())
+        );
+        assert_eq!(children[1].tag, Tag::new(Class::Context, 0));
+        assert_eq!(
+            children[1].value,
+            Value::Constructed(vec![Element::new(Tag::BOOL, Value::Boolean(true))])
+        );
+        assert_eq!(children[2].value, Value::Primitive(vec![0xCA, 0xFE]));
+        assert_eq!(children[3].value, Value::Utf8String("hi".into()));
+        assert_eq!(
+            children[5].value,
+            Value::Set(vec![Element::new(Tag::NULL, Value::Null)])
+        );
+        assert!(matches!(children[6].value, Value::UtcTime(_)));
+
+        assert_eq!(encode(&element).unwrap(), input);
+    }
+
+    #[test]
+    fn ber_is_reencoded_losslessly() {
+        let input = [
+            0x30, 0x80, // SEQUENCE, indefinite length
+            0x02, 0x81, 0x01, 0x05, // INTEGER 5, long form length
+            0x24, 0x80, 0x04, 0x01, 0x61, 0x04, 0x01, 0x62, 0x00,
+            0x00, // constructed OCTET STRING
+            0x01, 0x01, 0x01, // BOOLEAN TRUE, not encoded as 0xFF
+            0x00, 0x00,
+        ];
+
+        let mut element = decode_ber(&input).unwrap();
+        assert_eq!(encode(&element).unwrap(), input);
+        assert!(decode_der(&input).is_err());
+
+        let Value::Sequence(children) = &mut element.value else {
+            panic!("expected a sequence, found {element:?}");
+        };
+        assert_eq!(children[1].value, Value::OctetString(b"ab".to_vec()));
+
+        children[0].value = Value::Integer(6.into());
+        assert_eq!(
+            encode(&element).unwrap(),
+            [
+                0x30, 0x80, 0x02, 0x01, 0x06, 0x24, 0x80, 0x04, 0x01, 0x61, 0x04, 0x01, 0x62, 0x00,
+                0x00, 0x01, 0x01, 0x01, 0x00, 0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn new_elements() {
+        let element = Element::new(
+            Tag::SEQUENCE,
+            Value::Sequence(vec![
+                Element::new(Tag::INTEGER, Value::Integer(1.into())),
+                Element::new(Tag::new(Class::Context, 0), Value::Boolean(true)),
+                Element::new(
+                    Tag::new(Class::Context, 1),
+                    Value::Constructed(vec![Element::new(
+                        Tag::PRINTABLE_STRING,
+                        Value::PrintableString("a".try_into().unwrap()),
+                    )]),
+                ),
+            ]),
+        );
+
+        let encoded = encode(&element).unwrap();
+        assert_eq!(
+            encoded,
+            [0x30, 0x0B, 0x02, 0x01, 0x01, 0x80, 0x01, 0xFF, 0xA1, 0x03, 0x13, 0x01, 0x61]
+        );
+        assert_eq!(decode_der(&encoded).unwrap().tag, Tag::SEQUENCE);
+    }
+
+    #[test]
+    fn changing_tag_reencodes_element() {
+        let mut element = decode_der(&[0xA0, 0x03, 0x02, 0x01, 0x01]).unwrap();
+        element.tag = Tag::new(Class::Context, 2);
+        assert_eq!(encode(&element).unwrap(), [0xA2, 0x03, 0x02, 0x01, 0x01]);
+    }
+
+    #[test]
+    fn deep_nesting_is_limited() {
+        let input = [0x30, 0x80].repeat(100_000);
+        let error = decode_ber(&input).unwrap_err();
+        assert!(error.is_limit_exceeded(), "{error}");
+    }
+}
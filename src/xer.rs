@@ -0,0 +1,496 @@
+//! # XML Encoding Rules
+//!
+//! Codec functions for Basic XER and Canonical XER (CXER), as defined in
+//! X.693. Values are encoded as an XML document whose root element is named
+//! after the ASN.1 type being encoded, fields and `CHOICE` alternatives use
+//! the names from `Constructed::FIELDS` and `Choice::IDENTIFIERS`.
+use crate::types::{AsnType, Tag, TagTree};
+
+pub mod de;
+pub mod enc;
+mod xml;
+
+/// Attempts to decode `T` from `input` using XER. Canonical XER is a subset of
+/// Basic XER, so this also decodes CXER.
+/// # Errors
+/// Returns error specific to XER decoder if decoding is not possible.
+pub fn decode<T: crate::Decode>(input: &str) -> Result<T, crate::error::DecodeError> {
+    T::decode(&mut de::Decoder::new(input)?)
+}
+
+/// Attempts to encode `value` to Basic XER.
+/// # Errors
+/// Returns error specific to XER encoder if encoding is not possible.
+pub fn encode<T: crate::Encode>(
+    value: &T,
+) -> Result<alloc::string::String, crate::error::EncodeError> {
+    encode_with_options(value, enc::EncoderOptions::basic())
+}
+
+/// Attempts to encode `value` to XER, writing the encoding to `writer`.
+/// # Errors
+/// Returns error specific to XER encoder if encoding is not possible, or
+/// if writing fails.
+#[cfg(feature = "std")]
+pub fn encode_to_writer<T: crate::Encode>(
+    value: &T,
+    mut writer: impl std::io::Write,
+) -> Result<(), crate::error::EncodeError> {
+    writer
+        .write_all(encode(value)?.as_bytes())
+        .map_err(|error| crate::error::EncodeError::io(error, crate::Codec::Xer))
+}
+
+/// Attempts to decode `T` from the rest of `reader` using XER, which must
+/// contain UTF-8.
+/// # Errors
+/// Returns error specific to XER decoder if decoding is not possible, or
+/// if reading fails.
+#[cfg(feature = "std")]
+pub fn decode_from_reader<T: crate::Decode>(
+    mut reader: impl std::io::Read,
+) -> Result<T, crate::error::DecodeError> {
+    let mut input = alloc::string::String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|error| crate::error::DecodeError::io(error, crate::Codec::Xer))?;
+    decode(&input)
+}
+
+/// Attempts to encode `value` to Canonical XER.
+/// # Errors
+/// Returns error specific to XER encoder if encoding is not possible.
+pub fn encode_canonical<T: crate::Encode>(
+    value: &T,
+) -> Result<alloc::string::String, crate::error::EncodeError> {
+    encode_with_options(value, enc::EncoderOptions::canonical())
+}
+
+fn encode_with_options<T: crate::Encode>(
+    value: &T,
+    options: enc::EncoderOptions,
+) -> Result<alloc::string::String, crate::error::EncodeError> {
+    let mut encoder = enc::Encoder::new(options);
+    value.encode(&mut encoder)?;
+    Ok(encoder.to_xml(type_name::<T>()))
+}
+
+/// The name of the XML element used for a value of `T` when it isn't
+/// identified by a field or alternative name, which is the type's ASN.1
+/// identifier. Types without one, such as open types, use `VALUE`.
+fn type_name<T: AsnType + ?Sized>() -> &'static str {
+    T::IDENTIFIER.unwrap_or("VALUE")
+}
+
+/// Whether the items of a `SEQUENCE OF T` or `SET OF T` are encoded as an
+/// "XMLValueList", rather than each being wrapped in an element named after
+/// `T`. This is the case for types whose values are already delimited by an
+/// element of their own: `BOOLEAN`, `ENUMERATED` and untagged `CHOICE`s.
+fn uses_value_list<T: AsnType + ?Sized>() -> bool {
+    match T::TAG {
+        Tag::BOOL | Tag::ENUMERATED => true,
+        Tag::EOC => !matches!(T::TAG_TREE, TagTree::Choice(&[])),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    macro_rules! round_trip_xer {
+        ($typ:ty, $value:expr, $expected:expr) => {{
+            let value: $typ = $value;
+            let expected: &'static str = $expected;
+            let actual_encoding = crate::xer::encode(&value).unwrap();
+
+            pretty_assertions::assert_eq!(expected, &*actual_encoding);
+
+            let decoded_value: $typ = crate::xer::decode(&actual_encoding).unwrap();
+
+            pretty_assertions::assert_eq!(value, decoded_value);
+        }};
+    }
+
+    macro_rules! round_trip_cxer {
+        ($typ:ty, $value:expr, $expected:expr) => {{
+            let value: $typ = $value;
+            let expected: &'static str = $expected;
+            let actual_encoding = crate::xer::encode_canonical(&value).unwrap();
+
+            pretty_assertions::assert_eq!(expected, &*actual_encoding);
+
+            let decoded_value: $typ = crate::xer::decode(&actual_encoding).unwrap();
+
+            pretty_assertions::assert_eq!(value, decoded_value);
+        }};
+    }
+
+    use crate as rasn;
+    use crate::prelude::*;
+
+    #[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+    #[rasn(automatic_tags)]
+    #[rasn(crate_root = "crate")]
+    #[non_exhaustive]
+    struct TestTypeA {
+        #[rasn(value("0..3", extensible))]
+        juice: Integer,
+        wine: Inner,
+        #[rasn(extension_addition)]
+        grappa: BitString,
+    }
+
+    #[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+    #[rasn(choice, automatic_tags)]
+    #[rasn(crate_root = "crate")]
+    enum Inner {
+        #[rasn(value("0..3"))]
+        Wine(u8),
+    }
+
+    #[derive(AsnType, Decode, Encode, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    #[rasn(automatic_tags, enumerated)]
+    #[rasn(crate_root = "crate")]
+    enum SimpleEnum {
+        Test1 = 5,
+        Test2 = 2,
+    }
+
+    #[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+    #[rasn(automatic_tags)]
+    #[rasn(crate_root = "crate")]
+    struct Optionals {
+        required: bool,
+        optional: Option<Utf8String>,
+        list: SequenceOf<SimpleEnum>,
+    }
+
+    #[derive(AsnType, Decode, Encode, Debug, PartialEq)]
+    #[rasn(set)]
+    #[rasn(crate_root = "crate")]
+    struct Set {
+        #[rasn(tag(context, 1))]
+        b: Integer,
+        #[rasn(tag(context, 0))]
+        a: Integer,
+    }
+
+    #[test]
+    fn bool() {
+        round_trip_xer!(bool, true, "<BOOLEAN><true/></BOOLEAN>");
+        round_trip_xer!(bool, false, "<BOOLEAN><false/></BOOLEAN>");
+    }
+
+    #[test]
+    fn integer() {
+        round_trip_xer!(u8, 1, "<INTEGER>1</INTEGER>");
+        round_trip_xer!(i64, -1234567, "<INTEGER>-1234567</INTEGER>");
+        round_trip_xer!(
+            Integer,
+            Integer::from(1) << 80,
+            "<INTEGER>1208925819614629174706176</INTEGER>"
+        );
+    }
+
+    #[test]
+    fn real() {
+        round_trip_xer!(f64, 1.5, "<REAL>1.5E0</REAL>");
+        round_trip_xer!(f64, -0.1, "<REAL>-1E-1</REAL>");
+        round_trip_xer!(f64, 0.0, "<REAL>0</REAL>");
+        round_trip_xer!(Real, Real::decimal(-12500, -2), "<REAL>-1.25E2</REAL>");
+        round_trip_xer!(Real, Real::MinusZero, "<REAL>-0</REAL>");
+        round_trip_xer!(Real, Real::PlusInfinity, "<REAL><PLUS-INFINITY/></REAL>");
+        round_trip_xer!(Real, Real::MinusInfinity, "<REAL><MINUS-INFINITY/></REAL>");
+        round_trip_xer!(Real, Real::NotANumber, "<REAL><NOT-A-NUMBER/></REAL>");
+        assert_eq!(
+            12.5,
+            crate::xer::decode::<f64>("<REAL> 12,5 </REAL>").unwrap()
+        );
+        assert_eq!(
+            f64::INFINITY,
+            crate::xer::decode::<f64>("<REAL>INF</REAL>").unwrap()
+        );
+        assert!(crate::xer::decode::<f64>("<REAL>1.5X</REAL>").is_err());
+    }
+
+    #[test]
+    fn null() {
+        round_trip_xer!((), (), "<NULL/>");
+    }
+
+    #[test]
+    fn bit_string() {
+        round_trip_xer!(
+            BitString,
+            [true, false, true, true, false].into_iter().collect(),
+            "<BIT_STRING>10110</BIT_STRING>"
+        );
+    }
+
+    #[test]
+    fn octet_string() {
+        round_trip_xer!(
+            OctetString,
+            OctetString::from_static(&[0x01, 0xAB, 0xFF]),
+            "<OCTET_STRING>01ABFF</OCTET_STRING>"
+        );
+    }
+
+    #[test]
+    fn object_identifier() {
+        round_trip_xer!(
+            ObjectIdentifier,
+            ObjectIdentifier::new(&[1, 2, 840, 113549]).unwrap(),
+            "<OBJECT_IDENTIFIER>1.2.840.113549</OBJECT_IDENTIFIER>"
+        );
+    }
+
+    #[test]
+    fn oid_iri() {
+        round_trip_xer!(
+            OidIri,
+            OidIri::new("/ISO/Registration_Authority/19785.CBEFF").unwrap(),
+            "<OID_IRI>/ISO/Registration_Authority/19785.CBEFF</OID_IRI>"
+        );
+        round_trip_xer!(
+            RelativeOidIri,
+            RelativeOidIri::new("Registration_Authority/19785.CBEFF").unwrap(),
+            "<RELATIVE_OID_IRI>Registration_Authority/19785.CBEFF</RELATIVE_OID_IRI>"
+        );
+    }
+
+    #[test]
+    fn time_types() {
+        round_trip_xer!(
+            TimeOfDay,
+            TimeOfDay::from_hms_opt(13, 30, 5).unwrap(),
+            "<TIME_OF_DAY>13:30:05</TIME_OF_DAY>"
+        );
+        round_trip_xer!(
+            DateTime,
+            Date::from_ymd_opt(2024, 2, 29)
+                .unwrap()
+                .and_hms_opt(13, 30, 5)
+                .unwrap(),
+            "<DATE_TIME>2024-02-29T13:30:05</DATE_TIME>"
+        );
+    }
+
+    #[test]
+    fn character_strings() {
+        round_trip_xer!(
+            Utf8String,
+            "a < b & c".into(),
+            "<UTF8String>a &lt; b &amp; c</UTF8String>"
+        );
+        round_trip_xer!(
+            Ia5String,
+            "hello".try_into().unwrap(),
+            "<IA5String>hello</IA5String>"
+        );
+        round_trip_xer!(
+            BmpString,
+            "Grüße".try_into().unwrap(),
+            "<BMPString>Grüße</BMPString>"
+        );
+        round_trip_xer!(
+            TeletexString,
+            TeletexString::from(b"abc".to_vec()),
+            "<TeletexString>abc</TeletexString>"
+        );
+        round_trip_xer!(
+            TeletexString,
+            TeletexString::from(alloc::vec![0xE8, 0xC2, b'o', b'd', 0xC2, b'z']),
+            "<TeletexString>Łódź</TeletexString>"
+        );
+    }
+
+    #[test]
+    fn enumerated() {
+        round_trip_xer!(
+            SimpleEnum,
+            SimpleEnum::Test1,
+            "<SimpleEnum><Test1/></SimpleEnum>"
+        );
+        round_trip_xer!(
+            SimpleEnum,
+            SimpleEnum::Test2,
+            "<SimpleEnum><Test2/></SimpleEnum>"
+        );
+    }
+
+    #[test]
+    fn choice() {
+        round_trip_xer!(Inner, Inner::Wine(2), "<Inner><Wine>2</Wine></Inner>");
+    }
+
+    #[test]
+    fn sequence() {
+        round_trip_xer!(
+            TestTypeA,
+            TestTypeA {
+                juice: 0.into(),
+                wine: Inner::Wine(4),
+                grappa: [true, false].into_iter().collect(),
+            },
+            "<TestTypeA>\n  <juice>0</juice>\n  <wine><Wine>4</Wine></wine>\n  <grappa>10</grappa>\n</TestTypeA>"
+        );
+        round_trip_xer!(
+            Optionals,
+            Optionals {
+                required: true,
+                optional: None,
+                list: alloc::vec![SimpleEnum::Test2, SimpleEnum::Test1],
+            },
+            "<Optionals>\n  <required><true/></required>\n  <list><Test2/><Test1/></list>\n</Optionals>"
+        );
+    }
+
+    #[test]
+    fn sequence_of() {
+        round_trip_xer!(
+            SequenceOf<u8>,
+            alloc::vec![1, 2],
+            "<SEQUENCE_OF>\n  <INTEGER>1</INTEGER>\n  <INTEGER>2</INTEGER>\n</SEQUENCE_OF>"
+        );
+        round_trip_xer!(
+            SequenceOf<Inner>,
+            alloc::vec![Inner::Wine(1)],
+            "<SEQUENCE_OF><Wine>1</Wine></SEQUENCE_OF>"
+        );
+        round_trip_xer!(SequenceOf<bool>, alloc::vec![], "<SEQUENCE_OF/>");
+        round_trip_xer!(
+            SequenceOf<Box<Utf8String>>,
+            alloc::vec![Box::new("a".into())],
+            "<SEQUENCE_OF><UTF8String>a</UTF8String></SEQUENCE_OF>"
+        );
+    }
+
+    #[test]
+    fn canonical() {
+        round_trip_cxer!(
+            TestTypeA,
+            TestTypeA {
+                juice: 3.into(),
+                wine: Inner::Wine(1),
+                grappa: BitString::new(),
+            },
+            "<TestTypeA><juice>3</juice><wine><Wine>1</Wine></wine><grappa/></TestTypeA>"
+        );
+        round_trip_cxer!(
+            Set,
+            Set {
+                b: 2.into(),
+                a: 1.into(),
+            },
+            "<Set><a>1</a><b>2</b></Set>"
+        );
+        round_trip_cxer!(
+            SetOf<u8>,
+            [10, 2].into_iter().collect(),
+            "<SET_OF><INTEGER>10</INTEGER><INTEGER>2</INTEGER></SET_OF>"
+        );
+    }
+
+    #[test]
+    fn decoding_accepts_whitespace_and_references() {
+        let decoded: Optionals = crate::xer::decode(
+            "<?xml version=\"1.0\"?>\n<!-- comment -->\n<Optionals>\n  <list>\n    <Test1/>\n  </list>\n  <optional>&#x41;&#66;<![CDATA[<C>]]></optional>\n  <required>\n    <false/>\n  </required>\n</Optionals>",
+        )
+        .unwrap();
+        assert_eq!(
+            Optionals {
+                required: false,
+                optional: Some("AB<C>".into()),
+                list: alloc::vec![SimpleEnum::Test1],
+            },
+            decoded
+        );
+    }
+
+    #[test]
+    fn decoding_rejects_invalid_input() {
+        assert!(crate::xer::decode::<bool>("<BOOLEAN><maybe/></BOOLEAN>").is_err());
+        assert!(crate::xer::decode::<SimpleEnum>("<SimpleEnum><Test3/></SimpleEnum>").is_err());
+        assert!(crate::xer::decode::<Integer>("<INTEGER>1</BOOLEAN>").is_err());
+        assert!(crate::xer::decode::<OctetString>("<OCTET_STRING>ABC</OCTET_STRING>").is_err());
+    }
+
+    #[test]
+    fn decoding_rejects_deeply_nested_input() {
+        let depth = 1_000_000;
+        let input = "<a>".repeat(depth) + &"</a>".repeat(depth);
+        let error = crate::xer::decode::<bool>(&input).unwrap_err();
+        assert!(error.is_limit_exceeded());
+
+        let depth = crate::de::Limits::DEFAULT.max_depth + 1;
+        let input = "<a>".repeat(depth) + &"</a>".repeat(depth);
+        assert!(crate::xer::decode::<bool>(&input)
+            .unwrap_err()
+            .is_limit_exceeded());
+    }
+
+    #[test]
+    fn limits() {
+        use crate::de::{Limit, Limits};
+
+        fn decode<T: crate::Decode>(input: &str, limits: Limits) -> Result<T, Limit> {
+            crate::xer::de::Decoder::with_limits(input, limits)
+                .and_then(|mut decoder| T::decode(&mut decoder))
+                .map_err(|error| match *error.kind {
+                    crate::error::DecodeErrorKind::LimitExceeded { limit, .. } => limit,
+                    kind => panic!("unexpected error: {kind}"),
+                })
+        }
+
+        let nested = "<SEQUENCE_OF><SEQUENCE_OF><NULL/></SEQUENCE_OF></SEQUENCE_OF>";
+        assert!(decode::<Vec<Vec<()>>>(nested, Limits::default()).is_ok());
+        assert_eq!(
+            Err(Limit::Depth),
+            decode::<Vec<Vec<()>>>(
+                nested,
+                Limits {
+                    max_depth: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        assert_eq!(
+            Err(Limit::Elements),
+            decode::<Vec<()>>(
+                "<SEQUENCE_OF><NULL/><NULL/><NULL/></SEQUENCE_OF>",
+                Limits {
+                    max_elements: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        assert_eq!(
+            Err(Limit::Length),
+            decode::<Utf8String>(
+                "<UTF8String>abc</UTF8String>",
+                Limits {
+                    max_length: 2,
+                    ..Limits::default()
+                }
+            )
+        );
+        let strings = "<SEQUENCE_OF><OCTET_STRING>0102</OCTET_STRING><OCTET_STRING>0304</OCTET_STRING></SEQUENCE_OF>";
+        let total_length = |max_total_length| Limits {
+            max_total_length,
+            ..Limits::default()
+        };
+        assert!(decode::<Vec<OctetString>>(strings, total_length(4)).is_ok());
+        assert_eq!(
+            Err(Limit::TotalLength),
+            decode::<Vec<OctetString>>(strings, total_length(3))
+        );
+    }
+
+    #[test]
+    fn deeply_nested_elements_are_dropped_iteratively() {
+        let depth = 1_000_000;
+        let input = "<a>".repeat(depth) + &"</a>".repeat(depth);
+        let element = super::xml::Element::parse(&input, &crate::de::Limits::UNLIMITED).unwrap();
+        drop(element);
+    }
+}
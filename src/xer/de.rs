@@ -0,0 +1,734 @@
+//! # Decoding XER
+
+use alloc::{string::String, vec::Vec};
+
+use super::xml::Element;
+use crate::{
+    de::Limits,
+    error::*,
+    types::{fields::Fields, *},
+    Decode,
+};
+
+macro_rules! decode_xer_value {
+    ($decoder_fn:expr, $input:expr) => {
+        $input
+            .pop()
+            .flatten()
+            .ok_or_else(|| DecodeError::from(XerDecodeErrorKind::eoi()))
+            .and_then($decoder_fn)
+    };
+}
+
+pub struct Decoder {
+    stack: Vec<Option<Element>>,
+    limits: Limits,
+    /// The sum of the lengths decoded so far, checked against the maximum
+    /// total length.
+    total_length: usize,
+}
+
+impl Decoder {
+    pub fn new(input: &str) -> Result<Self, <Decoder as crate::de::Decoder>::Error> {
+        Self::with_limits(input, Limits::DEFAULT)
+    }
+
+    /// Creates a decoder for `input` that fails if the decoded value exceeds
+    /// `limits`. Lengths are measured in characters for strings, and in
+    /// octets or bits for `OCTET STRING` and `BIT STRING` values.
+    pub fn with_limits(
+        input: &str,
+        limits: Limits,
+    ) -> Result<Self, <Decoder as crate::de::Decoder>::Error> {
+        let mut decoder = Self::from(Element::parse(input, &limits)?);
+        decoder.limits = limits;
+        Ok(decoder)
+    }
+
+    /// Returns `length` if neither it, nor the total length of the values
+    /// decoded so far, exceed the decoder's limits.
+    fn check_length(&mut self, length: usize) -> Result<usize, DecodeError> {
+        self.limits
+            .check_length_within_total(length, &mut self.total_length, crate::Codec::Xer)
+    }
+
+    fn decode_string(&mut self) -> Result<String, DecodeError> {
+        let string = decode_xer_value!(Self::string_from_value, self.stack)?;
+        self.check_length(string.chars().count())?;
+        Ok(string)
+    }
+
+    fn decode_octets(&mut self) -> Result<Vec<u8>, DecodeError> {
+        let octets = decode_xer_value!(Self::octet_string_from_value, self.stack)?;
+        self.check_length(octets.len())?;
+        Ok(octets)
+    }
+}
+
+impl From<Element> for Decoder {
+    fn from(value: Element) -> Self {
+        Self {
+            stack: alloc::vec![Some(value)],
+            limits: Limits::DEFAULT,
+            total_length: 0,
+        }
+    }
+}
+
+impl crate::Decoder for Decoder {
+    type Error = DecodeError;
+
+    fn decode_any(&mut self) -> Result<Any, Self::Error> {
+        self.decode_octets().map(Any::new)
+    }
+
+    fn decode_bit_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<BitString, Self::Error> {
+        let bit_string = decode_xer_value!(Self::bit_string_from_value, self.stack)?;
+        self.check_length(bit_string.len())?;
+        Ok(bit_string)
+    }
+
+    fn decode_bool(&mut self, _t: crate::Tag) -> Result<bool, Self::Error> {
+        decode_xer_value!(Self::boolean_from_value, self.stack)
+    }
+
+    fn decode_enumerated<E: Enumerated>(&mut self, _t: crate::Tag) -> Result<E, Self::Error> {
+        decode_xer_value!(Self::enumerated_from_value, self.stack)
+    }
+
+    fn decode_integer(&mut self, _t: crate::Tag, _c: Constraints) -> Result<Integer, Self::Error> {
+        decode_xer_value!(Self::integer_from_value, self.stack)
+    }
+
+    fn decode_real(&mut self, _t: crate::Tag, _c: Constraints) -> Result<Real, Self::Error> {
+        decode_xer_value!(Self::real_from_value, self.stack)
+    }
+
+    fn decode_null(&mut self, _t: crate::Tag) -> Result<(), Self::Error> {
+        decode_xer_value!(Self::null_from_value, self.stack)
+    }
+
+    fn decode_object_identifier(
+        &mut self,
+        _t: crate::Tag,
+    ) -> Result<ObjectIdentifier, Self::Error> {
+        decode_xer_value!(Self::object_identifier_from_value, self.stack)
+    }
+
+    fn decode_relative_oid(&mut self, _t: crate::Tag) -> Result<RelativeOid, Self::Error> {
+        decode_xer_value!(Self::relative_oid_from_value, self.stack)
+    }
+
+    fn decode_oid_iri(&mut self, _t: crate::Tag) -> Result<OidIri, Self::Error> {
+        decode_xer_value!(Self::oid_iri_from_value, self.stack)
+    }
+
+    fn decode_relative_oid_iri(&mut self, _t: crate::Tag) -> Result<RelativeOidIri, Self::Error> {
+        decode_xer_value!(Self::relative_oid_iri_from_value, self.stack)
+    }
+
+    fn decode_sequence<D, DF, F>(
+        &mut self,
+        _: crate::Tag,
+        _: Option<DF>,
+        decode_fn: F,
+    ) -> Result<D, Self::Error>
+    where
+        D: Constructed,
+        F: FnOnce(&mut Self) -> Result<D, Self::Error>,
+    {
+        let mut last = self
+            .stack
+            .pop()
+            .flatten()
+            .ok_or_else(XerDecodeErrorKind::eoi)?;
+        let mut field_names = [D::FIELDS, D::EXTENDED_FIELDS.unwrap_or(Fields::empty())]
+            .iter()
+            .flat_map(|f| f.iter())
+            .map(|f| f.name)
+            .collect::<Vec<&str>>();
+        field_names.reverse();
+        for name in field_names {
+            self.stack.push(last.remove_child(name));
+        }
+
+        (decode_fn)(self)
+    }
+
+    fn decode_sequence_of<D: crate::Decode>(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<SequenceOf<D>, Self::Error> {
+        decode_xer_value!(|v| self.sequence_of_from_value(v), self.stack)
+    }
+
+    fn decode_set_of<D: crate::Decode + Ord>(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<SetOf<D>, Self::Error> {
+        decode_xer_value!(|v| self.set_of_from_value(v), self.stack)
+    }
+
+    fn decode_octet_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<alloc::vec::Vec<u8>, Self::Error> {
+        self.decode_octets()
+    }
+
+    fn decode_utf8_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<Utf8String, Self::Error> {
+        self.decode_string()
+    }
+
+    fn decode_visible_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<VisibleString, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::VISIBLE_STRING,
+                alloc::format!("Error transforming VisibleString: {e:?}"),
+                crate::Codec::Xer,
+            )
+        })
+    }
+
+    fn decode_general_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<GeneralString, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::GENERAL_STRING,
+                alloc::format!("Error transforming GeneralString: {e:?}"),
+                crate::Codec::Xer,
+            )
+        })
+    }
+
+    fn decode_graphic_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<GraphicString, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::GRAPHIC_STRING,
+                alloc::format!("Error transforming GraphicString: {e:?}"),
+                crate::Codec::Xer,
+            )
+        })
+    }
+
+    fn decode_videotex_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<VideotexString, Self::Error> {
+        self.decode_string().map(VideotexString::from)
+    }
+
+    fn decode_ia5_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<Ia5String, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::IA5_STRING,
+                alloc::format!("Error transforming IA5String: {e:?}"),
+                crate::Codec::Xer,
+            )
+        })
+    }
+
+    fn decode_printable_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<PrintableString, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::PRINTABLE_STRING,
+                alloc::format!("Error transforming PrintableString: {e:?}"),
+                crate::Codec::Xer,
+            )
+        })
+    }
+
+    fn decode_numeric_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<NumericString, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::NUMERIC_STRING,
+                alloc::format!("Error transforming NumericString: {e:?}"),
+                crate::Codec::Xer,
+            )
+        })
+    }
+
+    fn decode_teletex_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<TeletexString, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::TELETEX_STRING,
+                alloc::format!("Error transforming TeletexString: {e:?}"),
+                crate::Codec::Xer,
+            )
+        })
+    }
+
+    fn decode_bmp_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<BmpString, Self::Error> {
+        self.decode_string()?.try_into().map_err(|e| {
+            DecodeError::string_conversion_failed(
+                Tag::BMP_STRING,
+                alloc::format!("Error transforming BMPString: {e:?}"),
+                crate::Codec::Xer,
+            )
+        })
+    }
+
+    fn decode_universal_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<UniversalString, Self::Error> {
+        self.decode_string().map(UniversalString::from)
+    }
+
+    fn decode_explicit_prefix<D: crate::Decode>(
+        &mut self,
+        _t: crate::Tag,
+    ) -> Result<D, Self::Error> {
+        D::decode(self)
+    }
+
+    fn decode_utc_time(&mut self, _t: crate::Tag) -> Result<UtcTime, Self::Error> {
+        decode_xer_value!(Self::utc_time_from_value, self.stack)
+    }
+
+    fn decode_generalized_time(&mut self, _t: crate::Tag) -> Result<GeneralizedTime, Self::Error> {
+        decode_xer_value!(Self::general_time_from_value, self.stack)
+    }
+
+    fn decode_date(&mut self, _t: crate::Tag) -> Result<Date, Self::Error> {
+        decode_xer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_time_of_day(&mut self, _t: crate::Tag) -> Result<TimeOfDay, Self::Error> {
+        decode_xer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_date_time(&mut self, _t: crate::Tag) -> Result<DateTime, Self::Error> {
+        decode_xer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_duration(&mut self, _t: crate::Tag) -> Result<Duration, Self::Error> {
+        decode_xer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_time(&mut self, _t: crate::Tag) -> Result<TimeValue, Self::Error> {
+        decode_xer_value!(Self::time_type_from_value, self.stack)
+    }
+
+    fn decode_lossless_generalized_time(
+        &mut self,
+        _t: crate::Tag,
+    ) -> Result<LosslessGeneralizedTime, Self::Error> {
+        decode_xer_value!(Self::lossless_generalized_time_from_value, self.stack)
+    }
+
+    fn decode_set<FIELDS, SET, D, F>(
+        &mut self,
+        _t: crate::Tag,
+        decode_fn: D,
+        field_fn: F,
+    ) -> Result<SET, Self::Error>
+    where
+        SET: crate::Decode + Constructed,
+        FIELDS: crate::Decode,
+        D: Fn(&mut Self, usize, crate::Tag) -> Result<FIELDS, Self::Error>,
+        F: FnOnce(alloc::vec::Vec<FIELDS>) -> Result<SET, Self::Error>,
+    {
+        let mut last = self
+            .stack
+            .pop()
+            .flatten()
+            .ok_or_else(XerDecodeErrorKind::eoi)?;
+        let mut field_indices = SET::FIELDS.iter().enumerate().collect::<Vec<_>>();
+        let mut fields = alloc::vec![];
+        field_indices.sort_by_key(|(_, field)| field.tag_tree.smallest_tag());
+        for (index, field) in field_indices {
+            self.stack.push(last.remove_child(field.name));
+            fields.push((decode_fn)(self, index, field.tag)?);
+        }
+
+        for (index, field) in SET::EXTENDED_FIELDS
+            .iter()
+            .flat_map(|fields| fields.iter())
+            .enumerate()
+        {
+            self.stack.push(last.remove_child(field.name));
+            fields.push((decode_fn)(self, index + SET::FIELDS.len(), field.tag)?);
+        }
+
+        (field_fn)(fields)
+    }
+
+    fn decode_choice<D>(&mut self, _c: Constraints) -> Result<D, Self::Error>
+    where
+        D: DecodeChoice,
+    {
+        decode_xer_value!(|v| self.choice_from_value::<D>(v), self.stack)
+    }
+
+    fn decode_optional<D: crate::Decode>(&mut self) -> Result<Option<D>, Self::Error> {
+        match self.stack.pop().ok_or_else(XerDecodeErrorKind::eoi)? {
+            None => Ok(None),
+            v => {
+                self.stack.push(v);
+                Some(D::decode(self)).transpose()
+            }
+        }
+    }
+
+    fn decode_optional_with_tag<D: crate::Decode>(
+        &mut self,
+        _: crate::Tag,
+    ) -> Result<Option<D>, Self::Error> {
+        self.decode_optional()
+    }
+
+    fn decode_optional_with_constraints<D: crate::Decode>(
+        &mut self,
+        _: Constraints,
+    ) -> Result<Option<D>, Self::Error> {
+        self.decode_optional()
+    }
+
+    fn decode_optional_with_tag_and_constraints<D: crate::Decode>(
+        &mut self,
+        _t: crate::Tag,
+        _c: Constraints,
+    ) -> Result<Option<D>, Self::Error> {
+        self.decode_optional()
+    }
+
+    fn decode_extension_addition_with_constraints<D>(
+        &mut self,
+        _: Constraints,
+    ) -> Result<Option<D>, Self::Error>
+    where
+        D: crate::Decode,
+    {
+        self.decode_optional()
+    }
+
+    fn decode_extension_addition_group<D: crate::Decode + Constructed>(
+        &mut self,
+    ) -> Result<Option<D>, Self::Error> {
+        self.decode_optional()
+    }
+
+    fn codec(&self) -> crate::Codec {
+        crate::Codec::Xer
+    }
+}
+
+// -------------------------------------------------------------------
+//
+//                        HELPER METHODS
+//
+// -------------------------------------------------------------------
+
+impl Element {
+    /// Removes and returns the first child element named `name`.
+    fn remove_child(&mut self, name: &str) -> Option<Element> {
+        let index = self.children.iter().position(|c| c.name == name)?;
+        Some(self.children.remove(index))
+    }
+
+    /// Returns the name of the single empty child element, as used for
+    /// `BOOLEAN` and `ENUMERATED` values.
+    fn value_identifier(&self) -> Option<&str> {
+        match &*self.children {
+            [child] if child.children.is_empty() && child.text.trim().is_empty() => {
+                Some(&child.name)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Decoder {
+    fn bit_string_from_value(value: Element) -> Result<BitString, DecodeError> {
+        Ok(value
+            .text
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .try_fold(BitString::new(), |mut acc, bit| {
+                match bit {
+                    '0' => acc.push(false),
+                    '1' => acc.push(true),
+                    c => return Err(XerDecodeErrorKind::InvalidBitString { invalid: c }),
+                }
+                Ok(acc)
+            })?)
+    }
+
+    fn boolean_from_value(mut value: Element) -> Result<bool, DecodeError> {
+        match value.value_identifier() {
+            Some("true") => Ok(true),
+            Some("false") => Ok(false),
+            _ => Err(XerDecodeErrorKind::TypeMismatch {
+                needed: "boolean",
+                found: core::mem::take(&mut value.name),
+            }
+            .into()),
+        }
+    }
+
+    fn enumerated_from_value<E: Enumerated>(value: Element) -> Result<E, DecodeError> {
+        let identifier =
+            value
+                .value_identifier()
+                .ok_or_else(|| XerDecodeErrorKind::TypeMismatch {
+                    needed: "enumerated identifier",
+                    found: value.name.clone(),
+                })?;
+        Ok(E::from_identifier(identifier).ok_or_else(|| {
+            XerDecodeErrorKind::InvalidEnumIdentifier {
+                identifier: identifier.into(),
+            }
+        })?)
+    }
+
+    fn integer_from_value(value: Element) -> Result<Integer, DecodeError> {
+        let text = value.text.trim();
+        Ok(text
+            .strip_prefix('+')
+            .unwrap_or(text)
+            .parse()
+            .map_err(|_| XerDecodeErrorKind::TypeMismatch {
+                needed: "integer",
+                found: value.text.clone(),
+            })?)
+    }
+
+    fn real_from_value(value: Element) -> Result<Real, DecodeError> {
+        let real = match value.value_identifier() {
+            Some("PLUS-INFINITY") => Some(Real::PlusInfinity),
+            Some("MINUS-INFINITY") => Some(Real::MinusInfinity),
+            Some("NOT-A-NUMBER") => Some(Real::NotANumber),
+            _ => match value.text.trim() {
+                "INF" => Some(Real::PlusInfinity),
+                "-INF" => Some(Real::MinusInfinity),
+                "NaN" => Some(Real::NotANumber),
+                text => Real::from_iso6093(text),
+            },
+        };
+
+        Ok(real.ok_or_else(|| XerDecodeErrorKind::TypeMismatch {
+            needed: "real",
+            found: value.text.clone(),
+        })?)
+    }
+
+    fn null_from_value(mut value: Element) -> Result<(), DecodeError> {
+        Ok((value.children.is_empty() && value.text.trim().is_empty())
+            .then_some(())
+            .ok_or(XerDecodeErrorKind::TypeMismatch {
+                needed: "null",
+                found: core::mem::take(&mut value.text),
+            })?)
+    }
+
+    fn object_identifier_from_value(value: Element) -> Result<ObjectIdentifier, DecodeError> {
+        Ok(value
+            .text
+            .trim()
+            .split('.')
+            .map(str::parse)
+            .collect::<Result<Vec<u32>, _>>()
+            .ok()
+            .and_then(|arcs| Oid::new(&arcs).map(ObjectIdentifier::from))
+            .ok_or_else(|| XerDecodeErrorKind::InvalidObjectIdentifier {
+                value: value.text.clone(),
+            })?)
+    }
+
+    fn relative_oid_from_value(value: Element) -> Result<RelativeOid, DecodeError> {
+        Ok(value
+            .text
+            .trim()
+            .split('.')
+            .map(str::parse)
+            .collect::<Result<Vec<u32>, _>>()
+            .ok()
+            .and_then(RelativeOid::new)
+            .ok_or_else(|| XerDecodeErrorKind::InvalidObjectIdentifier {
+                value: value.text.clone(),
+            })?)
+    }
+
+    fn oid_iri_from_value(value: Element) -> Result<OidIri, DecodeError> {
+        let iri = value.text.trim();
+        OidIri::new(iri).ok_or_else(|| DecodeError::invalid_oid_iri(iri.into(), crate::Codec::Xer))
+    }
+
+    fn relative_oid_iri_from_value(value: Element) -> Result<RelativeOidIri, DecodeError> {
+        let iri = value.text.trim();
+        RelativeOidIri::new(iri)
+            .ok_or_else(|| DecodeError::invalid_oid_iri(iri.into(), crate::Codec::Xer))
+    }
+
+    /// Splits a `SEQUENCE OF` or `SET OF` value into the values of its items,
+    /// the reverse of `Encoder::encode_items`.
+    fn items_from_value<D: Decode>(
+        &self,
+        mut value: Element,
+    ) -> Result<impl Iterator<Item = Element>, DecodeError> {
+        let value_list = super::uses_value_list::<D>();
+        self.limits
+            .check_elements(value.children.len(), crate::Codec::Xer)?;
+        Ok(core::mem::take(&mut value.children)
+            .into_iter()
+            .map(move |child| {
+                if value_list {
+                    Element::with_children("", alloc::vec![child])
+                } else {
+                    child
+                }
+            }))
+    }
+
+    fn sequence_of_from_value<D: Decode>(
+        &mut self,
+        value: Element,
+    ) -> Result<SequenceOf<D>, DecodeError> {
+        self.items_from_value::<D>(value)?
+            .map(|v| {
+                self.stack.push(Some(v));
+                D::decode(self)
+            })
+            .collect()
+    }
+
+    fn set_of_from_value<D: Decode + Ord>(
+        &mut self,
+        value: Element,
+    ) -> Result<SetOf<D>, DecodeError> {
+        self.items_from_value::<D>(value)?
+            .try_fold(SetOf::new(), |mut acc, v| {
+                self.stack.push(Some(v));
+                acc.insert(D::decode(self)?);
+                Ok(acc)
+            })
+    }
+
+    fn string_from_value(mut value: Element) -> Result<String, DecodeError> {
+        if value.children.is_empty() {
+            Ok(core::mem::take(&mut value.text))
+        } else {
+            Err(XerDecodeErrorKind::TypeMismatch {
+                needed: "string",
+                found: core::mem::take(&mut value.name),
+            }
+            .into())
+        }
+    }
+
+    fn choice_from_value<D>(&mut self, mut value: Element) -> Result<D, DecodeError>
+    where
+        D: DecodeChoice,
+    {
+        let tag = if value.children.is_empty() {
+            None
+        } else {
+            Some(value.children.remove(0))
+        }
+        .and_then(|v| {
+            D::IDENTIFIERS
+                .iter()
+                .position(|id| id.eq_ignore_ascii_case(&v.name))
+                .map(|i| (i, v))
+        })
+        .map_or(Tag::EOC, |(i, v)| {
+            match variants::Variants::from_slice(
+                &[D::VARIANTS, D::EXTENDED_VARIANTS.unwrap_or(&[])].concat(),
+            )
+            .get(i)
+            {
+                Some(t) => {
+                    self.stack.push(Some(v));
+                    *t
+                }
+                None => Tag::EOC,
+            }
+        });
+        D::from_tag(self, tag)
+    }
+
+    fn octet_string_from_value(value: Element) -> Result<alloc::vec::Vec<u8>, DecodeError> {
+        let hex = value
+            .text
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect::<String>();
+        if hex.len() % 2 != 0 || !hex.is_ascii() {
+            return Err(XerDecodeErrorKind::InvalidOctetString {}.into());
+        }
+        Ok((0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..=i + 1], 16))
+            .collect::<Result<alloc::vec::Vec<u8>, _>>()
+            .map_err(|_| XerDecodeErrorKind::InvalidOctetString {})?)
+    }
+
+    fn utc_time_from_value(value: Element) -> Result<chrono::DateTime<chrono::Utc>, DecodeError> {
+        crate::ber::de::Decoder::parse_any_utc_time_string(value.text.trim().into())
+    }
+
+    fn general_time_from_value(
+        value: Element,
+    ) -> Result<chrono::DateTime<chrono::FixedOffset>, DecodeError> {
+        crate::ber::de::Decoder::parse_any_generalized_time_string(value.text.trim().into())
+    }
+
+    fn lossless_generalized_time_from_value(
+        value: Element,
+    ) -> Result<LosslessGeneralizedTime, DecodeError> {
+        let string = value.text.trim();
+        string
+            .parse()
+            .map_err(|reason| DecodeError::invalid_time(reason, crate::Codec::Xer))
+    }
+
+    fn time_type_from_value<T: TimeType>(value: Element) -> Result<T, DecodeError> {
+        T::from_extended(value.text.trim())
+            .map_err(|reason| DecodeError::invalid_time(reason, crate::Codec::Xer))
+    }
+}
@@ -0,0 +1,602 @@
+//! # Encoding XER.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::xml::Element;
+use crate::{
+    error::{EncodeError, XerEncodeErrorKind},
+    types::{fields::Fields, strings::StaticPermittedAlphabet, variants, TimeType},
+};
+
+/// Options for configuring the [`Encoder`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EncoderOptions {
+    canonical: bool,
+}
+
+impl EncoderOptions {
+    /// Returns the default encoding rules options for Basic XER.
+    #[must_use]
+    pub const fn basic() -> Self {
+        Self { canonical: false }
+    }
+
+    /// Returns the default encoding rules options for Canonical XER.
+    #[must_use]
+    pub const fn canonical() -> Self {
+        Self { canonical: true }
+    }
+}
+
+pub struct Encoder {
+    options: EncoderOptions,
+    stack: Vec<&'static str>,
+    constructed_stack: Vec<Vec<Element>>,
+    root_value: Option<Element>,
+}
+
+impl Encoder {
+    pub fn new(options: EncoderOptions) -> Self {
+        Self {
+            options,
+            stack: alloc::vec![],
+            constructed_stack: alloc::vec![],
+            root_value: None,
+        }
+    }
+
+    pub(crate) fn root_value(self) -> Result<Element, EncodeError> {
+        Ok(self
+            .root_value
+            .ok_or(XerEncodeErrorKind::NoRootValueFound)?)
+    }
+
+    /// Writes the encoded value as an XML document with `name` as the
+    /// root element. Basic XER is indented, Canonical XER contains no
+    /// whitespace between elements.
+    pub fn to_xml(self, name: &str) -> String {
+        let canonical = self.options.canonical;
+        let mut output = String::new();
+        if let Some(mut root) = self.root_value {
+            root.name = name.into();
+            root.write(&mut output, (!canonical).then_some(0));
+        }
+        output
+    }
+
+    fn update_root_or_constructed(&mut self, value: Element) -> Result<(), EncodeError> {
+        match self.stack.pop() {
+            Some(id) => {
+                let mut value = value;
+                value.name = id.into();
+                self.constructed_stack
+                    .last_mut()
+                    .ok_or_else(|| XerEncodeErrorKind::XmlEncoder {
+                        msg: "Internal stack mismatch!".into(),
+                    })?
+                    .push(value);
+            }
+            None => {
+                self.root_value = Some(value);
+            }
+        };
+        Ok(())
+    }
+
+    fn encode_text(&mut self, text: impl Into<String>) -> Result<(), EncodeError> {
+        self.update_root_or_constructed(Element::with_text("", text))
+    }
+
+    fn encode_empty_element(&mut self, name: &str) -> Result<(), EncodeError> {
+        self.update_root_or_constructed(Element::with_children("", alloc::vec![Element::new(name)]))
+    }
+
+    fn pop_constructed(&mut self) -> Result<Vec<Element>, EncodeError> {
+        Ok(self
+            .constructed_stack
+            .pop()
+            .ok_or_else(|| XerEncodeErrorKind::XmlEncoder {
+                msg: "Internal stack mismatch!".into(),
+            })?)
+    }
+
+    /// Encodes each of `values` as a separate item of a `SEQUENCE OF` or
+    /// `SET OF`, wrapped in an element named after the item type, unless the
+    /// type's values are already delimited by their own element.
+    fn encode_items<'a, E: crate::Encode + 'a>(
+        &self,
+        values: impl Iterator<Item = &'a E>,
+    ) -> Result<Vec<Element>, EncodeError> {
+        let mut items = Vec::new();
+        for value in values {
+            let mut item_encoder = Self::new(self.options);
+            value.encode(&mut item_encoder)?;
+            let mut item = item_encoder.root_value()?;
+            if super::uses_value_list::<E>() {
+                items.append(&mut item.children);
+            } else {
+                item.name = super::type_name::<E>().into();
+                items.push(item);
+            }
+        }
+        Ok(items)
+    }
+}
+
+impl crate::Encoder for Encoder {
+    type Ok = ();
+
+    type Error = EncodeError;
+
+    fn encode_any(
+        &mut self,
+        t: crate::Tag,
+        value: &crate::types::Any,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_octet_string(t, <_>::default(), &value.contents)
+    }
+
+    fn encode_bool(&mut self, _: crate::Tag, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.encode_empty_element(if value { "true" } else { "false" })
+    }
+
+    fn encode_bit_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::BitStr,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(
+            value
+                .iter()
+                .map(|bit| if *bit { '1' } else { '0' })
+                .collect::<String>(),
+        )
+    }
+
+    fn encode_enumerated<E: crate::types::Enumerated>(
+        &mut self,
+        _: crate::Tag,
+        value: &E,
+    ) -> Result<Self::Ok, Self::Error> {
+        let identifier = value
+            .identifier()
+            .ok_or(XerEncodeErrorKind::MissingIdentifier)?;
+        self.encode_empty_element(identifier)
+    }
+
+    fn encode_object_identifier(
+        &mut self,
+        _t: crate::Tag,
+        value: &[u32],
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(
+            value
+                .iter()
+                .map(|arc| alloc::format!("{arc}"))
+                .collect::<Vec<String>>()
+                .join("."),
+        )
+    }
+
+    fn encode_relative_oid(
+        &mut self,
+        t: crate::Tag,
+        value: &[u32],
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_object_identifier(t, value)
+    }
+
+    fn encode_oid_iri(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::OidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(value.to_string())
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::RelativeOidIri,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(value.to_string())
+    }
+
+    fn encode_integer(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &num_bigint::BigInt,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(value.to_str_radix(10))
+    }
+
+    fn encode_real(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::Real,
+    ) -> Result<Self::Ok, Self::Error> {
+        use crate::types::Real;
+        match value {
+            Real::PlusInfinity => self.encode_empty_element("PLUS-INFINITY"),
+            Real::MinusInfinity => self.encode_empty_element("MINUS-INFINITY"),
+            Real::NotANumber => self.encode_empty_element("NOT-A-NUMBER"),
+            finite => self.encode_text(finite.to_decimal_string().ok_or_else(|| {
+                XerEncodeErrorKind::UnrepresentableReal {
+                    value: finite.clone(),
+                }
+            })?),
+        }
+    }
+
+    fn encode_null(&mut self, _: crate::Tag) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(Element::default())
+    }
+
+    fn encode_octet_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &[u8],
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(value.iter().fold(String::new(), |mut acc, byte| {
+            acc.push_str(&alloc::format!("{byte:02X}"));
+            acc
+        }))
+    }
+
+    fn encode_general_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::GeneralString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(value))
+    }
+
+    fn encode_graphic_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::GraphicString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(value))
+    }
+
+    fn encode_videotex_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::VideotexString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(value))
+    }
+
+    fn encode_utf8_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(value)
+    }
+
+    fn encode_visible_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::VisibleString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(value.as_iso646_bytes()))
+    }
+
+    fn encode_ia5_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::Ia5String,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(value.as_iso646_bytes()))
+    }
+
+    fn encode_printable_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::PrintableString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(value.as_bytes()))
+    }
+
+    fn encode_numeric_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::NumericString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(value.as_bytes()))
+    }
+
+    fn encode_teletex_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::TeletexString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(
+            value
+                .to_unicode()
+                .map_err(|e| EncodeError::teletex_conversion_failed(e, crate::Codec::Xer))?,
+        )
+    }
+
+    fn encode_bmp_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::BmpString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(
+            value
+                .chars()
+                .map(|ch| char::from_u32(ch).unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect::<String>(),
+        )
+    }
+
+    fn encode_universal_string(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &crate::types::UniversalString,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(value.to_string())
+    }
+
+    fn encode_generalized_time(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::GeneralizedTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(
+            &crate::ber::enc::Encoder::datetime_to_canonical_generalized_time_bytes(value),
+        ))
+    }
+
+    fn encode_lossless_generalized_time(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::LosslessGeneralizedTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(value.to_string())
+    }
+
+    fn encode_utc_time(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::UtcTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_text(String::from_utf8_lossy(
+            &crate::ber::enc::Encoder::datetime_to_canonical_utc_time_bytes(value),
+        ))
+    }
+
+    fn encode_date(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::Date,
+    ) -> Result<Self::Ok, Self::Error> {
+        let string = value
+            .to_extended()
+            .map_err(|reason| EncodeError::invalid_time(reason, crate::Codec::Xer))?;
+        self.encode_text(string)
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::TimeOfDay,
+    ) -> Result<Self::Ok, Self::Error> {
+        let string = value
+            .to_extended()
+            .map_err(|reason| EncodeError::invalid_time(reason, crate::Codec::Xer))?;
+        self.encode_text(string)
+    }
+
+    fn encode_date_time(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::DateTime,
+    ) -> Result<Self::Ok, Self::Error> {
+        let string = value
+            .to_extended()
+            .map_err(|reason| EncodeError::invalid_time(reason, crate::Codec::Xer))?;
+        self.encode_text(string)
+    }
+
+    fn encode_duration(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::Duration,
+    ) -> Result<Self::Ok, Self::Error> {
+        let string = value
+            .to_extended()
+            .map_err(|reason| EncodeError::invalid_time(reason, crate::Codec::Xer))?;
+        self.encode_text(string)
+    }
+
+    fn encode_time(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::TimeValue,
+    ) -> Result<Self::Ok, Self::Error> {
+        let string = value
+            .to_extended()
+            .map_err(|reason| EncodeError::invalid_time(reason, crate::Codec::Xer))?;
+        self.encode_text(string)
+    }
+
+    fn encode_explicit_prefix<V: crate::Encode>(
+        &mut self,
+        _: crate::Tag,
+        value: &V,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.encode(self)
+    }
+
+    fn encode_sequence<C, F>(
+        &mut self,
+        _t: crate::Tag,
+        encoder_scope: F,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        C: crate::types::Constructed,
+        F: FnOnce(&mut Self) -> Result<(), Self::Error>,
+    {
+        let mut field_names = [C::FIELDS, C::EXTENDED_FIELDS.unwrap_or(Fields::empty())]
+            .iter()
+            .flat_map(|f| f.iter())
+            .map(|f| f.name)
+            .collect::<Vec<&str>>();
+        field_names.reverse();
+        for name in field_names {
+            self.stack.push(name);
+        }
+        self.constructed_stack.push(Vec::new());
+        (encoder_scope)(self)?;
+        let children = self.pop_constructed()?;
+        self.update_root_or_constructed(Element::with_children("", children))
+    }
+
+    fn encode_sequence_of<E: crate::Encode>(
+        &mut self,
+        _t: crate::Tag,
+        value: &[E],
+        _c: crate::types::Constraints,
+    ) -> Result<Self::Ok, Self::Error> {
+        let items = self.encode_items(value.iter())?;
+        self.update_root_or_constructed(Element::with_children("", items))
+    }
+
+    fn encode_set<C, F>(&mut self, tag: crate::Tag, value: F) -> Result<Self::Ok, Self::Error>
+    where
+        C: crate::types::Constructed,
+        F: FnOnce(&mut Self) -> Result<(), Self::Error>,
+    {
+        self.encode_sequence::<C, F>(tag, value)?;
+
+        if self.options.canonical {
+            // CXER encodes the components of a `SET` in the canonical order
+            // of their tags, rather than in textual order.
+            let field_tag = |name: &str| {
+                C::FIELDS
+                    .iter()
+                    .chain(C::EXTENDED_FIELDS.iter().flat_map(|fields| fields.iter()))
+                    .find(|field| field.name == name)
+                    .map(|field| field.tag_tree.smallest_tag())
+            };
+            let set = match self.constructed_stack.last_mut() {
+                Some(children) => children.last_mut(),
+                None => self.root_value.as_mut(),
+            };
+            if let Some(set) = set {
+                set.children.sort_by_key(|child| field_tag(&child.name));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn encode_set_of<E: crate::Encode>(
+        &mut self,
+        _t: crate::Tag,
+        value: &crate::types::SetOf<E>,
+        _c: crate::types::Constraints,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut items = self.encode_items(value.iter())?;
+        if self.options.canonical {
+            // CXER orders the items of a `SET OF` by their encodings.
+            items.sort_by_cached_key(|item| {
+                let mut output = String::new();
+                item.write(&mut output, None);
+                output
+            });
+        }
+        self.update_root_or_constructed(Element::with_children("", items))
+    }
+
+    fn encode_some<E: crate::Encode>(&mut self, value: &E) -> Result<Self::Ok, Self::Error> {
+        value.encode(self)
+    }
+
+    fn encode_some_with_tag_and_constraints<E: crate::Encode>(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: &E,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.encode(self)
+    }
+
+    fn encode_none<E: crate::Encode>(&mut self) -> Result<Self::Ok, Self::Error> {
+        self.stack.pop();
+        Ok(())
+    }
+
+    fn encode_none_with_tag(&mut self, _t: crate::Tag) -> Result<Self::Ok, Self::Error> {
+        self.stack.pop();
+        Ok(())
+    }
+
+    fn encode_choice<E: crate::Encode + crate::types::Choice>(
+        &mut self,
+        _c: crate::types::Constraints,
+        _t: crate::types::Tag,
+        identifier: &'static str,
+        encode_fn: impl FnOnce(&mut Self) -> Result<crate::Tag, Self::Error>,
+    ) -> Result<Self::Ok, Self::Error> {
+        let variants = variants::Variants::from_slice(
+            &[E::VARIANTS, E::EXTENDED_VARIANTS.unwrap_or(&[])].concat(),
+        );
+        if variants.is_empty() {
+            self.update_root_or_constructed(Element::default())
+        } else {
+            self.constructed_stack.push(Vec::new());
+            self.stack.push(identifier);
+            (encode_fn)(self)?;
+            let children = self.pop_constructed()?;
+            self.update_root_or_constructed(Element::with_children("", children))
+        }
+    }
+
+    fn encode_extension_addition<E: crate::Encode>(
+        &mut self,
+        _t: crate::Tag,
+        _c: crate::types::Constraints,
+        value: E,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.encode(self)
+    }
+
+    fn encode_extension_addition_group<E>(
+        &mut self,
+        value: Option<&E>,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        E: crate::Encode + crate::types::Constructed,
+    {
+        match value {
+            Some(v) => v.encode(self),
+            None => self.encode_none::<E>(),
+        }
+    }
+
+    fn codec(&self) -> crate::Codec {
+        crate::Codec::Xer
+    }
+}
@@ -0,0 +1,207 @@
+//! A minimal owned XML element tree used as the intermediate representation
+//! for XER, in the same way JER uses `jzon::JsonValue`.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{de::Limits, error::DecodeError};
+
+/// An XML element, XER only ever uses elements containing either text or
+/// other elements, so mixed content is flattened into `text` and `children`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Element {
+    pub name: String,
+    pub text: String,
+    pub children: Vec<Element>,
+}
+
+impl Element {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            text: String::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_text(name: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            text: text.into(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_children(name: impl Into<String>, children: Vec<Element>) -> Self {
+        Self {
+            name: name.into(),
+            text: String::new(),
+            children,
+        }
+    }
+
+    /// Parses the root element of an XML document, failing if elements are
+    /// nested deeper than `limits` allow.
+    pub fn parse(input: &str, limits: &Limits) -> Result<Self, DecodeError> {
+        let parse_error = |msg: String| DecodeError::parser_fail(msg, crate::Codec::Xer);
+        let mut stack: Vec<Element> = Vec::new();
+        let mut root = None;
+
+        for token in xmlparser::Tokenizer::from(input) {
+            let token = token.map_err(|e| parse_error(alloc::format!("Error parsing XER {e}")))?;
+            match token {
+                xmlparser::Token::ElementStart { local, .. } => {
+                    if root.is_some() {
+                        return Err(parse_error("Multiple root elements".into()));
+                    }
+                    limits.check_depth(stack.len() + 1, crate::Codec::Xer)?;
+                    stack.push(Element::new(local.as_str()));
+                }
+                xmlparser::Token::ElementEnd { end, .. } => {
+                    if let xmlparser::ElementEnd::Close(_, local) = end {
+                        if stack.last().map(|e| &*e.name) != Some(local.as_str()) {
+                            return Err(parse_error(alloc::format!(
+                                "Unexpected closing tag </{}>",
+                                local.as_str()
+                            )));
+                        }
+                    }
+                    if !matches!(end, xmlparser::ElementEnd::Open) {
+                        let element = stack
+                            .pop()
+                            .ok_or_else(|| parse_error("Unbalanced closing tag".into()))?;
+                        match stack.last_mut() {
+                            Some(parent) => parent.children.push(element),
+                            None => root = Some(element),
+                        }
+                    }
+                }
+                xmlparser::Token::Text { text } => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&unescape(text.as_str())?);
+                    }
+                }
+                xmlparser::Token::Cdata { text, .. } => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(text.as_str());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        root.ok_or_else(|| parse_error("No root element found".into()))
+    }
+
+    /// Whether the element is a leaf, containing no other elements.
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Whether the element has no content at all.
+    fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.children.is_empty()
+    }
+
+    /// Writes the element as XML. When `indent` is `Some`, elements
+    /// containing more than a single leaf or a list of empty elements are
+    /// split over multiple lines.
+    pub fn write(&self, output: &mut String, indent: Option<usize>) {
+        output.push('<');
+        output.push_str(&self.name);
+
+        if self.is_empty() {
+            output.push_str("/>");
+            return;
+        }
+
+        output.push('>');
+        escape(&self.text, output);
+
+        if !self.children.is_empty() {
+            let inline = matches!(&*self.children, [child] if child.is_leaf())
+                || self.children.iter().all(Element::is_empty);
+            let indent = indent.filter(|_| !inline);
+            let child_indent = indent.map(|indent| indent + 1);
+            for child in &self.children {
+                if let Some(indent) = child_indent {
+                    push_newline(output, indent);
+                }
+                child.write(output, child_indent);
+            }
+            if let Some(indent) = indent {
+                push_newline(output, indent);
+            }
+        }
+
+        output.push_str("</");
+        output.push_str(&self.name);
+        output.push('>');
+    }
+}
+
+/// Drops the children of deeply nested elements one at a time, rather than
+/// recursively.
+impl Drop for Element {
+    fn drop(&mut self) {
+        let mut descendants = core::mem::take(&mut self.children);
+        while let Some(mut element) = descendants.pop() {
+            descendants.append(&mut element.children);
+        }
+    }
+}
+
+fn push_newline(output: &mut String, indent: usize) {
+    output.push('\n');
+    for _ in 0..indent {
+        output.push_str("  ");
+    }
+}
+
+fn escape(text: &str, output: &mut String) {
+    for ch in text.chars() {
+        match ch {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            ch => output.push(ch),
+        }
+    }
+}
+
+fn unescape(text: &str) -> Result<String, DecodeError> {
+    let invalid_reference = |reference: &str| {
+        DecodeError::parser_fail(
+            alloc::format!("Invalid XML reference: &{reference};"),
+            crate::Codec::Xer,
+        )
+    };
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(';')
+            .ok_or_else(|| invalid_reference(&rest[start + 1..]))?;
+        let reference = &rest[start + 1..start + end];
+        let ch = match reference {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => reference
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| reference.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32)
+                .ok_or_else(|| invalid_reference(reference))?,
+        };
+        output.push(ch);
+        rest = &rest[start + end + 1..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
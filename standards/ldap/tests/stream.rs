@@ -0,0 +1,79 @@
+use rasn::{ber, prelude::*};
+use rasn_ldap::*;
+
+fn messages() -> Vec<LdapMessage> {
+    vec![
+        LdapMessage::new(
+            1,
+            ProtocolOp::BindRequest(BindRequest::new(
+                3,
+                OctetString::from_static(b"cn=admin,dc=example,dc=com"),
+                AuthenticationChoice::Simple(OctetString::from_static(b"secret")),
+            )),
+        ),
+        LdapMessage::new(
+            1,
+            ProtocolOp::BindResponse(BindResponse::new(
+                ResultCode::Success,
+                OctetString::new(),
+                OctetString::new(),
+                None,
+                None,
+            )),
+        ),
+        LdapMessage::new(2, ProtocolOp::UnbindRequest(UnbindRequest)),
+    ]
+}
+
+fn encoded_stream() -> Vec<u8> {
+    messages()
+        .iter()
+        .flat_map(|message| ber::encode(message).unwrap())
+        .collect()
+}
+
+#[test]
+fn decode_with_remainder() {
+    let stream = encoded_stream();
+
+    let (first, remainder) = ber::decode_with_remainder::<LdapMessage>(&stream).unwrap();
+    assert_eq!(first, messages()[0]);
+    assert_eq!(remainder, &stream[ber::encode(&first).unwrap().len()..]);
+
+    let (second, remainder) = ber::decode_with_remainder::<LdapMessage>(remainder).unwrap();
+    let (third, remainder) = ber::decode_with_remainder::<LdapMessage>(remainder).unwrap();
+    assert_eq!(vec![first, second, third], messages());
+    assert!(remainder.is_empty());
+}
+
+#[test]
+fn decode_iter() {
+    let stream = encoded_stream();
+    let decoded = ber::decode_iter::<LdapMessage>(&stream)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(decoded, messages());
+
+    // A truncated message ends iteration with an error.
+    let mut iter = ber::decode_iter::<LdapMessage>(&stream[..stream.len() - 1]);
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_ok());
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn stream_decoder() {
+    let mut decoder = ber::stream::StreamDecoder::ber();
+    let mut decoded = Vec::new();
+
+    for chunk in encoded_stream().chunks(7) {
+        decoder.push(chunk);
+        while let Some(message) = decoder.decode::<LdapMessage>().unwrap() {
+            decoded.push(message);
+        }
+    }
+
+    assert_eq!(decoded, messages());
+    assert!(decoder.buffered().is_empty());
+}
@@ -0,0 +1,186 @@
+use rasn::{prelude::*, Codec};
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+#[rasn(automatic_tags)]
+struct Payload {
+    #[rasn(value("0..=9"))]
+    id: u8,
+    name: Utf8String,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+#[rasn(automatic_tags)]
+struct Message {
+    contents: Containing<Payload>,
+    der_contents: Containing<Payload, encoded_by::Der>,
+    bits: Containing<Payload, encoded_by::Outer, BitString>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+struct Attributed {
+    #[rasn(tag(0), containing)]
+    contents: Payload,
+    #[rasn(tag(1), containing(encoded_by = "der"))]
+    der_contents: Option<Payload>,
+    #[rasn(tag(2), containing(bit_string))]
+    bits: Option<Payload>,
+    #[rasn(tag(explicit(context, 9)), containing(encoded_by = "ber"))]
+    explicit: Payload,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Wrapped {
+    #[rasn(tag(0))]
+    contents: Containing<Payload>,
+    #[rasn(tag(1))]
+    der_contents: Option<Containing<Payload, encoded_by::Der>>,
+    #[rasn(tag(2))]
+    bits: Option<Containing<Payload, encoded_by::Outer, BitString>>,
+    #[rasn(tag(explicit(context, 9)))]
+    explicit: Containing<Payload, encoded_by::Ber>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(automatic_tags)]
+struct Relay {
+    contents: LazyContaining<Payload>,
+}
+
+fn payload(id: u8) -> Payload {
+    Payload {
+        id,
+        name: "nas".into(),
+    }
+}
+
+#[test]
+fn round_trips() {
+    let message = Message {
+        contents: payload(1).into(),
+        der_contents: payload(2).into(),
+        bits: payload(3).into(),
+    };
+
+    for codec in [Codec::Ber, Codec::Der, Codec::Uper, Codec::Aper, Codec::Oer] {
+        let encoded = codec.encode_to_binary(&message).unwrap();
+        assert_eq!(
+            message,
+            codec.decode_from_binary(&encoded).unwrap(),
+            "{codec}"
+        );
+    }
+}
+
+#[test]
+fn contents_use_the_named_encoding() {
+    let contents: Containing<Payload, encoded_by::Der> = payload(2).into();
+    let der = rasn::der::encode(&payload(2)).unwrap();
+
+    assert_eq!(
+        rasn::uper::encode(&OctetString::from(der)).unwrap(),
+        rasn::uper::encode(&contents).unwrap(),
+    );
+
+    let contents: Containing<Payload> = payload(2).into();
+    let uper = rasn::uper::encode(&payload(2)).unwrap();
+
+    assert_eq!(
+        rasn::uper::encode(&OctetString::from(uper)).unwrap(),
+        rasn::uper::encode(&contents).unwrap(),
+    );
+}
+
+#[test]
+fn attribute_matches_wrapper() {
+    let attributed = Attributed {
+        contents: payload(1),
+        der_contents: Some(payload(2)),
+        bits: None,
+        explicit: payload(4),
+    };
+    let wrapped = Wrapped {
+        contents: payload(1).into(),
+        der_contents: Some(payload(2).into()),
+        bits: None,
+        explicit: payload(4).into(),
+    };
+
+    for codec in [Codec::Ber, Codec::Uper, Codec::Oer] {
+        let encoded = codec.encode_to_binary(&attributed).unwrap();
+        assert_eq!(
+            codec.encode_to_binary(&wrapped).unwrap(),
+            encoded,
+            "{codec}"
+        );
+        assert_eq!(
+            attributed,
+            codec.decode_from_binary(&encoded).unwrap(),
+            "{codec}"
+        );
+    }
+
+    let attributed = Attributed {
+        der_contents: None,
+        bits: Some(payload(3)),
+        ..attributed
+    };
+    let encoded = rasn::uper::encode(&attributed).unwrap();
+    assert_eq!(attributed, rasn::uper::decode(&encoded).unwrap());
+}
+
+#[test]
+fn lazy_contents_are_passed_through() {
+    let contents = rasn::uper::encode(&payload(1)).unwrap();
+    let relay = Relay {
+        contents: LazyContaining::from_contents(Codec::Uper, contents.clone()),
+    };
+
+    let encoded = rasn::uper::encode(&relay).unwrap();
+    let decoded: Relay = rasn::uper::decode(&encoded).unwrap();
+    assert_eq!(contents, decoded.contents.as_bytes());
+    assert_eq!(payload(1), decoded.contents.decode().unwrap());
+    assert_eq!(encoded, rasn::uper::encode(&decoded).unwrap());
+
+    let eager = Message {
+        contents: payload(1).into(),
+        der_contents: payload(2).into(),
+        bits: payload(3).into(),
+    };
+    let encoded = rasn::uper::encode(&eager.contents).unwrap();
+    let lazy: LazyContaining<Payload> = rasn::uper::decode(&encoded).unwrap();
+    assert_eq!(Codec::Uper, lazy.codec());
+    assert_eq!(payload(1), lazy.decode().unwrap());
+}
+
+#[test]
+fn lazy_contents_are_transcoded() {
+    let relay = Relay {
+        contents: LazyContaining::encode(&payload(1), Codec::Uper).unwrap(),
+    };
+
+    let encoded = rasn::ber::encode(&relay).unwrap();
+    let decoded: Relay = rasn::ber::decode(&encoded).unwrap();
+    assert_eq!(Codec::Ber, decoded.contents.codec());
+    assert_eq!(
+        rasn::ber::encode(&payload(1)).unwrap(),
+        decoded.contents.as_bytes()
+    );
+    assert_eq!(payload(1), decoded.contents.decode().unwrap());
+}
+
+#[test]
+fn contained_values_are_validated() {
+    let message = Message {
+        contents: Payload {
+            id: 10,
+            name: "nas".into(),
+        }
+        .into(),
+        der_contents: payload(2).into(),
+        bits: payload(3).into(),
+    };
+
+    let error = message.validate().unwrap_err();
+    assert_eq!(1, error.violations.len());
+    assert_eq!("contents.id", error.violations[0].path.to_string());
+}
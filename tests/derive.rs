@@ -209,3 +209,49 @@ fn enum_with_encoder_tag_name_variants() {
         },
     }
 }
+
+#[test]
+fn borrowed_fields() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(delegate)]
+    struct Name<'a>(&'a str);
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Inner<'a> {
+        name: Name<'a>,
+        #[rasn(tag(explicit(0)))]
+        value: AnyRef<'a>,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    struct Message<'a> {
+        id: u32,
+        payload: &'a [u8],
+        #[rasn(tag(1))]
+        comment: Option<&'a str>,
+        #[rasn(tag(2))]
+        extra: Option<&'a [u8]>,
+        inner: Inner<'a>,
+    }
+
+    let value = ber::encode(&true).unwrap();
+    let message = Message {
+        id: 7,
+        payload: &[1, 2, 3],
+        comment: Some("hello"),
+        extra: None,
+        inner: Inner {
+            name: Name("inner"),
+            value: AnyRef::new(&value),
+        },
+    };
+
+    let encoded = ber::encode(&message).unwrap();
+    let decoded: Message = ber::decode_borrowed(&encoded).unwrap();
+    assert_eq!(message, decoded);
+    assert!(encoded.as_ptr_range().contains(&decoded.payload.as_ptr()));
+    assert_eq!(
+        decoded,
+        der::decode_borrowed(&der::encode(&message).unwrap()).unwrap()
+    );
+}
@@ -0,0 +1,229 @@
+use rasn::{error::DecodeErrorKind, prelude::*, Codec};
+
+const BASIC_CONSTRAINTS: &Oid = Oid::const_new(&[2, 5, 29, 19]);
+const SUBJECT_KEY_IDENTIFIER: &Oid = Oid::const_new(&[2, 5, 29, 14]);
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+pub struct BasicConstraints {
+    #[rasn(default)]
+    ca: bool,
+    path_len_constraint: Option<Integer>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExtensionValue {
+    BasicConstraints(BasicConstraints),
+    SubjectKeyIdentifier(OctetString),
+    Unknown(Any),
+}
+
+impl InformationObjectSet for ExtensionValue {
+    type Id = ObjectIdentifier;
+
+    fn encode_value(&self, codec: Codec) -> Result<Vec<u8>, rasn::error::EncodeError> {
+        match self {
+            Self::BasicConstraints(value) => codec.encode_to_binary(value),
+            Self::SubjectKeyIdentifier(value) => codec.encode_to_binary(value),
+            Self::Unknown(value) => Ok(value.as_bytes().to_vec()),
+        }
+    }
+
+    fn decode_value(
+        id: &ObjectIdentifier,
+        codec: Codec,
+        input: &[u8],
+    ) -> Result<Self, rasn::error::DecodeError> {
+        if **id == *BASIC_CONSTRAINTS {
+            codec.decode_from_binary(input).map(Self::BasicConstraints)
+        } else if **id == *SUBJECT_KEY_IDENTIFIER {
+            codec
+                .decode_from_binary(input)
+                .map(Self::SubjectKeyIdentifier)
+        } else {
+            Ok(Self::Unknown(Any::new(input.to_vec())))
+        }
+    }
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+pub struct Extension {
+    extn_id: ObjectIdentifier,
+    #[rasn(default)]
+    critical: bool,
+    #[rasn(identified_by = "extn_id")]
+    extn_value: ExtensionValue,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Message {
+    Ping,
+    Text(Utf8String),
+}
+
+impl InformationObjectSet for Message {
+    type Id = u8;
+
+    fn encode_value(&self, codec: Codec) -> Result<Vec<u8>, rasn::error::EncodeError> {
+        match self {
+            Self::Ping => codec.encode_to_binary(&()),
+            Self::Text(value) => codec.encode_to_binary(value),
+        }
+    }
+
+    fn decode_value(id: &u8, codec: Codec, input: &[u8]) -> Result<Self, rasn::error::DecodeError> {
+        match id {
+            0 => codec.decode_from_binary::<()>(input).map(|_| Self::Ping),
+            1 => codec.decode_from_binary(input).map(Self::Text),
+            _ => Err(rasn::error::DecodeError::unknown_information_object(
+                id, codec,
+            )),
+        }
+    }
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+pub struct Envelope {
+    kind: u8,
+    #[rasn(identified_by = "kind")]
+    message: Option<Message>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(automatic_tags)]
+pub struct TaggedEnvelope {
+    kind: u8,
+    #[rasn(identified_by = "kind")]
+    message: Message,
+    trailer: Option<bool>,
+}
+
+macro_rules! round_trip {
+    ($codec:ident, $value:expr) => {{
+        let value = $value;
+        let encoded = rasn::$codec::encode(&value).unwrap();
+        assert_eq!(value, rasn::$codec::decode(&encoded).unwrap());
+    }};
+}
+
+fn extensions() -> Vec<Extension> {
+    vec![
+        Extension {
+            extn_id: BASIC_CONSTRAINTS.to_owned(),
+            critical: true,
+            extn_value: ExtensionValue::BasicConstraints(BasicConstraints {
+                ca: true,
+                path_len_constraint: Some(0.into()),
+            }),
+        },
+        Extension {
+            extn_id: SUBJECT_KEY_IDENTIFIER.to_owned(),
+            critical: false,
+            extn_value: ExtensionValue::SubjectKeyIdentifier(OctetString::from_static(&[1, 2, 3])),
+        },
+    ]
+}
+
+#[test]
+fn object_identifier_set() {
+    for extension in extensions() {
+        round_trip!(ber, extension.clone());
+        round_trip!(der, extension.clone());
+        round_trip!(uper, extension.clone());
+        round_trip!(aper, extension.clone());
+        round_trip!(oer, extension.clone());
+    }
+}
+
+#[test]
+fn open_type_contains_complete_encoding() {
+    let extension = extensions().remove(1);
+    let encoded = rasn::der::encode(&extension).unwrap();
+
+    // SEQUENCE { OID 2.5.29.14, OCTET STRING '010203'H }, with the open type
+    // being the complete encoding of the OCTET STRING.
+    assert_eq!(
+        encoded,
+        [0x30, 0x0A, 0x06, 0x03, 0x55, 0x1D, 0x0E, 0x04, 0x03, 0x01, 0x02, 0x03]
+    );
+}
+
+#[test]
+fn unknown_object_is_kept_by_set() {
+    let encoded = rasn::der::encode(&Extension {
+        extn_id: Oid::const_new(&[1, 2, 3]).to_owned(),
+        critical: false,
+        extn_value: ExtensionValue::Unknown(Any::new(vec![0x05, 0x00])),
+    })
+    .unwrap();
+
+    let extension: Extension = rasn::der::decode(&encoded).unwrap();
+    assert_eq!(
+        extension.extn_value,
+        ExtensionValue::Unknown(Any::new(vec![0x05, 0x00]))
+    );
+}
+
+#[test]
+fn unknown_object_is_rejected_by_set() {
+    // Encoding doesn't check the identifier, so this encodes a value under an
+    // identifier that isn't in the set.
+    let encoded = rasn::uper::encode(&Envelope {
+        kind: 2,
+        message: Some(Message::Text("hi".into())),
+    })
+    .unwrap();
+
+    let error = rasn::uper::decode::<Envelope>(&encoded).unwrap_err();
+    assert!(matches!(
+        &*error.kind,
+        DecodeErrorKind::FieldError { nested, .. }
+            if matches!(&*nested.kind, DecodeErrorKind::UnknownInformationObject { .. })
+    ));
+}
+
+#[test]
+fn integer_set() {
+    for message in [None, Some(Message::Ping), Some(Message::Text("hi".into()))] {
+        let envelope = Envelope {
+            kind: matches!(message, Some(Message::Text(_))).into(),
+            message,
+        };
+        round_trip!(ber, envelope.clone());
+        round_trip!(uper, envelope.clone());
+        round_trip!(aper, envelope.clone());
+        round_trip!(oer, envelope.clone());
+    }
+}
+
+#[test]
+fn per_empty_open_type_is_one_zero_octet() {
+    let envelope = Envelope {
+        kind: 0,
+        message: Some(Message::Ping),
+    };
+
+    // Presence bit and `kind`, followed by the length and contents of the open
+    // type, which replace the empty encoding of NULL with a single zero octet.
+    let encoded = rasn::uper::encode(&envelope).unwrap();
+    assert_eq!(encoded, [0x80, 0x00, 0x80, 0x00]);
+    assert_eq!(envelope, rasn::uper::decode(&encoded).unwrap());
+}
+
+#[test]
+fn tagged_open_type_is_explicit() {
+    let envelope = TaggedEnvelope {
+        kind: 1,
+        message: Message::Text("hi".into()),
+        trailer: Some(true),
+    };
+
+    // [1] EXPLICIT wrapping the complete encoding of the UTF8String.
+    let encoded = rasn::ber::encode(&envelope).unwrap();
+    assert_eq!(
+        encoded,
+        [0x30, 0x0C, 0x80, 0x01, 0x01, 0xA1, 0x04, 0x0C, 0x02, b'h', b'i', 0x82, 0x01, 0xFF]
+    );
+    assert_eq!(envelope, rasn::ber::decode(&encoded).unwrap());
+    round_trip!(uper, envelope.clone());
+    round_trip!(oer, envelope);
+}
@@ -0,0 +1,181 @@
+//! Parameterized types, modelled on the S1AP protocol IE containers:
+//!
+//! ```asn1
+//! ProtocolIE-Container {S1AP-PROTOCOL-IES : IEsSetParam} ::=
+//!     SEQUENCE (SIZE (0..maxProtocolIEs)) OF
+//!     ProtocolIE-Field {{IEsSetParam}}
+//!
+//! ProtocolIE-Field {S1AP-PROTOCOL-IES : IEsSetParam} ::= SEQUENCE {
+//!     id          S1AP-PROTOCOL-IES.&id           ({IEsSetParam}),
+//!     criticality S1AP-PROTOCOL-IES.&criticality  ({IEsSetParam}{@id}),
+//!     value       S1AP-PROTOCOL-IES.&Value        ({IEsSetParam}{@id})
+//! }
+//! ```
+use rasn::{prelude::*, Codec};
+
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+#[rasn(enumerated)]
+pub enum Criticality {
+    Reject,
+    Ignore,
+    Notify,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+pub struct ProtocolIeField<T: InformationObjectSet<Id = u16>> {
+    id: u16,
+    criticality: Criticality,
+    #[rasn(identified_by = "id")]
+    value: T,
+}
+
+// `T` is only used by the identified field of `ProtocolIeField`, so it isn't
+// bounded by the derived traits.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(delegate, size("0..=MAX"), bound = "")]
+pub struct ProtocolIeContainer<T: InformationObjectSet<Id = u16>, const MAX: usize>(
+    pub SequenceOf<ProtocolIeField<T>>,
+);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResetIes {
+    Cause(u8),
+    ResetType(Utf8String),
+}
+
+impl InformationObjectSet for ResetIes {
+    type Id = u16;
+
+    fn encode_value(&self, codec: Codec) -> Result<Vec<u8>, rasn::error::EncodeError> {
+        match self {
+            Self::Cause(value) => codec.encode_to_binary(value),
+            Self::ResetType(value) => codec.encode_to_binary(value),
+        }
+    }
+
+    fn decode_value(
+        id: &u16,
+        codec: Codec,
+        input: &[u8],
+    ) -> Result<Self, rasn::error::DecodeError> {
+        match id {
+            2 => codec.decode_from_binary(input).map(Self::Cause),
+            92 => codec.decode_from_binary(input).map(Self::ResetType),
+            _ => Err(rasn::error::DecodeError::unknown_information_object(
+                id, codec,
+            )),
+        }
+    }
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+pub struct Reset<const MAX: usize> {
+    protocol_ies: ProtocolIeContainer<ResetIes, MAX>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+pub struct Window<const MIN: usize, const MAX: usize> {
+    #[rasn(size("MIN..MAX"))]
+    items: SequenceOf<bool>,
+    #[rasn(value("MIN..=MAX"))]
+    position: Integer,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+pub struct Tree<T> {
+    value: T,
+    children: SequenceOf<Tree<T>>,
+}
+
+fn reset<const MAX: usize>(count: usize) -> Reset<MAX> {
+    let ies = [
+        ProtocolIeField {
+            id: 2,
+            criticality: Criticality::Ignore,
+            value: ResetIes::Cause(7),
+        },
+        ProtocolIeField {
+            id: 92,
+            criticality: Criticality::Reject,
+            value: ResetIes::ResetType("s1".into()),
+        },
+        ProtocolIeField {
+            id: 2,
+            criticality: Criticality::Notify,
+            value: ResetIes::Cause(0),
+        },
+    ];
+
+    Reset {
+        protocol_ies: ProtocolIeContainer(ies[..count].to_vec()),
+    }
+}
+
+#[test]
+fn parameterized_container() {
+    let reset = reset::<65535>(3);
+
+    let encoded = rasn::uper::encode(&reset).unwrap();
+    assert_eq!(reset, rasn::uper::decode(&encoded).unwrap());
+    let encoded = rasn::aper::encode(&reset).unwrap();
+    assert_eq!(reset, rasn::aper::decode(&encoded).unwrap());
+    let encoded = rasn::ber::encode(&reset).unwrap();
+    assert_eq!(reset, rasn::ber::decode(&encoded).unwrap());
+}
+
+#[test]
+fn size_parameter() {
+    let encoded = rasn::uper::encode(&reset::<2>(2)).unwrap();
+    assert_eq!(reset::<2>(2), rasn::uper::decode(&encoded).unwrap());
+    assert!(rasn::uper::encode(&reset::<2>(3)).is_err());
+
+    // The length of the container is encoded in as few bits as its bounds
+    // allow, here 2 bits for 0..=3 rather than 16 bits for 0..=65535.
+    let small = rasn::uper::encode(&reset::<3>(3)).unwrap();
+    let large = rasn::uper::encode(&reset::<65535>(3)).unwrap();
+    assert!(small.len() < large.len());
+}
+
+#[test]
+fn value_and_size_parameters() {
+    let window = Window::<1, 4> {
+        items: vec![true, false, true],
+        position: 4.into(),
+    };
+    let encoded = rasn::uper::encode(&window).unwrap();
+    assert_eq!(window, rasn::uper::decode(&encoded).unwrap());
+
+    // `MIN..MAX` excludes `MAX`, while `MIN..=MAX` includes it.
+    assert!(rasn::uper::encode(&Window::<1, 4> {
+        items: vec![true; 4],
+        position: 4.into(),
+    })
+    .is_err());
+
+    // The length of `items` in 2 bits for 1..=3, its single item, and then
+    // `position` in 2 bits for 1..=4.
+    let window = Window::<1, 4> {
+        items: vec![true],
+        position: 4.into(),
+    };
+    let encoded = rasn::uper::encode(&window).unwrap();
+    assert_eq!(encoded, [0b0011_1000]);
+    assert_eq!(window, rasn::uper::decode(&encoded).unwrap());
+}
+
+#[test]
+fn recursive_type_parameter() {
+    let tree = Tree {
+        value: true,
+        children: vec![Tree {
+            value: false,
+            children: vec![],
+        }],
+    };
+
+    let encoded = rasn::ber::encode(&tree).unwrap();
+    assert_eq!(tree, rasn::ber::decode(&encoded).unwrap());
+    let encoded = rasn::uper::encode(&tree).unwrap();
+    assert_eq!(tree, rasn::uper::decode(&encoded).unwrap());
+    assert!(tree.validate().is_ok());
+}
@@ -0,0 +1,198 @@
+use rasn::{
+    error::{EncodeErrorKind, PathSegment, ViolationKind},
+    prelude::*,
+    types::constraints::Bounded,
+};
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+#[rasn(automatic_tags)]
+struct Device {
+    #[rasn(size("1..=8"))]
+    name: Utf8String,
+    #[rasn(value("0..=100"))]
+    battery: u8,
+    #[rasn(size("0..=2"))]
+    sensors: SequenceOf<Sensor>,
+    status: Option<Status>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+#[rasn(automatic_tags)]
+struct Sensor {
+    #[rasn(from("0..=9"))]
+    serial: PrintableString,
+    #[rasn(value("-40..=85", extensible))]
+    temperature: Integer,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+#[rasn(choice, automatic_tags)]
+enum Status {
+    #[rasn(size("2"))]
+    Code(OctetString),
+    Message(Ia5String),
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+#[rasn(delegate, size("1..=3"))]
+struct Levels(SequenceOf<u8>);
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+#[rasn(delegate, size("1..=3", extensible))]
+struct ExtensibleLevels(SequenceOf<u8>);
+
+fn sensor(serial: &str, temperature: i32) -> Sensor {
+    Sensor {
+        serial: PrintableString::try_from(serial).unwrap(),
+        temperature: temperature.into(),
+    }
+}
+
+fn device() -> Device {
+    Device {
+        name: "probe".into(),
+        battery: 80,
+        sensors: vec![sensor("01", 21), sensor("02", -3)],
+        status: Some(Status::Code(OctetString::from_static(&[0, 1]))),
+    }
+}
+
+#[test]
+fn valid() {
+    assert_eq!(device().validate(), Ok(()));
+}
+
+#[test]
+fn nested_violations() {
+    let mut device = device();
+    device.name = "temperature probe".into();
+    device.sensors[1].serial = PrintableString::try_from("A2").unwrap();
+    device.status = Some(Status::Code(OctetString::from_static(&[0])));
+
+    let error = device.validate().unwrap_err();
+    let violations: Vec<_> = error
+        .violations
+        .iter()
+        .map(|violation| (violation.path.to_string(), violation.kind.clone()))
+        .collect();
+
+    assert_eq!(
+        violations,
+        [
+            (
+                "name".into(),
+                ViolationKind::Size {
+                    length: 17,
+                    expected: Bounded::new(1, 8),
+                }
+            ),
+            (
+                "sensors[1].serial".into(),
+                ViolationKind::PermittedAlphabet {
+                    character: u32::from('A'),
+                }
+            ),
+            (
+                "status.Code".into(),
+                ViolationKind::Size {
+                    length: 1,
+                    expected: Bounded::Single(2),
+                }
+            ),
+        ]
+    );
+    assert_eq!(
+        error.violations[1].path.segments(),
+        [
+            PathSegment::Field("sensors"),
+            PathSegment::Index(1),
+            PathSegment::Field("serial"),
+        ]
+    );
+}
+
+#[test]
+fn values() {
+    let mut device = device();
+    device.battery = 101;
+    device.sensors.push(sensor("03", 0));
+
+    let error = device.validate().unwrap_err();
+    assert_eq!(error.violations.len(), 2);
+    assert_eq!(error.violations[0].path.to_string(), "battery");
+    assert_eq!(
+        error.violations[0].kind,
+        ViolationKind::Value {
+            value: 101.into(),
+            expected: Bounded::new(0, 100),
+        }
+    );
+    assert_eq!(error.violations[1].path.to_string(), "sensors");
+}
+
+#[test]
+fn extensible() {
+    // Values outside of the root of an extensible constraint are permitted,
+    // as they may be from a later version of the specification.
+    let mut device = device();
+    device.sensors[0].temperature = 1000.into();
+    assert_eq!(device.validate(), Ok(()));
+    assert_eq!(ExtensibleLevels(vec![1; 4]).validate(), Ok(()));
+
+    assert_eq!(Levels(vec![1; 3]).validate(), Ok(()));
+    let error = Levels(vec![1; 4]).validate().unwrap_err();
+    assert!(error.violations[0].path.is_empty());
+    assert_eq!(
+        error.violations[0].kind,
+        ViolationKind::Size {
+            length: 4,
+            expected: Bounded::new(1, 3),
+        }
+    );
+}
+
+#[test]
+fn encoder_option() {
+    let mut device = device();
+    device.battery = 101;
+
+    // BER doesn't check constraints unless it's asked to.
+    assert!(rasn::ber::encode(&device).is_ok());
+    assert!(rasn::der::encode(&device).is_ok());
+    assert!(rasn::jer::encode(&device).is_ok());
+
+    let options = rasn::ber::enc::EncoderOptions::der().with_constraint_validation(true);
+    let mut encoder = rasn::ber::enc::Encoder::new(options);
+    let error = device.encode(&mut encoder).unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        EncodeErrorKind::ValueConstraintNotSatisfied { .. }
+    ));
+
+    let options = rasn::jer::enc::EncoderOptions::default().with_constraint_validation(true);
+    let mut encoder = rasn::jer::enc::Encoder::with_options(options);
+    let error = device.encode(&mut encoder).unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        EncodeErrorKind::ValueConstraintNotSatisfied { .. }
+    ));
+
+    device.battery = 100;
+    device.sensors[0].serial = PrintableString::try_from("A1").unwrap();
+    let mut encoder = rasn::ber::enc::Encoder::new(
+        rasn::ber::enc::EncoderOptions::ber().with_constraint_validation(true),
+    );
+    let error = device.encode(&mut encoder).unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        EncodeErrorKind::AlphabetConstraintNotSatisfied { .. }
+    ));
+
+    // Valid values encode the same as without the option.
+    let device = self::device();
+    let mut encoder = rasn::ber::enc::Encoder::new(
+        rasn::ber::enc::EncoderOptions::der().with_constraint_validation(true),
+    );
+    device.encode(&mut encoder).unwrap();
+    assert_eq!(encoder.output(), rasn::der::encode(&device).unwrap());
+}